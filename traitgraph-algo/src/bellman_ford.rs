@@ -0,0 +1,161 @@
+use crate::dijkstra::{DijkstraWeight, DijkstraWeightedEdgeData};
+use crate::error::{AlgorithmError, AlgorithmResult, GraphAlgorithmError};
+use crate::path_tree::PathTree;
+use traitgraph::interface::StaticGraph;
+
+/// Computes the shortest paths from the given source to all nodes of the graph with the Bellman-Ford algorithm.
+///
+/// In contrast to [Dijkstra](crate::dijkstra::Dijkstra), edge weights may be negative.
+/// If a cycle of negative weight is reachable from the source, then shortest paths are not defined,
+/// and [AlgorithmError::NegativeCycle] is returned with the edges of such a cycle.
+/// Negative cycles that are not reachable from the source are ignored.
+///
+/// The algorithm relaxes all edges in rounds, and stops early once a round does not change any distance.
+/// Hence it runs in `O(n * m)` time in the worst case, and in `O(m)` time if the edges happen to be ordered along the shortest paths.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::bellman_ford::bellman_ford;
+/// use traitgraph_algo::error::AlgorithmError;
+///
+/// let mut graph = PetGraph::new();
+/// let n0 = graph.add_node(());
+/// let n1 = graph.add_node(());
+/// let n2 = graph.add_node(());
+/// graph.add_edge(n0, n1, 4);
+/// let e02 = graph.add_edge(n0, n2, 5);
+/// let e21 = graph.add_edge(n2, n1, -3);
+///
+/// let paths = bellman_ford(&graph, n0).unwrap();
+/// assert_eq!(paths.distance(n1), Some(&2));
+/// assert_eq!(paths.path_to(&graph, n1), Some(vec![e02, e21]));
+///
+/// let e12 = graph.add_edge(n1, n2, 2);
+/// assert_eq!(
+///     bellman_ford(&graph, n0).unwrap_err(),
+///     AlgorithmError::NegativeCycle { cycle: vec![e12, e21] }
+/// );
+/// ```
+pub fn bellman_ford<
+    WeightType: DijkstraWeight,
+    EdgeData: DijkstraWeightedEdgeData<WeightType>,
+    Graph: StaticGraph<EdgeData = EdgeData>,
+>(
+    graph: &Graph,
+    source: Graph::NodeIndex,
+) -> AlgorithmResult<PathTree<Graph, WeightType>, Graph> {
+    let mut paths = PathTree::new(graph, source, WeightType::zero());
+
+    // Without negative cycles, all shortest paths have less than `node_count` edges,
+    // so there is a change in round `node_count` only if there is a negative cycle.
+    let mut last_changed_node = None;
+    for _ in 0..graph.node_count() {
+        last_changed_node = None;
+        for edge in graph.edge_indices() {
+            let endpoints = graph.edge_endpoints(edge);
+            let Some(from_distance) = paths.distance(endpoints.from_node) else {
+                continue;
+            };
+            let new_distance = from_distance.clone() + graph.edge_data(edge).weight();
+            if paths
+                .distance(endpoints.to_node)
+                .map_or(true, |to_distance| new_distance < *to_distance)
+            {
+                paths.set(endpoints.to_node, new_distance, edge);
+                last_changed_node = Some(endpoints.to_node);
+            }
+        }
+
+        if last_changed_node.is_none() {
+            break;
+        }
+    }
+
+    match last_changed_node {
+        Some(node) => Err(negative_cycle(graph, &paths, node)),
+        None => Ok(paths),
+    }
+}
+
+/// Returns the negative cycle that is reached by following the back pointers from the given node,
+/// which must have been changed in the last round of the Bellman-Ford algorithm.
+fn negative_cycle<Graph: StaticGraph, WeightType>(
+    graph: &Graph,
+    paths: &PathTree<Graph, WeightType>,
+    node: Graph::NodeIndex,
+) -> GraphAlgorithmError<Graph> {
+    // After `node_count` steps, we are guaranteed to be on the cycle.
+    let mut cycle_node = node;
+    for _ in 0..graph.node_count() {
+        let edge = paths
+            .predecessor_edge(cycle_node)
+            .expect("changed nodes have a back pointer");
+        cycle_node = graph.edge_endpoints(edge).from_node;
+    }
+
+    let mut cycle = Vec::new();
+    let mut current_node = cycle_node;
+    loop {
+        let edge = paths
+            .predecessor_edge(current_node)
+            .expect("nodes on a negative cycle have a back pointer");
+        cycle.push(edge);
+        current_node = graph.edge_endpoints(edge).from_node;
+        if current_node == cycle_node {
+            break;
+        }
+    }
+    cycle.reverse();
+    AlgorithmError::NegativeCycle { cycle }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bellman_ford::bellman_ford;
+    use crate::error::AlgorithmError;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_negative_cycle() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+        // The edges are inserted in an order that requires several rounds.
+        graph.add_edge(n[3], n[4], 1i64);
+        graph.add_edge(n[2], n[3], -2);
+        graph.add_edge(n[1], n[2], 3);
+        graph.add_edge(n[0], n[1], 1);
+        let e40 = graph.add_edge(n[4], n[0], 5);
+
+        let paths = bellman_ford(&graph, n[1]).unwrap();
+        let distances: Vec<_> = n
+            .iter()
+            .map(|&node| paths.distance(node).copied())
+            .collect();
+        assert_eq!(distances, [Some(7), Some(0), Some(3), Some(1), Some(2)]);
+        assert_eq!(paths.path_to(&graph, n[1]), Some(vec![]));
+        assert_eq!(paths.path_to(&graph, n[0]).unwrap().last(), Some(&e40));
+
+        // A negative cycle that does not pass through the source.
+        let source = graph.add_node(());
+        graph.add_edge(source, n[4], 0);
+        let e21 = graph.add_edge(n[2], n[1], -4);
+        let Err(AlgorithmError::NegativeCycle { cycle }) = bellman_ford(&graph, source) else {
+            panic!("expected a negative cycle");
+        };
+        assert_eq!(cycle.len(), 2);
+        assert!(cycle.contains(&e21));
+        assert!(bellman_ford(&graph, n[0]).is_err());
+
+        // A negative cycle that is not reachable from the source.
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..3).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[1], n[2], -1i64);
+        graph.add_edge(n[2], n[1], -1);
+        let paths = bellman_ford(&graph, n[0]).unwrap();
+        assert_eq!(paths.distance(n[1]), None);
+    }
+}
@@ -43,7 +43,7 @@ where
 {
     let mut result = Vec::new();
     let mut nodes: Vec<_> = graph.node_indices().collect();
-    let mut visited = vec![false; graph.node_count()];
+    let mut visited = vec![false; graph.node_index_bound()];
     let mut bfs = PreOrderUndirectedBfs::new_without_start(graph);
 
     while let Some(start) = nodes.pop() {
@@ -207,13 +207,14 @@ pub fn naively_compute_strong_bridges<Graph: StaticGraph>(graph: &Graph) -> Vec<
 ///
 /// If the graph is empty, no SCCs are returned.
 /// Otherwise, an array is returned that maps each node to a root node representing its SCC.
-/// Node that if the node ids are not consecutive, this mapping is still returned as consecutive array.
+/// The array has length [node_index_bound](traitgraph::interface::ImmutableGraphContainer::node_index_bound),
+/// and indices that do not belong to a node are mapped to themselves.
 pub fn decompose_strongly_connected_components<Graph: StaticGraph>(
     graph: &Graph,
 ) -> Vec<Graph::NodeIndex> {
-    let mut result: Vec<_> = graph.node_indices().collect();
+    let mut result: Vec<_> = (0..graph.node_index_bound()).map(Into::into).collect();
     let mut nodes = LinkedList::new();
-    let mut visited = vec![false; graph.node_count()];
+    let mut visited = vec![false; graph.node_index_bound()];
     // 0 will be overridden with the first reset.
     let mut dfs = PostOrderForwardDfs::new_without_start(graph);
 
@@ -307,6 +308,7 @@ pub fn decompose_strongly_connected_components_non_consecutive<Graph: StaticGrap
 /// Extract the subgraphs of the given graph according to the given node_mapping.
 ///
 /// The node indices of the graph are assumed to match the indices of the vector given as node mapping.
+/// Entries of the node mapping at indices that do not belong to a node are ignored.
 /// The return value is a vector of graphs of which each is the induced subgraph of a set of nodes with the same mapped value.
 pub fn extract_subgraphs_from_node_mapping<Graph: Default + MutableGraphContainer + StaticGraph>(
     graph: &Graph,
@@ -317,10 +319,10 @@ where
     Graph::EdgeData: Clone,
 {
     // invert node mapping in linear time
-    let mut root_node_map = vec![usize::MAX; graph.node_count()];
+    let mut root_node_map = vec![usize::MAX; graph.node_index_bound()];
     let mut subgraph_node_indices = Vec::new();
-    for (node_index, root_node) in node_mapping.iter().enumerate() {
-        let node_index = Graph::NodeIndex::from(node_index);
+    for node_index in graph.node_indices() {
+        let root_node = node_mapping[node_index.as_usize()];
         let subgraph_index = root_node_map[root_node.as_usize()];
         if subgraph_index == usize::MAX {
             root_node_map[root_node.as_usize()] = subgraph_node_indices.len();
@@ -332,7 +334,7 @@ where
 
     // extract subgraphs
     let mut id_map = Vec::new();
-    let mut extracted_nodes = vec![Graph::OptionalNodeIndex::new_none(); graph.node_count()];
+    let mut extracted_nodes = vec![Graph::OptionalNodeIndex::new_none(); graph.node_index_bound()];
 
     subgraph_node_indices
         .iter()
@@ -381,6 +383,7 @@ mod tests {
     };
     use std::fmt::Debug;
     use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::implementation::stable_graph::StableGraph;
     use traitgraph::index::GraphIndex;
     use traitgraph::interface::{GraphBase, ImmutableGraphContainer, MutableGraphContainer};
 
@@ -1281,4 +1284,30 @@ mod tests {
         let expected = vec![e1, e2, e6, e10, e11, e12, e13, e14, e15, e16, e17, e18];
         debug_assert_eq!(expected, naively_compute_strong_bridges(&graph));
     }
+
+    #[test]
+    fn test_strongly_connected_components_with_removed_nodes() {
+        let mut graph = StableGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[2], 10);
+        graph.add_edge(n[2], n[0], 11);
+        graph.add_edge(n[1], n[4], 12);
+        graph.add_edge(n[4], n[3], 13);
+        graph.add_edge(n[3], n[4], 14);
+        graph.remove_node(n[1]);
+
+        let components = decompose_strongly_connected_components(&graph);
+        debug_assert_eq!(components.len(), 5);
+        debug_assert_eq!(components[0], components[2]);
+        debug_assert_eq!(components[3], components[4]);
+        debug_assert_ne!(components[0], components[3]);
+
+        let mut extracted = extract_subgraphs_from_node_mapping(&graph, &components);
+        extracted.sort_by_key(|subgraph| *subgraph.node_data(0.into()));
+        debug_assert_eq!(extracted.len(), 2);
+        debug_assert_node_data(&extracted[0], &mut [0, 2]);
+        debug_assert_edge_data(&extracted[0], &mut [10, 11]);
+        debug_assert_node_data(&extracted[1], &mut [3, 4]);
+        debug_assert_edge_data(&extracted[1], &mut [13, 14]);
+    }
 }
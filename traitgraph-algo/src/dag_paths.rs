@@ -0,0 +1,172 @@
+use crate::dijkstra::{DijkstraWeight, DijkstraWeightedEdgeData};
+use crate::error::AlgorithmResult;
+use crate::path_tree::PathTree;
+use crate::topological_sort::topological_sort;
+use traitgraph::interface::StaticGraph;
+
+/// Computes the shortest paths from the given source to all nodes of the given directed acyclic graph.
+///
+/// The nodes are processed once in topological order, such that the algorithm runs in linear time.
+/// Edge weights may be negative.
+///
+/// Returns [AlgorithmError::CyclicGraph](crate::error::AlgorithmError::CyclicGraph) if the graph is not acyclic.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::dag_paths::dag_shortest_paths;
+///
+/// let mut graph = PetGraph::new();
+/// let n0 = graph.add_node(());
+/// let n1 = graph.add_node(());
+/// let n2 = graph.add_node(());
+/// graph.add_edge(n0, n2, 1);
+/// let e01 = graph.add_edge(n0, n1, 2);
+/// let e12 = graph.add_edge(n1, n2, -3);
+///
+/// let paths = dag_shortest_paths(&graph, n0).unwrap();
+/// assert_eq!(paths.distance(n2), Some(&-1));
+/// assert_eq!(paths.path_to(&graph, n2), Some(vec![e01, e12]));
+/// ```
+pub fn dag_shortest_paths<
+    WeightType: DijkstraWeight,
+    EdgeData: DijkstraWeightedEdgeData<WeightType>,
+    Graph: StaticGraph<EdgeData = EdgeData>,
+>(
+    graph: &Graph,
+    source: Graph::NodeIndex,
+) -> AlgorithmResult<PathTree<Graph, WeightType>, Graph> {
+    dag_paths(graph, source, |new_distance, distance| {
+        new_distance < distance
+    })
+}
+
+/// Computes the longest paths from the given source to all nodes of the given directed acyclic graph.
+///
+/// This allows for example to compute the critical path of a workflow, where the edges are weighted by the duration of the tasks.
+/// The nodes are processed once in topological order, such that the algorithm runs in linear time.
+/// Edge weights may be negative.
+///
+/// Returns [AlgorithmError::CyclicGraph](crate::error::AlgorithmError::CyclicGraph) if the graph is not acyclic.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::dag_paths::dag_longest_paths;
+///
+/// let mut graph = PetGraph::new();
+/// let n0 = graph.add_node(());
+/// let n1 = graph.add_node(());
+/// let n2 = graph.add_node(());
+/// let e02 = graph.add_edge(n0, n2, 5);
+/// graph.add_edge(n0, n1, 2);
+/// graph.add_edge(n1, n2, 2);
+///
+/// let paths = dag_longest_paths(&graph, n0).unwrap();
+/// assert_eq!(paths.distance(n2), Some(&5));
+/// assert_eq!(paths.path_to(&graph, n2), Some(vec![e02]));
+/// ```
+pub fn dag_longest_paths<
+    WeightType: DijkstraWeight,
+    EdgeData: DijkstraWeightedEdgeData<WeightType>,
+    Graph: StaticGraph<EdgeData = EdgeData>,
+>(
+    graph: &Graph,
+    source: Graph::NodeIndex,
+) -> AlgorithmResult<PathTree<Graph, WeightType>, Graph> {
+    dag_paths(graph, source, |new_distance, distance| {
+        new_distance > distance
+    })
+}
+
+/// Computes the optimal paths from the given source, where `is_better(new_distance, distance)` decides if a new distance replaces the current one.
+fn dag_paths<
+    WeightType: DijkstraWeight,
+    EdgeData: DijkstraWeightedEdgeData<WeightType>,
+    Graph: StaticGraph<EdgeData = EdgeData>,
+>(
+    graph: &Graph,
+    source: Graph::NodeIndex,
+    is_better: impl Fn(&WeightType, &WeightType) -> bool,
+) -> AlgorithmResult<PathTree<Graph, WeightType>, Graph> {
+    let order = topological_sort(graph)?;
+    let mut paths = PathTree::new(graph, source, WeightType::zero());
+
+    // Nodes before the source in topological order are not reachable from it.
+    let source_position = order
+        .iter()
+        .position(|&node| node == source)
+        .expect("the topological order contains all nodes");
+    for &node in &order[source_position..] {
+        let Some(distance) = paths.distance(node).cloned() else {
+            continue;
+        };
+
+        for neighbor in graph.out_neighbors(node) {
+            let new_distance = distance.clone() + graph.edge_data(neighbor.edge_id).weight();
+            if paths
+                .distance(neighbor.node_id)
+                .map_or(true, |neighbor_distance| {
+                    is_better(&new_distance, neighbor_distance)
+                })
+            {
+                paths.set(neighbor.node_id, new_distance, neighbor.edge_id);
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bellman_ford::bellman_ford;
+    use crate::dag_paths::{dag_longest_paths, dag_shortest_paths};
+    use crate::error::AlgorithmError;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_dag_paths() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+        for (from, to, weight) in [
+            (5, 0, 4i32),
+            (0, 1, 3),
+            (0, 2, -1),
+            (2, 1, 2),
+            (1, 3, -2),
+            (2, 3, 6),
+            (3, 4, 1),
+        ] {
+            graph.add_edge(n[from], n[to], weight);
+        }
+
+        let shortest = dag_shortest_paths(&graph, n[0]).unwrap();
+        assert_eq!(
+            shortest.distances(),
+            bellman_ford(&graph, n[0]).unwrap().distances()
+        );
+        assert_eq!(
+            shortest.distances(),
+            [Some(0), Some(1), Some(-1), Some(-1), Some(0), None]
+        );
+
+        let longest = dag_longest_paths(&graph, n[5]).unwrap();
+        assert_eq!(
+            longest.distances(),
+            [Some(4), Some(7), Some(3), Some(9), Some(10), Some(0)]
+        );
+        assert_eq!(longest.path_to(&graph, n[4]).unwrap().len(), 4);
+
+        graph.add_edge(n[4], n[2], 0);
+        assert!(matches!(
+            dag_longest_paths(&graph, n[0]),
+            Err(AlgorithmError::CyclicGraph { .. })
+        ));
+    }
+}
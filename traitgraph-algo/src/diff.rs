@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use traitgraph::interface::{Edge, GraphBase, ImmutableGraphContainer};
+
+/// The differences between two graphs `a` and `b`, as computed by [diff_graphs] or [diff_graphs_by_key].
+///
+/// Removed nodes and edges are only in `a`, added nodes and edges are only in `b`,
+/// and changed nodes and edges are matched between `a` and `b` but have different data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphDiff<GraphA: GraphBase, GraphB: GraphBase> {
+    /// The nodes of `a` that have no match in `b`.
+    pub removed_nodes: Vec<GraphA::NodeIndex>,
+    /// The nodes of `b` that have no match in `a`.
+    pub added_nodes: Vec<GraphB::NodeIndex>,
+    /// The matched nodes of `a` and `b` whose data differs.
+    pub changed_nodes: Vec<(GraphA::NodeIndex, GraphB::NodeIndex)>,
+    /// The edges of `a` that have no match in `b`.
+    pub removed_edges: Vec<GraphA::EdgeIndex>,
+    /// The edges of `b` that have no match in `a`.
+    pub added_edges: Vec<GraphB::EdgeIndex>,
+    /// The matched edges of `a` and `b` whose data differs.
+    pub changed_edges: Vec<(GraphA::EdgeIndex, GraphB::EdgeIndex)>,
+}
+
+impl<GraphA: GraphBase, GraphB: GraphBase> GraphDiff<GraphA, GraphB> {
+    /// Returns true if there are no differences between the graphs.
+    pub fn is_empty(&self) -> bool {
+        self.removed_nodes.is_empty()
+            && self.added_nodes.is_empty()
+            && self.changed_nodes.is_empty()
+            && self.removed_edges.is_empty()
+            && self.added_edges.is_empty()
+            && self.changed_edges.is_empty()
+    }
+}
+
+impl<GraphA: GraphBase, GraphB: GraphBase> Default for GraphDiff<GraphA, GraphB> {
+    fn default() -> Self {
+        Self {
+            removed_nodes: Vec::new(),
+            added_nodes: Vec::new(),
+            changed_nodes: Vec::new(),
+            removed_edges: Vec::new(),
+            added_edges: Vec::new(),
+            changed_edges: Vec::new(),
+        }
+    }
+}
+
+/// Computes the differences between the graphs `a` and `b`, matching nodes and edges by their indices.
+///
+/// Two nodes are matched if they have the same index.
+/// Two edges are matched if they have the same index and the same endpoints.
+pub fn diff_graphs<
+    GraphA: ImmutableGraphContainer,
+    GraphB: ImmutableGraphContainer<
+        NodeData = GraphA::NodeData,
+        EdgeData = GraphA::EdgeData,
+        NodeIndex = GraphA::NodeIndex,
+        EdgeIndex = GraphA::EdgeIndex,
+    >,
+>(
+    a: &GraphA,
+    b: &GraphB,
+) -> GraphDiff<GraphA, GraphB>
+where
+    GraphA::NodeData: PartialEq,
+    GraphA::EdgeData: PartialEq,
+{
+    let mut diff = GraphDiff::default();
+
+    for node in a.node_indices() {
+        if !b.contains_node_index(node) {
+            diff.removed_nodes.push(node);
+        } else if a.node_data(node) != b.node_data(node) {
+            diff.changed_nodes.push((node, node));
+        }
+    }
+    diff.added_nodes.extend(
+        b.node_indices()
+            .filter(|&node| !a.contains_node_index(node)),
+    );
+
+    let is_matched =
+        |edge| b.contains_edge_index(edge) && a.edge_endpoints(edge) == b.edge_endpoints(edge);
+    for edge in a.edge_indices() {
+        if !is_matched(edge) {
+            diff.removed_edges.push(edge);
+        } else if a.edge_data(edge) != b.edge_data(edge) {
+            diff.changed_edges.push((edge, edge));
+        }
+    }
+    diff.added_edges.extend(
+        b.edge_indices()
+            .filter(|&edge| !a.contains_edge_index(edge) || !is_matched(edge)),
+    );
+
+    diff
+}
+
+/// Computes the differences between the graphs `a` and `b`, matching nodes and edges by a key computed from the node data.
+///
+/// Two nodes are matched if they have the same key.
+/// Two edges are matched if the keys of their tails and heads are the same.
+/// If multiple nodes or edges have the same key, then they are matched in the order of their indices,
+/// where parallel edges with equal data are matched with priority.
+pub fn diff_graphs_by_key<
+    GraphA: ImmutableGraphContainer,
+    GraphB: ImmutableGraphContainer<NodeData = GraphA::NodeData, EdgeData = GraphA::EdgeData>,
+    Key: Hash + Eq,
+>(
+    a: &GraphA,
+    b: &GraphB,
+    node_key: impl Fn(&GraphA::NodeData) -> Key,
+) -> GraphDiff<GraphA, GraphB>
+where
+    GraphA::NodeData: PartialEq,
+    GraphA::EdgeData: PartialEq,
+{
+    let mut diff = GraphDiff::default();
+
+    let mut unmatched_nodes: HashMap<_, Vec<_>> = HashMap::new();
+    for node in b.node_indices() {
+        unmatched_nodes
+            .entry(node_key(b.node_data(node)))
+            .or_default()
+            .push(node);
+    }
+    // Pop nodes in the order of their indices.
+    unmatched_nodes
+        .values_mut()
+        .for_each(|nodes| nodes.reverse());
+    for node in a.node_indices() {
+        match unmatched_nodes
+            .get_mut(&node_key(a.node_data(node)))
+            .and_then(Vec::pop)
+        {
+            Some(b_node) => {
+                if a.node_data(node) != b.node_data(b_node) {
+                    diff.changed_nodes.push((node, b_node));
+                }
+            }
+            None => diff.removed_nodes.push(node),
+        }
+    }
+    diff.added_nodes
+        .extend(unmatched_nodes.into_values().flatten());
+    diff.added_nodes.sort_unstable();
+
+    let edge_key =
+        |data_a: &GraphA::NodeData, data_b: &GraphA::NodeData| (node_key(data_a), node_key(data_b));
+    let mut unmatched_edges: HashMap<_, Vec<_>> = HashMap::new();
+    for edge in b.edge_indices() {
+        let Edge { from_node, to_node } = b.edge_endpoints(edge);
+        unmatched_edges
+            .entry(edge_key(b.node_data(from_node), b.node_data(to_node)))
+            .or_default()
+            .push(edge);
+    }
+    for edge in a.edge_indices() {
+        let Edge { from_node, to_node } = a.edge_endpoints(edge);
+        let candidates = unmatched_edges
+            .get_mut(&edge_key(a.node_data(from_node), a.node_data(to_node)))
+            .filter(|candidates| !candidates.is_empty());
+        if let Some(candidates) = candidates {
+            let edge_data = a.edge_data(edge);
+            if let Some(position) = candidates
+                .iter()
+                .position(|&b_edge| b.edge_data(b_edge) == edge_data)
+            {
+                candidates.remove(position);
+            } else {
+                diff.changed_edges.push((edge, candidates.remove(0)));
+            }
+        } else {
+            diff.removed_edges.push(edge);
+        }
+    }
+    diff.added_edges
+        .extend(unmatched_edges.into_values().flatten());
+    diff.added_edges.sort_unstable();
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diff::{diff_graphs, diff_graphs_by_key};
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_diff_by_index() {
+        let mut a = PetGraph::new();
+        let n: Vec<_> = (0..3).map(|i| a.add_node(i)).collect();
+        a.add_edge(n[0], n[1], 10);
+        a.add_edge(n[1], n[2], 11);
+        a.add_edge(n[2], n[0], 12);
+
+        let mut b = a.clone();
+        debug_assert!(diff_graphs(&a, &b).is_empty());
+
+        *b.node_data_mut(n[1]) = 5;
+        *b.edge_data_mut(2.into()) = 20;
+        b.remove_edge(0.into());
+        let n3 = b.add_node(3);
+        b.add_edge(n[2], n3, 13);
+
+        let diff = diff_graphs(&a, &b);
+        debug_assert_eq!(diff.removed_nodes, []);
+        debug_assert_eq!(diff.added_nodes, [n3]);
+        debug_assert_eq!(diff.changed_nodes, [(n[1], n[1])]);
+        // Removing edge 0 moved edge 2 to index 0.
+        debug_assert_eq!(diff.removed_edges, [0.into(), 2.into()]);
+        debug_assert_eq!(diff.added_edges, [0.into(), 2.into()]);
+        debug_assert_eq!(diff.changed_edges, []);
+    }
+
+    #[test]
+    fn test_diff_by_key() {
+        let mut a = PetGraph::new();
+        let a_x = a.add_node(("x", 0));
+        let a_y = a.add_node(("y", 0));
+        let a_z = a.add_node(("z", 0));
+        let a_xy = a.add_edge(a_x, a_y, 1);
+        let a_yz = a.add_edge(a_y, a_z, 2);
+        a.add_edge(a_z, a_x, 3);
+
+        let mut b = PetGraph::new();
+        let b_w = b.add_node(("w", 0));
+        let b_z = b.add_node(("z", 0));
+        let b_y = b.add_node(("y", 1));
+        let b_yz = b.add_edge(b_y, b_z, 5);
+        let b_zy = b.add_edge(b_z, b_y, 4);
+
+        let diff = diff_graphs_by_key(&a, &b, |(label, _)| *label);
+        debug_assert_eq!(diff.removed_nodes, [a_x]);
+        debug_assert_eq!(diff.added_nodes, [b_w]);
+        debug_assert_eq!(diff.changed_nodes, [(a_y, b_y)]);
+        debug_assert_eq!(diff.removed_edges, [a_xy, 2.into()]);
+        debug_assert_eq!(diff.added_edges, [b_zy]);
+        debug_assert_eq!(diff.changed_edges, [(a_yz, b_yz)]);
+    }
+}
@@ -0,0 +1,330 @@
+use crate::dijkstra::back_pointers::{
+    DijkstraBackPointerArray, DijkstraBackPointers, NoopDijkstraBackPointers,
+};
+use crate::dijkstra::epoch_array_dijkstra_node_weight_array::EpochNodeWeightArray;
+use crate::dijkstra::performance_counters::DijkstraPerformanceData;
+use crate::dijkstra::{
+    DijkstraExhaustiveness, DijkstraHeap, DijkstraStatus, DijkstraWeight, DijkstraWeightedEdgeData,
+    NodeWeightArray,
+};
+use std::collections::BinaryHeap;
+use std::marker::PhantomData;
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{GraphBase, StaticGraph};
+use traitgraph::walks::VecEdgeWalk;
+
+/// An A* implementation with the same set of common optimisations as the [DefaultDijkstra](super::DefaultDijkstra).
+pub type DefaultAStar<Graph, WeightType> = AStar<
+    Graph,
+    WeightType,
+    EpochNodeWeightArray<WeightType>,
+    BinaryHeap<std::cmp::Reverse<(WeightType, <Graph as GraphBase>::NodeIndex)>>,
+>;
+
+/// An A* implementation with the same set of common optimisations as the [DefaultDijkstra](super::DefaultDijkstra) that records back pointers,
+/// such that shortest paths can be reconstructed with [extract_path](AStar::extract_path).
+pub type DefaultPathAStar<Graph, WeightType> = AStar<
+    Graph,
+    WeightType,
+    EpochNodeWeightArray<WeightType>,
+    BinaryHeap<std::cmp::Reverse<(WeightType, <Graph as GraphBase>::NodeIndex)>>,
+    DijkstraBackPointerArray<Graph>,
+>;
+
+/// An estimate of the weight of a shortest path from a node to the target of an A* search.
+///
+/// For A* to compute shortest paths, the heuristic must be admissible,
+/// i.e. the estimate must never be larger than the weight of an actual shortest path to the target.
+/// In particular, the estimate of the target itself must be zero.
+/// The heuristic must be evaluated to the same value each time it is called for the same node during a search.
+pub trait Heuristic<Graph: GraphBase, WeightType> {
+    /// Returns a lower bound for the weight of a shortest path from the given node to the target.
+    fn estimate(&self, graph: &Graph, node: Graph::NodeIndex) -> WeightType;
+}
+
+impl<Graph: GraphBase, WeightType, Function: Fn(&Graph, Graph::NodeIndex) -> WeightType>
+    Heuristic<Graph, WeightType> for Function
+{
+    fn estimate(&self, graph: &Graph, node: Graph::NodeIndex) -> WeightType {
+        self(graph, node)
+    }
+}
+
+/// A heuristic that always estimates zero, which makes A* behave like Dijkstra's algorithm.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct ZeroHeuristic;
+
+impl<Graph: GraphBase, WeightType: DijkstraWeight> Heuristic<Graph, WeightType> for ZeroHeuristic {
+    fn estimate(&self, _graph: &Graph, _node: Graph::NodeIndex) -> WeightType {
+        WeightType::zero()
+    }
+}
+
+/// Data structure for the A* shortest path algorithm.
+///
+/// A* is a variant of Dijkstra's algorithm that searches for a single target,
+/// and that processes nodes in the order of their distance from the source plus the estimate of a [Heuristic] for their distance to the target.
+/// With a good heuristic, such as the straight-line distance in a road network, much fewer nodes are processed than with Dijkstra's algorithm.
+///
+/// The algorithm reuses the [NodeWeightArray] and [DijkstraHeap] abstractions of [Dijkstra](super::Dijkstra),
+/// where the heap is keyed by the sum of the distance from the source and the estimate.
+/// Like for Dijkstra, shortest paths can be reconstructed by setting `BackPointers` to [DijkstraBackPointerArray].
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+/// use traitgraph_algo::dijkstra::astar::DefaultPathAStar;
+/// use traitgraph_algo::dijkstra::performance_counters::NoopDijkstraPerformanceCounter;
+///
+/// // Nodes are positions on a line, edges are weighted by the distance of their endpoints.
+/// let mut graph = PetGraph::new();
+/// let n: Vec<_> = [0u32, 1, 3, 4].into_iter().map(|x| graph.add_node(x)).collect();
+/// let e01 = graph.add_edge(n[0], n[1], 1u32);
+/// let e13 = graph.add_edge(n[1], n[3], 3);
+/// graph.add_edge(n[0], n[2], 3);
+/// graph.add_edge(n[2], n[3], 2);
+///
+/// let target = n[3];
+/// let heuristic = |graph: &PetGraph<u32, u32>, node| {
+///     graph.node_data(target).abs_diff(*graph.node_data(node))
+/// };
+/// let mut astar = DefaultPathAStar::new(&graph);
+/// let (distance, _) = astar.shortest_path_len(
+///     &graph,
+///     n[0],
+///     target,
+///     &heuristic,
+///     u32::MAX,
+///     usize::MAX,
+///     usize::MAX,
+///     NoopDijkstraPerformanceCounter,
+/// );
+/// assert_eq!(distance, Some(4));
+/// assert_eq!(astar.extract_path(&graph, target), Some(vec![e01, e13]));
+/// ```
+pub struct AStar<
+    Graph: GraphBase,
+    WeightType: DijkstraWeight,
+    NodeWeights: NodeWeightArray<WeightType>,
+    Heap: DijkstraHeap<WeightType, Graph::NodeIndex>,
+    BackPointers: DijkstraBackPointers<Graph> = NoopDijkstraBackPointers,
+> {
+    heap: Heap,
+    back_pointers: BackPointers,
+    source: Option<Graph::NodeIndex>,
+    node_weights: NodeWeights,
+    graph: PhantomData<Graph>,
+    _weight_type_phantom: PhantomData<WeightType>,
+}
+
+impl<
+        WeightType: DijkstraWeight + Eq,
+        EdgeData: DijkstraWeightedEdgeData<WeightType>,
+        Graph: StaticGraph<EdgeData = EdgeData>,
+        NodeWeights: NodeWeightArray<WeightType>,
+        Heap: DijkstraHeap<WeightType, Graph::NodeIndex>,
+        BackPointers: DijkstraBackPointers<Graph>,
+    > AStar<Graph, WeightType, NodeWeights, Heap, BackPointers>
+{
+    /// Create the data structures for the given graph.
+    pub fn new(graph: &Graph) -> Self {
+        Self {
+            heap: Default::default(),
+            back_pointers: BackPointers::new(graph.node_index_bound()),
+            source: None,
+            node_weights: NodeWeights::new(graph.node_index_bound()),
+            graph: Default::default(),
+            _weight_type_phantom: Default::default(),
+        }
+    }
+
+    /// Compute the weight of a shortest path from source to target, with given maximum weight.
+    /// Returns the weight, or `None` if there is no path of weight at most `max_weight`, together with the status of the search.
+    /// If the search is not complete, then the returned weight is `None`.
+    ///
+    /// **max_node_weight_data_size:** the maximum number of nodes for which a weight can be stored before the search aborts.
+    #[allow(clippy::too_many_arguments)]
+    pub fn shortest_path_len<
+        SearchHeuristic: Heuristic<Graph, WeightType>,
+        DijkstraPerformance: DijkstraPerformanceData,
+    >(
+        &mut self,
+        graph: &Graph,
+        source: Graph::NodeIndex,
+        target: Graph::NodeIndex,
+        heuristic: &SearchHeuristic,
+        max_weight: WeightType,
+        max_node_weight_data_size: usize,
+        max_heap_data_size: usize,
+        mut performance_data: DijkstraPerformance,
+    ) -> (Option<WeightType>, DijkstraStatus<DijkstraPerformance>) {
+        self.heap.insert(heuristic.estimate(graph, source), source);
+        // The back pointers are kept after the search, such that paths can be extracted.
+        self.back_pointers.clear();
+        self.source = Some(source);
+        self.node_weights.set(source.as_usize(), WeightType::zero());
+        let mut result = None;
+        let mut exhaustiveness = DijkstraExhaustiveness::Complete;
+
+        while let Some((key, node_index)) = self.heap.remove_min() {
+            performance_data.add_iteration();
+            // Check if the node was already processed with a lower weight.
+            // Since the heuristic is fixed, a lower weight results in a lower key.
+            let weight = self.node_weights.get(node_index.as_usize());
+            if weight.clone() + heuristic.estimate(graph, node_index) < key {
+                performance_data.add_unnecessary_heap_element();
+                continue;
+            }
+
+            // The key is a lower bound for the weight of all remaining paths to the target.
+            if key > max_weight {
+                break;
+            }
+
+            if node_index == target {
+                result = Some(weight);
+                break;
+            }
+
+            // Relax neighbors
+            for out_neighbor in graph.out_neighbors(node_index) {
+                let new_neighbor_weight =
+                    weight.clone() + graph.edge_data(out_neighbor.edge_id).weight();
+                let neighbor_weight = self.node_weights.get_mut(out_neighbor.node_id.as_usize());
+                if new_neighbor_weight < *neighbor_weight {
+                    *neighbor_weight = new_neighbor_weight.clone();
+                    let new_neighbor_key =
+                        new_neighbor_weight + heuristic.estimate(graph, out_neighbor.node_id);
+                    self.heap.insert(new_neighbor_key, out_neighbor.node_id);
+                    self.back_pointers
+                        .set(out_neighbor.node_id, out_neighbor.edge_id);
+                }
+            }
+
+            performance_data.record_heap_size(self.heap.size());
+            performance_data.record_distance_array_size(self.node_weights.size());
+
+            if self.node_weights.size() > max_node_weight_data_size {
+                exhaustiveness = DijkstraExhaustiveness::PartialNodeWeights;
+                break;
+            } else if self.heap.size() > max_heap_data_size {
+                exhaustiveness = DijkstraExhaustiveness::PartialHeap;
+                break;
+            }
+        }
+
+        self.heap.clear();
+        self.node_weights.clear();
+        performance_data.finish_dijkstra();
+        (
+            result,
+            DijkstraStatus {
+                exhaustiveness,
+                performance_data,
+            },
+        )
+    }
+}
+
+impl<
+        WeightType: DijkstraWeight,
+        Graph: StaticGraph,
+        NodeWeights: NodeWeightArray<WeightType>,
+        Heap: DijkstraHeap<WeightType, Graph::NodeIndex>,
+    > AStar<Graph, WeightType, NodeWeights, Heap, DijkstraBackPointerArray<Graph>>
+{
+    /// Returns the edges of a path from the source of the last search to the given target,
+    /// or `None` if the target was not reached by the last search.
+    /// If the target is the source, then the path is empty.
+    ///
+    /// The path is a shortest path if the target is the target of the last search and its weight was returned.
+    /// Otherwise, the path might be longer than a shortest path.
+    pub fn extract_path(
+        &self,
+        graph: &Graph,
+        target: Graph::NodeIndex,
+    ) -> Option<VecEdgeWalk<Graph>> {
+        self.back_pointers.path_to(graph, self.source?, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dijkstra::astar::{DefaultAStar, ZeroHeuristic};
+    use crate::dijkstra::performance_counters::DijkstraPerformanceCounter;
+    use crate::dijkstra::DijkstraExhaustiveness;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_astar_grid() {
+        // A 10x10 grid with unit weights in all four directions.
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..100u32)
+            .map(|i| graph.add_node((i % 10, i / 10)))
+            .collect();
+        for i in 0..100 {
+            if i % 10 < 9 {
+                graph.add_edge(n[i], n[i + 1], 1u32);
+                graph.add_edge(n[i + 1], n[i], 1u32);
+            }
+            if i < 90 {
+                graph.add_edge(n[i], n[i + 10], 1u32);
+                graph.add_edge(n[i + 10], n[i], 1u32);
+            }
+        }
+
+        let target = n[9];
+        let manhattan = |graph: &PetGraph<(u32, u32), u32>, node| {
+            let (x, y) = *graph.node_data(node);
+            let (target_x, target_y) = *graph.node_data(target);
+            x.abs_diff(target_x) + y.abs_diff(target_y)
+        };
+
+        let mut astar = DefaultAStar::new(&graph);
+        let (distance, manhattan_status) = astar.shortest_path_len(
+            &graph,
+            n[0],
+            target,
+            &manhattan,
+            u32::MAX,
+            usize::MAX,
+            usize::MAX,
+            DijkstraPerformanceCounter::default(),
+        );
+        assert_eq!(distance, Some(9));
+        assert_eq!(
+            manhattan_status.exhaustiveness,
+            DijkstraExhaustiveness::Complete
+        );
+
+        let (distance, zero_status) = astar.shortest_path_len(
+            &graph,
+            n[0],
+            target,
+            &ZeroHeuristic,
+            u32::MAX,
+            usize::MAX,
+            usize::MAX,
+            DijkstraPerformanceCounter::default(),
+        );
+        assert_eq!(distance, Some(9));
+        assert!(
+            manhattan_status.performance_data.iterations < zero_status.performance_data.iterations
+        );
+
+        let (distance, _) = astar.shortest_path_len(
+            &graph,
+            n[0],
+            target,
+            &manhattan,
+            8,
+            usize::MAX,
+            usize::MAX,
+            DijkstraPerformanceCounter::default(),
+        );
+        assert_eq!(distance, None);
+    }
+}
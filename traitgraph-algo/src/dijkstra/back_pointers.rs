@@ -0,0 +1,102 @@
+use traitgraph::index::{GraphIndex, OptionalGraphIndex};
+use traitgraph::interface::{GraphBase, ImmutableGraphContainer};
+use traitgraph::walks::VecEdgeWalk;
+
+/// A data structure to store the back pointers of Dijkstra's algorithm,
+/// i.e. the edge through which each node was reached with its current weight.
+///
+/// This allows to choose whether shortest paths can be reconstructed, or only their lengths are computed.
+pub trait DijkstraBackPointers<Graph: GraphBase> {
+    /// Create a new back pointer data structure for the given amount of nodes.
+    fn new(size: usize) -> Self;
+
+    /// Sets the back pointer of the given node to the given edge.
+    fn set(&mut self, node: Graph::NodeIndex, edge: Graph::EdgeIndex);
+
+    /// Returns the back pointer of the given node, or `None` if it has none.
+    /// Always returns `None` if the back pointers are not stored.
+    fn get(&self, node: Graph::NodeIndex) -> Option<Graph::EdgeIndex>;
+
+    /// Removes all back pointers.
+    fn clear(&mut self);
+}
+
+/// Back pointers that are not stored, such that only the lengths of shortest paths can be computed.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct NoopDijkstraBackPointers;
+
+impl<Graph: GraphBase> DijkstraBackPointers<Graph> for NoopDijkstraBackPointers {
+    fn new(_size: usize) -> Self {
+        Self
+    }
+
+    #[inline]
+    fn set(&mut self, _node: Graph::NodeIndex, _edge: Graph::EdgeIndex) {}
+
+    #[inline]
+    fn get(&self, _node: Graph::NodeIndex) -> Option<Graph::EdgeIndex> {
+        None
+    }
+
+    fn clear(&mut self) {}
+}
+
+/// Back pointers stored in an array indexed by node.
+///
+/// To avoid iterating over the whole array when clearing, the nodes whose back pointer was set are recorded separately.
+#[derive(Debug, Clone)]
+pub struct DijkstraBackPointerArray<Graph: GraphBase> {
+    back_pointers: Vec<Graph::OptionalEdgeIndex>,
+    set_nodes: Vec<Graph::NodeIndex>,
+}
+
+impl<Graph: ImmutableGraphContainer> DijkstraBackPointerArray<Graph> {
+    /// Returns the edges of the path from the given source to the given target by following the back pointers from the target,
+    /// or `None` if a node without back pointer is reached before the source.
+    /// If the target is the source, then the path is empty.
+    pub fn path_to(
+        &self,
+        graph: &Graph,
+        source: Graph::NodeIndex,
+        target: Graph::NodeIndex,
+    ) -> Option<VecEdgeWalk<Graph>> {
+        let mut path = Vec::new();
+        let mut current_node = target;
+        while current_node != source {
+            let edge = self.get(current_node)?;
+            path.push(edge);
+            current_node = graph.edge_endpoints(edge).from_node;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<Graph: GraphBase> DijkstraBackPointers<Graph> for DijkstraBackPointerArray<Graph> {
+    fn new(size: usize) -> Self {
+        Self {
+            back_pointers: vec![Graph::OptionalEdgeIndex::new_none(); size],
+            set_nodes: Vec::new(),
+        }
+    }
+
+    #[inline]
+    fn set(&mut self, node: Graph::NodeIndex, edge: Graph::EdgeIndex) {
+        let back_pointer = &mut self.back_pointers[node.as_usize()];
+        if back_pointer.is_none() {
+            self.set_nodes.push(node);
+        }
+        *back_pointer = edge.into();
+    }
+
+    #[inline]
+    fn get(&self, node: Graph::NodeIndex) -> Option<Graph::EdgeIndex> {
+        self.back_pointers[node.as_usize()].into()
+    }
+
+    fn clear(&mut self) {
+        for node in self.set_nodes.drain(..) {
+            self.back_pointers[node.as_usize()] = Graph::OptionalEdgeIndex::new_none();
+        }
+    }
+}
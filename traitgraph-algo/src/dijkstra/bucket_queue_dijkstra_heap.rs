@@ -0,0 +1,196 @@
+use crate::dijkstra::DijkstraHeap;
+use std::collections::VecDeque;
+
+/// A bucket queue that can be used as [DijkstraHeap] if the weights are small non-negative integers.
+/// Using it in Dijkstra's algorithm results in Dial's algorithm.
+///
+/// The queue stores one bucket of entries per weight, starting from the minimum weight in the queue.
+/// Inserting and removing an entry takes amortised constant time, plus the time to skip over empty buckets.
+/// In Dijkstra's algorithm, the weights in the queue differ by at most the maximum edge weight,
+/// so there are never more buckets than the maximum edge weight plus one.
+/// Hence, this is faster than a binary heap if the edge weights are small, e.g. in unweighted graphs.
+///
+/// Entries of equal weight are removed in reverse order of insertion.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::dijkstra::bucket_queue_dijkstra_heap::BucketQueueHeap;
+/// use traitgraph_algo::dijkstra::epoch_array_dijkstra_node_weight_array::EpochNodeWeightArray;
+/// use traitgraph_algo::dijkstra::performance_counters::NoopDijkstraPerformanceCounter;
+/// use traitgraph_algo::dijkstra::Dijkstra;
+///
+/// let mut graph = PetGraph::new();
+/// let n0 = graph.add_node(());
+/// let n1 = graph.add_node(());
+/// graph.add_edge(n0, n1, 3usize);
+///
+/// let mut dijkstra =
+///     Dijkstra::<_, usize, EpochNodeWeightArray<_>, BucketQueueHeap<_, _>>::new(&graph);
+/// let mut distances = Vec::new();
+/// dijkstra.shortest_path_lens(
+///     &graph,
+///     n0,
+///     &vec![false, true],
+///     1,
+///     usize::MAX,
+///     false,
+///     &mut distances,
+///     usize::MAX,
+///     usize::MAX,
+///     NoopDijkstraPerformanceCounter,
+/// );
+/// assert_eq!(distances, [(n1, 3)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BucketQueueHeap<WeightType, IndexType> {
+    /// The bucket at position `i` contains the entries of weight `offset + i`.
+    buckets: VecDeque<Vec<(WeightType, IndexType)>>,
+    offset: usize,
+    len: usize,
+}
+
+impl<WeightType, IndexType> Default for BucketQueueHeap<WeightType, IndexType> {
+    fn default() -> Self {
+        Self {
+            buckets: VecDeque::new(),
+            offset: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<WeightType: Copy + TryInto<usize>, IndexType> BucketQueueHeap<WeightType, IndexType> {
+    #[inline]
+    fn bucket_of(weight: WeightType) -> usize {
+        weight
+            .try_into()
+            .unwrap_or_else(|_| panic!("the weight does not fit into a bucket index"))
+    }
+}
+
+impl<WeightType: Copy + TryInto<usize>, IndexType> DijkstraHeap<WeightType, IndexType>
+    for BucketQueueHeap<WeightType, IndexType>
+{
+    fn insert(&mut self, weight: WeightType, index: IndexType) {
+        let bucket = Self::bucket_of(weight);
+        if self.buckets.is_empty() {
+            self.offset = bucket;
+        } else if bucket < self.offset {
+            // Dijkstra's algorithm never inserts weights below the minimum, but other users might.
+            for _ in bucket..self.offset {
+                self.buckets.push_front(Vec::new());
+            }
+            self.offset = bucket;
+        }
+
+        let position = bucket - self.offset;
+        if position >= self.buckets.len() {
+            self.buckets.resize_with(position + 1, Vec::new);
+        }
+        self.buckets[position].push((weight, index));
+        self.len += 1;
+    }
+
+    fn remove_min(&mut self) -> Option<(WeightType, IndexType)> {
+        loop {
+            if let Some(entry) = self.buckets.front_mut()?.pop() {
+                self.len -= 1;
+                return Some(entry);
+            }
+
+            self.buckets.pop_front();
+            self.offset += 1;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.buckets.clear();
+        self.offset = 0;
+        self.len = 0;
+    }
+
+    fn size(&mut self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dijkstra::bucket_queue_dijkstra_heap::BucketQueueHeap;
+    use crate::dijkstra::epoch_array_dijkstra_node_weight_array::EpochNodeWeightArray;
+    use crate::dijkstra::performance_counters::NoopDijkstraPerformanceCounter;
+    use crate::dijkstra::{DefaultDijkstra, Dijkstra, DijkstraHeap};
+    use crate::random::deterministic_rng;
+    use rand::Rng;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_heap_order() {
+        let mut heap = BucketQueueHeap::default();
+        heap.insert(5u32, 'a');
+        heap.insert(7, 'b');
+        heap.insert(2, 'c');
+        heap.insert(5, 'd');
+        assert_eq!(heap.size(), 4);
+        assert_eq!(heap.remove_min(), Some((2, 'c')));
+        assert_eq!(heap.remove_min().map(|(weight, _)| weight), Some(5));
+        heap.insert(6, 'e');
+        assert_eq!(heap.remove_min().map(|(weight, _)| weight), Some(5));
+        assert_eq!(heap.remove_min(), Some((6, 'e')));
+        assert_eq!(heap.remove_min(), Some((7, 'b')));
+        assert_eq!(heap.remove_min(), None);
+        assert_eq!(heap.size(), 0);
+    }
+
+    #[test]
+    fn test_same_as_binary_heap() {
+        let mut random = deterministic_rng(0);
+        let mut graph = PetGraph::new();
+        let nodes: Vec<_> = (0..50).map(|_| graph.add_node(())).collect();
+        for _ in 0..200 {
+            let from = nodes[random.random_range(0..nodes.len())];
+            let to = nodes[random.random_range(0..nodes.len())];
+            graph.add_edge(from, to, random.random_range(0..5usize));
+        }
+
+        let targets = vec![true; graph.node_count()];
+        let mut binary_dijkstra = DefaultDijkstra::new(&graph);
+        let mut bucket_dijkstra =
+            Dijkstra::<_, _, EpochNodeWeightArray<_>, BucketQueueHeap<_, _>>::new(&graph);
+        for source in graph.node_indices() {
+            let mut expected = Vec::new();
+            binary_dijkstra.shortest_path_lens(
+                &graph,
+                source,
+                &targets,
+                targets.len(),
+                usize::MAX,
+                false,
+                &mut expected,
+                usize::MAX,
+                usize::MAX,
+                NoopDijkstraPerformanceCounter,
+            );
+            let mut actual = Vec::new();
+            bucket_dijkstra.shortest_path_lens(
+                &graph,
+                source,
+                &targets,
+                targets.len(),
+                usize::MAX,
+                false,
+                &mut actual,
+                usize::MAX,
+                usize::MAX,
+                NoopDijkstraPerformanceCounter,
+            );
+            expected.sort_unstable();
+            actual.sort_unstable();
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use crate::dijkstra::{DijkstraWeight, DijkstraWeightedEdgeData};
+use crate::traversal::{ForwardNeighborStrategy, TraversalNeighborStrategy};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::marker::PhantomData;
+use traitgraph::index::{GraphIndex, OptionalGraphIndex};
+use traitgraph::interface::{GraphBase, StaticGraph};
+use traitgraph::walks::VecEdgeWalk;
+
+/// Data structure for an edge-centric variant of Dijkstra's shortest path algorithm.
+///
+/// The search states are edges instead of nodes.
+/// The successors of an edge are the edges incident to its head, as defined by the `NeighborStrategy`:
+/// the outgoing edges of its head for [ForwardNeighborStrategy], and the incoming edges of its tail for
+/// [BackwardNeighborStrategy](crate::traversal::BackwardNeighborStrategy).
+///
+/// The distance from an edge `a` to an edge `b` is the minimum weight of a walk from `a` to `b`,
+/// where the weight of a walk is the sum of the weights of all its edges except the first.
+/// Hence, the distance from an edge to itself is zero, and the distance to a successor is the weight of the successor.
+pub struct EdgeDijkstra<
+    Graph: GraphBase,
+    WeightType: DijkstraWeight,
+    NeighborStrategy = ForwardNeighborStrategy,
+> {
+    heap: BinaryHeap<Reverse<(WeightType, Graph::EdgeIndex)>>,
+    edge_weights: Vec<WeightType>,
+    back_pointers: Vec<Graph::OptionalEdgeIndex>,
+    /// The edges whose weight was set during the current search.
+    touched_edges: Vec<Graph::EdgeIndex>,
+    neighbor_strategy: PhantomData<NeighborStrategy>,
+}
+
+impl<
+        WeightType: DijkstraWeight,
+        EdgeData: DijkstraWeightedEdgeData<WeightType>,
+        Graph: StaticGraph<EdgeData = EdgeData>,
+        NeighborStrategy: TraversalNeighborStrategy<Graph>,
+    > EdgeDijkstra<Graph, WeightType, NeighborStrategy>
+{
+    /// Create the data structures for the given graph.
+    pub fn new(graph: &Graph) -> Self {
+        Self {
+            heap: Default::default(),
+            edge_weights: vec![WeightType::infinity(); graph.edge_index_bound()],
+            back_pointers: vec![Graph::OptionalEdgeIndex::new_none(); graph.edge_index_bound()],
+            touched_edges: Default::default(),
+            neighbor_strategy: PhantomData,
+        }
+    }
+
+    /// Computes the distances from `source` to all edges whose distance is at most `max_weight`.
+    ///
+    /// The edges are returned in order of increasing distance, starting with `source` itself.
+    pub fn shortest_edge_distances(
+        &mut self,
+        graph: &Graph,
+        source: Graph::EdgeIndex,
+        max_weight: WeightType,
+    ) -> Vec<(Graph::EdgeIndex, WeightType)> {
+        let mut distances = Vec::new();
+        self.search(graph, source, max_weight, |edge, weight| {
+            distances.push((edge, weight.clone()));
+            false
+        });
+        self.reset();
+        distances
+    }
+
+    /// Computes a shortest walk from `source` to `target`, or returns `None` if `target` is not reachable from `source`.
+    ///
+    /// The walk starts with `source` and ends with `target`, and is returned together with its weight.
+    pub fn shortest_edge_walk(
+        &mut self,
+        graph: &Graph,
+        source: Graph::EdgeIndex,
+        target: Graph::EdgeIndex,
+    ) -> Option<(WeightType, VecEdgeWalk<Graph>)> {
+        let mut target_weight = None;
+        self.search(graph, source, WeightType::infinity(), |edge, weight| {
+            if edge == target {
+                target_weight = Some(weight.clone());
+                true
+            } else {
+                false
+            }
+        });
+
+        let result = target_weight.map(|weight| {
+            let mut walk = vec![target];
+            let mut current = target;
+            while current != source {
+                current = self.back_pointers[current.as_usize()].unwrap();
+                walk.push(current);
+            }
+            walk.reverse();
+            (weight, walk)
+        });
+        self.reset();
+        result
+    }
+
+    /// Runs the search from `source`, calling `finalise` for each edge once its distance is final.
+    /// The search stops if `finalise` returns true.
+    fn search(
+        &mut self,
+        graph: &Graph,
+        source: Graph::EdgeIndex,
+        max_weight: WeightType,
+        mut finalise: impl FnMut(Graph::EdgeIndex, &WeightType) -> bool,
+    ) {
+        self.set_weight(source, WeightType::zero());
+        self.heap.push(Reverse((WeightType::zero(), source)));
+
+        while let Some(Reverse((weight, edge))) = self.heap.pop() {
+            // Check if the edge was already processed
+            if self.edge_weights[edge.as_usize()] < weight {
+                continue;
+            }
+            if weight > max_weight {
+                break;
+            }
+            if finalise(edge, &weight) {
+                break;
+            }
+
+            // Relax successors
+            for node in NeighborStrategy::edge_neighbor_iterator(graph, edge) {
+                for neighbor in NeighborStrategy::neighbor_iterator(graph, node) {
+                    let successor = neighbor.edge_id;
+                    let successor_weight = weight.clone() + graph.edge_data(successor).weight();
+                    if successor_weight < self.edge_weights[successor.as_usize()] {
+                        self.set_weight(successor, successor_weight.clone());
+                        self.back_pointers[successor.as_usize()] = edge.into();
+                        self.heap.push(Reverse((successor_weight, successor)));
+                    }
+                }
+            }
+        }
+    }
+
+    fn set_weight(&mut self, edge: Graph::EdgeIndex, weight: WeightType) {
+        if self.edge_weights[edge.as_usize()] == WeightType::infinity() {
+            self.touched_edges.push(edge);
+        }
+        self.edge_weights[edge.as_usize()] = weight;
+    }
+
+    fn reset(&mut self) {
+        self.heap.clear();
+        for edge in self.touched_edges.drain(..) {
+            self.edge_weights[edge.as_usize()] = WeightType::infinity();
+            self.back_pointers[edge.as_usize()] = Graph::OptionalEdgeIndex::new_none();
+        }
+    }
+}
+
+/// Computes the unweighted distances from `source` to all edges reachable from it, using a breadth-first search over edges.
+///
+/// The successors of an edge are defined as in [EdgeDijkstra], and the distance of an edge is the number of edges of a shortest walk to it, excluding `source`.
+/// The returned vector is indexed by edge indices, and contains `None` for unreachable edges.
+pub fn edge_bfs_distances<
+    Graph: StaticGraph,
+    NeighborStrategy: TraversalNeighborStrategy<Graph>,
+>(
+    graph: &Graph,
+    source: Graph::EdgeIndex,
+) -> Vec<Option<usize>> {
+    let mut distances = vec![None; graph.edge_index_bound()];
+    distances[source.as_usize()] = Some(0);
+    let mut queue = VecDeque::from([source]);
+
+    while let Some(edge) = queue.pop_front() {
+        let distance = distances[edge.as_usize()].unwrap();
+        for node in NeighborStrategy::edge_neighbor_iterator(graph, edge) {
+            for neighbor in NeighborStrategy::neighbor_iterator(graph, node) {
+                let successor_distance = &mut distances[neighbor.edge_id.as_usize()];
+                if successor_distance.is_none() {
+                    *successor_distance = Some(distance + 1);
+                    queue.push_back(neighbor.edge_id);
+                }
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dijkstra::edge_dijkstra::{edge_bfs_distances, EdgeDijkstra};
+    use crate::traversal::{BackwardNeighborStrategy, ForwardNeighborStrategy};
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_edge_dijkstra() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        let e01 = graph.add_edge(n[0], n[1], 1);
+        let e12 = graph.add_edge(n[1], n[2], 5);
+        let e13 = graph.add_edge(n[1], n[3], 1);
+        let e32 = graph.add_edge(n[3], n[2], 2);
+        let e20 = graph.add_edge(n[2], n[0], 1);
+
+        let mut dijkstra = EdgeDijkstra::<_, usize>::new(&graph);
+        debug_assert_eq!(
+            dijkstra.shortest_edge_distances(&graph, e01, 3),
+            [(e01, 0), (e13, 1), (e32, 3)]
+        );
+        debug_assert_eq!(
+            dijkstra.shortest_edge_walk(&graph, e01, e20),
+            Some((4, vec![e01, e13, e32, e20]))
+        );
+        debug_assert_eq!(
+            dijkstra.shortest_edge_walk(&graph, e12, e12),
+            Some((0, vec![e12]))
+        );
+
+        let mut backward_dijkstra = EdgeDijkstra::<_, usize, BackwardNeighborStrategy>::new(&graph);
+        debug_assert_eq!(
+            backward_dijkstra.shortest_edge_walk(&graph, e20, e01),
+            Some((4, vec![e20, e32, e13, e01]))
+        );
+
+        graph.remove_edge(e20);
+        let mut dijkstra = EdgeDijkstra::<_, usize>::new(&graph);
+        debug_assert_eq!(dijkstra.shortest_edge_walk(&graph, e12, e01), None);
+    }
+
+    #[test]
+    fn test_edge_bfs_distances() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..3).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[1], n[1], ());
+        graph.add_edge(n[2], n[2], ());
+
+        debug_assert_eq!(
+            edge_bfs_distances::<_, ForwardNeighborStrategy>(&graph, 0.into()),
+            [Some(0), Some(1), Some(1), Some(2)]
+        );
+        debug_assert_eq!(
+            edge_bfs_distances::<_, ForwardNeighborStrategy>(&graph, 3.into()),
+            [None, None, None, Some(0)]
+        );
+    }
+}
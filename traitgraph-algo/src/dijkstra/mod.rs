@@ -1,3 +1,6 @@
+use crate::dijkstra::back_pointers::{
+    DijkstraBackPointerArray, DijkstraBackPointers, NoopDijkstraBackPointers,
+};
 use crate::dijkstra::epoch_array_dijkstra_node_weight_array::EpochNodeWeightArray;
 use crate::dijkstra::performance_counters::DijkstraPerformanceData;
 use std::collections::BinaryHeap;
@@ -6,9 +9,19 @@ use std::marker::PhantomData;
 use std::ops::Add;
 use traitgraph::index::{GraphIndex, NodeIndex};
 use traitgraph::interface::{GraphBase, StaticGraph};
+use traitgraph::walks::VecEdgeWalk;
 
 mod dijkstra_weight_implementations;
 
+/// The A* search algorithm, a goal-directed variant of Dijkstra's algorithm.
+pub mod astar;
+/// Storing the back pointers of Dijkstra's algorithm, for reconstructing shortest paths.
+pub mod back_pointers;
+
+/// A bucket queue as [DijkstraHeap] for small integer weights.
+pub mod bucket_queue_dijkstra_heap;
+/// An edge-centric variant of Dijkstra's algorithm, computing distances between edges.
+pub mod edge_dijkstra;
 /// Using an epoched array as [NodeWeightArray].
 pub mod epoch_array_dijkstra_node_weight_array;
 /// Contains the implementation of the [NodeWeightArray] as [hashbrown::HashMap].
@@ -26,6 +39,16 @@ pub type DefaultDijkstra<Graph, WeightType> = Dijkstra<
     BinaryHeap<std::cmp::Reverse<(WeightType, <Graph as GraphBase>::NodeIndex)>>,
 >;
 
+/// A Dijkstra implementation with a set of common optimisations that records back pointers,
+/// such that shortest paths can be reconstructed with [extract_path](Dijkstra::extract_path).
+pub type DefaultPathDijkstra<Graph, WeightType> = Dijkstra<
+    Graph,
+    WeightType,
+    EpochNodeWeightArray<WeightType>,
+    BinaryHeap<std::cmp::Reverse<(WeightType, <Graph as GraphBase>::NodeIndex)>>,
+    DijkstraBackPointerArray<Graph>,
+>;
+
 /// A weight-type usable in Dijkstra's algorithm.
 pub trait DijkstraWeight: Ord + Add<Output = Self> + Sized + Clone {
     /// The infinity value of this type.
@@ -187,16 +210,20 @@ pub struct DijkstraStatus<DijkstraPerformance: DijkstraPerformanceData> {
 
 /// Data structure for Dijkstra's shortest path algorithm.
 ///
-/// This variant of Dijkstra's algorithm supports only computing the length of a shortest path, and not the shortest path itself.
+/// By default, this variant of Dijkstra's algorithm supports only computing the length of a shortest path, and not the shortest path itself.
 /// Therefore it does not need an array of back pointers for each node, saving a bit of memory.
+/// To reconstruct shortest paths, set `BackPointers` to [DijkstraBackPointerArray] (see [DefaultPathDijkstra]),
+/// and use [extract_path](Self::extract_path) after a search.
 pub struct Dijkstra<
     Graph: GraphBase,
     WeightType: DijkstraWeight,
     NodeWeights: NodeWeightArray<WeightType>,
     Heap: DijkstraHeap<WeightType, Graph::NodeIndex>,
+    BackPointers: DijkstraBackPointers<Graph> = NoopDijkstraBackPointers,
 > {
     heap: Heap,
-    // back_pointers: Vec<Graph::OptionalNodeIndex>,
+    back_pointers: BackPointers,
+    source: Option<Graph::NodeIndex>,
     node_weights: NodeWeights,
     graph: PhantomData<Graph>,
     _weight_type_phantom: PhantomData<WeightType>,
@@ -208,14 +235,16 @@ impl<
         Graph: StaticGraph<EdgeData = EdgeData>,
         NodeWeights: NodeWeightArray<WeightType>,
         Heap: DijkstraHeap<WeightType, Graph::NodeIndex>,
-    > Dijkstra<Graph, WeightType, NodeWeights, Heap>
+        BackPointers: DijkstraBackPointers<Graph>,
+    > Dijkstra<Graph, WeightType, NodeWeights, Heap, BackPointers>
 {
     /// Create the data structures for the given graph.
     pub fn new(graph: &Graph) -> Self {
         Self {
             heap: Default::default(),
-            // back_pointers: vec![Default::default(); graph.node_count()],
-            node_weights: NodeWeights::new(graph.node_count()),
+            back_pointers: BackPointers::new(graph.node_index_bound()),
+            source: None,
+            node_weights: NodeWeights::new(graph.node_index_bound()),
             graph: Default::default(),
             _weight_type_phantom: Default::default(),
         }
@@ -244,7 +273,9 @@ impl<
     ) -> DijkstraStatus<DijkstraPerformance> {
         //println!("Shortest path lens of {}", source.as_usize());
         self.heap.insert(WeightType::zero(), source);
-        //self.back_pointers[source.as_usize()] = source.into();
+        // The back pointers are kept after the search, such that paths can be extracted.
+        self.back_pointers.clear();
+        self.source = Some(source);
         self.node_weights.set(source.as_usize(), WeightType::zero());
         distances.clear();
         let mut exhaustiveness = DijkstraExhaustiveness::Complete;
@@ -286,7 +317,8 @@ impl<
                 if new_neighbor_weight < *neighbor_weight {
                     *neighbor_weight = new_neighbor_weight.clone();
                     self.heap.insert(new_neighbor_weight, out_neighbor.node_id);
-                    //self.back_pointers[out_neighbor.node_id.as_usize()] = node_index.into();
+                    self.back_pointers
+                        .set(out_neighbor.node_id, out_neighbor.edge_id);
                 }
             }
 
@@ -303,9 +335,6 @@ impl<
         }
 
         self.heap.clear();
-        /*for back_pointer in &mut self.back_pointers {
-            *back_pointer = Default::default();
-        }*/
         self.node_weights.clear();
         performance_data.finish_dijkstra();
         DijkstraStatus {
@@ -315,10 +344,32 @@ impl<
     }
 }
 
+impl<
+        WeightType: DijkstraWeight,
+        Graph: StaticGraph,
+        NodeWeights: NodeWeightArray<WeightType>,
+        Heap: DijkstraHeap<WeightType, Graph::NodeIndex>,
+    > Dijkstra<Graph, WeightType, NodeWeights, Heap, DijkstraBackPointerArray<Graph>>
+{
+    /// Returns the edges of a path from the source of the last search to the given target,
+    /// or `None` if the target was not reached by the last search.
+    /// If the target is the source, then the path is empty.
+    ///
+    /// The path is a shortest path if the target was returned as a target by the last search.
+    /// Otherwise, if the search was aborted before the target was finalised, the path might be longer than a shortest path.
+    pub fn extract_path(
+        &self,
+        graph: &Graph,
+        target: Graph::NodeIndex,
+    ) -> Option<VecEdgeWalk<Graph>> {
+        self.back_pointers.path_to(graph, self.source?, target)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::dijkstra::performance_counters::NoopDijkstraPerformanceCounter;
-    use crate::dijkstra::DefaultDijkstra;
+    use crate::dijkstra::{DefaultDijkstra, DefaultPathDijkstra};
     use traitgraph::implementation::petgraph_impl::PetGraph;
     use traitgraph::interface::MutableGraphContainer;
 
@@ -434,4 +485,52 @@ mod tests {
         );
         debug_assert_eq!(distances, vec![(n3, 4)]);
     }
+
+    #[test]
+    fn test_dijkstra_extract_path() {
+        let mut graph = PetGraph::new();
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+        let e12 = graph.add_edge(n1, n2, 2);
+        let e23 = graph.add_edge(n2, n3, 2);
+        graph.add_edge(n1, n3, 5);
+
+        let mut dijkstra = DefaultPathDijkstra::new(&graph);
+        let mut distances = Vec::new();
+        let targets = vec![false, false, true, false];
+        assert_eq!(dijkstra.extract_path(&graph, n3), None);
+        dijkstra.shortest_path_lens(
+            &graph,
+            n1,
+            &targets,
+            1,
+            6,
+            false,
+            &mut distances,
+            usize::MAX,
+            usize::MAX,
+            NoopDijkstraPerformanceCounter,
+        );
+        assert_eq!(distances, vec![(n3, 4)]);
+        assert_eq!(dijkstra.extract_path(&graph, n3), Some(vec![e12, e23]));
+        assert_eq!(dijkstra.extract_path(&graph, n1), Some(vec![]));
+        assert_eq!(dijkstra.extract_path(&graph, n4), None);
+
+        dijkstra.shortest_path_lens(
+            &graph,
+            n2,
+            &targets,
+            1,
+            6,
+            false,
+            &mut distances,
+            usize::MAX,
+            usize::MAX,
+            NoopDijkstraPerformanceCounter,
+        );
+        assert_eq!(dijkstra.extract_path(&graph, n3), Some(vec![e23]));
+        assert_eq!(dijkstra.extract_path(&graph, n1), None);
+    }
 }
@@ -0,0 +1,935 @@
+use crate::components::decompose_strongly_connected_components;
+use crate::error::{AlgorithmError, AlgorithmResult};
+use crate::traversal::ForwardNeighborStrategy;
+use crate::visitor::{Control, DepthFirstSearch, DfsVisitor};
+use hashbrown::{HashMap, HashSet};
+use std::marker::PhantomData;
+use traitgraph::implementation::observed_graph::GraphObserver;
+use traitgraph::implementation::subgraphs::incremental_subgraph::IncrementalSubgraph;
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{
+    Edge, GraphBase, ImmutableGraphContainer, NavigableGraph, StaticGraph,
+};
+
+/// A union-find structure over the node indices `0..len`, using union by size and path compression.
+#[derive(Debug, Clone, Default)]
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    set_count: usize,
+}
+
+impl UnionFind {
+    fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    fn push(&mut self) -> usize {
+        let element = self.parent.len();
+        self.parent.push(element);
+        self.size.push(1);
+        self.set_count += 1;
+        element
+    }
+
+    fn reset(&mut self, len: usize) {
+        self.parent.clear();
+        self.parent.extend(0..len);
+        self.size.clear();
+        self.size.resize(len, 1);
+        self.set_count = len;
+    }
+
+    /// Finds the representative of `element` without modifying the structure.
+    fn find(&self, mut element: usize) -> usize {
+        while self.parent[element] != element {
+            element = self.parent[element];
+        }
+        element
+    }
+
+    /// Finds the representative of `element`, halving the path to it.
+    fn find_mut(&mut self, mut element: usize) -> usize {
+        while self.parent[element] != element {
+            self.parent[element] = self.parent[self.parent[element]];
+            element = self.parent[element];
+        }
+        element
+    }
+
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find_mut(a), self.find_mut(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.set_count -= 1;
+        true
+    }
+}
+
+/// Tracks the weakly connected components of a graph to which nodes and edges are only ever added.
+///
+/// Connectivity queries take `O(log n)` time in the worst case, and are near-constant in practice since paths are compressed while inserting edges.
+/// The structure does not hold a reference to the graph, so it needs to be informed about every node and edge added to the graph.
+#[derive(Debug, Clone)]
+pub struct IncrementalConnectivity<Graph: GraphBase> {
+    union_find: UnionFind,
+    /// The amount of indices below the node index bound of the initial graph that do not belong to a node.
+    vacant_node_count: usize,
+    graph: PhantomData<Graph>,
+}
+
+impl<Graph: GraphBase> Default for IncrementalConnectivity<Graph> {
+    fn default() -> Self {
+        Self {
+            union_find: Default::default(),
+            vacant_node_count: 0,
+            graph: PhantomData,
+        }
+    }
+}
+
+impl<Graph: ImmutableGraphContainer> IncrementalConnectivity<Graph> {
+    /// Creates a connectivity structure containing the nodes and edges of the given graph.
+    pub fn new(graph: &Graph) -> Self {
+        let mut result = Self::default();
+        result.union_find.reset(graph.node_index_bound());
+        // Indices without a node are singletons that are not counted as components.
+        result.vacant_node_count = graph.node_index_bound() - graph.node_count();
+        result.union_find.set_count -= result.vacant_node_count;
+        for edge_id in graph.edge_indices() {
+            let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+            result.add_edge(from_node, to_node);
+        }
+        result
+    }
+}
+
+impl<Graph: GraphBase> IncrementalConnectivity<Graph> {
+    /// Adds a new node that is not connected to any other node, and returns its index.
+    ///
+    /// This should be called once for every node added to the graph, such that the indices of the graph and this structure match.
+    pub fn add_node(&mut self) -> Graph::NodeIndex {
+        self.union_find.push().into()
+    }
+
+    /// Connects the components of the given nodes.
+    /// Returns true if the nodes were not connected before.
+    pub fn add_edge(&mut self, from: Graph::NodeIndex, to: Graph::NodeIndex) -> bool {
+        self.union_find.union(from.as_usize(), to.as_usize())
+    }
+
+    /// Returns true if the given nodes are in the same weakly connected component.
+    pub fn connected(&self, a: Graph::NodeIndex, b: Graph::NodeIndex) -> bool {
+        self.representative(a) == self.representative(b)
+    }
+
+    /// Returns a node of the component of the given node.
+    /// All nodes of the same component have the same representative.
+    pub fn representative(&self, node: Graph::NodeIndex) -> Graph::NodeIndex {
+        self.union_find.find(node.as_usize()).into()
+    }
+
+    /// Returns the number of nodes in the component of the given node.
+    pub fn component_size(&self, node: Graph::NodeIndex) -> usize {
+        self.union_find.size[self.union_find.find(node.as_usize())]
+    }
+
+    /// Returns the number of weakly connected components.
+    pub fn component_count(&self) -> usize {
+        self.union_find.set_count
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.union_find.len() - self.vacant_node_count
+    }
+}
+
+/// Tracks the weakly connected components of a graph to which nodes and edges are added and from which they are removed.
+///
+/// Insertions are as cheap as in [IncrementalConnectivity].
+/// Removals only mark the components as outdated, and the next query recomputes them in `O(n + m)` time.
+/// Hence, this structure is most efficient if removals occur in batches between queries.
+///
+/// The structure does not hold a reference to the graph.
+/// It can either be informed about mutations manually, or be used as a [GraphObserver] of an
+/// [ObservedGraph](traitgraph::implementation::observed_graph::ObservedGraph) to stay synchronised automatically.
+/// As observer, it supports both graphs that move their last node into the place of a removed node,
+/// and graphs that leave the index of a removed node vacant and may reuse it later.
+#[derive(Debug, Clone)]
+pub struct DynamicConnectivity<Graph: GraphBase> {
+    union_find: UnionFind,
+    /// The multiplicity of each edge, with its endpoints ordered by index.
+    edges: HashMap<(usize, usize), usize>,
+    /// The bound on the node indices.
+    node_index_bound: usize,
+    /// The amount of indices below the node index bound that do not belong to a node.
+    vacant_node_count: usize,
+    outdated: bool,
+    graph: PhantomData<Graph>,
+}
+
+impl<Graph: GraphBase> Default for DynamicConnectivity<Graph> {
+    fn default() -> Self {
+        Self {
+            union_find: Default::default(),
+            edges: Default::default(),
+            node_index_bound: 0,
+            vacant_node_count: 0,
+            outdated: false,
+            graph: PhantomData,
+        }
+    }
+}
+
+impl<Graph: ImmutableGraphContainer> DynamicConnectivity<Graph> {
+    /// Creates a connectivity structure containing the nodes and edges of the given graph.
+    pub fn new(graph: &Graph) -> Self {
+        let mut result = Self {
+            node_index_bound: graph.node_index_bound(),
+            vacant_node_count: graph.node_index_bound() - graph.node_count(),
+            outdated: true,
+            ..Self::default()
+        };
+        for edge_id in graph.edge_indices() {
+            let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+            result.add_edge(from_node, to_node);
+        }
+        result
+    }
+}
+
+impl<Graph: GraphBase> DynamicConnectivity<Graph> {
+    /// Adds a new node that is not connected to any other node, and returns its index.
+    pub fn add_node(&mut self) -> Graph::NodeIndex {
+        let node = self.node_index_bound;
+        self.node_index_bound += 1;
+        if !self.outdated {
+            self.union_find.push();
+        }
+        node.into()
+    }
+
+    /// Adds an edge between the given nodes.
+    /// Returns true if the nodes were not connected before.
+    pub fn add_edge(&mut self, from: Graph::NodeIndex, to: Graph::NodeIndex) -> bool {
+        let (from, to) = (from.as_usize(), to.as_usize());
+        *self.edges.entry(Self::edge_key(from, to)).or_default() += 1;
+        self.update();
+        self.union_find.union(from, to)
+    }
+
+    /// Removes an edge between the given nodes.
+    /// Returns false if there is no such edge.
+    pub fn remove_edge(&mut self, from: Graph::NodeIndex, to: Graph::NodeIndex) -> bool {
+        let key = Self::edge_key(from.as_usize(), to.as_usize());
+        let Some(multiplicity) = self.edges.get_mut(&key) else {
+            return false;
+        };
+
+        *multiplicity -= 1;
+        if *multiplicity == 0 {
+            self.edges.remove(&key);
+            // Self-loops do not contribute to connectivity.
+            if key.0 != key.1 {
+                self.outdated = true;
+            }
+        }
+        true
+    }
+
+    /// Removes the node with the highest index, which must not have any incident edges.
+    pub fn remove_last_node(&mut self) {
+        debug_assert!(self.node_count() > 0);
+        self.node_index_bound -= 1;
+        self.outdated = true;
+    }
+
+    /// Removes the given node without moving any other node, leaving its index vacant.
+    /// The node must not have any incident edges.
+    pub fn vacate_node(&mut self, node: Graph::NodeIndex) {
+        debug_assert!(node.as_usize() < self.node_index_bound);
+        debug_assert!(self.node_count() > 0);
+        self.vacant_node_count += 1;
+        self.outdated = true;
+    }
+
+    /// Adds a new node that is not connected to any other node at the given index,
+    /// which must either be vacant or at least the node index bound.
+    /// Indices between the node index bound and the given index are vacant afterwards.
+    pub fn occupy_node(&mut self, node: Graph::NodeIndex) {
+        let node = node.as_usize();
+        if node < self.node_index_bound {
+            debug_assert!(self.vacant_node_count > 0);
+            self.vacant_node_count -= 1;
+            // The vacant index is a singleton in the union-find structure already.
+            if !self.outdated {
+                self.union_find.set_count += 1;
+            }
+        } else {
+            self.vacant_node_count += node - self.node_index_bound;
+            self.node_index_bound = node + 1;
+            self.outdated = true;
+        }
+    }
+
+    /// Renames the node `from` to `to`, where `to` must be an unused index below the node index bound.
+    ///
+    /// This requires time linear in the number of distinct edges.
+    pub fn move_node(&mut self, from: Graph::NodeIndex, to: Graph::NodeIndex) {
+        let (from, to) = (from.as_usize(), to.as_usize());
+        self.edges = self
+            .edges
+            .drain()
+            .map(|((a, b), multiplicity)| {
+                let rename = |node| if node == from { to } else { node };
+                (Self::edge_key(rename(a), rename(b)), multiplicity)
+            })
+            .collect();
+        self.outdated = true;
+    }
+
+    /// Returns true if the given nodes are in the same weakly connected component.
+    pub fn connected(&mut self, a: Graph::NodeIndex, b: Graph::NodeIndex) -> bool {
+        self.update();
+        self.union_find.find_mut(a.as_usize()) == self.union_find.find_mut(b.as_usize())
+    }
+
+    /// Returns the number of weakly connected components.
+    pub fn component_count(&mut self) -> usize {
+        self.update();
+        self.union_find.set_count
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.node_index_bound - self.vacant_node_count
+    }
+
+    fn edge_key(a: usize, b: usize) -> (usize, usize) {
+        (a.min(b), a.max(b))
+    }
+
+    /// Recomputes the components if edges were removed since the last computation.
+    fn update(&mut self) {
+        if self.outdated {
+            self.union_find.reset(self.node_index_bound);
+            // Indices without a node are singletons that are not counted as components.
+            self.union_find.set_count -= self.vacant_node_count;
+            for &(a, b) in self.edges.keys() {
+                self.union_find.union(a, b);
+            }
+            self.outdated = false;
+        }
+    }
+}
+
+impl<Graph: ImmutableGraphContainer> GraphObserver<Graph> for DynamicConnectivity<Graph> {
+    fn node_added(&mut self, _graph: &Graph, node_id: Graph::NodeIndex) {
+        if node_id.as_usize() == self.node_index_bound {
+            self.add_node();
+        } else {
+            self.occupy_node(node_id);
+        }
+    }
+
+    fn edge_added(&mut self, graph: &Graph, edge_id: Graph::EdgeIndex) {
+        let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+        self.add_edge(from_node, to_node);
+    }
+
+    fn node_removed(
+        &mut self,
+        graph: &Graph,
+        node_id: Graph::NodeIndex,
+        _node_data: &Graph::NodeData,
+    ) {
+        if graph.contains_node_index(node_id) {
+            // The last node was moved into the place of the removed node, which is reported by `node_moved` next.
+            self.remove_last_node();
+        } else {
+            self.vacate_node(node_id);
+            // The graph may drop vacant indices at the end of its index range.
+            let node_index_bound = graph.node_index_bound();
+            debug_assert!(node_index_bound <= self.node_index_bound);
+            self.vacant_node_count -= self.node_index_bound - node_index_bound;
+            self.node_index_bound = node_index_bound;
+        }
+    }
+
+    fn edge_removed(
+        &mut self,
+        _graph: &Graph,
+        _edge_id: Graph::EdgeIndex,
+        endpoints: &Edge<Graph::NodeIndex>,
+        _edge_data: &Graph::EdgeData,
+    ) {
+        let removed = self.remove_edge(endpoints.from_node, endpoints.to_node);
+        debug_assert!(removed);
+    }
+
+    fn node_moved(&mut self, _graph: &Graph, from: Graph::NodeIndex, to: Graph::NodeIndex) {
+        self.move_node(from, to);
+    }
+
+    fn cleared(&mut self, _graph: &Graph) {
+        *self = Self::default();
+    }
+}
+
+/// Maintains the strongly connected components of a graph to which edges are only ever added, together with a topological order of the components.
+///
+/// This uses the online topological ordering algorithm of Pearce and Kelly, extended to merge components whenever an inserted edge closes a cycle.
+/// Inserting an edge only explores the components that lie between its endpoints in the current topological order,
+/// and does not explore anything if the edge agrees with the current order.
+///
+/// The structure does not hold a reference to the graph, but requires it when inserting edges.
+/// All previously inserted edges must be contained in the graph passed to the methods, while the inserted edge itself may or may not be contained yet.
+/// This allows to check if an edge would create a cycle before adding it to the graph.
+///
+/// For graphs that are built in the steps of an [IncrementalSubgraph], use [insert_current_step](Self::insert_current_step).
+#[derive(Debug, Clone)]
+pub struct IncrementalStronglyConnectedComponents<Graph: GraphBase> {
+    /// The representative of the component of each node.
+    component: Vec<usize>,
+    /// The nodes of each component, stored at the index of its representative.
+    members: Vec<Vec<usize>>,
+    /// The key of each component in the topological order, stored at the index of its representative.
+    /// Keys are unique but not necessarily consecutive.
+    order: Vec<usize>,
+    /// A key that is larger than all keys in `order`.
+    next_order: usize,
+    component_count: usize,
+    graph: PhantomData<Graph>,
+}
+
+impl<Graph: GraphBase> IncrementalStronglyConnectedComponents<Graph> {
+    /// Creates a structure for a graph with the given amount of nodes and no edges.
+    pub fn with_node_count(node_count: usize) -> Self {
+        Self {
+            component: (0..node_count).collect(),
+            members: (0..node_count).map(|node| vec![node]).collect(),
+            order: (0..node_count).collect(),
+            next_order: node_count,
+            component_count: node_count,
+            graph: PhantomData,
+        }
+    }
+
+    /// Adds a new node that has no incident edges, and returns its index.
+    /// The node is ordered after all existing nodes.
+    pub fn add_node(&mut self) -> Graph::NodeIndex {
+        let node = self.component.len();
+        self.component.push(node);
+        self.members.push(vec![node]);
+        self.order.push(self.next_order);
+        self.next_order += 1;
+        self.component_count += 1;
+        node.into()
+    }
+
+    /// Returns the representative of the strongly connected component of the given node.
+    /// All nodes of the same component have the same representative.
+    pub fn representative(&self, node: Graph::NodeIndex) -> Graph::NodeIndex {
+        self.component[node.as_usize()].into()
+    }
+
+    /// Returns true if the given nodes are in the same strongly connected component.
+    pub fn strongly_connected(&self, a: Graph::NodeIndex, b: Graph::NodeIndex) -> bool {
+        self.component[a.as_usize()] == self.component[b.as_usize()]
+    }
+
+    /// Returns the nodes in the strongly connected component of the given node.
+    pub fn component_nodes(
+        &self,
+        node: Graph::NodeIndex,
+    ) -> impl '_ + Iterator<Item = Graph::NodeIndex> {
+        self.members[self.component[node.as_usize()]]
+            .iter()
+            .map(|&node| node.into())
+    }
+
+    /// Returns the number of strongly connected components.
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// Returns true if the component of `a` comes before the component of `b` in the maintained topological order.
+    pub fn precedes(&self, a: Graph::NodeIndex, b: Graph::NodeIndex) -> bool {
+        self.order[self.component[a.as_usize()]] < self.order[self.component[b.as_usize()]]
+    }
+
+    /// Returns the representatives of all strongly connected components in topological order.
+    pub fn topological_order(&self) -> Vec<Graph::NodeIndex> {
+        let mut representatives: Vec<_> = (0..self.component.len())
+            .filter(|&node| self.component[node] == node && !self.members[node].is_empty())
+            .collect();
+        representatives.sort_unstable_by_key(|&representative| self.order[representative]);
+        representatives.into_iter().map(Into::into).collect()
+    }
+}
+
+impl<Graph: ImmutableGraphContainer + NavigableGraph>
+    IncrementalStronglyConnectedComponents<Graph>
+{
+    /// Creates a structure containing the nodes and edges of the given graph.
+    pub fn new(graph: &Graph) -> Self
+    where
+        Graph: StaticGraph,
+    {
+        let mut result = Self::with_node_count(graph.node_index_bound());
+
+        // Ordering components by their maximum finishing time in a depth-first search yields a topological order.
+        struct FinishOrder(Vec<usize>);
+        impl<Graph: GraphBase> DfsVisitor<Graph> for FinishOrder {
+            fn finish_node(&mut self, node: Graph::NodeIndex) -> Control {
+                self.0.push(node.as_usize());
+                Control::Continue
+            }
+        }
+        let mut finish_order = FinishOrder(Vec::with_capacity(graph.node_count()));
+        DepthFirstSearch::<_, ForwardNeighborStrategy>::new(graph).visit_all(&mut finish_order);
+
+        let components = decompose_strongly_connected_components(graph);
+        result.order.fill(usize::MAX);
+        // Indices without a node do not form components.
+        for node in 0..graph.node_index_bound() {
+            if !graph.contains_node_index(node.into()) {
+                result.members[node].clear();
+                result.component_count -= 1;
+            }
+        }
+        for (rank, &node) in finish_order.0.iter().rev().enumerate() {
+            let representative = components[node].as_usize();
+            result.order[representative] = result.order[representative].min(rank);
+            if representative != node {
+                result.members[node].clear();
+                result.members[representative].push(node);
+                result.component[node] = representative;
+                result.component_count -= 1;
+            }
+        }
+
+        result
+    }
+
+    /// Returns true if inserting an edge from `from` to `to` would create a cycle.
+    pub fn would_create_cycle(
+        &self,
+        graph: &Graph,
+        from: Graph::NodeIndex,
+        to: Graph::NodeIndex,
+    ) -> bool {
+        let (from, to) = (
+            self.component[from.as_usize()],
+            self.component[to.as_usize()],
+        );
+        if from == to {
+            return true;
+        }
+        if self.order[from] < self.order[to] {
+            return false;
+        }
+
+        let upper_bound = self.order[from];
+        self.search(graph, to, |order| order <= upper_bound, true)
+            .contains(&from)
+    }
+
+    /// Inserts an edge from `from` to `to`, merging all components on the cycles it closes.
+    /// Returns true if components were merged.
+    pub fn insert_edge(
+        &mut self,
+        graph: &Graph,
+        from: Graph::NodeIndex,
+        to: Graph::NodeIndex,
+    ) -> bool {
+        self.insert(graph, from, to, true)
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Inserts an edge from `from` to `to` if it does not create a cycle.
+    /// Otherwise, returns an error and leaves the structure unchanged.
+    pub fn insert_edge_acyclic(
+        &mut self,
+        graph: &Graph,
+        from: Graph::NodeIndex,
+        to: Graph::NodeIndex,
+    ) -> AlgorithmResult<(), Graph> {
+        self.insert(graph, from, to, false).map(|_| ())
+    }
+
+    fn insert(
+        &mut self,
+        graph: &Graph,
+        from: Graph::NodeIndex,
+        to: Graph::NodeIndex,
+        allow_cycles: bool,
+    ) -> AlgorithmResult<bool, Graph> {
+        let (from_component, to_component) = (
+            self.component[from.as_usize()],
+            self.component[to.as_usize()],
+        );
+        if from_component == to_component {
+            return if allow_cycles {
+                Ok(false)
+            } else {
+                Err(AlgorithmError::CyclicGraph { node: from })
+            };
+        }
+        if self.order[from_component] < self.order[to_component] {
+            return Ok(false);
+        }
+
+        // The edge violates the topological order, so the components between its endpoints need to be reordered.
+        let (lower_bound, upper_bound) = (self.order[to_component], self.order[from_component]);
+        let forward = self.search(graph, to_component, |order| order <= upper_bound, true);
+        let closes_cycle = forward.contains(&from_component);
+        if closes_cycle && !allow_cycles {
+            return Err(AlgorithmError::CyclicGraph { node: from });
+        }
+        let backward = self.search(graph, from_component, |order| order >= lower_bound, false);
+
+        // The components on the closed cycles are exactly those reachable from `to` that reach `from`.
+        let cycle: HashSet<_> = if closes_cycle {
+            forward.intersection(&backward).copied().collect()
+        } else {
+            HashSet::new()
+        };
+
+        let mut keys: Vec<_> = forward
+            .union(&backward)
+            .map(|&component| self.order[component])
+            .collect();
+        keys.sort_unstable();
+        let sorted = |components: &HashSet<usize>| {
+            let mut components: Vec<_> = components
+                .iter()
+                .copied()
+                .filter(|component| !cycle.contains(component))
+                .collect();
+            components.sort_unstable_by_key(|&component| self.order[component]);
+            components
+        };
+        let mut reordered = sorted(&backward);
+        let merged_component = cycle
+            .iter()
+            .copied()
+            .max_by_key(|&component| (self.members[component].len(), component));
+        reordered.extend(merged_component);
+        reordered.extend(sorted(&forward));
+        for (component, key) in reordered.into_iter().zip(keys) {
+            self.order[component] = key;
+        }
+
+        if let Some(merged_component) = merged_component {
+            for component in cycle {
+                if component != merged_component {
+                    let members = core::mem::take(&mut self.members[component]);
+                    for &node in &members {
+                        self.component[node] = merged_component;
+                    }
+                    self.members[merged_component].extend(members);
+                    self.component_count -= 1;
+                }
+            }
+        }
+
+        Ok(closes_cycle)
+    }
+
+    /// Returns all components reachable from `start` via components whose order satisfies `is_in_bounds`.
+    fn search(
+        &self,
+        graph: &Graph,
+        start: usize,
+        is_in_bounds: impl Fn(usize) -> bool,
+        forward: bool,
+    ) -> HashSet<usize> {
+        let mut visited = HashSet::from_iter([start]);
+        let mut stack = vec![start];
+
+        while let Some(component) = stack.pop() {
+            let mut visit = |neighbor: Graph::NodeIndex| {
+                let neighbor_component = self.component[neighbor.as_usize()];
+                if is_in_bounds(self.order[neighbor_component])
+                    && visited.insert(neighbor_component)
+                {
+                    stack.push(neighbor_component);
+                }
+            };
+
+            for &node in &self.members[component] {
+                if forward {
+                    graph
+                        .out_neighbors(node.into())
+                        .for_each(|neighbor| visit(neighbor.node_id));
+                } else {
+                    graph
+                        .in_neighbors(node.into())
+                        .for_each(|neighbor| visit(neighbor.node_id));
+                }
+            }
+        }
+
+        visited
+    }
+}
+
+impl<'a, Graph: ImmutableGraphContainer + NavigableGraph>
+    IncrementalStronglyConnectedComponents<IncrementalSubgraph<'a, Graph>>
+{
+    /// Inserts all edges added to the given subgraph in its current step.
+    /// Returns true if components were merged.
+    ///
+    /// Edges removed from the subgraph are ignored, since the components only support insertions.
+    pub fn insert_current_step(&mut self, subgraph: &IncrementalSubgraph<'a, Graph>) -> bool {
+        let mut merged = false;
+        for &edge_id in subgraph
+            .new_edges()
+            .iter()
+            .filter(|&&edge_id| subgraph.contains_edge_index(edge_id))
+        {
+            let Edge { from_node, to_node } = subgraph.edge_endpoints(edge_id);
+            merged |= self.insert_edge(subgraph, from_node, to_node);
+        }
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic_connectivity::{
+        DynamicConnectivity, IncrementalConnectivity, IncrementalStronglyConnectedComponents,
+    };
+    use crate::error::AlgorithmError;
+    use traitgraph::implementation::observed_graph::ObservedGraph;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::implementation::slab_graph::SlabGraph;
+    use traitgraph::implementation::stable_graph::StableGraph;
+    use traitgraph::implementation::subgraphs::incremental_subgraph::IncrementalSubgraph;
+    use traitgraph::interface::subgraph::MutableSubgraph;
+    use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_incremental_connectivity() {
+        let mut graph = PetGraph::<(), ()>::new();
+        let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[1], ());
+
+        let mut connectivity = IncrementalConnectivity::new(&graph);
+        debug_assert!(connectivity.connected(n[1], n[0]));
+        debug_assert!(!connectivity.connected(n[1], n[2]));
+        debug_assert_eq!(connectivity.component_count(), 3);
+
+        let n4 = connectivity.add_node();
+        debug_assert!(connectivity.add_edge(n[3], n4));
+        debug_assert!(connectivity.add_edge(n[2], n[1]));
+        debug_assert!(!connectivity.add_edge(n[0], n[2]));
+        debug_assert_eq!(connectivity.component_count(), 2);
+        debug_assert_eq!(connectivity.component_size(n[0]), 3);
+        debug_assert_eq!(connectivity.component_size(n4), 2);
+        debug_assert!(connectivity.connected(n[0], n[2]));
+        debug_assert!(!connectivity.connected(n[0], n4));
+    }
+
+    #[test]
+    fn test_dynamic_connectivity_as_observer() {
+        let mut graph =
+            ObservedGraph::new(PetGraph::<(), ()>::new(), DynamicConnectivity::default());
+        let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        let e01 = graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[0], ());
+        graph.add_edge(n[2], n[3], ());
+        debug_assert_eq!(graph.observer_mut().component_count(), 1);
+
+        graph.remove_edge(e01);
+        debug_assert_eq!(graph.observer_mut().component_count(), 1);
+
+        // Removing node 2 moves node 3 into its place.
+        graph.remove_node(n[2]);
+        debug_assert_eq!(graph.observer_mut().component_count(), 3);
+        graph.add_edge(n[2], n[1], ());
+        debug_assert!(graph.observer_mut().connected(n[1], n[2]));
+        debug_assert!(!graph.observer_mut().connected(n[0], n[2]));
+        debug_assert_eq!(graph.observer_mut().component_count(), 2);
+    }
+
+    #[test]
+    fn test_dynamic_connectivity_as_observer_of_stable_graph() {
+        let mut graph =
+            ObservedGraph::new(StableGraph::<(), ()>::new(), DynamicConnectivity::default());
+        let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[3], n[3], ());
+        debug_assert_eq!(graph.observer_mut().component_count(), 2);
+
+        // Removing node 1 leaves its index vacant, and does not move node 3.
+        graph.remove_node(n[1]);
+        debug_assert_eq!(graph.observer().node_count(), 3);
+        debug_assert_eq!(graph.observer_mut().component_count(), 3);
+        graph.add_edge(n[2], n[3], ());
+        debug_assert!(graph.observer_mut().connected(n[2], n[3]));
+        debug_assert!(!graph.observer_mut().connected(n[0], n[2]));
+        debug_assert_eq!(graph.observer_mut().component_count(), 2);
+
+        // Removing the last node keeps the node index bound of a stable graph.
+        graph.remove_node(n[3]);
+        debug_assert_eq!(graph.observer().node_count(), 2);
+        debug_assert_eq!(graph.observer_mut().component_count(), 2);
+        let n4 = graph.add_node(());
+        graph.add_edge(n4, n[0], ());
+        debug_assert!(graph.observer_mut().connected(n[0], n4));
+        debug_assert_eq!(graph.observer().node_count(), 3);
+        debug_assert_eq!(graph.observer_mut().component_count(), 2);
+    }
+
+    #[test]
+    fn test_dynamic_connectivity_as_observer_of_slab_graph() {
+        let mut graph =
+            ObservedGraph::new(SlabGraph::<(), ()>::new(), DynamicConnectivity::default());
+        let n: Vec<_> = (0..3).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[2], ());
+        graph.remove_node(n[1]);
+        debug_assert_eq!(graph.observer_mut().component_count(), 1);
+
+        // The vacant index of node 1 is reused.
+        let n3 = graph.add_node(());
+        debug_assert_eq!(n3, n[1]);
+        debug_assert_eq!(graph.observer().node_count(), 3);
+        debug_assert_eq!(graph.observer_mut().component_count(), 2);
+        graph.add_edge(n3, n[2], ());
+        debug_assert_eq!(graph.observer_mut().component_count(), 1);
+    }
+
+    #[test]
+    fn test_incremental_strongly_connected_components() {
+        let mut graph = PetGraph::<(), ()>::new();
+        let n: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+        let mut components = IncrementalStronglyConnectedComponents::new(&graph);
+
+        for (from, to) in [(3, 4), (2, 3), (1, 2), (0, 1)] {
+            graph.add_edge(n[from], n[to], ());
+            debug_assert!(!components.insert_edge(&graph, n[from], n[to]));
+        }
+        debug_assert_eq!(components.topological_order(), n);
+        debug_assert!(components.would_create_cycle(&graph, n[3], n[1]));
+        debug_assert!(!components.would_create_cycle(&graph, n[1], n[3]));
+        debug_assert_eq!(
+            components.insert_edge_acyclic(&graph, n[4], n[0]),
+            Err(AlgorithmError::CyclicGraph { node: n[4] })
+        );
+        debug_assert_eq!(components.component_count(), 5);
+
+        graph.add_edge(n[3], n[1], ());
+        debug_assert!(components.insert_edge(&graph, n[3], n[1]));
+        debug_assert_eq!(components.component_count(), 3);
+        debug_assert!(components.strongly_connected(n[1], n[3]));
+        debug_assert!(components.strongly_connected(n[2], n[3]));
+        debug_assert!(!components.strongly_connected(n[0], n[1]));
+        let mut nodes: Vec<_> = components.component_nodes(n[2]).collect();
+        nodes.sort_unstable();
+        debug_assert_eq!(nodes, [n[1], n[2], n[3]]);
+        debug_assert!(components.precedes(n[0], n[2]));
+        debug_assert!(components.precedes(n[2], n[4]));
+
+        let recomputed = IncrementalStronglyConnectedComponents::new(&graph);
+        debug_assert_eq!(recomputed.component_count(), 3);
+        debug_assert!(recomputed.strongly_connected(n[1], n[3]));
+        debug_assert!(recomputed.precedes(n[0], n[2]));
+        debug_assert!(recomputed.precedes(n[2], n[4]));
+    }
+
+    #[test]
+    fn test_reordering_without_cycle() {
+        let mut graph = PetGraph::<(), ()>::new();
+        let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        let mut components = IncrementalStronglyConnectedComponents::new(&graph);
+        for (from, to) in [(0, 1), (2, 3), (3, 0)] {
+            graph.add_edge(n[from], n[to], ());
+            debug_assert!(components
+                .insert_edge_acyclic(&graph, n[from], n[to])
+                .is_ok());
+        }
+        for (from, to) in [(2, 3), (3, 0), (0, 1)] {
+            debug_assert!(components.precedes(n[from], n[to]));
+        }
+        debug_assert_eq!(components.component_count(), 4);
+    }
+
+    #[test]
+    fn test_incremental_subgraph_steps() {
+        let mut graph = PetGraph::<(), ()>::new();
+        let n: Vec<_> = (0..3).map(|_| graph.add_node(())).collect();
+        let e: Vec<_> = [(0, 1), (1, 2), (2, 0)]
+            .into_iter()
+            .map(|(from, to)| graph.add_edge(n[from], n[to], ()))
+            .collect();
+
+        let mut subgraph = IncrementalSubgraph::new_with_incremental_steps(&graph, 2);
+        n.iter().for_each(|&node| subgraph.enable_node(node));
+        subgraph.enable_edge(e[0]);
+        subgraph.enable_edge(e[1]);
+        subgraph.set_current_step(1);
+        subgraph.enable_edge(e[2]);
+
+        let mut components =
+            IncrementalStronglyConnectedComponents::with_node_count(graph.node_index_bound());
+        subgraph.set_current_step(0);
+        debug_assert!(!components.insert_current_step(&subgraph));
+        debug_assert_eq!(components.component_count(), 3);
+        subgraph.set_current_step(1);
+        debug_assert!(components.insert_current_step(&subgraph));
+        debug_assert_eq!(components.component_count(), 1);
+    }
+
+    #[test]
+    fn test_graph_with_removed_nodes() {
+        let mut graph = StableGraph::<(), ()>::new();
+        let n: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[4], ());
+        graph.add_edge(n[4], n[0], ());
+        graph.add_edge(n[2], n[3], ());
+        graph.remove_node(n[1]);
+        graph.remove_node(n[3]);
+
+        let mut connectivity = IncrementalConnectivity::new(&graph);
+        debug_assert_eq!(connectivity.node_count(), 3);
+        debug_assert_eq!(connectivity.component_count(), 2);
+        debug_assert!(connectivity.connected(n[0], n[4]));
+        let n5 = connectivity.add_node();
+        debug_assert_eq!(n5, graph.add_node(()));
+        debug_assert!(connectivity.add_edge(n[2], n5));
+        debug_assert_eq!(connectivity.component_count(), 2);
+
+        let mut connectivity = DynamicConnectivity::new(&graph);
+        debug_assert_eq!(connectivity.node_count(), 4);
+        debug_assert_eq!(connectivity.component_count(), 3);
+        debug_assert!(connectivity.remove_edge(n[0], n[4]));
+        debug_assert_eq!(connectivity.component_count(), 3);
+        debug_assert!(connectivity.remove_edge(n[4], n[0]));
+        debug_assert_eq!(connectivity.component_count(), 4);
+
+        let mut components = IncrementalStronglyConnectedComponents::new(&graph);
+        debug_assert_eq!(components.component_count(), 3);
+        debug_assert!(components.strongly_connected(n[0], n[4]));
+        debug_assert_eq!(components.topological_order().len(), 3);
+        graph.add_edge(n[4], n[2], ());
+        debug_assert!(!components.insert_edge(&graph, n[4], n[2]));
+        debug_assert!(components.precedes(n[0], n[2]));
+        let n6 = graph.add_node(());
+        debug_assert_eq!(components.add_node(), n6);
+        graph.add_edge(n6, n[0], ());
+        debug_assert!(!components.insert_edge(&graph, n6, n[0]));
+        debug_assert_eq!(components.component_count(), 4);
+        debug_assert!(components.precedes(n6, n[4]));
+    }
+}
@@ -0,0 +1,81 @@
+use thiserror::Error;
+use traitgraph::interface::GraphBase;
+
+/// The errors that can be returned by the algorithms of this crate.
+///
+/// The error is generic over the node and edge index types of the graph the algorithm was run on,
+/// such that it can report the nodes and edges that caused the algorithm to fail.
+/// Use [GraphAlgorithmError] to refer to the error type of a concrete graph.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AlgorithmError<NodeIndex, EdgeIndex> {
+    /// The algorithm requires an acyclic graph, but the graph contains a cycle through the given node.
+    #[error("the graph contains a cycle through node {node:?}")]
+    CyclicGraph {
+        /// A node on a cycle of the graph.
+        node: NodeIndex,
+    },
+
+    /// The algorithm requires an acyclic graph, but the given nodes could not be ordered
+    /// because they lie on a cycle or are reachable from a cycle.
+    #[error(
+        "the graph contains a cycle, the nodes {nodes:?} lie on or are reachable from a cycle"
+    )]
+    CyclicRegion {
+        /// The nodes that lie on a cycle or are reachable from a cycle, ordered by their index.
+        nodes: Vec<NodeIndex>,
+    },
+
+    /// The algorithm requires a graph without negative cycles, but the graph contains the given negative cycle.
+    #[error("the graph contains a negative cycle {cycle:?}")]
+    NegativeCycle {
+        /// The edges of a negative cycle, in the order they are traversed.
+        cycle: Vec<EdgeIndex>,
+    },
+
+    /// The algorithm requires all terminals to be connected, but the given terminals are not connected.
+    #[error("the terminals {source_terminal:?} and {target_terminal:?} are not connected")]
+    DisconnectedTerminals {
+        /// A terminal from which the other terminal cannot be reached.
+        source_terminal: NodeIndex,
+        /// A terminal that cannot be reached from the source terminal.
+        target_terminal: NodeIndex,
+    },
+}
+
+/// The [AlgorithmError] type for the given graph type.
+pub type GraphAlgorithmError<Graph> =
+    AlgorithmError<<Graph as GraphBase>::NodeIndex, <Graph as GraphBase>::EdgeIndex>;
+
+/// The result type of fallible algorithms run on the given graph type.
+pub type AlgorithmResult<T, Graph> = Result<T, GraphAlgorithmError<Graph>>;
+
+#[cfg(test)]
+mod tests {
+    use crate::error::GraphAlgorithmError;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+
+    type Error = GraphAlgorithmError<PetGraph<(), ()>>;
+
+    #[test]
+    fn test_error_messages() {
+        debug_assert_eq!(
+            Error::NegativeCycle {
+                cycle: vec![3.into(), 5.into()],
+            }
+            .to_string(),
+            "the graph contains a negative cycle [3, 5]"
+        );
+        debug_assert_eq!(
+            Error::CyclicGraph { node: 2.into() }.to_string(),
+            "the graph contains a cycle through node 2"
+        );
+        debug_assert_eq!(
+            Error::CyclicRegion {
+                nodes: vec![1.into(), 4.into()],
+            }
+            .to_string(),
+            "the graph contains a cycle, the nodes [1, 4] lie on or are reachable from a cycle"
+        );
+    }
+}
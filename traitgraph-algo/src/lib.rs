@@ -1,15 +1,39 @@
 #![warn(missing_docs)]
 //! This crate offers traits for abstract graph algorithms.
 
+/// The Bellman-Ford shortest path algorithm, which supports negative edge weights.
+pub mod bellman_ford;
 /// Algorithms related to graph components, i.e. finding the strongly or weakly connected components of a graph or checking if a graph is strongly connected.
 pub mod components;
+/// Shortest and longest paths in directed acyclic graphs.
+pub mod dag_paths;
+/// Computing the differences between two graphs.
+pub mod diff;
 /// Dijkstra's shortest path algorithm.
 pub mod dijkstra;
+/// Maintaining the weakly and strongly connected components of a graph while it is being modified.
+pub mod dynamic_connectivity;
+/// The error types returned by fallible algorithms of this crate.
+pub mod error;
 /// Algorithms related to Eulerian graphs.
 pub mod eulerian;
+/// Trees of optimal paths from a source node, as computed by single-source path algorithms.
+pub mod path_tree;
 /// Algorithms to create certain parameterisable graph classes, like binary trees.
 pub mod predefined_graphs;
 /// A trait for bidirected queues to abstract over the different implementations in the standard library.
 pub mod queue;
+/// Helpers for reproducible randomised algorithms.
+pub mod random;
+/// Random walks through graphs, the basis of several sampling methods.
+pub mod random_walk;
+/// Summary statistics of graphs, like the degree distribution.
+pub mod statistics;
+/// Computing topological orders of directed acyclic graphs.
+pub mod topological_sort;
+/// Local transformations of graphs, like merging and splitting nodes.
+pub mod transformation;
 /// Algorithms for graph traversals, i.e. preorder breadth or depth first search as well as postorder depth first search.
 pub mod traversal;
+/// A visitor-based framework for depth-first search algorithms.
+pub mod visitor;
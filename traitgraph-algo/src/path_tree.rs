@@ -0,0 +1,81 @@
+use traitgraph::index::{GraphIndex, OptionalGraphIndex};
+use traitgraph::interface::{GraphBase, ImmutableGraphContainer};
+use traitgraph::walks::VecEdgeWalk;
+
+/// The distances of all nodes from a source node, together with a tree of paths that realise these distances.
+///
+/// This is the result of single-source path algorithms like [bellman_ford](crate::bellman_ford::bellman_ford) or [dag_longest_paths](crate::dag_paths::dag_longest_paths).
+/// Depending on the algorithm, the paths are shortest or longest paths.
+#[derive(Debug, Clone)]
+pub struct PathTree<Graph: GraphBase, WeightType> {
+    source: Graph::NodeIndex,
+    distances: Vec<Option<WeightType>>,
+    back_pointers: Vec<Graph::OptionalEdgeIndex>,
+}
+
+impl<Graph: GraphBase, WeightType> PathTree<Graph, WeightType> {
+    /// Creates a new path tree in which only the source node is reached, with the given distance.
+    pub(crate) fn new(graph: &Graph, source: Graph::NodeIndex, source_distance: WeightType) -> Self
+    where
+        Graph: ImmutableGraphContainer,
+    {
+        let mut distances: Vec<_> = (0..graph.node_index_bound()).map(|_| None).collect();
+        distances[source.as_usize()] = Some(source_distance);
+        Self {
+            source,
+            distances,
+            back_pointers: vec![Graph::OptionalEdgeIndex::new_none(); graph.node_index_bound()],
+        }
+    }
+
+    /// Sets the distance of the given node, and the edge through which it was reached.
+    pub(crate) fn set(
+        &mut self,
+        node: Graph::NodeIndex,
+        distance: WeightType,
+        edge: Graph::EdgeIndex,
+    ) {
+        self.distances[node.as_usize()] = Some(distance);
+        self.back_pointers[node.as_usize()] = edge.into();
+    }
+
+    /// Returns the source node of the paths.
+    pub fn source(&self) -> Graph::NodeIndex {
+        self.source
+    }
+
+    /// Returns the distance of the given node from the source, or `None` if it is not reachable from the source.
+    pub fn distance(&self, node: Graph::NodeIndex) -> Option<&WeightType> {
+        self.distances[node.as_usize()].as_ref()
+    }
+
+    /// Returns the distances of all nodes from the source, indexed by node.
+    /// Nodes that are not reachable from the source have a distance of `None`.
+    pub fn distances(&self) -> &[Option<WeightType>] {
+        &self.distances
+    }
+
+    /// Returns the last edge of the path to the given node,
+    /// or `None` if the node is the source or not reachable from the source.
+    pub fn predecessor_edge(&self, node: Graph::NodeIndex) -> Option<Graph::EdgeIndex> {
+        self.back_pointers[node.as_usize()].into()
+    }
+
+    /// Returns the edges of the path from the source to the given node,
+    /// or `None` if the node is not reachable from the source.
+    /// If the node is the source, then the path is empty.
+    pub fn path_to(&self, graph: &Graph, node: Graph::NodeIndex) -> Option<VecEdgeWalk<Graph>>
+    where
+        Graph: ImmutableGraphContainer,
+    {
+        self.distance(node)?;
+        let mut path = Vec::new();
+        let mut current_node = node;
+        while let Some(edge) = self.predecessor_edge(current_node) {
+            path.push(edge);
+            current_node = graph.edge_endpoints(edge).from_node;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
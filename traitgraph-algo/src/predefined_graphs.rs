@@ -54,6 +54,8 @@ pub fn compute_m_from_n_and_c(n: usize, c: f64) -> usize {
 /// Creates a random hamiltonian graph with the given amount of nodes.
 /// Assumes that the graph is empty.
 /// The amount of arcs will be `c * n * (log(n) + log(log(n)))`, where `n` is the amount of nodes.
+///
+/// The result only depends on the state of `random`, so using a [DeterministicRng](crate::random::DeterministicRng) with a fixed seed creates the same graph on every run.
 pub fn create_random_hamiltonian_graph<Graph: DynamicGraph, Random: Rng>(
     graph: &mut Graph,
     node_amount: usize,
@@ -105,6 +107,8 @@ pub fn create_random_hamiltonian_graph<Graph: DynamicGraph, Random: Rng>(
 /// Creates a random graph with the given amount of nodes.
 /// Assumes that the graph is empty.
 /// The amount of arcs will be `c * n * (log(n) + log(log(n)))`, where `n` is the amount of nodes.
+///
+/// The result only depends on the state of `random`, so using a [DeterministicRng](crate::random::DeterministicRng) with a fixed seed creates the same graph on every run.
 pub fn create_random_graph<Graph: DynamicGraph, Random: Rng>(
     graph: &mut Graph,
     node_amount: usize,
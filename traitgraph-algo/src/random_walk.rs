@@ -0,0 +1,391 @@
+use crate::dijkstra::{DijkstraWeight, DijkstraWeightedEdgeData};
+use rand::seq::IteratorRandom;
+use rand::Rng;
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{GraphBase, Neighbor, StaticGraph};
+
+/// A strategy for choosing the next step of a random walk.
+pub trait RandomWalkTransitions<Graph: GraphBase> {
+    /// Chooses a random out-neighbor of the given node, or returns `None` if the walk cannot continue from the node.
+    fn choose_neighbor<Random: Rng>(
+        &self,
+        graph: &Graph,
+        node: Graph::NodeIndex,
+        random: &mut Random,
+    ) -> Option<Neighbor<Graph::NodeIndex, Graph::EdgeIndex>>;
+}
+
+impl<Graph: GraphBase, Transitions: RandomWalkTransitions<Graph>> RandomWalkTransitions<Graph>
+    for &Transitions
+{
+    fn choose_neighbor<Random: Rng>(
+        &self,
+        graph: &Graph,
+        node: Graph::NodeIndex,
+        random: &mut Random,
+    ) -> Option<Neighbor<Graph::NodeIndex, Graph::EdgeIndex>> {
+        (*self).choose_neighbor(graph, node, random)
+    }
+}
+
+/// Transitions of a random walk that choose each out-neighbor with the same probability.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UniformTransitions;
+
+impl<Graph: StaticGraph> RandomWalkTransitions<Graph> for UniformTransitions {
+    fn choose_neighbor<Random: Rng>(
+        &self,
+        graph: &Graph,
+        node: Graph::NodeIndex,
+        random: &mut Random,
+    ) -> Option<Neighbor<Graph::NodeIndex, Graph::EdgeIndex>> {
+        graph.out_neighbors(node).choose(random)
+    }
+}
+
+/// Transitions of a random walk that choose each out-edge with a probability proportional to its weight.
+///
+/// The transitions are precomputed as an alias table for each node, which takes linear time and space in the size of the graph.
+/// Afterwards, each step of a walk takes constant time, independent of the degree of the current node.
+/// The precomputed transitions are only valid for the graph they were created from, and can be shared by any amount of walks through this graph.
+/// A walk that reaches a node without out-edges or where all out-edges have a weight of zero ends there.
+#[derive(Debug, Clone)]
+pub struct AliasTableTransitions<Graph: GraphBase> {
+    /// The out-neighbors of node `i` are stored at indices `offsets[i]..offsets[i + 1]` of the other arrays.
+    offsets: Vec<usize>,
+    neighbors: Vec<Neighbor<Graph::NodeIndex, Graph::EdgeIndex>>,
+    /// The probability to choose the neighbor itself instead of its alias, once the neighbor was chosen uniformly.
+    probabilities: Vec<f64>,
+    /// The alias of each neighbor, as offset from the first neighbor of the same node.
+    aliases: Vec<usize>,
+}
+
+impl<Graph: StaticGraph> AliasTableTransitions<Graph> {
+    /// Precomputes the transitions for the given graph, using the given function to compute the weight of each edge.
+    ///
+    /// Panics if a weight is negative or not finite.
+    pub fn new(graph: &Graph, weight: impl Fn(Graph::EdgeIndex) -> f64) -> Self {
+        let mut offsets = Vec::with_capacity(graph.node_index_bound() + 1);
+        let mut neighbors = Vec::with_capacity(graph.edge_count());
+        let mut probabilities = Vec::with_capacity(graph.edge_count());
+        let mut aliases = Vec::with_capacity(graph.edge_count());
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+
+        for node_index in 0..graph.node_index_bound() {
+            let node = Graph::NodeIndex::from(node_index);
+            offsets.push(neighbors.len());
+            if !graph.contains_node_index(node) {
+                continue;
+            }
+
+            let offset = neighbors.len();
+            neighbors.extend(graph.out_neighbors(node));
+            let weights = neighbors[offset..].iter().map(|neighbor| {
+                let weight = weight(neighbor.edge_id);
+                assert!(
+                    weight >= 0.0 && weight.is_finite(),
+                    "edge weights must be non-negative and finite"
+                );
+                weight
+            });
+            probabilities.extend(weights);
+            let degree = neighbors.len() - offset;
+            aliases.extend(0..degree);
+
+            let node_probabilities = &mut probabilities[offset..];
+            let node_aliases = &mut aliases[offset..];
+            let total_weight: f64 = node_probabilities.iter().sum();
+            if total_weight == 0.0 {
+                // The walk cannot continue from this node, so it is stored like a node without out-neighbors.
+                neighbors.truncate(offset);
+                probabilities.truncate(offset);
+                aliases.truncate(offset);
+                continue;
+            }
+
+            // Vose's alias method: scale the weights such that their average is one,
+            // and repeatedly fill up a neighbor with a weight below one with the excess of a neighbor with a weight above one.
+            for probability in node_probabilities.iter_mut() {
+                *probability *= degree as f64 / total_weight;
+            }
+            small.clear();
+            large.clear();
+            for (i, probability) in node_probabilities.iter().enumerate() {
+                if *probability < 1.0 {
+                    small.push(i);
+                } else {
+                    large.push(i);
+                }
+            }
+            while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+                small.pop();
+                node_aliases[less] = more;
+                node_probabilities[more] -= 1.0 - node_probabilities[less];
+                if node_probabilities[more] < 1.0 {
+                    large.pop();
+                    small.push(more);
+                }
+            }
+            // Remaining entries are one up to rounding errors.
+            for i in small.drain(..).chain(large.drain(..)) {
+                node_probabilities[i] = 1.0;
+            }
+        }
+        offsets.push(neighbors.len());
+
+        Self {
+            offsets,
+            neighbors,
+            probabilities,
+            aliases,
+        }
+    }
+
+    /// Precomputes the transitions for the given graph, using the weights of the edge data as used by [Dijkstra](crate::dijkstra::Dijkstra).
+    ///
+    /// Weights of types that cannot be converted into `f64` losslessly, like `u64`, can be used via [new](Self::new) with an explicit conversion.
+    pub fn from_edge_data<WeightType: DijkstraWeight + Into<f64>>(graph: &Graph) -> Self
+    where
+        Graph::EdgeData: DijkstraWeightedEdgeData<WeightType>,
+    {
+        Self::new(graph, |edge| graph.edge_data(edge).weight().into())
+    }
+}
+
+impl<Graph: StaticGraph> RandomWalkTransitions<Graph> for AliasTableTransitions<Graph> {
+    fn choose_neighbor<Random: Rng>(
+        &self,
+        graph: &Graph,
+        node: Graph::NodeIndex,
+        random: &mut Random,
+    ) -> Option<Neighbor<Graph::NodeIndex, Graph::EdgeIndex>> {
+        debug_assert_eq!(self.offsets.len(), graph.node_index_bound() + 1);
+        let offset = self.offsets[node.as_usize()];
+        let limit = self.offsets[node.as_usize() + 1];
+        if offset == limit {
+            return None;
+        }
+
+        let i = offset + random.random_range(0..limit - offset);
+        let i = if random.random::<f64>() < self.probabilities[i] {
+            i
+        } else {
+            offset + self.aliases[i]
+        };
+        Some(self.neighbors[i].clone())
+    }
+}
+
+/// A random walk through a graph, starting from a fixed node and moving to a random out-neighbor in each step.
+///
+/// By default, each out-neighbor is chosen with the same probability.
+/// Other transition probabilities can be used via [new_with_transitions](Self::new_with_transitions),
+/// e.g. [AliasTableTransitions] for probabilities proportional to edge weights.
+///
+/// The iterator returns the start node first, followed by the node reached in each step.
+/// The walk ends when it reaches a node from which the transitions cannot continue, e.g. because it has no out-neighbors, or after the maximum amount of steps set with [with_max_length](Self::with_max_length).
+/// Without a maximum length, the walk on a graph where each node has an out-neighbor never ends.
+/// Optionally, the walk jumps back to the start node with a fixed probability instead of making a step, see [with_restart_probability](Self::with_restart_probability).
+/// A jump counts as a step.
+///
+/// The walk only depends on the state of `random`, so using a [DeterministicRng](crate::random::DeterministicRng) with a fixed seed creates the same walk on every run.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{MutableGraphContainer, NavigableGraph};
+/// use traitgraph_algo::random::deterministic_rng;
+/// use traitgraph_algo::random_walk::RandomWalk;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[0], nodes[1], ());
+/// graph.add_edge(nodes[0], nodes[2], ());
+/// graph.add_edge(nodes[1], nodes[0], ());
+/// graph.add_edge(nodes[2], nodes[0], ());
+///
+/// let walk: Vec<_> = RandomWalk::new(&graph, nodes[0], deterministic_rng(0))
+///     .with_max_length(10)
+///     .collect();
+/// assert_eq!(walk.len(), 11);
+/// assert_eq!(walk[0], nodes[0]);
+/// for step in walk.windows(2) {
+///     assert!(graph.contains_edge_between(step[0], step[1]));
+/// }
+/// ```
+pub struct RandomWalk<'a, Graph: GraphBase, Random, Transitions = UniformTransitions> {
+    graph: &'a Graph,
+    random: Random,
+    transitions: Transitions,
+    start: Graph::NodeIndex,
+    /// The node returned last, or `None` if the start node was not returned yet.
+    current_node: Option<Graph::NodeIndex>,
+    remaining_length: Option<usize>,
+    restart_probability: f64,
+}
+
+impl<'a, Graph: StaticGraph, Random: Rng> RandomWalk<'a, Graph, Random> {
+    /// Creates a new unbounded random walk without restarts, starting from the given node.
+    pub fn new(graph: &'a Graph, start: Graph::NodeIndex, random: Random) -> Self {
+        Self::new_with_transitions(graph, start, UniformTransitions, random)
+    }
+}
+
+impl<'a, Graph: StaticGraph, Random: Rng, Transitions: RandomWalkTransitions<Graph>>
+    RandomWalk<'a, Graph, Random, Transitions>
+{
+    /// Creates a new unbounded random walk without restarts, starting from the given node and choosing steps with the given transitions.
+    pub fn new_with_transitions(
+        graph: &'a Graph,
+        start: Graph::NodeIndex,
+        transitions: Transitions,
+        random: Random,
+    ) -> Self {
+        Self {
+            graph,
+            random,
+            transitions,
+            start,
+            current_node: None,
+            remaining_length: None,
+            restart_probability: 0.0,
+        }
+    }
+
+    /// Limits the walk to the given amount of steps.
+    /// The walk then returns at most `max_length + 1` nodes, including the start node.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.remaining_length = Some(max_length);
+        self
+    }
+
+    /// Makes the walk jump back to the start node with the given probability in each step.
+    ///
+    /// Panics if the probability is not between zero and one.
+    pub fn with_restart_probability(mut self, restart_probability: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&restart_probability),
+            "the restart probability must be between zero and one"
+        );
+        self.restart_probability = restart_probability;
+        self
+    }
+}
+
+impl<Graph: StaticGraph, Random: Rng, Transitions: RandomWalkTransitions<Graph>> Iterator
+    for RandomWalk<'_, Graph, Random, Transitions>
+{
+    type Item = Graph::NodeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(current_node) = self.current_node else {
+            self.current_node = Some(self.start);
+            return self.current_node;
+        };
+
+        if let Some(remaining_length) = &mut self.remaining_length {
+            if *remaining_length == 0 {
+                return None;
+            }
+            *remaining_length -= 1;
+        }
+
+        let next_node = if self.restart_probability > 0.0
+            && self.random.random_bool(self.restart_probability)
+        {
+            self.start
+        } else {
+            let neighbor =
+                self.transitions
+                    .choose_neighbor(self.graph, current_node, &mut self.random);
+            if neighbor.is_none() {
+                self.remaining_length = Some(0);
+            }
+            neighbor?.node_id
+        };
+
+        self.current_node = Some(next_node);
+        self.current_node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::deterministic_rng;
+    use crate::random_walk::{AliasTableTransitions, RandomWalk};
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::index::GraphIndex;
+    use traitgraph::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_dead_end_and_restart() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+
+        let walk: Vec<_> = RandomWalk::new(&graph, n[0], deterministic_rng(0)).collect();
+        assert_eq!(walk, n);
+
+        let walk: Vec<_> = RandomWalk::new(&graph, n[0], deterministic_rng(0))
+            .with_restart_probability(1.0)
+            .with_max_length(4)
+            .collect();
+        assert_eq!(walk, [n[0]; 5]);
+
+        let walk: Vec<_> = RandomWalk::new(&graph, n[1], deterministic_rng(0))
+            .with_max_length(0)
+            .collect();
+        assert_eq!(walk, [n[1]]);
+
+        let mut seen_restart = false;
+        let walk: Vec<_> = RandomWalk::new(&graph, n[0], deterministic_rng(3))
+            .with_restart_probability(0.5)
+            .with_max_length(100)
+            .collect();
+        for step in walk.windows(2) {
+            match step {
+                [_, next] if *next == n[0] => seen_restart = true,
+                [previous, next] => assert_eq!(next.as_usize(), previous.as_usize() + 1),
+                _ => unreachable!(),
+            }
+        }
+        assert!(seen_restart);
+    }
+
+    #[test]
+    fn test_weighted_transitions() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        for (i, weight) in [1u32, 0, 3, 6].into_iter().enumerate() {
+            graph.add_edge(n[0], n[i + 1], weight);
+            graph.add_edge(n[i + 1], n[0], 1);
+        }
+        let transitions = AliasTableTransitions::from_edge_data(&graph);
+
+        let mut counts = [0; 5];
+        for node in
+            RandomWalk::new_with_transitions(&graph, n[0], &transitions, deterministic_rng(0))
+                .with_max_length(20000)
+        {
+            counts[node.as_usize()] += 1;
+        }
+        assert_eq!(counts[0], 10001);
+        assert_eq!(counts[2], 0);
+        // The expected counts are 1000, 3000 and 6000.
+        assert!((900..1100).contains(&counts[1]), "{counts:?}");
+        assert!((2800..3200).contains(&counts[3]), "{counts:?}");
+        assert!((5700..6300).contains(&counts[4]), "{counts:?}");
+
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..2).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 0u32);
+        let transitions = AliasTableTransitions::from_edge_data(&graph);
+        let walk: Vec<_> =
+            RandomWalk::new_with_transitions(&graph, n[0], transitions, deterministic_rng(0))
+                .collect();
+        assert_eq!(walk, [n[0]]);
+    }
+}
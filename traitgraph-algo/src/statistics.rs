@@ -0,0 +1,131 @@
+use traitgraph::interface::StaticGraph;
+
+/// Summary statistics of a graph, as computed by [GraphStatistics::compute].
+///
+/// The degree of a node is the sum of its in- and out-degree, so self-loops count twice towards the degree of their node.
+/// Hence the sum of all degrees is twice the amount of edges.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphStatistics {
+    /// The amount of nodes in the graph.
+    pub node_count: usize,
+    /// The amount of edges in the graph.
+    pub edge_count: usize,
+    /// The amount of nodes with each degree, i.e. `degree_distribution[d]` is the amount of nodes with degree `d`.
+    ///
+    /// The last entry is the amount of nodes with the maximum degree, and the vector is empty if the graph has no nodes.
+    pub degree_distribution: Vec<usize>,
+    /// The minimum degree of a node, or zero if the graph has no nodes.
+    pub min_degree: usize,
+    /// The maximum degree of a node, or zero if the graph has no nodes.
+    pub max_degree: usize,
+    /// The mean degree of the nodes, or zero if the graph has no nodes.
+    pub mean_degree: f64,
+    /// The amount of edges whose tail and head are the same node.
+    pub self_loop_count: usize,
+    /// The amount of edges that are parallel to another edge with a lower index,
+    /// i.e. the amount of edges that need to be removed to make the graph free of parallel edges.
+    pub parallel_edge_count: usize,
+    /// The amount of nodes without incoming edges.
+    pub source_count: usize,
+    /// The amount of nodes without outgoing edges.
+    pub sink_count: usize,
+}
+
+impl GraphStatistics {
+    /// Computes the statistics of the given graph.
+    ///
+    /// Isolated nodes count both as sources and as sinks.
+    pub fn compute<Graph: StaticGraph>(graph: &Graph) -> Self {
+        let mut degree_distribution = Vec::new();
+        let mut source_count = 0;
+        let mut sink_count = 0;
+
+        for node_id in graph.node_indices() {
+            let in_degree = graph.in_degree(node_id);
+            let out_degree = graph.out_degree(node_id);
+            let degree = in_degree + out_degree;
+            if degree >= degree_distribution.len() {
+                degree_distribution.resize(degree + 1, 0);
+            }
+            degree_distribution[degree] += 1;
+
+            if in_degree == 0 {
+                source_count += 1;
+            }
+            if out_degree == 0 {
+                sink_count += 1;
+            }
+        }
+
+        let node_count = graph.node_count();
+        let edge_count = graph.edge_count();
+        let min_degree = degree_distribution
+            .iter()
+            .position(|&count| count > 0)
+            .unwrap_or(0);
+        let max_degree = degree_distribution.len().saturating_sub(1);
+        let mean_degree = if node_count == 0 {
+            0.0
+        } else {
+            (2 * edge_count) as f64 / node_count as f64
+        };
+
+        Self {
+            node_count,
+            edge_count,
+            degree_distribution,
+            min_degree,
+            max_degree,
+            mean_degree,
+            self_loop_count: graph.self_loops().count(),
+            parallel_edge_count: graph
+                .parallel_edge_groups()
+                .iter()
+                .map(|group| group.len() - 1)
+                .sum(),
+            source_count,
+            sink_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphStatistics;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_statistics() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[2], ());
+        graph.add_edge(n[2], n[2], ());
+
+        let statistics = GraphStatistics::compute(&graph);
+        debug_assert_eq!(
+            statistics,
+            GraphStatistics {
+                node_count: 5,
+                edge_count: 6,
+                degree_distribution: vec![2, 0, 0, 1, 1, 1],
+                min_degree: 0,
+                max_degree: 5,
+                mean_degree: 2.4,
+                self_loop_count: 2,
+                parallel_edge_count: 3,
+                source_count: 3,
+                sink_count: 2,
+            }
+        );
+
+        let statistics = GraphStatistics::compute(&PetGraph::<(), ()>::new());
+        debug_assert_eq!(statistics.degree_distribution, Vec::<usize>::new());
+        debug_assert_eq!(statistics.max_degree, 0);
+        debug_assert_eq!(statistics.mean_degree, 0.0);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::error::{AlgorithmError, AlgorithmResult};
+use crate::visitor::{depth_first_visit, Control, DfsVisitor};
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{GraphBase, StaticGraph};
+
+/// Computes a topological order of the nodes of the given graph using a depth-first search,
+/// i.e. an order in which each node comes before all nodes it has an edge to.
+///
+/// Returns [AlgorithmError::CyclicGraph] with a node on a cycle if the graph is not acyclic.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::error::AlgorithmError;
+/// use traitgraph_algo::topological_sort::topological_sort;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[2], nodes[0], ());
+/// graph.add_edge(nodes[0], nodes[1], ());
+/// assert_eq!(topological_sort(&graph), Ok(vec![nodes[2], nodes[0], nodes[1]]));
+///
+/// graph.add_edge(nodes[1], nodes[2], ());
+/// assert!(matches!(
+///     topological_sort(&graph),
+///     Err(AlgorithmError::CyclicGraph { .. })
+/// ));
+/// ```
+pub fn topological_sort<Graph: StaticGraph>(
+    graph: &Graph,
+) -> AlgorithmResult<Vec<Graph::NodeIndex>, Graph> {
+    let mut visitor = TopologicalSortVisitor::<Graph> {
+        postorder: Vec::with_capacity(graph.node_count()),
+        cycle_node: None,
+    };
+    depth_first_visit(graph, graph.node_indices(), &mut visitor);
+
+    if let Some(node) = visitor.cycle_node {
+        Err(AlgorithmError::CyclicGraph { node })
+    } else {
+        let mut order = visitor.postorder;
+        order.reverse();
+        Ok(order)
+    }
+}
+
+struct TopologicalSortVisitor<Graph: GraphBase> {
+    postorder: Vec<Graph::NodeIndex>,
+    cycle_node: Option<Graph::NodeIndex>,
+}
+
+impl<Graph: GraphBase> DfsVisitor<Graph> for TopologicalSortVisitor<Graph> {
+    fn back_edge(
+        &mut self,
+        _edge: Graph::EdgeIndex,
+        _from: Graph::NodeIndex,
+        to: Graph::NodeIndex,
+    ) -> Control {
+        self.cycle_node = Some(to);
+        Control::Break
+    }
+
+    fn finish_node(&mut self, node: Graph::NodeIndex) -> Control {
+        self.postorder.push(node);
+        Control::Continue
+    }
+}
+
+/// Computes a topological order of the nodes of the given graph using Kahn's algorithm,
+/// i.e. by repeatedly removing a node without incoming edges.
+///
+/// In contrast to [topological_sort], if the graph is not acyclic,
+/// this returns [AlgorithmError::CyclicRegion] with all nodes that could not be ordered.
+/// These are the nodes that lie on a cycle or are reachable from a cycle.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::error::AlgorithmError;
+/// use traitgraph_algo::topological_sort::kahn_topological_sort;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[0], nodes[1], ());
+/// graph.add_edge(nodes[1], nodes[2], ());
+/// graph.add_edge(nodes[2], nodes[1], ());
+/// graph.add_edge(nodes[2], nodes[3], ());
+///
+/// assert_eq!(
+///     kahn_topological_sort(&graph),
+///     Err(AlgorithmError::CyclicRegion {
+///         nodes: vec![nodes[1], nodes[2], nodes[3]]
+///     })
+/// );
+/// ```
+pub fn kahn_topological_sort<Graph: StaticGraph>(
+    graph: &Graph,
+) -> AlgorithmResult<Vec<Graph::NodeIndex>, Graph> {
+    let mut iterator = TopologicalSortIterator::new(graph);
+    let mut order = Vec::with_capacity(graph.node_count());
+    for node in iterator.by_ref() {
+        match node {
+            Ok(node) => order.push(node),
+            Err(_) => {
+                return Err(AlgorithmError::CyclicRegion {
+                    nodes: iterator.remaining_nodes().collect(),
+                })
+            }
+        }
+    }
+    Ok(order)
+}
+
+/// An iterator over the nodes of a graph in topological order, computed lazily with Kahn's algorithm.
+///
+/// Each node is returned once all its predecessors were returned.
+/// If the graph contains a cycle, then the iterator returns all nodes that are not reachable from a cycle,
+/// followed by a single [AlgorithmError::CyclicGraph] carrying a node on a cycle.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::error::AlgorithmError;
+/// use traitgraph_algo::topological_sort::TopologicalSortIterator;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[0], nodes[1], ());
+/// graph.add_edge(nodes[1], nodes[2], ());
+/// graph.add_edge(nodes[2], nodes[1], ());
+///
+/// let order: Vec<_> = TopologicalSortIterator::new(&graph).collect();
+/// assert_eq!(order[..2], [Ok(nodes[0]), Ok(nodes[3])]);
+/// assert!(matches!(order[2], Err(AlgorithmError::CyclicGraph { .. })));
+/// assert_eq!(order.len(), 3);
+/// ```
+pub struct TopologicalSortIterator<'a, Graph: GraphBase> {
+    graph: &'a Graph,
+    remaining_in_degrees: Vec<usize>,
+    /// The nodes whose predecessors have all been returned, used as a stack.
+    ready_nodes: Vec<Graph::NodeIndex>,
+    returned_node_count: usize,
+    finished: bool,
+}
+
+impl<'a, Graph: StaticGraph> TopologicalSortIterator<'a, Graph> {
+    /// Creates a new iterator over the nodes of the given graph in topological order.
+    pub fn new(graph: &'a Graph) -> Self {
+        let mut remaining_in_degrees = vec![0; graph.node_index_bound()];
+        for node in graph.node_indices() {
+            remaining_in_degrees[node.as_usize()] = graph.in_degree(node);
+        }
+        let mut ready_nodes: Vec<_> = graph
+            .node_indices()
+            .filter(|node| remaining_in_degrees[node.as_usize()] == 0)
+            .collect();
+        ready_nodes.reverse();
+
+        Self {
+            graph,
+            remaining_in_degrees,
+            ready_nodes,
+            returned_node_count: 0,
+            finished: false,
+        }
+    }
+
+    /// Returns the nodes that have a predecessor that was not returned yet, ordered by their index.
+    ///
+    /// After the iterator returned an error, these are exactly the nodes that lie on a cycle or are reachable from a cycle.
+    pub fn remaining_nodes(&self) -> impl '_ + Iterator<Item = Graph::NodeIndex> {
+        self.graph
+            .node_indices()
+            .filter(|node| self.remaining_in_degrees[node.as_usize()] > 0)
+    }
+
+    /// Returns a node on a cycle of the nodes that were not returned yet.
+    /// Must only be called if no node is ready but not all nodes were returned.
+    fn find_cycle_node(&self) -> Graph::NodeIndex {
+        let mut node = self
+            .remaining_nodes()
+            .next()
+            .expect("all nodes were returned");
+        let mut visited = vec![false; self.graph.node_index_bound()];
+
+        // Each node that was not returned has a predecessor that was not returned,
+        // so walking backwards eventually repeats a node, which lies on a cycle.
+        while !visited[node.as_usize()] {
+            visited[node.as_usize()] = true;
+            node = self
+                .graph
+                .in_neighbors(node)
+                .map(|neighbor| neighbor.node_id)
+                .find(|predecessor| self.remaining_in_degrees[predecessor.as_usize()] > 0)
+                .expect("a node that was not returned has no predecessor that was not returned");
+        }
+
+        node
+    }
+}
+
+impl<Graph: StaticGraph> Iterator for TopologicalSortIterator<'_, Graph> {
+    type Item = AlgorithmResult<Graph::NodeIndex, Graph>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        if let Some(node) = self.ready_nodes.pop() {
+            self.returned_node_count += 1;
+            for neighbor in self.graph.out_neighbors(node) {
+                let in_degree = &mut self.remaining_in_degrees[neighbor.node_id.as_usize()];
+                *in_degree -= 1;
+                if *in_degree == 0 {
+                    self.ready_nodes.push(neighbor.node_id);
+                }
+            }
+            Some(Ok(node))
+        } else {
+            self.finished = true;
+            if self.returned_node_count < self.graph.node_count() {
+                Some(Err(AlgorithmError::CyclicGraph {
+                    node: self.find_cycle_node(),
+                }))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AlgorithmError;
+    use crate::topological_sort::{
+        kahn_topological_sort, topological_sort, TopologicalSortIterator,
+    };
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::index::GraphIndex;
+    use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    fn assert_topological<Graph: ImmutableGraphContainer + NavigableGraph>(
+        graph: &Graph,
+        order: &[Graph::NodeIndex],
+    ) {
+        assert_eq!(order.len(), graph.node_count());
+        let mut position = vec![usize::MAX; graph.node_index_bound()];
+        for (i, node) in order.iter().enumerate() {
+            position[node.as_usize()] = i;
+        }
+        for edge in graph.edge_indices() {
+            let endpoints = graph.edge_endpoints(edge);
+            assert!(
+                position[endpoints.from_node.as_usize()] < position[endpoints.to_node.as_usize()]
+            );
+        }
+    }
+
+    #[test]
+    fn test_dag_and_cycle() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)] {
+            graph.add_edge(n[from], n[to], ());
+        }
+
+        let order = topological_sort(&graph).unwrap();
+        assert_topological(&graph, &order);
+        let order: Vec<_> = TopologicalSortIterator::new(&graph)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_topological(&graph, &order);
+        let order = kahn_topological_sort(&graph).unwrap();
+        assert_topological(&graph, &order);
+
+        graph.add_edge(n[1], n[2], ());
+        let expected_cycle = [n[1], n[2], n[3]];
+        let Err(AlgorithmError::CyclicGraph { node }) = topological_sort(&graph) else {
+            panic!("cycle not detected");
+        };
+        assert!(expected_cycle.contains(&node));
+
+        let mut iterator = TopologicalSortIterator::new(&graph);
+        let order: Vec<_> = iterator.by_ref().map_while(Result::ok).collect();
+        assert_eq!(order, [n[4], n[5], n[0]]);
+        assert_eq!(iterator.next(), None);
+        let Some(Err(AlgorithmError::CyclicGraph { node })) =
+            TopologicalSortIterator::new(&graph).nth(3)
+        else {
+            panic!("cycle not detected");
+        };
+        assert!(expected_cycle.contains(&node));
+        assert_eq!(
+            kahn_topological_sort(&graph),
+            Err(AlgorithmError::CyclicRegion {
+                nodes: expected_cycle.to_vec()
+            })
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use traitgraph::interface::{DynamicGraph, Edge};
+
+/// Merges the `victims` into the `survivor`, i.e. moves all edges incident to the victims onto the survivor and removes the victims.
+///
+/// The data of each victim is combined into the data of the survivor using `combine`, in ascending order of the victims' indices.
+/// All moved edges are kept, so edges may become parallel to existing edges,
+/// and edges between the survivor and a victim or between two victims become self-loops of the survivor.
+/// The moved edges are re-added in ascending order of their original indices, with each edge added exactly once.
+///
+/// Since removing nodes and edges may change the indices of the remaining ones, this returns the index of the survivor after merging.
+/// Moves of nodes are tracked assuming that the graph either moves the node with the highest index into the place of a removed node,
+/// or does not change the indices of the remaining nodes at all.
+///
+/// Panics if the survivor is among the victims.
+pub fn merge_nodes<Graph: DynamicGraph>(
+    graph: &mut Graph,
+    survivor: Graph::NodeIndex,
+    victims: &[Graph::NodeIndex],
+    mut combine: impl FnMut(&mut Graph::NodeData, Graph::NodeData),
+) -> Graph::NodeIndex {
+    let mut victims = victims.to_vec();
+    victims.sort_unstable();
+    victims.dedup();
+    assert!(
+        victims.binary_search(&survivor).is_err(),
+        "the survivor cannot be merged into itself"
+    );
+
+    let mut edge_ids = Vec::new();
+    for &victim in &victims {
+        edge_ids.extend(graph.out_neighbors(victim).map(|neighbor| neighbor.edge_id));
+        edge_ids.extend(graph.in_neighbors(victim).map(|neighbor| neighbor.edge_id));
+    }
+    edge_ids.sort_unstable();
+    edge_ids.dedup();
+
+    // Remove in descending order, such that the indices of the edges still to be removed stay valid.
+    // Endpoints that are merged into the survivor are stored as `None`.
+    let merged = |node_id: Graph::NodeIndex| {
+        (node_id != survivor && victims.binary_search(&node_id).is_err()).then_some(node_id)
+    };
+    let mut edges: Vec<_> = edge_ids
+        .iter()
+        .rev()
+        .map(|&edge_id| {
+            let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+            let edge_data = graph.remove_edge(edge_id).unwrap();
+            (merged(from_node), merged(to_node), edge_data)
+        })
+        .collect();
+    edges.reverse();
+
+    let mut survivor = survivor;
+    let mut victim_data = Vec::with_capacity(victims.len());
+    for &victim in victims.iter().rev() {
+        let last_node_id = graph.node_indices().last().unwrap();
+        victim_data.push(graph.remove_node(victim).unwrap());
+
+        // The last node may have been moved into the place of the removed node.
+        if last_node_id != victim
+            && graph.contains_node_index(victim)
+            && !graph.contains_node_index(last_node_id)
+        {
+            if survivor == last_node_id {
+                survivor = victim;
+            }
+            for node_id in edges
+                .iter_mut()
+                .flat_map(|(from_node, to_node, _)| [from_node, to_node])
+                .flatten()
+            {
+                if *node_id == last_node_id {
+                    *node_id = victim;
+                }
+            }
+        }
+    }
+
+    for node_data in victim_data.into_iter().rev() {
+        combine(graph.node_data_mut(survivor), node_data);
+    }
+
+    for (from_node, to_node, edge_data) in edges {
+        graph.add_edge(
+            from_node.unwrap_or(survivor),
+            to_node.unwrap_or(survivor),
+            edge_data,
+        );
+    }
+
+    survivor
+}
+
+/// Splits the given node into two, by adding a new node with `new_node_data` and moving some of the edges incident to the given node onto it.
+///
+/// An incident edge is moved if `move_edge` returns true for it.
+/// The callback is invoked once for each incident edge on the unmodified graph.
+/// When a self-loop is moved, both of its endpoints are moved to the new node.
+/// The moved edges are re-added in ascending order of their original indices.
+/// If `connecting_edge_data` is given, then an edge from the given node to the new node is added afterwards.
+///
+/// Returns the index of the new node.
+/// The index of the given node stays the same, but the indices of other edges may change as by [`remove_edge`](traitgraph::interface::MutableGraphContainer::remove_edge).
+pub fn split_node<Graph: DynamicGraph>(
+    graph: &mut Graph,
+    node_id: Graph::NodeIndex,
+    new_node_data: Graph::NodeData,
+    mut move_edge: impl FnMut(&Graph, Graph::EdgeIndex) -> bool,
+    connecting_edge_data: Option<Graph::EdgeData>,
+) -> Graph::NodeIndex {
+    let mut edge_ids: Vec<_> = graph
+        .out_neighbors(node_id)
+        .chain(graph.in_neighbors(node_id))
+        .map(|neighbor| neighbor.edge_id)
+        .collect();
+    edge_ids.sort_unstable();
+    edge_ids.dedup();
+    edge_ids.retain(|&edge_id| move_edge(graph, edge_id));
+
+    // Remove in descending order, such that the indices of the edges still to be removed stay valid.
+    let mut edges: Vec<_> = edge_ids
+        .iter()
+        .rev()
+        .map(|&edge_id| {
+            let endpoints = graph.edge_endpoints(edge_id);
+            (endpoints, graph.remove_edge(edge_id).unwrap())
+        })
+        .collect();
+    edges.reverse();
+
+    let new_node_id = graph.add_node(new_node_data);
+    let replace = |endpoint| {
+        if endpoint == node_id {
+            new_node_id
+        } else {
+            endpoint
+        }
+    };
+    for (Edge { from_node, to_node }, edge_data) in edges {
+        graph.add_edge(replace(from_node), replace(to_node), edge_data);
+    }
+    if let Some(edge_data) = connecting_edge_data {
+        graph.add_edge(node_id, new_node_id, edge_data);
+    }
+
+    new_node_id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_nodes, split_node};
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_merge_nodes() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = ["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(|data| graph.add_node(data.to_string()))
+            .collect();
+        graph.add_edge(n[0], n[1], 0);
+        graph.add_edge(n[1], n[4], 1);
+        graph.add_edge(n[2], n[1], 2);
+        graph.add_edge(n[3], n[1], 3);
+        graph.add_edge(n[3], n[3], 4);
+        graph.add_edge(n[4], n[2], 5);
+        graph.add_edge(n[0], n[2], 6);
+
+        let survivor = merge_nodes(&mut graph, n[4], &[n[3], n[1]], |survivor, victim| {
+            survivor.push_str(&victim)
+        });
+        debug_assert_eq!(graph.node_count(), 3);
+        debug_assert_eq!(graph.edge_count(), 7);
+        debug_assert_eq!(graph.node_data(survivor), "ebd");
+
+        let node = |data: &str| {
+            graph
+                .node_indices()
+                .find(|&node_id| graph.node_data(node_id) == data)
+                .unwrap()
+        };
+        let (a, c) = (node("a"), node("c"));
+        debug_assert_eq!(graph.edge_count_between(survivor, survivor), 3);
+        debug_assert_eq!(graph.edge_count_between(a, survivor), 1);
+        debug_assert_eq!(graph.edge_count_between(c, survivor), 1);
+        debug_assert_eq!(graph.edge_count_between(survivor, c), 1);
+        debug_assert_eq!(graph.edge_count_between(a, c), 1);
+
+        // The moved edges are re-added in their original order.
+        let moved_edges: Vec<_> = graph
+            .edge_indices()
+            .skip(2)
+            .map(|edge_id| *graph.edge_data(edge_id))
+            .collect();
+        debug_assert_eq!(moved_edges, [0, 1, 2, 3, 4]);
+
+        // c is moved into the place of b.
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[2], n[1], ());
+        let survivor = merge_nodes(&mut graph, n[0], &[n[1]], |survivor, victim| {
+            *survivor += victim
+        });
+        debug_assert_eq!(survivor, n[0]);
+        debug_assert_eq!(*graph.node_data(n[1]), 2);
+        debug_assert!(graph.contains_edge_between(n[1], n[0]));
+    }
+
+    #[test]
+    fn test_split_node() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 'a');
+        graph.add_edge(n[2], n[1], 'b');
+        graph.add_edge(n[1], n[3], 'c');
+        graph.add_edge(n[1], n[1], 'd');
+        graph.add_edge(n[1], n[2], 'e');
+
+        // Move all outgoing edges, including the self-loop, to the new node.
+        let split = split_node(
+            &mut graph,
+            n[1],
+            10,
+            |graph, edge_id| graph.edge_endpoints(edge_id).from_node == n[1],
+            Some('f'),
+        );
+        debug_assert_eq!(graph.node_count(), 5);
+        debug_assert_eq!(graph.edge_count(), 6);
+        debug_assert_eq!(*graph.node_data(split), 10);
+        debug_assert_eq!(graph.in_degree(n[1]), 2);
+        debug_assert_eq!(graph.out_degree(n[1]), 1);
+        debug_assert!(graph.contains_edge_between(n[1], split));
+        debug_assert!(graph.contains_edge_between(split, n[3]));
+        debug_assert!(graph.contains_edge_between(split, n[2]));
+        debug_assert!(graph.contains_edge_between(split, split));
+        debug_assert_eq!(
+            graph
+                .edge_indices()
+                .map(|edge_id| *graph.edge_data(edge_id))
+                .collect::<String>(),
+            "abcdef"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_merge_into_itself() {
+        let mut graph = PetGraph::<_, ()>::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        merge_nodes(&mut graph, a, &[b, a], |_, _| {});
+    }
+}
@@ -0,0 +1,153 @@
+use crate::traversal::{ForwardNeighborStrategy, TraversalNeighborStrategy};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::marker::PhantomData;
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{GraphBase, StaticGraph};
+
+/// A forward best-first search in a directed graph.
+pub type ForwardBestFirstSearch<'a, Graph, Priority, PriorityFunction> =
+    BestFirstSearch<'a, Graph, ForwardNeighborStrategy, Priority, PriorityFunction>;
+
+/// A best-first search, i.e. a traversal that always continues with the discovered node of minimum priority.
+///
+/// The priority of each node is computed once by the priority function when the node is discovered.
+/// Nodes of equal priority are returned in the order of their indices.
+/// To return nodes of maximum priority first, wrap the priority in [Reverse].
+/// The direction of the search is defined by the `NeighborStrategy`.
+///
+/// In contrast to [Dijkstra](crate::dijkstra::Dijkstra), the priority of a node does not depend on the path through which it was discovered.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+/// use traitgraph_algo::traversal::best_first::ForwardBestFirstSearch;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = [0, 5, 3, 1].into_iter().map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[0], nodes[1], ());
+/// graph.add_edge(nodes[0], nodes[2], ());
+/// graph.add_edge(nodes[2], nodes[3], ());
+///
+/// let order: Vec<_> = ForwardBestFirstSearch::new(&graph, nodes[0], |graph: &PetGraph<_, _>, node| {
+///     *graph.node_data(node)
+/// })
+/// .map(|node| *graph.node_data(node))
+/// .collect();
+/// assert_eq!(order, [0, 3, 1, 5]);
+/// ```
+pub struct BestFirstSearch<'a, Graph: GraphBase, NeighborStrategy, Priority, PriorityFunction> {
+    graph: &'a Graph,
+    priority_function: PriorityFunction,
+    queue: BinaryHeap<Reverse<(Priority, Graph::NodeIndex)>>,
+    discovered: Vec<bool>,
+    neighbor_strategy: PhantomData<NeighborStrategy>,
+}
+
+impl<
+        'a,
+        Graph: StaticGraph,
+        NeighborStrategy: TraversalNeighborStrategy<Graph>,
+        Priority: Ord,
+        PriorityFunction: Fn(&Graph, Graph::NodeIndex) -> Priority,
+    > BestFirstSearch<'a, Graph, NeighborStrategy, Priority, PriorityFunction>
+{
+    /// Creates a new best-first search that operates on the given graph starting from the given node.
+    pub fn new(
+        graph: &'a Graph,
+        start: Graph::NodeIndex,
+        priority_function: PriorityFunction,
+    ) -> Self {
+        Self::new_with_starts(graph, [start], priority_function)
+    }
+
+    /// Creates a new best-first search that operates on the given graph starting from all given nodes.
+    pub fn new_with_starts(
+        graph: &'a Graph,
+        starts: impl IntoIterator<Item = Graph::NodeIndex>,
+        priority_function: PriorityFunction,
+    ) -> Self {
+        let mut result = Self {
+            graph,
+            priority_function,
+            queue: BinaryHeap::new(),
+            discovered: vec![false; graph.node_index_bound()],
+            neighbor_strategy: Default::default(),
+        };
+        for start in starts {
+            result.discover(start);
+        }
+        result
+    }
+
+    /// Returns true if the given node was discovered, i.e. if it was returned already or is waiting in the queue.
+    pub fn is_discovered(&self, node: Graph::NodeIndex) -> bool {
+        self.discovered[node.as_usize()]
+    }
+
+    /// Returns the priority of the node that will be returned next, or `None` if the search is finished.
+    pub fn peek_priority(&self) -> Option<&Priority> {
+        self.queue.peek().map(|Reverse((priority, _))| priority)
+    }
+
+    fn discover(&mut self, node: Graph::NodeIndex) {
+        let discovered = &mut self.discovered[node.as_usize()];
+        if !*discovered {
+            *discovered = true;
+            let priority = (self.priority_function)(self.graph, node);
+            self.queue.push(Reverse((priority, node)));
+        }
+    }
+}
+
+impl<
+        Graph: StaticGraph,
+        NeighborStrategy: TraversalNeighborStrategy<Graph>,
+        Priority: Ord,
+        PriorityFunction: Fn(&Graph, Graph::NodeIndex) -> Priority,
+    > Iterator for BestFirstSearch<'_, Graph, NeighborStrategy, Priority, PriorityFunction>
+{
+    type Item = Graph::NodeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((_, node)) = self.queue.pop()?;
+        for neighbor in NeighborStrategy::neighbor_iterator(self.graph, node) {
+            self.discover(neighbor.node_id);
+        }
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::best_first::BestFirstSearch;
+    use crate::traversal::UndirectedNeighborStrategy;
+    use std::cmp::Reverse;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::index::GraphIndex;
+    use traitgraph::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_maximum_priority_first() {
+        let mut graph = PetGraph::<(), ()>::new();
+        let n: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[1], n[0], ());
+        graph.add_edge(n[0], n[3], ());
+        graph.add_edge(n[3], n[5], ());
+        graph.add_edge(n[4], n[1], ());
+
+        let mut search = BestFirstSearch::<_, UndirectedNeighborStrategy, _, _>::new_with_starts(
+            &graph,
+            [n[0], n[2]],
+            |_: &PetGraph<_, _>, node| Reverse(node.as_usize()),
+        );
+        assert_eq!(search.peek_priority(), Some(&Reverse(2)));
+        assert!(!search.is_discovered(n[5]));
+        let order: Vec<_> = search.by_ref().collect();
+        assert_eq!(order, [n[2], n[0], n[3], n[5], n[1], n[4]]);
+        assert!(search.is_discovered(n[5]));
+        assert_eq!(search.peek_priority(), None);
+    }
+}
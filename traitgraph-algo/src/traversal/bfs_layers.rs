@@ -0,0 +1,145 @@
+use crate::traversal::{
+    BackwardNeighborStrategy, ForwardNeighborStrategy, TraversalNeighborStrategy,
+    UndirectedNeighborStrategy,
+};
+use std::marker::PhantomData;
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{GraphBase, StaticGraph};
+
+/// The layers of a forward BFS in a directed graph.
+pub type ForwardBfsLayers<'a, Graph> = BfsLayers<'a, Graph, ForwardNeighborStrategy>;
+/// The layers of a backward BFS in a directed graph.
+pub type BackwardBfsLayers<'a, Graph> = BfsLayers<'a, Graph, BackwardNeighborStrategy>;
+/// The layers of a BFS that treats each directed edge as an undirected edge.
+pub type UndirectedBfsLayers<'a, Graph> = BfsLayers<'a, Graph, UndirectedNeighborStrategy>;
+
+/// A breadth-first search that returns whole layers of nodes instead of single nodes.
+///
+/// The `i`th layer contains the nodes with a distance of exactly `i` from the start nodes,
+/// i.e. the first layer contains the start nodes themselves.
+/// The nodes of a layer are ordered in the same way as a [PreOrderTraversal](super::PreOrderTraversal) with the [BfsQueueStrategy](super::BfsQueueStrategy) would return them.
+/// The direction of the search is defined by the `NeighborStrategy`.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::traversal::bfs_layers::ForwardBfsLayers;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[0], nodes[1], ());
+/// graph.add_edge(nodes[0], nodes[2], ());
+/// graph.add_edge(nodes[2], nodes[3], ());
+/// graph.add_edge(nodes[3], nodes[0], ());
+///
+/// let layer_sizes: Vec<_> = ForwardBfsLayers::new(&graph, nodes[0])
+///     .map(|layer| layer.len())
+///     .collect();
+/// assert_eq!(layer_sizes, [1, 2, 1]);
+/// ```
+pub struct BfsLayers<'a, Graph: GraphBase, NeighborStrategy> {
+    graph: &'a Graph,
+    visited: Vec<bool>,
+    current_layer: Vec<Graph::NodeIndex>,
+    neighbor_strategy: PhantomData<NeighborStrategy>,
+}
+
+impl<'a, Graph: StaticGraph, NeighborStrategy: TraversalNeighborStrategy<Graph>>
+    BfsLayers<'a, Graph, NeighborStrategy>
+{
+    /// Creates a new layered BFS that operates on the given graph starting from the given node.
+    pub fn new(graph: &'a Graph, start: Graph::NodeIndex) -> Self {
+        Self::new_with_starts(graph, [start])
+    }
+
+    /// Creates a new layered BFS that operates on the given graph starting from all given nodes.
+    /// Duplicate start nodes are ignored.
+    pub fn new_with_starts(
+        graph: &'a Graph,
+        starts: impl IntoIterator<Item = Graph::NodeIndex>,
+    ) -> Self {
+        let mut visited = vec![false; graph.node_index_bound()];
+        let current_layer = starts
+            .into_iter()
+            .filter(|start| !std::mem::replace(&mut visited[start.as_usize()], true))
+            .collect();
+        Self {
+            graph,
+            visited,
+            current_layer,
+            neighbor_strategy: Default::default(),
+        }
+    }
+
+    /// Returns true if the given node is contained in a layer that has been returned already,
+    /// or in the layer that will be returned next.
+    pub fn is_visited(&self, node: Graph::NodeIndex) -> bool {
+        self.visited[node.as_usize()]
+    }
+}
+
+impl<Graph: StaticGraph, NeighborStrategy: TraversalNeighborStrategy<Graph>> Iterator
+    for BfsLayers<'_, Graph, NeighborStrategy>
+{
+    type Item = Vec<Graph::NodeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_layer.is_empty() {
+            return None;
+        }
+
+        let mut next_layer = Vec::new();
+        for &node in &self.current_layer {
+            for neighbor in NeighborStrategy::neighbor_iterator(self.graph, node) {
+                let visited = &mut self.visited[neighbor.node_id.as_usize()];
+                if !*visited {
+                    *visited = true;
+                    next_layer.push(neighbor.node_id);
+                }
+            }
+        }
+
+        Some(std::mem::replace(&mut self.current_layer, next_layer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::bfs_layers::UndirectedBfsLayers;
+    use crate::traversal::PreOrderUndirectedBfs;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::{MutableGraphContainer, NodeOrEdge};
+
+    #[test]
+    fn test_same_as_bfs() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..8).map(|i| graph.add_node(i)).collect();
+        for i in 0..7 {
+            graph.add_edge(n[(i * 3) % 8], n[(i * 5 + 1) % 8], ());
+        }
+
+        let mut bfs = PreOrderUndirectedBfs::new(&graph, n[0]).with_distances();
+        let bfs_order: Vec<_> = bfs
+            .by_ref()
+            .filter_map(|node_or_edge| match node_or_edge {
+                NodeOrEdge::Node(node) => Some(node),
+                NodeOrEdge::Edge(_) => None,
+            })
+            .collect();
+
+        let layers: Vec<_> = UndirectedBfsLayers::new(&graph, n[0]).collect();
+        assert_eq!(layers.concat(), bfs_order);
+        for (distance, layer) in layers.iter().enumerate() {
+            for &node in layer {
+                assert_eq!(bfs.distance_of(node), Some(distance));
+            }
+        }
+
+        let layers: Vec<_> =
+            UndirectedBfsLayers::new_with_starts(&graph, [n[1], n[0], n[1]]).collect();
+        assert_eq!(layers[0], [n[1], n[0]]);
+        assert_eq!(layers.concat().len(), bfs_order.len());
+    }
+}
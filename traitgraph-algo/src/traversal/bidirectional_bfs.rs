@@ -0,0 +1,210 @@
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{GraphBase, Neighbor, StaticGraph};
+
+/// A path with the minimum amount of edges, as returned by [bidirectional_bfs].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HopShortestPath<NodeIndex, EdgeIndex> {
+    /// The node on the path at which the forward and the backward search met.
+    pub meeting_node: NodeIndex,
+    /// The edges of the path from the source to the target.
+    pub path: Vec<EdgeIndex>,
+}
+
+impl<NodeIndex, EdgeIndex> HopShortestPath<NodeIndex, EdgeIndex> {
+    /// Returns the amount of edges on the path.
+    pub fn hop_distance(&self) -> usize {
+        self.path.len()
+    }
+}
+
+/// Computes a path from `source` to `target` with the minimum amount of edges,
+/// or returns `None` if `target` is not reachable from `source`.
+///
+/// The search runs a forward BFS from the source and a backward BFS from the target, and always expands a whole level of the side with the smaller frontier.
+/// It stops after the first level in which the two searches meet.
+/// On graphs where the amount of nodes grows quickly with the distance, this visits far fewer nodes than a one-sided BFS.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::traversal::bidirectional_bfs::bidirectional_bfs;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// let e01 = graph.add_edge(nodes[0], nodes[1], ());
+/// let e12 = graph.add_edge(nodes[1], nodes[2], ());
+/// let e23 = graph.add_edge(nodes[2], nodes[3], ());
+///
+/// let path = bidirectional_bfs(&graph, nodes[0], nodes[3]).unwrap();
+/// assert_eq!(path.hop_distance(), 3);
+/// assert_eq!(path.path, [e01, e12, e23]);
+/// assert_eq!(bidirectional_bfs(&graph, nodes[3], nodes[0]), None);
+/// ```
+pub fn bidirectional_bfs<Graph: StaticGraph>(
+    graph: &Graph,
+    source: Graph::NodeIndex,
+    target: Graph::NodeIndex,
+) -> Option<HopShortestPath<Graph::NodeIndex, Graph::EdgeIndex>> {
+    if source == target {
+        return Some(HopShortestPath {
+            meeting_node: source,
+            path: Vec::new(),
+        });
+    }
+
+    let mut forward = SearchSide::<Graph>::new(graph, source);
+    let mut backward = SearchSide::<Graph>::new(graph, target);
+
+    loop {
+        if forward.frontier.is_empty() || backward.frontier.is_empty() {
+            return None;
+        }
+
+        if forward.frontier.len() <= backward.frontier.len() {
+            if let Some(meeting) =
+                forward.expand_level(&backward, |node_id| graph.out_neighbors(node_id))
+            {
+                let mut path = forward.trace(meeting.near_node);
+                path.reverse();
+                path.push(meeting.edge_id);
+                path.extend(backward.trace(meeting.far_node));
+                return Some(HopShortestPath {
+                    meeting_node: meeting.far_node,
+                    path,
+                });
+            }
+        } else if let Some(meeting) =
+            backward.expand_level(&forward, |node_id| graph.in_neighbors(node_id))
+        {
+            let mut path = forward.trace(meeting.far_node);
+            path.reverse();
+            path.push(meeting.edge_id);
+            path.extend(backward.trace(meeting.near_node));
+            return Some(HopShortestPath {
+                meeting_node: meeting.far_node,
+                path,
+            });
+        }
+    }
+}
+
+/// An edge connecting a node visited by one side of the search to a node visited by the other side.
+struct Meeting<NodeIndex, EdgeIndex> {
+    distance: usize,
+    near_node: NodeIndex,
+    edge_id: EdgeIndex,
+    far_node: NodeIndex,
+}
+
+/// The state of one direction of a bidirectional BFS.
+struct SearchSide<Graph: GraphBase> {
+    frontier: Vec<Graph::NodeIndex>,
+    next_frontier: Vec<Graph::NodeIndex>,
+    distances: Vec<usize>,
+    predecessors: Vec<Option<Neighbor<Graph::NodeIndex, Graph::EdgeIndex>>>,
+}
+
+impl<Graph: StaticGraph> SearchSide<Graph> {
+    fn new(graph: &Graph, start: Graph::NodeIndex) -> Self {
+        let mut distances = vec![usize::MAX; graph.node_index_bound()];
+        distances[start.as_usize()] = 0;
+        Self {
+            frontier: vec![start],
+            next_frontier: Vec::new(),
+            distances,
+            predecessors: vec![None; graph.node_index_bound()],
+        }
+    }
+
+    /// Expands all nodes in the frontier, and returns the shortest connection to the other side found while doing so.
+    fn expand_level<Neighbors: Iterator<Item = Neighbor<Graph::NodeIndex, Graph::EdgeIndex>>>(
+        &mut self,
+        other: &Self,
+        neighbors: impl Fn(Graph::NodeIndex) -> Neighbors,
+    ) -> Option<Meeting<Graph::NodeIndex, Graph::EdgeIndex>> {
+        let mut best_meeting: Option<Meeting<_, _>> = None;
+        for &node_id in &self.frontier {
+            let distance = self.distances[node_id.as_usize()] + 1;
+            for neighbor in neighbors(node_id) {
+                let other_distance = other.distances[neighbor.node_id.as_usize()];
+                if other_distance != usize::MAX
+                    && best_meeting
+                        .as_ref()
+                        .map_or(true, |meeting| distance + other_distance < meeting.distance)
+                {
+                    best_meeting = Some(Meeting {
+                        distance: distance + other_distance,
+                        near_node: node_id,
+                        edge_id: neighbor.edge_id,
+                        far_node: neighbor.node_id,
+                    });
+                }
+
+                if self.distances[neighbor.node_id.as_usize()] == usize::MAX {
+                    self.distances[neighbor.node_id.as_usize()] = distance;
+                    self.predecessors[neighbor.node_id.as_usize()] = Some(Neighbor {
+                        edge_id: neighbor.edge_id,
+                        node_id,
+                    });
+                    self.next_frontier.push(neighbor.node_id);
+                }
+            }
+        }
+
+        core::mem::swap(&mut self.frontier, &mut self.next_frontier);
+        self.next_frontier.clear();
+        best_meeting
+    }
+
+    /// Returns the edges from the given node back to the start of this side, in the order in which they are traced back.
+    fn trace(&self, mut node_id: Graph::NodeIndex) -> Vec<Graph::EdgeIndex> {
+        let mut path = Vec::new();
+        while let Some(predecessor) = &self.predecessors[node_id.as_usize()] {
+            path.push(predecessor.edge_id);
+            node_id = predecessor.node_id;
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::bidirectional_bfs::bidirectional_bfs;
+    use crate::traversal::PreOrderForwardBfs;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_same_distances_as_bfs() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..30).map(|i| graph.add_node(i)).collect();
+        for i in 0..30 {
+            graph.add_edge(n[i], n[(i * 7 + 3) % 30], ());
+            graph.add_edge(n[i], n[(i * 11 + 5) % 30], ());
+        }
+
+        for &source in &n {
+            let mut bfs = PreOrderForwardBfs::new(&graph, source).with_distances();
+            bfs.by_ref().for_each(|_| {});
+            for &target in &n {
+                let path = bidirectional_bfs(&graph, source, target);
+                assert_eq!(
+                    path.as_ref().map(|path| path.hop_distance()),
+                    bfs.distance_of(target)
+                );
+
+                if let Some(path) = path {
+                    let mut current = source;
+                    for &edge_id in &path.path {
+                        let edge = graph.edge_endpoints(edge_id);
+                        assert_eq!(edge.from_node, current);
+                        current = edge.to_node;
+                    }
+                    assert_eq!(current, target);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use crate::traversal::TraversalNeighborStrategy;
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{GraphBase, StaticGraph};
+
+/// A preorder depth-first search that only expands nodes up to a maximum depth from the start node.
+/// The direction of the search is defined by the `NeighborStrategy`.
+///
+/// In contrast to a [PreOrderTraversal](super::PreOrderTraversal) with a [DfsQueueStrategy](super::DfsQueueStrategy),
+/// this keeps only the neighbor iterators of the nodes on the current path on its stack,
+/// and a node is expanded again if it is reached by a shorter path than before.
+/// Hence, exactly the nodes with a distance of at most `max_depth` from the start node are visited,
+/// each in the order in which it is reached first.
+/// Expanding nodes again takes at most `O(max_depth * m)` time for `m` edges.
+///
+/// For a breadth-first search with a limited depth, use [with_max_depth](super::PreOrderTraversal::with_max_depth).
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::traversal::depth_limited::DepthLimitedDfs;
+/// use traitgraph_algo::traversal::ForwardNeighborStrategy;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[0], nodes[1], ());
+/// graph.add_edge(nodes[1], nodes[2], ());
+/// graph.add_edge(nodes[2], nodes[3], ());
+///
+/// let visited: Vec<_> = DepthLimitedDfs::<_, ForwardNeighborStrategy>::new(&graph, nodes[0], 2).collect();
+/// assert_eq!(visited, [(nodes[0], 0), (nodes[1], 1), (nodes[2], 2)]);
+/// ```
+pub struct DepthLimitedDfs<
+    'a,
+    Graph: GraphBase,
+    NeighborStrategy: 'a + TraversalNeighborStrategy<Graph>,
+> {
+    graph: &'a Graph,
+    max_depth: usize,
+    start: Option<Graph::NodeIndex>,
+    stack: Vec<NeighborStrategy::Iterator<'a>>,
+    depths: Vec<usize>,
+    cut_off: bool,
+}
+
+impl<'a, Graph: StaticGraph, NeighborStrategy: TraversalNeighborStrategy<Graph>>
+    DepthLimitedDfs<'a, Graph, NeighborStrategy>
+{
+    /// Creates a new traversal that operates on the given graph starting from the given node,
+    /// visiting all nodes with a distance of at most `max_depth`.
+    pub fn new(graph: &'a Graph, start: Graph::NodeIndex, max_depth: usize) -> Self {
+        let mut result = Self {
+            graph,
+            max_depth,
+            start: None,
+            stack: Vec::new(),
+            depths: vec![usize::MAX; graph.node_index_bound()],
+            cut_off: false,
+        };
+        result.reset(start, max_depth);
+        result
+    }
+
+    /// Resets the traversal to start from the given node with the given maximum depth.
+    /// This reuses the memory of the previous traversal.
+    pub fn reset(&mut self, start: Graph::NodeIndex, max_depth: usize) {
+        self.max_depth = max_depth;
+        self.stack.clear();
+        self.depths.fill(usize::MAX);
+        self.depths[start.as_usize()] = 0;
+        self.start = Some(start);
+        self.cut_off = false;
+    }
+
+    /// Returns the minimum depth at which the given node was reached so far, or `None` if the node has not yet been visited.
+    pub fn depth_of(&self, node: Graph::NodeIndex) -> Option<usize> {
+        let depth = self.depths[node.as_usize()];
+        (depth != usize::MAX).then_some(depth)
+    }
+
+    /// Returns true if the traversal reached a node with neighbors at the maximum depth, such that its neighbors were not expanded.
+    /// If this is false after the traversal has finished, then the traversal visited all nodes reachable from the start node.
+    pub fn is_cut_off(&self) -> bool {
+        self.cut_off
+    }
+
+    fn expand(&mut self, node: Graph::NodeIndex, depth: usize) {
+        let mut neighbors = NeighborStrategy::neighbor_iterator(self.graph, node);
+        if depth < self.max_depth {
+            self.stack.push(neighbors);
+        } else if neighbors.next().is_some() {
+            self.cut_off = true;
+        }
+    }
+}
+
+impl<Graph: StaticGraph, NeighborStrategy: TraversalNeighborStrategy<Graph>> Iterator
+    for DepthLimitedDfs<'_, Graph, NeighborStrategy>
+{
+    /// A visited node together with the depth at which it was reached first.
+    type Item = (Graph::NodeIndex, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(start) = self.start.take() {
+            self.expand(start, 0);
+            return Some((start, 0));
+        }
+
+        loop {
+            let depth = self.stack.len();
+            let Some(neighbor) = self.stack.last_mut()?.next() else {
+                self.stack.pop();
+                continue;
+            };
+
+            let previous_depth = self.depths[neighbor.node_id.as_usize()];
+            if depth < previous_depth {
+                self.depths[neighbor.node_id.as_usize()] = depth;
+                self.expand(neighbor.node_id, depth);
+                if previous_depth == usize::MAX {
+                    return Some((neighbor.node_id, depth));
+                }
+            }
+        }
+    }
+}
+
+/// An iterative deepening depth-first search that finds a node closest to the start node that satisfies a predicate.
+/// The direction of the search is defined by the `NeighborStrategy`.
+///
+/// The search runs a [DepthLimitedDfs] with increasing maximum depths, until it finds a node satisfying the predicate.
+/// This finds a node with the minimum distance like a breadth-first search,
+/// but only keeps the neighbor iterators of the nodes on the current path in memory, and not the whole frontier.
+/// On the other hand, nodes close to the start node are visited once per iteration, so the predicate may be evaluated multiple times for the same node.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+/// use traitgraph_algo::traversal::depth_limited::IterativeDeepeningDfs;
+/// use traitgraph_algo::traversal::ForwardNeighborStrategy;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i * 10)).collect();
+/// graph.add_edge(nodes[0], nodes[1], ());
+/// graph.add_edge(nodes[1], nodes[2], ());
+/// graph.add_edge(nodes[0], nodes[3], ());
+///
+/// let mut search = IterativeDeepeningDfs::<_, ForwardNeighborStrategy>::new(&graph, nodes[0]);
+/// let found = search.search(None, |node_id| *graph.node_data(node_id) >= 20);
+/// assert_eq!(found, Some((nodes[3], 1)));
+/// ```
+pub struct IterativeDeepeningDfs<
+    'a,
+    Graph: GraphBase,
+    NeighborStrategy: 'a + TraversalNeighborStrategy<Graph>,
+> {
+    start: Graph::NodeIndex,
+    dfs: DepthLimitedDfs<'a, Graph, NeighborStrategy>,
+}
+
+impl<'a, Graph: StaticGraph, NeighborStrategy: TraversalNeighborStrategy<Graph>>
+    IterativeDeepeningDfs<'a, Graph, NeighborStrategy>
+{
+    /// Creates a new search that operates on the given graph starting from the given node.
+    pub fn new(graph: &'a Graph, start: Graph::NodeIndex) -> Self {
+        Self {
+            start,
+            dfs: DepthLimitedDfs::new(graph, start, 0),
+        }
+    }
+
+    /// Returns a node with the minimum distance from the start node that satisfies the given predicate, together with its distance.
+    /// Returns `None` if no such node is reachable within a distance of `max_depth`, or at all if `max_depth` is `None`.
+    pub fn search(
+        &mut self,
+        max_depth: Option<usize>,
+        mut predicate: impl FnMut(Graph::NodeIndex) -> bool,
+    ) -> Option<(Graph::NodeIndex, usize)> {
+        let mut depth = 0;
+        loop {
+            self.dfs.reset(self.start, depth);
+            if let Some((node, _)) = self.dfs.by_ref().find(|&(node, _)| predicate(node)) {
+                // No node satisfied the predicate in the previous iteration, so the distance of the node is exactly the depth.
+                return Some((node, depth));
+            }
+
+            if !self.dfs.is_cut_off() || max_depth.is_some_and(|max_depth| depth >= max_depth) {
+                return None;
+            }
+            depth += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::depth_limited::{DepthLimitedDfs, IterativeDeepeningDfs};
+    use crate::traversal::{ForwardNeighborStrategy, UndirectedNeighborStrategy};
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_shorter_path_found_later() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        // The long path is explored first, since the neighbors are iterated in reverse insertion order.
+        graph.add_edge(n[0], n[3], ());
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[3], ());
+        graph.add_edge(n[3], n[4], ());
+
+        let mut traversal = DepthLimitedDfs::<_, ForwardNeighborStrategy>::new(&graph, n[0], 3);
+        let mut visited: Vec<_> = traversal.by_ref().map(|(node, _)| node).collect();
+        visited.sort();
+        assert_eq!(visited, n);
+        assert_eq!(traversal.depth_of(n[3]), Some(1));
+        assert_eq!(traversal.depth_of(n[4]), Some(2));
+        assert!(traversal.is_cut_off());
+
+        traversal.reset(n[2], 0);
+        assert_eq!(traversal.by_ref().collect::<Vec<_>>(), [(n[2], 0)]);
+        assert_eq!(traversal.depth_of(n[3]), None);
+
+        traversal.reset(n[4], 10);
+        assert_eq!(traversal.by_ref().count(), 1);
+        assert!(!traversal.is_cut_off());
+
+        let traversal = DepthLimitedDfs::<_, UndirectedNeighborStrategy>::new(&graph, n[4], 1);
+        assert_eq!(traversal.count(), 2);
+    }
+
+    #[test]
+    fn test_iterative_deepening() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[3], ());
+        graph.add_edge(n[3], n[0], ());
+        graph.add_edge(n[4], n[5], ());
+
+        let mut search = IterativeDeepeningDfs::<_, ForwardNeighborStrategy>::new(&graph, n[0]);
+        assert_eq!(search.search(None, |node| node == n[0]), Some((n[0], 0)));
+        assert_eq!(
+            search.search(None, |node| node == n[2] || node == n[3]),
+            Some((n[2], 2))
+        );
+        assert_eq!(search.search(Some(2), |node| node == n[3]), None);
+        assert_eq!(search.search(Some(3), |node| node == n[3]), Some((n[3], 3)));
+        assert_eq!(search.search(None, |node| node == n[5]), None);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::traversal::{ForwardNeighborStrategy, TraversalNeighborStrategy};
+use crate::visitor::{Control, DepthFirstSearch, DfsVisitor};
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{GraphBase, Neighbor, StaticGraph};
+
+/// The search forest of a depth-first search, given as a parent array.
+///
+/// Each visited node except for the roots has a parent, which is the node from which it was discovered, together with the tree edge in the original graph.
+/// Additionally, the forest stores the order in which the nodes were discovered and finished.
+pub struct DfsForest<Graph: GraphBase> {
+    roots: Vec<Graph::NodeIndex>,
+    parents: Vec<Option<Neighbor<Graph::NodeIndex, Graph::EdgeIndex>>>,
+    preorder: Vec<Graph::NodeIndex>,
+    postorder: Vec<Graph::NodeIndex>,
+}
+
+impl<Graph: StaticGraph> DfsForest<Graph> {
+    /// Runs a depth-first search from each of the given roots that has not been visited yet, and returns its search forest.
+    /// The direction of the search is defined by the `NeighborStrategy`.
+    pub fn new<NeighborStrategy: TraversalNeighborStrategy<Graph>>(
+        graph: &Graph,
+        roots: impl IntoIterator<Item = Graph::NodeIndex>,
+    ) -> Self {
+        let mut forest = Self {
+            roots: Vec::new(),
+            parents: vec![None; graph.node_index_bound()],
+            preorder: Vec::new(),
+            postorder: Vec::new(),
+        };
+        DepthFirstSearch::<_, NeighborStrategy>::new(graph).visit(roots, &mut forest);
+        forest
+    }
+
+    /// Returns the roots of the search trees, in the order in which they were visited.
+    pub fn roots(&self) -> &[Graph::NodeIndex] {
+        &self.roots
+    }
+
+    /// Returns the parent of the given node together with the tree edge connecting them,
+    /// or `None` if the node is a root or was not visited.
+    pub fn parent(
+        &self,
+        node: Graph::NodeIndex,
+    ) -> Option<&Neighbor<Graph::NodeIndex, Graph::EdgeIndex>> {
+        self.parents[node.as_usize()].as_ref()
+    }
+
+    /// Returns the visited nodes in the order in which they were discovered.
+    pub fn preorder(&self) -> &[Graph::NodeIndex] {
+        &self.preorder
+    }
+
+    /// Returns the visited nodes in the order in which they were finished.
+    pub fn postorder(&self) -> &[Graph::NodeIndex] {
+        &self.postorder
+    }
+
+    /// Returns the tree edges of the forest, in the order in which their heads were discovered.
+    pub fn tree_edges(&self) -> impl '_ + Iterator<Item = Graph::EdgeIndex> {
+        self.preorder
+            .iter()
+            .filter_map(|&node| self.parent(node).map(|parent| parent.edge_id))
+    }
+}
+
+impl<Graph: GraphBase> DfsVisitor<Graph> for DfsForest<Graph> {
+    fn start_root(&mut self, root: Graph::NodeIndex) -> Control {
+        self.roots.push(root);
+        Control::Continue
+    }
+
+    fn discover_node(&mut self, node: Graph::NodeIndex) -> Control {
+        self.preorder.push(node);
+        Control::Continue
+    }
+
+    fn tree_edge(
+        &mut self,
+        edge: Graph::EdgeIndex,
+        from: Graph::NodeIndex,
+        to: Graph::NodeIndex,
+    ) -> Control {
+        self.parents[to.as_usize()] = Some(Neighbor {
+            edge_id: edge,
+            node_id: from,
+        });
+        Control::Continue
+    }
+
+    fn finish_node(&mut self, node: Graph::NodeIndex) -> Control {
+        self.postorder.push(node);
+        Control::Continue
+    }
+}
+
+/// Runs a forward depth-first search from all nodes of the graph, in the order of their indices, and returns its search forest.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::traversal::dfs_forest::dfs_forest;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// let e01 = graph.add_edge(nodes[0], nodes[1], ());
+/// graph.add_edge(nodes[1], nodes[0], ());
+/// let e32 = graph.add_edge(nodes[3], nodes[2], ());
+///
+/// let forest = dfs_forest(&graph);
+/// assert_eq!(forest.roots(), [nodes[0], nodes[2], nodes[3]]);
+/// assert_eq!(forest.parent(nodes[1]).unwrap().node_id, nodes[0]);
+/// assert_eq!(forest.tree_edges().collect::<Vec<_>>(), [e01]);
+/// assert!(forest.parent(nodes[2]).is_none());
+/// # let _ = e32;
+/// ```
+pub fn dfs_forest<Graph: StaticGraph>(graph: &Graph) -> DfsForest<Graph> {
+    DfsForest::new::<ForwardNeighborStrategy>(graph, graph.node_indices())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::dfs_forest::DfsForest;
+    use crate::traversal::UndirectedNeighborStrategy;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_undirected_forest() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[1], n[0], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[0], ());
+        graph.add_edge(n[4], n[3], ());
+
+        let forest = DfsForest::new::<UndirectedNeighborStrategy>(&graph, [n[0], n[3], n[0]]);
+        assert_eq!(forest.roots(), [n[0], n[3]]);
+        assert_eq!(forest.preorder().len(), 5);
+        assert_eq!(forest.postorder().len(), 5);
+        assert_eq!(forest.postorder().last(), Some(&n[3]));
+        assert_eq!(forest.tree_edges().count(), 3);
+        for edge_id in forest.tree_edges() {
+            let edge = graph.edge_endpoints(edge_id);
+            let child = if forest.parent(edge.to_node).map(|parent| parent.edge_id) == Some(edge_id)
+            {
+                edge.to_node
+            } else {
+                edge.from_node
+            };
+            assert_eq!(forest.parent(child).unwrap().edge_id, edge_id);
+        }
+        assert!(forest.parent(n[5]).is_none());
+    }
+}
@@ -0,0 +1,248 @@
+use crate::traversal::{
+    BackwardNeighborStrategy, ForbiddenSubgraph, ForwardNeighborStrategy, NoForbiddenSubgraph,
+    TraversalNeighborStrategy, UndirectedNeighborStrategy,
+};
+use traitgraph::index::{GraphIndex, OptionalGraphIndex};
+use traitgraph::interface::{GraphBase, NodeOrEdge, StaticGraph};
+
+/// A post-order forward DFS in a directed graph that also reports the traversed edges.
+pub type EdgePostOrderForwardDfs<'a, Graph> =
+    DfsEdgePostOrderTraversal<'a, Graph, ForwardNeighborStrategy>;
+/// A post-order backward DFS in a directed graph that also reports the traversed edges.
+pub type EdgePostOrderBackwardDfs<'a, Graph> =
+    DfsEdgePostOrderTraversal<'a, Graph, BackwardNeighborStrategy>;
+/// A post-order DFS that treats each directed edge as an undirected edge, and that also reports the traversed edges.
+pub type EdgePostOrderUndirectedDfs<'a, Graph> =
+    DfsEdgePostOrderTraversal<'a, Graph, UndirectedNeighborStrategy>;
+
+/// A generic depth first postorder graph traversal with edge-granularity.
+///
+/// The traversal is generic over the graph implementation and the direction of the search (`NeighborStrategy`).
+///
+/// In contrast to the [DfsPostOrderTraversal](super::DfsPostOrderTraversal), this traversal returns not only nodes, but also the traversed edges.
+/// Each edge is returned after the search below its target node is complete,
+/// i.e. directly after its target node if the target node was discovered via this edge, and immediately otherwise.
+/// Each node is returned after all its edges.
+/// In a directed acyclic graph, this means that when an edge is returned, its target node has been returned already.
+///
+/// Moreover, the traversal computes the postorder rank of each visited node.
+/// Additionally, a forbidden subgraph can be passed using the `next_with_forbidden_subgraph` method to disable some edges and nodes in the traversal.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{MutableGraphContainer, NodeOrEdge};
+/// use traitgraph_algo::traversal::edge_postorder::EdgePostOrderForwardDfs;
+///
+/// let mut graph = PetGraph::new();
+/// let n0 = graph.add_node(0);
+/// let n1 = graph.add_node(1);
+/// let e01 = graph.add_edge(n0, n1, ());
+/// let e10 = graph.add_edge(n1, n0, ());
+///
+/// let order: Vec<_> = EdgePostOrderForwardDfs::new(&graph, n0).collect();
+/// assert_eq!(
+///     order,
+///     [
+///         NodeOrEdge::Edge(e10),
+///         NodeOrEdge::Node(n1),
+///         NodeOrEdge::Edge(e01),
+///         NodeOrEdge::Node(n0),
+///     ]
+/// );
+/// ```
+pub struct DfsEdgePostOrderTraversal<
+    'a,
+    Graph: GraphBase,
+    NeighborStrategy: 'a + TraversalNeighborStrategy<Graph>,
+> {
+    graph: &'a Graph,
+    stack: Vec<StackFrame<'a, Graph, NeighborStrategy>>,
+    discovered: Vec<bool>,
+    rank: Vec<Graph::OptionalNodeIndex>,
+    current_rank: Graph::NodeIndex,
+}
+
+/// A node on the search path, together with the state of the iteration over its neighbors.
+struct StackFrame<
+    'a,
+    Graph: 'a + GraphBase,
+    NeighborStrategy: 'a + TraversalNeighborStrategy<Graph>,
+> {
+    node: Graph::NodeIndex,
+    neighbor_iterator: NeighborStrategy::Iterator<'a>,
+    /// The edge leading to the node of the frame above this one, which is returned once that node is finished.
+    pending_edge: Option<Graph::EdgeIndex>,
+}
+
+impl<'a, Graph: StaticGraph, NeighborStrategy: TraversalNeighborStrategy<Graph>>
+    DfsEdgePostOrderTraversal<'a, Graph, NeighborStrategy>
+{
+    /// Creates a new traversal that operates on the given graph, starting from the given node.
+    pub fn new(graph: &'a Graph, start: Graph::NodeIndex) -> Self {
+        let mut result = Self::new_without_start(graph);
+        result.continue_traversal_from(start);
+        result
+    }
+
+    /// Creates a new traversal that operates on the given graph.
+    /// There is no starting node given, and to start the search, one of the `reset` methods needs to be used.
+    pub fn new_without_start(graph: &'a Graph) -> Self {
+        Self {
+            graph,
+            stack: Vec::new(),
+            discovered: vec![false; graph.node_index_bound()],
+            rank: vec![Graph::OptionalNodeIndex::new_none(); graph.node_index_bound()],
+            current_rank: 0.into(),
+        }
+    }
+
+    /// Resets the traversal to start from the given node.
+    pub fn reset(&mut self, start: Graph::NodeIndex) {
+        self.stack.clear();
+        self.discovered.fill(false);
+        self.rank.fill(Graph::OptionalNodeIndex::new_none());
+        self.current_rank = 0.into();
+        self.continue_traversal_from(start);
+    }
+
+    /// Resets the traversal to start from the given node without resetting the visited nodes.
+    pub fn continue_traversal_from(&mut self, start: Graph::NodeIndex) {
+        assert!(self.stack.is_empty());
+        self.discover(start);
+    }
+
+    /// Advances the traversal, ignoring all nodes and edges forbidden by `forbidden_subgraph`.
+    pub fn next_with_forbidden_subgraph<FN: ForbiddenSubgraph<Graph>>(
+        &mut self,
+        forbidden_subgraph: &FN,
+    ) -> Option<NodeOrEdge<Graph::NodeIndex, Graph::EdgeIndex>> {
+        self.next_internal(forbidden_subgraph)
+    }
+
+    #[inline]
+    fn next_internal<FS: ForbiddenSubgraph<Graph>>(
+        &mut self,
+        forbidden_subgraph: &FS,
+    ) -> Option<NodeOrEdge<Graph::NodeIndex, Graph::EdgeIndex>> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            if let Some(edge) = frame.pending_edge.take() {
+                return Some(NodeOrEdge::Edge(edge));
+            }
+
+            if let Some(neighbor) = frame.neighbor_iterator.next() {
+                if forbidden_subgraph.is_edge_forbidden(neighbor.edge_id)
+                    || forbidden_subgraph.is_node_forbidden(neighbor.node_id)
+                {
+                    continue;
+                }
+
+                if self.discovered[neighbor.node_id.as_usize()] {
+                    return Some(NodeOrEdge::Edge(neighbor.edge_id));
+                } else {
+                    frame.pending_edge = Some(neighbor.edge_id);
+                    self.discover(neighbor.node_id);
+                }
+            } else {
+                let node = frame.node;
+                self.stack.pop();
+                self.rank[node.as_usize()] = self.current_rank.into();
+                self.current_rank = self.current_rank + 1;
+                return Some(NodeOrEdge::Node(node));
+            }
+        }
+    }
+
+    fn discover(&mut self, node: Graph::NodeIndex) {
+        self.discovered[node.as_usize()] = true;
+        self.stack.push(StackFrame {
+            node,
+            neighbor_iterator: NeighborStrategy::neighbor_iterator(self.graph, node),
+            pending_edge: None,
+        });
+    }
+
+    /// Returns the rank of a node in depth-first search postorder, or `None` if the node has not yet been processed completely.
+    pub fn rank_of(&self, node: Graph::NodeIndex) -> Option<Graph::NodeIndex> {
+        let rank = self.rank[node.as_usize()];
+        rank.into()
+    }
+
+    /// Returns the postorder ranks of all nodes, indexed by node.
+    /// Nodes that have not yet been processed completely have a rank of `None`.
+    pub fn ranks(&self) -> &[Graph::OptionalNodeIndex] {
+        &self.rank
+    }
+
+    /// Consumes the traversal and returns the postorder ranks of all nodes, indexed by node.
+    /// Nodes that have not been processed completely have a rank of `None`.
+    pub fn into_ranks(self) -> Vec<Graph::OptionalNodeIndex> {
+        self.rank
+    }
+}
+
+impl<Graph: StaticGraph, NeighborStrategy: TraversalNeighborStrategy<Graph>> Iterator
+    for DfsEdgePostOrderTraversal<'_, Graph, NeighborStrategy>
+{
+    type Item = NodeOrEdge<Graph::NodeIndex, Graph::EdgeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_internal(&NoForbiddenSubgraph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::edge_postorder::EdgePostOrderForwardDfs;
+    use crate::traversal::ForbiddenNode;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::{MutableGraphContainer, NodeOrEdge};
+
+    #[test]
+    fn test_expression_dag() {
+        // (a + b) * b, where the multiplication node is the root.
+        let mut graph = PetGraph::new();
+        let times = graph.add_node('*');
+        let plus = graph.add_node('+');
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let e_times_plus = graph.add_edge(times, plus, ());
+        let e_times_b = graph.add_edge(times, b, ());
+        let e_plus_a = graph.add_edge(plus, a, ());
+        let e_plus_b = graph.add_edge(plus, b, ());
+
+        let mut traversal = EdgePostOrderForwardDfs::new(&graph, times);
+        let order: Vec<_> = traversal.by_ref().collect();
+        let position = |item| order.iter().position(|other| *other == item).unwrap();
+        assert_eq!(order.len(), 8);
+        assert_eq!(order.last(), Some(&NodeOrEdge::Node(times)));
+        for (edge, from, to) in [
+            (e_times_plus, times, plus),
+            (e_times_b, times, b),
+            (e_plus_a, plus, a),
+            (e_plus_b, plus, b),
+        ] {
+            assert!(position(NodeOrEdge::Node(to)) < position(NodeOrEdge::Edge(edge)));
+            assert!(position(NodeOrEdge::Edge(edge)) < position(NodeOrEdge::Node(from)));
+        }
+        assert_eq!(traversal.rank_of(times), Some(3.into()));
+
+        traversal.reset(times);
+        let forbidden = ForbiddenNode::new(plus);
+        let mut order = Vec::new();
+        while let Some(item) = traversal.next_with_forbidden_subgraph(&forbidden) {
+            order.push(item);
+        }
+        assert_eq!(
+            order,
+            [
+                NodeOrEdge::Node(b),
+                NodeOrEdge::Edge(e_times_b),
+                NodeOrEdge::Node(times)
+            ]
+        );
+        assert_eq!(traversal.rank_of(a), None);
+    }
+}
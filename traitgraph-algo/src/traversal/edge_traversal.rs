@@ -0,0 +1,312 @@
+use crate::queue::BidirectedQueue;
+use crate::traversal::{
+    BackwardNeighborStrategy, BfsQueueStrategy, DfsQueueStrategy, ForbiddenSubgraph,
+    ForwardNeighborStrategy, NoForbiddenSubgraph, TraversalNeighborStrategy,
+    UndirectedNeighborStrategy,
+};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use traitgraph::index::{GraphIndex, OptionalGraphIndex};
+use traitgraph::interface::{GraphBase, StaticGraph};
+
+/// A forward BFS over the edges of a directed graph.
+pub type ForwardEdgeBfs<'a, Graph> = EdgeTraversal<
+    'a,
+    Graph,
+    ForwardNeighborStrategy,
+    BfsQueueStrategy,
+    VecDeque<<Graph as GraphBase>::EdgeIndex>,
+>;
+/// A backward BFS over the edges of a directed graph.
+pub type BackwardEdgeBfs<'a, Graph> = EdgeTraversal<
+    'a,
+    Graph,
+    BackwardNeighborStrategy,
+    BfsQueueStrategy,
+    VecDeque<<Graph as GraphBase>::EdgeIndex>,
+>;
+/// A BFS over the edges of a graph that treats each directed edge as an undirected edge.
+pub type UndirectedEdgeBfs<'a, Graph> = EdgeTraversal<
+    'a,
+    Graph,
+    UndirectedNeighborStrategy,
+    BfsQueueStrategy,
+    VecDeque<<Graph as GraphBase>::EdgeIndex>,
+>;
+/// A forward DFS over the edges of a directed graph.
+pub type ForwardEdgeDfs<'a, Graph> = EdgeTraversal<
+    'a,
+    Graph,
+    ForwardNeighborStrategy,
+    DfsQueueStrategy,
+    VecDeque<<Graph as GraphBase>::EdgeIndex>,
+>;
+/// A backward DFS over the edges of a directed graph.
+pub type BackwardEdgeDfs<'a, Graph> = EdgeTraversal<
+    'a,
+    Graph,
+    BackwardNeighborStrategy,
+    DfsQueueStrategy,
+    VecDeque<<Graph as GraphBase>::EdgeIndex>,
+>;
+/// A DFS over the edges of a graph that treats each directed edge as an undirected edge.
+pub type UndirectedEdgeDfs<'a, Graph> = EdgeTraversal<
+    'a,
+    Graph,
+    UndirectedNeighborStrategy,
+    DfsQueueStrategy,
+    VecDeque<<Graph as GraphBase>::EdgeIndex>,
+>;
+
+/// A type that defines the order of edge processing in an [EdgeTraversal], i.e. queue-based or stack-based.
+/// This is the equivalent of [TraversalQueueStrategy](super::TraversalQueueStrategy) for edges.
+pub trait EdgeTraversalQueueStrategy<Graph: GraphBase, Queue: BidirectedQueue<Graph::EdgeIndex>> {
+    /// Insert an edge into the queue.
+    fn push(queue: &mut Queue, edge: Graph::EdgeIndex);
+    /// Remove and return an edge from the queue.
+    fn pop(queue: &mut Queue) -> Option<Graph::EdgeIndex>;
+}
+
+impl<Graph: GraphBase, Queue: BidirectedQueue<Graph::EdgeIndex>>
+    EdgeTraversalQueueStrategy<Graph, Queue> for BfsQueueStrategy
+{
+    fn push(queue: &mut Queue, edge: Graph::EdgeIndex) {
+        queue.push_back(edge)
+    }
+
+    fn pop(queue: &mut Queue) -> Option<Graph::EdgeIndex> {
+        queue.pop_front()
+    }
+}
+
+impl<Graph: GraphBase, Queue: BidirectedQueue<Graph::EdgeIndex>>
+    EdgeTraversalQueueStrategy<Graph, Queue> for DfsQueueStrategy
+{
+    fn push(queue: &mut Queue, edge: Graph::EdgeIndex) {
+        queue.push_back(edge)
+    }
+
+    fn pop(queue: &mut Queue) -> Option<Graph::EdgeIndex> {
+        queue.pop_back()
+    }
+}
+
+/// A generic graph traversal whose unit of visitation are edges instead of nodes.
+///
+/// The traversal is generic over the graph implementation,
+/// as well as the direction of the search (`NeighborStrategy`),
+/// the order of processing (`QueueStrategy`) and the queue implementation itself (`Queue`).
+///
+/// The neighbors of an edge are the edges returned by the `NeighborStrategy` for the nodes returned by its
+/// [edge_neighbor_iterator](TraversalNeighborStrategy::edge_neighbor_iterator).
+/// For example, in a forward traversal, the neighbors of an edge are the outgoing edges of its target node.
+/// Hence, this traversal behaves like a node traversal of the line graph, without constructing the line graph explicitly.
+///
+/// Moreover, the traversal computes the rank of each visited edge, which is the order in which the edges were discovered.
+/// Additionally, a forbidden subgraph can be passed using the `next_with_forbidden_subgraph` method to disable some edges and nodes in the traversal.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::traversal::edge_traversal::ForwardEdgeBfs;
+///
+/// let mut graph = PetGraph::new();
+/// let n0 = graph.add_node(0);
+/// let n1 = graph.add_node(1);
+/// let n2 = graph.add_node(2);
+/// let e01 = graph.add_edge(n0, n1, ());
+/// let e12 = graph.add_edge(n1, n2, ());
+/// let e20 = graph.add_edge(n2, n0, ());
+///
+/// let order: Vec<_> = ForwardEdgeBfs::new(&graph, e12).collect();
+/// assert_eq!(order, [e12, e20, e01]);
+/// ```
+pub struct EdgeTraversal<
+    'a,
+    Graph: GraphBase,
+    NeighborStrategy,
+    QueueStrategy,
+    Queue: BidirectedQueue<Graph::EdgeIndex>,
+> {
+    graph: &'a Graph,
+    queue: Queue,
+    rank: Vec<Graph::OptionalEdgeIndex>,
+    current_rank: Graph::EdgeIndex,
+    neighbor_strategy: PhantomData<NeighborStrategy>,
+    queue_strategy: PhantomData<QueueStrategy>,
+}
+
+impl<
+        'a,
+        Graph: StaticGraph,
+        NeighborStrategy: TraversalNeighborStrategy<Graph>,
+        QueueStrategy: EdgeTraversalQueueStrategy<Graph, Queue>,
+        Queue: BidirectedQueue<Graph::EdgeIndex>,
+    > EdgeTraversal<'a, Graph, NeighborStrategy, QueueStrategy, Queue>
+{
+    /// Creates a new traversal that operates on the given graph, starting from the given edge.
+    pub fn new(graph: &'a Graph, start: Graph::EdgeIndex) -> Self {
+        let mut result = Self::new_without_start(graph);
+        result.continue_traversal_from(start);
+        result
+    }
+
+    /// Creates a new traversal that operates on the given graph, starting from all edges returned by the `NeighborStrategy` for the given node.
+    pub fn new_from_node(graph: &'a Graph, start: Graph::NodeIndex) -> Self {
+        let mut result = Self::new_without_start(graph);
+        for neighbor in NeighborStrategy::neighbor_iterator(graph, start) {
+            result.discover(neighbor.edge_id);
+        }
+        result
+    }
+
+    /// Creates a new traversal that operates on the given graph.
+    /// There is no starting edge given, and to start the search, one of the `reset` methods needs to be used.
+    pub fn new_without_start(graph: &'a Graph) -> Self {
+        Self {
+            graph,
+            queue: Queue::default(),
+            rank: vec![Graph::OptionalEdgeIndex::new_none(); graph.edge_index_bound()],
+            current_rank: 0.into(),
+            neighbor_strategy: Default::default(),
+            queue_strategy: Default::default(),
+        }
+    }
+
+    /// Resets the traversal to start from the given edge.
+    pub fn reset(&mut self, start: Graph::EdgeIndex) {
+        self.queue.clear();
+        self.rank.fill(Graph::OptionalEdgeIndex::new_none());
+        self.current_rank = 0.into();
+        self.continue_traversal_from(start);
+    }
+
+    /// Resets the traversal to start from the given edge without resetting the visited edges.
+    /// Returns the rank of the starting edge, or `None` if it was visited already.
+    pub fn continue_traversal_from(&mut self, start: Graph::EdgeIndex) -> Option<Graph::EdgeIndex> {
+        debug_assert!(self.queue.is_empty());
+        self.discover(start)
+    }
+
+    /// Advances the traversal, ignoring all nodes and edges forbidden by `forbidden_subgraph`.
+    pub fn next_with_forbidden_subgraph<FN: ForbiddenSubgraph<Graph>>(
+        &mut self,
+        forbidden_subgraph: &FN,
+    ) -> Option<Graph::EdgeIndex> {
+        self.next_internal(forbidden_subgraph)
+    }
+
+    #[inline]
+    fn next_internal<FS: ForbiddenSubgraph<Graph>>(
+        &mut self,
+        forbidden_subgraph: &FS,
+    ) -> Option<Graph::EdgeIndex> {
+        let edge = QueueStrategy::pop(&mut self.queue)?;
+        for node in NeighborStrategy::edge_neighbor_iterator(self.graph, edge) {
+            if forbidden_subgraph.is_node_forbidden(node) {
+                continue;
+            }
+
+            for neighbor in NeighborStrategy::neighbor_iterator(self.graph, node) {
+                if !forbidden_subgraph.is_edge_forbidden(neighbor.edge_id)
+                    && !forbidden_subgraph.is_node_forbidden(neighbor.node_id)
+                {
+                    self.discover(neighbor.edge_id);
+                }
+            }
+        }
+
+        Some(edge)
+    }
+
+    /// Assigns a rank to the given edge and inserts it into the queue, if it was not discovered yet.
+    /// Returns the rank if it was assigned.
+    fn discover(&mut self, edge: Graph::EdgeIndex) -> Option<Graph::EdgeIndex> {
+        let rank_entry = &mut self.rank[edge.as_usize()];
+        if rank_entry.is_none() {
+            let rank = self.current_rank;
+            *rank_entry = rank.into();
+            self.current_rank = self.current_rank + 1;
+            QueueStrategy::push(&mut self.queue, edge);
+            Some(rank)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the rank of the given edge, or `None` if the edge has not yet been discovered.
+    pub fn rank_of(&self, edge: Graph::EdgeIndex) -> Option<Graph::EdgeIndex> {
+        let rank = self.rank[edge.as_usize()];
+        rank.into()
+    }
+
+    /// Returns the ranks of all edges, indexed by edge.
+    /// Edges that have not yet been discovered have a rank of `None`.
+    pub fn ranks(&self) -> &[Graph::OptionalEdgeIndex] {
+        &self.rank
+    }
+
+    /// Consumes the traversal and returns the ranks of all edges, indexed by edge.
+    /// Edges that have not been discovered have a rank of `None`.
+    pub fn into_ranks(self) -> Vec<Graph::OptionalEdgeIndex> {
+        self.rank
+    }
+}
+
+impl<
+        Graph: StaticGraph,
+        NeighborStrategy: TraversalNeighborStrategy<Graph>,
+        QueueStrategy: EdgeTraversalQueueStrategy<Graph, Queue>,
+        Queue: BidirectedQueue<Graph::EdgeIndex>,
+    > Iterator for EdgeTraversal<'_, Graph, NeighborStrategy, QueueStrategy, Queue>
+{
+    type Item = Graph::EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_internal(&NoForbiddenSubgraph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::edge_traversal::{ForwardEdgeDfs, UndirectedEdgeBfs};
+    use crate::traversal::ForbiddenNode;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::index::OptionalGraphIndex;
+    use traitgraph::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_edge_traversal() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e01 = graph.add_edge(n[0], n[1], ());
+        let e12 = graph.add_edge(n[1], n[2], ());
+        let e13 = graph.add_edge(n[1], n[3], ());
+        let e30 = graph.add_edge(n[3], n[0], ());
+
+        let mut traversal = ForwardEdgeDfs::new(&graph, e01);
+        let order: Vec<_> = traversal.by_ref().collect();
+        assert_eq!(order.len(), 4);
+        assert_eq!(order[0], e01);
+        assert_eq!(traversal.rank_of(e01), Some(0.into()));
+        assert!(traversal.rank_of(e12).is_some());
+        assert!(traversal.rank_of(e30).is_some());
+
+        let mut traversal = ForwardEdgeDfs::new_from_node(&graph, n[3]);
+        let forbidden = ForbiddenNode::new(n[2]);
+        let mut order = Vec::new();
+        while let Some(edge) = traversal.next_with_forbidden_subgraph(&forbidden) {
+            order.push(edge);
+        }
+        assert_eq!(order, [e30, e01, e13]);
+        assert_eq!(traversal.rank_of(e12), None);
+        assert_eq!(traversal.continue_traversal_from(e01), None);
+
+        let mut traversal = UndirectedEdgeBfs::new(&graph, e12);
+        assert_eq!(traversal.by_ref().count(), 4);
+        let ranks = traversal.into_ranks();
+        assert!(ranks.iter().all(|rank| rank.is_some()));
+    }
+}
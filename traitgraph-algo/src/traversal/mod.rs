@@ -1,16 +1,41 @@
 use crate::queue::BidirectedQueue;
+use crate::traversal::performance_counters::{
+    NoopTraversalPerformanceCounter, TraversalPerformanceData,
+};
 use std::collections::VecDeque;
 use std::iter::IntoIterator;
 use std::marker::PhantomData;
 use traitgraph::index::{GraphIndex, OptionalGraphIndex};
 use traitgraph::interface::NodeOrEdge;
 use traitgraph::interface::{
-    GraphBase, ImmutableGraphContainer, NavigableGraph, Neighbor, StaticGraph,
+    GraphBase, ImmutableGraphContainer, NavigableGraph, Neighbor, StaticGraph, UndirectedGraph,
 };
 
+/// A best-first search that processes nodes in the order of a custom priority.
+pub mod best_first;
+/// A breadth-first search that returns whole layers of nodes.
+pub mod bfs_layers;
+/// A breadth-first search from both ends of a path, for finding paths with the minimum amount of edges.
+pub mod bidirectional_bfs;
+/// Depth-first searches that are limited to a maximum depth, including iterative deepening.
+pub mod depth_limited;
+/// Extracting the search forest of a depth-first search.
+pub mod dfs_forest;
+/// A depth-first postorder traversal that also reports the traversed edges.
+pub mod edge_postorder;
+/// Traversals whose unit of visitation are edges instead of nodes.
+pub mod edge_traversal;
+/// Performance counters for graph traversals.
+pub mod performance_counters;
+/// Extracting the neighborhood of a set of nodes into a subgraph.
+pub mod reachable_subgraph;
+/// A depth-first traversal that returns nodes in reverse postorder.
+pub mod reverse_postorder;
 /// Functions and structures related to univocal traversals.
 /// Univocal traversals are traversals along unique out-edges or unique in-edges in a graph.
 pub mod univocal_traversal;
+/// Preorder traversals that visit all nodes of a graph, restarting from unvisited nodes.
+pub mod whole_graph;
 
 /// A normal forward BFS in a directed graph.
 pub type PreOrderForwardBfs<'a, Graph> = PreOrderTraversal<
@@ -81,6 +106,21 @@ pub type PostOrderUndirectedDfs<Graph> = DfsPostOrderTraversal<
     VecDeque<<Graph as GraphBase>::NodeIndex>,
 >;
 
+/// The exhaustiveness of a traversal that was advanced with a budget,
+/// see e.g. [PreOrderTraversal::next_with_budget].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TraversalExhaustiveness {
+    /// The traversal is finished, i.e. it returned `None`.
+    Complete,
+    /// The traversal was stopped because the budget was used up.
+    /// It may be resumed to return further elements.
+    Partial,
+}
+
+/// The node and edge from which each node was discovered in a traversal, indexed by node.
+type Predecessors<Graph> =
+    Vec<Option<Neighbor<<Graph as GraphBase>::NodeIndex, <Graph as GraphBase>::EdgeIndex>>>;
+
 /// A generic preorder graph traversal.
 ///
 /// The traversal is generic over the graph implementation,
@@ -90,18 +130,28 @@ pub type PostOrderUndirectedDfs<Graph> = DfsPostOrderTraversal<
 /// Moreover, the traversal computes the preorder rank of each visited node.
 /// Also, the traversal operates with edge-granularity, meaning that not just nodes are returned by the `next` method, but the traversed edges of each node as well.
 /// Additionally, a forbidden subgraph can be passed using the `next_with_forbidden_subgraph` method to disable some edges and nodes in the traversal.
+/// Optionally, the traversal records the predecessor of each visited node, such that the paths of the traversal tree can be reconstructed,
+/// see [with_predecessors](Self::with_predecessors),
+/// and the distance of each visited node from the start node, see [with_distances](Self::with_distances).
+/// Performance data about the queue and the forbidden subgraph can be collected with [with_performance_data](Self::with_performance_data).
 pub struct PreOrderTraversal<
     'a,
     Graph: GraphBase,
     NeighborStrategy: 'a + TraversalNeighborStrategy<Graph>,
     QueueStrategy,
     Queue: BidirectedQueue<Graph::NodeIndex>,
+    TraversalPerformance = NoopTraversalPerformanceCounter,
 > {
     graph: &'a Graph,
     queue: Queue,
     rank: Vec<Graph::OptionalNodeIndex>,
     current_rank: Graph::NodeIndex,
+    current_node: Graph::OptionalNodeIndex,
+    predecessors: Option<Predecessors<Graph>>,
+    distances: Option<Vec<usize>>,
+    max_depth: Option<usize>,
     neighbor_iterator: Option<NeighborStrategy::Iterator<'a>>,
+    performance_data: TraversalPerformance,
     neighbor_strategy: PhantomData<NeighborStrategy>,
     queue_strategy: PhantomData<QueueStrategy>,
 }
@@ -118,14 +168,19 @@ impl<
     pub fn new(graph: &'a Graph, start: Graph::NodeIndex) -> Self {
         let mut queue = Queue::default();
         QueueStrategy::push(&mut queue, start);
-        let mut rank = vec![Graph::OptionalNodeIndex::new_none(); graph.node_count()];
+        let mut rank = vec![Graph::OptionalNodeIndex::new_none(); graph.node_index_bound()];
         rank[start.as_usize()] = Some(0).into();
         Self {
             graph,
             queue,
             rank,
             current_rank: 1.into(),
+            current_node: Graph::OptionalNodeIndex::new_none(),
+            predecessors: None,
+            distances: None,
+            max_depth: None,
             neighbor_iterator: None,
+            performance_data: NoopTraversalPerformanceCounter,
             neighbor_strategy: Default::default(),
             queue_strategy: Default::default(),
         }
@@ -135,27 +190,110 @@ impl<
     /// Does not start the traversal.
     pub fn new_without_start(graph: &'a Graph) -> Self {
         let queue = Queue::default();
-        let rank = vec![Graph::OptionalNodeIndex::new_none(); graph.node_count()];
+        let rank = vec![Graph::OptionalNodeIndex::new_none(); graph.node_index_bound()];
         Self {
             graph,
             queue,
             rank,
             current_rank: 0.into(),
+            current_node: Graph::OptionalNodeIndex::new_none(),
+            predecessors: None,
+            distances: None,
+            max_depth: None,
             neighbor_iterator: None,
+            performance_data: NoopTraversalPerformanceCounter,
             neighbor_strategy: Default::default(),
             queue_strategy: Default::default(),
         }
     }
 
+    /// Collects performance data of this traversal in the given performance counter.
+    /// Nodes that are in the queue already, like the start node, are counted as pushed immediately.
+    pub fn with_performance_data<TraversalPerformance: TraversalPerformanceData>(
+        self,
+        mut performance_data: TraversalPerformance,
+    ) -> PreOrderTraversal<'a, Graph, NeighborStrategy, QueueStrategy, Queue, TraversalPerformance>
+    {
+        for _ in 0..self.queue.len() {
+            performance_data.add_queue_push();
+        }
+        performance_data.record_queue_size(self.queue.len());
+
+        PreOrderTraversal {
+            graph: self.graph,
+            queue: self.queue,
+            rank: self.rank,
+            current_rank: self.current_rank,
+            current_node: self.current_node,
+            predecessors: self.predecessors,
+            distances: self.distances,
+            max_depth: self.max_depth,
+            neighbor_iterator: self.neighbor_iterator,
+            performance_data,
+            neighbor_strategy: self.neighbor_strategy,
+            queue_strategy: self.queue_strategy,
+        }
+    }
+}
+
+impl<
+        'a,
+        Graph: StaticGraph,
+        NeighborStrategy: TraversalNeighborStrategy<Graph>,
+        QueueStrategy: TraversalQueueStrategy<Graph, Queue>,
+        Queue: BidirectedQueue<Graph::NodeIndex>,
+        TraversalPerformance: TraversalPerformanceData,
+    > PreOrderTraversal<'a, Graph, NeighborStrategy, QueueStrategy, Queue, TraversalPerformance>
+{
+    /// Enables recording the predecessor of each visited node,
+    /// i.e. the node and edge from which it was discovered.
+    /// This allows to query the paths of the traversal tree with [path_to](Self::path_to).
+    ///
+    /// This should be called before the traversal is advanced, as predecessors of already visited nodes are not recorded.
+    pub fn with_predecessors(mut self) -> Self {
+        self.predecessors = Some(vec![None; self.graph.node_index_bound()]);
+        self
+    }
+
+    /// Enables recording the distance of each visited node from the start node,
+    /// i.e. the amount of edges on its path in the traversal tree.
+    /// This allows to query the distances with [distance_of](Self::distance_of).
+    ///
+    /// When used with the [BfsQueueStrategy], the distance of a node is the minimum amount of edges on a path from the start node,
+    /// i.e. the BFS level of the node.
+    /// This should be called before the traversal is advanced, as distances of already visited nodes are not recorded.
+    pub fn with_distances(mut self) -> Self {
+        self.distances = Some(vec![0; self.graph.node_index_bound()]);
+        self
+    }
+
+    /// Limits the traversal to nodes with a distance of at most `max_depth` from the start node.
+    /// The edges of nodes at the maximum distance are not traversed.
+    /// This implies [with_distances](Self::with_distances).
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        if self.distances.is_none() {
+            self = self.with_distances();
+        }
+        self.max_depth = Some(max_depth);
+        self
+    }
+
     /// Resets the traversal to start from the given node.
     pub fn reset(&mut self, start: Graph::NodeIndex) {
         self.queue.clear();
-        QueueStrategy::push(&mut self.queue, start);
+        self.push(start);
         for rank in &mut self.rank {
             *rank = Graph::OptionalNodeIndex::new_none();
         }
         self.rank[start.as_usize()] = Some(0).into();
         self.current_rank = 1.into();
+        self.current_node = Graph::OptionalNodeIndex::new_none();
+        if let Some(predecessors) = &mut self.predecessors {
+            predecessors.fill(None);
+        }
+        if let Some(distances) = &mut self.distances {
+            distances.fill(0);
+        }
         self.neighbor_iterator = None;
     }
 
@@ -164,7 +302,7 @@ impl<
     pub fn continue_traversal_from(&mut self, start: Graph::NodeIndex) -> Graph::NodeIndex {
         debug_assert!(self.queue.is_empty());
         debug_assert!(self.neighbor_iterator.is_none());
-        QueueStrategy::push(&mut self.queue, start);
+        self.push(start);
         self.rank[start.as_usize()] = Some(self.current_rank).into();
         let result = self.current_rank;
         self.current_rank = self.current_rank + 1;
@@ -179,6 +317,41 @@ impl<
         self.next_internal(forbidden_subgraph)
     }
 
+    /// Advances the traversal by at most `budget` elements, i.e. nodes and edges, ignoring all nodes and edges forbidden by `forbidden_subgraph`.
+    /// Each returned element is passed to `visitor`.
+    ///
+    /// Returns [TraversalExhaustiveness::Complete] if the traversal finished, and [TraversalExhaustiveness::Partial] if it was stopped because the budget was used up.
+    /// In the latter case, the traversal can be resumed by calling this method again, or any of the other `next` methods.
+    /// If the traversal finishes exactly when the budget is used up, then it is reported as partial,
+    /// and resuming it reports it as complete without returning any further elements.
+    pub fn next_with_budget<FN: ForbiddenSubgraph<Graph>>(
+        &mut self,
+        budget: usize,
+        forbidden_subgraph: &FN,
+        mut visitor: impl FnMut(NodeOrEdge<Graph::NodeIndex, Graph::EdgeIndex>),
+    ) -> TraversalExhaustiveness {
+        for _ in 0..budget {
+            if let Some(node_or_edge) = self.next_internal(forbidden_subgraph) {
+                visitor(node_or_edge);
+            } else {
+                return TraversalExhaustiveness::Complete;
+            }
+        }
+
+        TraversalExhaustiveness::Partial
+    }
+
+    /// Returns the performance data collected by this traversal, see [with_performance_data](PreOrderTraversal::with_performance_data).
+    pub fn performance_data(&self) -> &TraversalPerformance {
+        &self.performance_data
+    }
+
+    fn push(&mut self, node: Graph::NodeIndex) {
+        QueueStrategy::push(&mut self.queue, node);
+        self.performance_data.add_queue_push();
+        self.performance_data.record_queue_size(self.queue.len());
+    }
+
     #[inline]
     fn next_internal<FS: ForbiddenSubgraph<Graph>>(
         &mut self,
@@ -187,15 +360,31 @@ impl<
         if let Some(neighbor_iterator) = self.neighbor_iterator.as_mut() {
             for neighbor in neighbor_iterator {
                 if forbidden_subgraph.is_edge_forbidden(neighbor.edge_id) {
+                    self.performance_data.add_skipped_forbidden_edge();
                     continue;
                 }
 
-                if !forbidden_subgraph.is_node_forbidden(neighbor.node_id) {
+                if forbidden_subgraph.is_node_forbidden(neighbor.node_id) {
+                    self.performance_data.add_skipped_forbidden_node();
+                } else {
                     let rank_entry = &mut self.rank[neighbor.node_id.as_usize()];
                     if rank_entry.is_none() {
                         *rank_entry = self.current_rank.into();
                         self.current_rank = self.current_rank + 1;
                         QueueStrategy::push(&mut self.queue, neighbor.node_id);
+                        self.performance_data.add_queue_push();
+                        self.performance_data.record_queue_size(self.queue.len());
+                        let current_node = self.current_node.into().unwrap();
+                        if let Some(predecessors) = &mut self.predecessors {
+                            predecessors[neighbor.node_id.as_usize()] = Some(Neighbor {
+                                edge_id: neighbor.edge_id,
+                                node_id: current_node,
+                            });
+                        }
+                        if let Some(distances) = &mut self.distances {
+                            distances[neighbor.node_id.as_usize()] =
+                                distances[current_node.as_usize()] + 1;
+                        }
                     }
                 }
 
@@ -210,7 +399,15 @@ impl<
                 !forbidden_subgraph.is_node_forbidden(first),
                 "A node became forbidden after being added to the queue. This is not supported."
             );
-            self.neighbor_iterator = Some(NeighborStrategy::neighbor_iterator(self.graph, first));
+            self.current_node = first.into();
+            let expand = match self.max_depth {
+                Some(max_depth) => self.distance_of(first).unwrap() < max_depth,
+                None => true,
+            };
+            if expand {
+                self.neighbor_iterator =
+                    Some(NeighborStrategy::neighbor_iterator(self.graph, first));
+            }
 
             Some(NodeOrEdge::Node(first))
         } else {
@@ -223,13 +420,70 @@ impl<
         let rank = self.rank[node.as_usize()];
         rank.into()
     }
+
+    /// Returns the ranks of all nodes, indexed by node.
+    /// Nodes that have not yet been visited have a rank of `None`.
+    pub fn ranks(&self) -> &[Graph::OptionalNodeIndex] {
+        &self.rank
+    }
+
+    /// Consumes the traversal and returns the ranks of all nodes, indexed by node.
+    /// Nodes that have not been visited have a rank of `None`.
+    pub fn into_ranks(self) -> Vec<Graph::OptionalNodeIndex> {
+        self.rank
+    }
+
+    /// Returns the node and edge from which the given node was discovered,
+    /// or `None` if the node is a start node or has not yet been visited.
+    ///
+    /// Panics if predecessors are not recorded, see [with_predecessors](Self::with_predecessors).
+    pub fn predecessor_of(
+        &self,
+        node: Graph::NodeIndex,
+    ) -> Option<Neighbor<Graph::NodeIndex, Graph::EdgeIndex>> {
+        let predecessors = self
+            .predecessors
+            .as_ref()
+            .expect("predecessors are not recorded");
+        predecessors[node.as_usize()].clone()
+    }
+
+    /// Returns the distance of the given node from the start node, or `None` if the node has not yet been visited.
+    /// Start nodes have a distance of zero, including those passed to [continue_traversal_from](Self::continue_traversal_from).
+    ///
+    /// Panics if distances are not recorded, see [with_distances](Self::with_distances).
+    pub fn distance_of(&self, node: Graph::NodeIndex) -> Option<usize> {
+        let distances = self.distances.as_ref().expect("distances are not recorded");
+        self.rank_of(node)?;
+        // Start nodes are never discovered through an edge, so their distance stays zero.
+        Some(distances[node.as_usize()])
+    }
+
+    /// Returns the edges of the path in the traversal tree from the start node to the given node,
+    /// or `None` if the node has not yet been visited.
+    /// In a BFS, this is a path with the minimum amount of edges.
+    ///
+    /// Panics if predecessors are not recorded, see [with_predecessors](Self::with_predecessors).
+    pub fn path_to(&self, node: Graph::NodeIndex) -> Option<Vec<Graph::EdgeIndex>> {
+        self.rank_of(node)?;
+        let mut path = Vec::new();
+        let mut current_node = node;
+        while let Some(predecessor) = self.predecessor_of(current_node) {
+            path.push(predecessor.edge_id);
+            current_node = predecessor.node_id;
+        }
+        path.reverse();
+        Some(path)
+    }
 }
 impl<
         Graph: StaticGraph,
         NeighborStrategy: TraversalNeighborStrategy<Graph>,
         QueueStrategy: TraversalQueueStrategy<Graph, Queue>,
         Queue: BidirectedQueue<Graph::NodeIndex>,
-    > Iterator for PreOrderTraversal<'_, Graph, NeighborStrategy, QueueStrategy, Queue>
+        TraversalPerformance: TraversalPerformanceData,
+    > Iterator
+    for PreOrderTraversal<'_, Graph, NeighborStrategy, QueueStrategy, Queue, TraversalPerformance>
 {
     type Item = NodeOrEdge<Graph::NodeIndex, Graph::EdgeIndex>;
 
@@ -246,14 +500,18 @@ impl<
 ///
 /// Moreover, the traversal computes the postorder rank of each visited node.
 /// This traversal operates with node-granularity, meaning that the `next` method returns nodes.
+/// Additionally, a forbidden subgraph can be passed using the `next_with_forbidden_subgraph` method to disable some edges and nodes in the traversal.
+/// Performance data about the queue and the forbidden subgraph can be collected with [with_performance_data](Self::with_performance_data).
 pub struct DfsPostOrderTraversal<
     Graph: GraphBase,
     NeighborStrategy,
     Queue: BidirectedQueue<Graph::NodeIndex>,
+    TraversalPerformance = NoopTraversalPerformanceCounter,
 > {
     queue: Queue,
     rank: Vec<Graph::OptionalNodeIndex>,
     current_rank: Graph::NodeIndex,
+    performance_data: TraversalPerformance,
     graph: PhantomData<Graph>,
     neighbor_strategy: PhantomData<NeighborStrategy>,
 }
@@ -268,11 +526,12 @@ impl<
     pub fn new(graph: &Graph, start: Graph::NodeIndex) -> Self {
         let mut queue = Queue::default();
         queue.push_back(start);
-        let rank = vec![Graph::OptionalNodeIndex::new_none(); graph.node_count()];
+        let rank = vec![Graph::OptionalNodeIndex::new_none(); graph.node_index_bound()];
         Self {
             queue,
             rank,
             current_rank: 0.into(),
+            performance_data: NoopTraversalPerformanceCounter,
             graph: Default::default(),
             neighbor_strategy: Default::default(),
         }
@@ -282,20 +541,50 @@ impl<
     /// There is no starting node given, and to start the search, one of the `reset` methods needs to be used.
     pub fn new_without_start(graph: &Graph) -> Self {
         let queue = Queue::default();
-        let rank = vec![Graph::OptionalNodeIndex::new_none(); graph.node_count()];
+        let rank = vec![Graph::OptionalNodeIndex::new_none(); graph.node_index_bound()];
         Self {
             queue,
             rank,
             current_rank: 0.into(),
+            performance_data: NoopTraversalPerformanceCounter,
             graph: Default::default(),
             neighbor_strategy: Default::default(),
         }
     }
 
+    /// Collects performance data of this traversal in the given performance counter.
+    /// Nodes that are in the queue already, like the start node, are counted as pushed immediately.
+    pub fn with_performance_data<TraversalPerformance: TraversalPerformanceData>(
+        self,
+        mut performance_data: TraversalPerformance,
+    ) -> DfsPostOrderTraversal<Graph, NeighborStrategy, Queue, TraversalPerformance> {
+        for _ in 0..self.queue.len() {
+            performance_data.add_queue_push();
+        }
+        performance_data.record_queue_size(self.queue.len());
+
+        DfsPostOrderTraversal {
+            queue: self.queue,
+            rank: self.rank,
+            current_rank: self.current_rank,
+            performance_data,
+            graph: self.graph,
+            neighbor_strategy: self.neighbor_strategy,
+        }
+    }
+}
+
+impl<
+        Graph: StaticGraph,
+        NeighborStrategy: TraversalNeighborStrategy<Graph>,
+        Queue: BidirectedQueue<Graph::NodeIndex>,
+        TraversalPerformance: TraversalPerformanceData,
+    > DfsPostOrderTraversal<Graph, NeighborStrategy, Queue, TraversalPerformance>
+{
     /// Resets the traversal to start from the given node.
     pub fn reset(&mut self, start: Graph::NodeIndex) {
         self.queue.clear();
-        self.queue.push_back(start);
+        self.push(start);
         for rank in &mut self.rank {
             *rank = Graph::OptionalNodeIndex::new_none();
         }
@@ -305,11 +594,62 @@ impl<
     /// Resets the traversal to start from the given node without resetting the visited nodes.
     pub fn continue_traversal_from(&mut self, start: Graph::NodeIndex) {
         assert!(self.queue.is_empty());
-        self.queue.push_back(start);
+        self.push(start);
     }
 
     /// Computes and returns the next node in depth-first search postorder.
     pub fn next(&mut self, graph: &'_ Graph) -> Option<Graph::NodeIndex> {
+        self.next_internal(graph, &NoForbiddenSubgraph)
+    }
+
+    /// Computes and returns the next node in depth-first search postorder, ignoring all nodes and edges forbidden by `forbidden_subgraph`.
+    pub fn next_with_forbidden_subgraph<FN: ForbiddenSubgraph<Graph>>(
+        &mut self,
+        graph: &'_ Graph,
+        forbidden_subgraph: &FN,
+    ) -> Option<Graph::NodeIndex> {
+        self.next_internal(graph, forbidden_subgraph)
+    }
+
+    /// Advances the traversal by at most `budget` nodes, ignoring all nodes and edges forbidden by `forbidden_subgraph`.
+    /// Each returned node is passed to `visitor`.
+    ///
+    /// See [PreOrderTraversal::next_with_budget] for the meaning of the return value.
+    pub fn next_with_budget<FN: ForbiddenSubgraph<Graph>>(
+        &mut self,
+        graph: &'_ Graph,
+        budget: usize,
+        forbidden_subgraph: &FN,
+        mut visitor: impl FnMut(Graph::NodeIndex),
+    ) -> TraversalExhaustiveness {
+        for _ in 0..budget {
+            if let Some(node) = self.next_internal(graph, forbidden_subgraph) {
+                visitor(node);
+            } else {
+                return TraversalExhaustiveness::Complete;
+            }
+        }
+
+        TraversalExhaustiveness::Partial
+    }
+
+    /// Returns the performance data collected by this traversal, see [with_performance_data](DfsPostOrderTraversal::with_performance_data).
+    pub fn performance_data(&self) -> &TraversalPerformance {
+        &self.performance_data
+    }
+
+    fn push(&mut self, node: Graph::NodeIndex) {
+        self.queue.push_back(node);
+        self.performance_data.add_queue_push();
+        self.performance_data.record_queue_size(self.queue.len());
+    }
+
+    #[inline]
+    fn next_internal<FS: ForbiddenSubgraph<Graph>>(
+        &mut self,
+        graph: &'_ Graph,
+        forbidden_subgraph: &FS,
+    ) -> Option<Graph::NodeIndex> {
         while let Some(first) = self.queue.pop_back() {
             let rank_entry = &mut self.rank[first.as_usize()];
             if *rank_entry == Self::explored_rank() {
@@ -319,13 +659,25 @@ impl<
 
                 return Some(first);
             } else if rank_entry.is_none() {
-                self.queue.push_back(first);
+                debug_assert!(
+                    !forbidden_subgraph.is_node_forbidden(first),
+                    "A node became forbidden after being added to the queue. This is not supported."
+                );
                 *rank_entry = Self::explored_rank();
+                self.push(first);
 
                 for neighbor in NeighborStrategy::neighbor_iterator(graph, first) {
-                    let rank_entry = &mut self.rank[neighbor.node_id.as_usize()];
-                    if rank_entry.is_none() {
-                        self.queue.push_back(neighbor.node_id);
+                    if forbidden_subgraph.is_edge_forbidden(neighbor.edge_id) {
+                        self.performance_data.add_skipped_forbidden_edge();
+                        continue;
+                    }
+                    if forbidden_subgraph.is_node_forbidden(neighbor.node_id) {
+                        self.performance_data.add_skipped_forbidden_node();
+                        continue;
+                    }
+
+                    if self.rank[neighbor.node_id.as_usize()].is_none() {
+                        self.push(neighbor.node_id);
                     }
                 }
             }
@@ -340,6 +692,21 @@ impl<
         rank.into()
     }
 
+    /// Returns the postorder ranks of all nodes, indexed by node.
+    /// Nodes that have not yet been visited have a rank of `None`.
+    ///
+    /// The ranks of nodes that have been visited but not yet processed completely are unspecified.
+    /// Once the traversal is finished, i.e. `next` returned `None`, all visited nodes have been processed completely.
+    pub fn ranks(&self) -> &[Graph::OptionalNodeIndex] {
+        &self.rank
+    }
+
+    /// Consumes the traversal and returns the postorder ranks of all nodes, indexed by node.
+    /// See [ranks](Self::ranks) for details.
+    pub fn into_ranks(self) -> Vec<Graph::OptionalNodeIndex> {
+        self.rank
+    }
+
     fn explored_rank() -> Graph::OptionalNodeIndex {
         Some(Graph::OptionalNodeIndex::new_none().as_usize_unchecked() - 1).into()
     }
@@ -554,6 +921,36 @@ impl<Graph: NavigableGraph + ImmutableGraphContainer> TraversalNeighborStrategy<
     }
 }
 
+/// A neighbor strategy that traverses all edges incident to a node of an [UndirectedGraph].
+///
+/// Unlike [UndirectedNeighborStrategy], this does not require the graph to be directed,
+/// such that algorithms that are generic over the neighbor strategy can be run on undirected graphs directly.
+pub struct UndirectedGraphNeighborStrategy;
+
+impl<Graph: UndirectedGraph> TraversalNeighborStrategy<Graph> for UndirectedGraphNeighborStrategy {
+    type Iterator<'a>
+        = Graph::Neighbors<'a>
+    where
+        Self: 'a,
+        Graph: 'a;
+    type EdgeNeighborIterator<'a>
+        = std::iter::Chain<std::iter::Once<Graph::NodeIndex>, std::iter::Once<Graph::NodeIndex>>
+    where
+        Graph: 'a;
+
+    fn neighbor_iterator(graph: &Graph, node: Graph::NodeIndex) -> Self::Iterator<'_> {
+        graph.neighbors(node)
+    }
+
+    fn edge_neighbor_iterator(
+        graph: &Graph,
+        edge: Graph::EdgeIndex,
+    ) -> Self::EdgeNeighborIterator<'_> {
+        let endpoints = graph.edge_endpoints(edge);
+        std::iter::once(endpoints.to_node).chain(std::iter::once(endpoints.from_node))
+    }
+}
+
 /// A queue strategy that works by the first-in first-out principle.
 pub struct BfsQueueStrategy;
 
@@ -586,10 +983,16 @@ impl<Graph: GraphBase, Queue: BidirectedQueue<Graph::NodeIndex>>
 
 #[cfg(test)]
 mod test {
-    use crate::traversal::{DfsPostOrderTraversal, ForwardNeighborStrategy};
+    use crate::traversal::performance_counters::TraversalPerformanceCounter;
+    use crate::traversal::{
+        BfsQueueStrategy, DfsPostOrderTraversal, ForbiddenEdge, ForbiddenNode,
+        ForwardNeighborStrategy, NoForbiddenSubgraph, PreOrderForwardBfs, PreOrderForwardDfs,
+        PreOrderTraversal, TraversalExhaustiveness, UndirectedGraphNeighborStrategy,
+    };
     use std::collections::VecDeque;
     use traitgraph::implementation::petgraph_impl::PetGraph;
-    use traitgraph::interface::{MutableGraphContainer, NavigableGraph};
+    use traitgraph::implementation::undirected_graph::Undirected;
+    use traitgraph::interface::{MutableGraphContainer, NavigableGraph, Neighbor, NodeOrEdge};
 
     #[test]
     fn test_postorder_traversal_simple() {
@@ -618,5 +1021,211 @@ mod test {
         debug_assert_eq!(ordering.next(&graph), Some(n1));
         debug_assert_eq!(ordering.next(&graph), Some(n0));
         debug_assert_eq!(ordering.next(&graph), None);
+        debug_assert_eq!(
+            ordering.into_ranks(),
+            [Some(3), Some(2), Some(1), Some(0)].map(Into::into)
+        );
+    }
+
+    #[test]
+    fn test_postorder_traversal_forbidden_subgraph() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        let e03 = graph.add_edge(n[0], n[3], ());
+        graph.add_edge(n[3], n[4], ());
+        graph.add_edge(n[0], n[4], ());
+
+        let mut ordering =
+            DfsPostOrderTraversal::<_, ForwardNeighborStrategy, VecDeque<_>>::new(&graph, n[0]);
+        let forbidden_node = ForbiddenNode::new(n[1]);
+        let mut visited = Vec::new();
+        while let Some(node) = ordering.next_with_forbidden_subgraph(&graph, &forbidden_node) {
+            visited.push(node);
+        }
+        debug_assert_eq!(visited.len(), 3);
+        debug_assert_eq!(visited.last(), Some(&n[0]));
+        debug_assert_eq!(ordering.rank_of(n[1]), None);
+        debug_assert_eq!(ordering.rank_of(n[2]), None);
+
+        ordering.reset(n[0]);
+        let forbidden_edge = ForbiddenEdge::new(e03);
+        let mut visited = Vec::new();
+        while let Some(node) = ordering.next_with_forbidden_subgraph(&graph, &forbidden_edge) {
+            visited.push(node);
+        }
+        debug_assert_eq!(visited.len(), 4);
+        debug_assert_eq!(ordering.rank_of(n[3]), None);
+    }
+
+    #[test]
+    fn test_performance_data() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[0], n[2], ());
+        let e23 = graph.add_edge(n[2], n[3], ());
+        graph.add_edge(n[3], n[0], ());
+
+        let mut traversal = PreOrderForwardBfs::new(&graph, n[0])
+            .with_performance_data(TraversalPerformanceCounter::default());
+        let forbidden_edge = ForbiddenEdge::new(e23);
+        while traversal
+            .next_with_forbidden_subgraph(&forbidden_edge)
+            .is_some()
+        {}
+        debug_assert_eq!(
+            traversal.performance_data(),
+            &TraversalPerformanceCounter {
+                queue_pushes: 3,
+                max_queue_size: 2,
+                skipped_forbidden_nodes: 0,
+                skipped_forbidden_edges: 1,
+            }
+        );
+
+        let mut ordering =
+            DfsPostOrderTraversal::<_, ForwardNeighborStrategy, VecDeque<_>>::new(&graph, n[0])
+                .with_performance_data(TraversalPerformanceCounter::default());
+        let forbidden_node = ForbiddenNode::new(n[1]);
+        while ordering
+            .next_with_forbidden_subgraph(&graph, &forbidden_node)
+            .is_some()
+        {}
+        debug_assert_eq!(ordering.performance_data().queue_pushes, 6);
+        debug_assert_eq!(ordering.performance_data().skipped_forbidden_nodes, 1);
+        debug_assert_eq!(ordering.performance_data().skipped_forbidden_edges, 0);
+    }
+
+    #[test]
+    fn test_budget() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[3], ());
+
+        let expected: Vec<_> = PreOrderForwardDfs::new(&graph, n[0]).collect();
+        assert_eq!(expected.len(), 7);
+        let mut traversal = PreOrderForwardDfs::new(&graph, n[0]);
+        let mut order = Vec::new();
+        let mut statuses = Vec::new();
+        for _ in 0..4 {
+            statuses.push(
+                traversal.next_with_budget(3, &NoForbiddenSubgraph, |node_or_edge| {
+                    order.push(node_or_edge)
+                }),
+            );
+        }
+        assert_eq!(order, expected);
+        assert_eq!(
+            statuses,
+            [
+                TraversalExhaustiveness::Partial,
+                TraversalExhaustiveness::Partial,
+                TraversalExhaustiveness::Complete,
+                TraversalExhaustiveness::Complete,
+            ]
+        );
+
+        let mut ordering =
+            DfsPostOrderTraversal::<_, ForwardNeighborStrategy, VecDeque<_>>::new(&graph, n[0]);
+        let mut order = Vec::new();
+        let forbidden_node = ForbiddenNode::new(n[3]);
+        assert_eq!(
+            ordering.next_with_budget(&graph, 3, &forbidden_node, |node| order.push(node)),
+            TraversalExhaustiveness::Partial
+        );
+        assert_eq!(order, [n[2], n[1], n[0]]);
+        assert_eq!(
+            ordering.next_with_budget(&graph, 3, &forbidden_node, |node| order.push(node)),
+            TraversalExhaustiveness::Complete
+        );
+        assert_eq!(order.len(), 3);
+    }
+
+    #[test]
+    fn test_undirected_graph_traversal() {
+        let mut graph = Undirected::new(PetGraph::new());
+        let n0 = graph.add_node(0);
+        let n1 = graph.add_node(1);
+        let n2 = graph.add_node(2);
+        let n3 = graph.add_node(3);
+        graph.add_edge(n1, n0, 10);
+        graph.add_edge(n2, n1, 11);
+        graph.add_edge(n3, n3, 12);
+
+        let visited: Vec<_> = PreOrderTraversal::<
+            _,
+            UndirectedGraphNeighborStrategy,
+            BfsQueueStrategy,
+            VecDeque<_>,
+        >::new(&graph, n0)
+        .filter_map(|node_or_edge| match node_or_edge {
+            NodeOrEdge::Node(node) => Some(node),
+            NodeOrEdge::Edge(_) => None,
+        })
+        .collect();
+        debug_assert_eq!(visited, [n0, n1, n2]);
+    }
+    #[test]
+    fn test_bfs_predecessors() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        let e01 = graph.add_edge(n[0], n[1], ());
+        let e12 = graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[3], ());
+        let e03 = graph.add_edge(n[0], n[3], ());
+        let e31 = graph.add_edge(n[3], n[1], ());
+
+        let mut traversal = PreOrderForwardBfs::new(&graph, n[0]).with_predecessors();
+        traversal.by_ref().for_each(|_| {});
+        debug_assert_eq!(traversal.path_to(n[0]), Some(vec![]));
+        debug_assert_eq!(traversal.path_to(n[1]), Some(vec![e01]));
+        debug_assert_eq!(traversal.path_to(n[2]), Some(vec![e01, e12]));
+        debug_assert_eq!(traversal.path_to(n[3]), Some(vec![e03]));
+        debug_assert_eq!(traversal.path_to(n[4]), None);
+        debug_assert_eq!(
+            traversal.predecessor_of(n[2]),
+            Some(Neighbor {
+                edge_id: e12,
+                node_id: n[1]
+            })
+        );
+        debug_assert_eq!(traversal.predecessor_of(n[0]), None);
+
+        traversal.reset(n[3]);
+        traversal.by_ref().for_each(|_| {});
+        debug_assert_eq!(traversal.path_to(n[2]), Some(vec![e31, e12]));
+        debug_assert_eq!(traversal.path_to(n[0]), None);
+    }
+    #[test]
+    fn test_bfs_distances() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[3], ());
+        graph.add_edge(n[0], n[2], ());
+
+        let mut traversal = PreOrderForwardBfs::new(&graph, n[0]).with_distances();
+        debug_assert_eq!(traversal.distance_of(n[0]), Some(0));
+        traversal.by_ref().for_each(|_| {});
+        let distances: Vec<_> = n.iter().map(|&node| traversal.distance_of(node)).collect();
+        debug_assert_eq!(distances, [Some(0), Some(1), Some(1), Some(2), None]);
+        debug_assert_eq!(
+            traversal.ranks(),
+            [Some(0), Some(2), Some(1), Some(3), None].map(Into::into)
+        );
+
+        let visited: Vec<_> = PreOrderForwardBfs::new(&graph, n[1])
+            .with_max_depth(1)
+            .filter_map(|node_or_edge| match node_or_edge {
+                NodeOrEdge::Node(node) => Some(node),
+                NodeOrEdge::Edge(_) => None,
+            })
+            .collect();
+        debug_assert_eq!(visited, [n[1], n[2]]);
     }
 }
@@ -0,0 +1,140 @@
+use std::ops::{Add, AddAssign};
+
+/// Performance data collected by graph traversals.
+/// This trait allows to collect the performance data optionally,
+/// by providing a type that either collects it, or ignores it.
+pub trait TraversalPerformanceData {
+    /// Increment the number of nodes pushed into the queue of the traversal.
+    fn add_queue_push(&mut self);
+
+    /// Record the current queue size of the traversal.
+    fn record_queue_size(&mut self, queue_size: usize);
+
+    /// Increment the number of nodes that were skipped because they are forbidden.
+    fn add_skipped_forbidden_node(&mut self);
+
+    /// Increment the number of edges that were skipped because they are forbidden.
+    fn add_skipped_forbidden_edge(&mut self);
+
+    /// Get the number of nodes pushed into the queue of the traversal.
+    fn queue_pushes(&self) -> Option<u64>;
+
+    /// Get the maximum queue size encountered at any point during execution.
+    fn max_queue_size(&self) -> Option<usize>;
+
+    /// Get the number of nodes that were skipped because they are forbidden.
+    fn skipped_forbidden_nodes(&self) -> Option<u64>;
+
+    /// Get the number of edges that were skipped because they are forbidden.
+    fn skipped_forbidden_edges(&self) -> Option<u64>;
+}
+
+/// A simple performance counter for graph traversals, keeping all supported counts.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct TraversalPerformanceCounter {
+    /// The number of nodes pushed into the queue.
+    pub queue_pushes: u64,
+    /// The maximum queue size.
+    pub max_queue_size: usize,
+    /// The number of nodes that were skipped because they are forbidden.
+    pub skipped_forbidden_nodes: u64,
+    /// The number of edges that were skipped because they are forbidden.
+    pub skipped_forbidden_edges: u64,
+}
+
+/// A performance counter for graph traversals that ignores all counts.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NoopTraversalPerformanceCounter;
+
+impl TraversalPerformanceData for TraversalPerformanceCounter {
+    fn add_queue_push(&mut self) {
+        self.queue_pushes += 1;
+    }
+
+    fn record_queue_size(&mut self, queue_size: usize) {
+        self.max_queue_size = self.max_queue_size.max(queue_size);
+    }
+
+    fn add_skipped_forbidden_node(&mut self) {
+        self.skipped_forbidden_nodes += 1;
+    }
+
+    fn add_skipped_forbidden_edge(&mut self) {
+        self.skipped_forbidden_edges += 1;
+    }
+
+    fn queue_pushes(&self) -> Option<u64> {
+        Some(self.queue_pushes)
+    }
+
+    fn max_queue_size(&self) -> Option<usize> {
+        Some(self.max_queue_size)
+    }
+
+    fn skipped_forbidden_nodes(&self) -> Option<u64> {
+        Some(self.skipped_forbidden_nodes)
+    }
+
+    fn skipped_forbidden_edges(&self) -> Option<u64> {
+        Some(self.skipped_forbidden_edges)
+    }
+}
+
+impl TraversalPerformanceData for NoopTraversalPerformanceCounter {
+    fn add_queue_push(&mut self) {}
+
+    fn record_queue_size(&mut self, _queue_size: usize) {}
+
+    fn add_skipped_forbidden_node(&mut self) {}
+
+    fn add_skipped_forbidden_edge(&mut self) {}
+
+    fn queue_pushes(&self) -> Option<u64> {
+        None
+    }
+
+    fn max_queue_size(&self) -> Option<usize> {
+        None
+    }
+
+    fn skipped_forbidden_nodes(&self) -> Option<u64> {
+        None
+    }
+
+    fn skipped_forbidden_edges(&self) -> Option<u64> {
+        None
+    }
+}
+
+impl Add for TraversalPerformanceCounter {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            queue_pushes: self.queue_pushes + rhs.queue_pushes,
+            max_queue_size: self.max_queue_size.max(rhs.max_queue_size),
+            skipped_forbidden_nodes: self.skipped_forbidden_nodes + rhs.skipped_forbidden_nodes,
+            skipped_forbidden_edges: self.skipped_forbidden_edges + rhs.skipped_forbidden_edges,
+        }
+    }
+}
+
+impl Add for NoopTraversalPerformanceCounter {
+    type Output = Self;
+
+    fn add(self, _rhs: Self) -> Self::Output {
+        Self
+    }
+}
+
+impl AddAssign for TraversalPerformanceCounter {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl AddAssign for NoopTraversalPerformanceCounter {
+    fn add_assign(&mut self, _rhs: Self) {
+        // do nothing
+    }
+}
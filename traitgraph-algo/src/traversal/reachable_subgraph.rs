@@ -0,0 +1,203 @@
+use crate::traversal::TraversalNeighborStrategy;
+use std::collections::VecDeque;
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::subgraph::MutableSubgraph;
+use traitgraph::interface::{ImmutableGraphContainer, StaticGraph};
+
+/// Enables all nodes reachable from the given seed nodes in the given subgraph, together with the edges between them.
+/// The direction of the search is defined by the `NeighborStrategy`, i.e. forward, backward or undirected.
+///
+/// The nodes are visited in breadth-first order, such that the depth of a node is its distance from the closest seed node.
+/// If `max_depth` is given, then only nodes up to that depth are enabled,
+/// i.e. a `max_depth` of zero enables only the seed nodes.
+/// An edge is enabled if it is incident to a node below the depth limit,
+/// so edges between two nodes at the maximum depth are not enabled.
+/// If the subgraph is [induced](MutableSubgraph::INDUCED), then only nodes are enabled,
+/// and it contains all edges between the enabled nodes, including those between two nodes at the maximum depth.
+///
+/// The subgraph is not cleared before, and its present nodes do not influence the search.
+/// Nodes and edges that are already present in the subgraph are not enabled again.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+/// use traitgraph_algo::traversal::ForwardNeighborStrategy;
+/// use traitgraph_algo::traversal::reachable_subgraph::fill_reachable_subgraph;
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[0], nodes[1], ());
+/// graph.add_edge(nodes[1], nodes[2], ());
+/// graph.add_edge(nodes[2], nodes[3], ());
+/// graph.add_edge(nodes[4], nodes[0], ());
+///
+/// let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+/// fill_reachable_subgraph::<_, ForwardNeighborStrategy, _>(&graph, &mut subgraph, [nodes[0]], Some(2));
+/// assert_eq!(subgraph.node_indices().collect::<Vec<_>>(), nodes[0..3]);
+/// assert_eq!(subgraph.edge_count(), 2);
+/// ```
+pub fn fill_reachable_subgraph<
+    Graph: StaticGraph,
+    NeighborStrategy: TraversalNeighborStrategy<Graph>,
+    Subgraph: MutableSubgraph<NodeIndex = Graph::NodeIndex, EdgeIndex = Graph::EdgeIndex>
+        + ImmutableGraphContainer,
+>(
+    graph: &Graph,
+    subgraph: &mut Subgraph,
+    seeds: impl IntoIterator<Item = Graph::NodeIndex>,
+    max_depth: Option<usize>,
+) {
+    let mut visited = vec![false; graph.node_index_bound()];
+    let mut queue = VecDeque::new();
+
+    for seed in seeds {
+        if !visited[seed.as_usize()] {
+            visited[seed.as_usize()] = true;
+            if !subgraph.contains_node_index(seed) {
+                subgraph.enable_node(seed);
+            }
+            queue.push_back((seed, 0));
+        }
+    }
+
+    while let Some((node_id, depth)) = queue.pop_front() {
+        if max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            continue;
+        }
+
+        for neighbor in NeighborStrategy::neighbor_iterator(graph, node_id) {
+            let neighbor_visited = &mut visited[neighbor.node_id.as_usize()];
+            if !*neighbor_visited {
+                *neighbor_visited = true;
+                if !subgraph.contains_node_index(neighbor.node_id) {
+                    subgraph.enable_node(neighbor.node_id);
+                }
+                queue.push_back((neighbor.node_id, depth + 1));
+            }
+            // In an undirected search, edges are examined from both endpoints.
+            if !Subgraph::INDUCED && !subgraph.contains_edge_index(neighbor.edge_id) {
+                subgraph.enable_edge(neighbor.edge_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::reachable_subgraph::fill_reachable_subgraph;
+    use crate::traversal::{BackwardNeighborStrategy, UndirectedNeighborStrategy};
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+    use traitgraph::implementation::subgraphs::incremental_subgraph::IncrementalSubgraph;
+    use traitgraph::implementation::subgraphs::induced_bit_vector_subgraph::InducedBitVectorSubgraph;
+    use traitgraph::interface::subgraph::MutableSubgraph;
+    use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_directions_and_depth() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        let e = [
+            graph.add_edge(n[0], n[1], ()),
+            graph.add_edge(n[1], n[2], ()),
+            graph.add_edge(n[3], n[2], ()),
+            graph.add_edge(n[4], n[3], ()),
+            graph.add_edge(n[2], n[2], ()),
+        ];
+
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        fill_reachable_subgraph::<_, BackwardNeighborStrategy, _>(
+            &graph,
+            &mut subgraph,
+            [n[2]],
+            None,
+        );
+        assert_eq!(
+            subgraph.node_indices().collect::<Vec<_>>(),
+            [n[0], n[1], n[2], n[3], n[4]]
+        );
+        assert_eq!(subgraph.edge_count(), 5);
+
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        fill_reachable_subgraph::<_, UndirectedNeighborStrategy, _>(
+            &graph,
+            &mut subgraph,
+            [n[0], n[5]],
+            Some(2),
+        );
+        assert_eq!(
+            subgraph.node_indices().collect::<Vec<_>>(),
+            [n[0], n[1], n[2], n[5]]
+        );
+        assert_eq!(subgraph.edge_indices().collect::<Vec<_>>(), [e[0], e[1]]);
+
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        fill_reachable_subgraph::<_, UndirectedNeighborStrategy, _>(
+            &graph,
+            &mut subgraph,
+            [n[3]],
+            Some(0),
+        );
+        assert_eq!(subgraph.node_indices().collect::<Vec<_>>(), [n[3]]);
+        assert_eq!(subgraph.edge_count(), 0);
+    }
+
+    #[test]
+    fn test_undirected_incremental_subgraph() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e = [
+            graph.add_edge(n[0], n[1], ()),
+            graph.add_edge(n[1], n[2], ()),
+            graph.add_edge(n[2], n[0], ()),
+            graph.add_edge(n[2], n[3], ()),
+        ];
+
+        let mut subgraph = IncrementalSubgraph::new_with_incremental_steps(&graph, 2);
+        subgraph.enable_node(n[0]);
+        subgraph.enable_node(n[1]);
+        subgraph.enable_edge(e[0]);
+        subgraph.set_current_step(1);
+        fill_reachable_subgraph::<_, UndirectedNeighborStrategy, _>(
+            &graph,
+            &mut subgraph,
+            [n[0], n[1]],
+            None,
+        );
+        assert_eq!(subgraph.node_count(), 4);
+        assert_eq!(subgraph.edge_count(), 4);
+        assert_eq!(*subgraph.new_nodes(), [n[2], n[3]]);
+        let mut new_edges = subgraph.new_edges().clone();
+        new_edges.sort_unstable();
+        assert_eq!(new_edges, e[1..]);
+    }
+
+    #[test]
+    fn test_induced_subgraph() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e = [
+            graph.add_edge(n[0], n[1], ()),
+            graph.add_edge(n[0], n[2], ()),
+            graph.add_edge(n[1], n[2], ()),
+            graph.add_edge(n[2], n[3], ()),
+        ];
+
+        let mut subgraph = InducedBitVectorSubgraph::new_empty(&graph);
+        fill_reachable_subgraph::<_, UndirectedNeighborStrategy, _>(
+            &graph,
+            &mut subgraph,
+            [n[0]],
+            Some(1),
+        );
+        assert_eq!(
+            subgraph.node_indices().collect::<Vec<_>>(),
+            [n[0], n[1], n[2]]
+        );
+        // The edge between the two nodes at the maximum depth is induced.
+        assert_eq!(subgraph.edge_indices().collect::<Vec<_>>(), e[..3]);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::traversal::{
+    BackwardNeighborStrategy, DfsPostOrderTraversal, ForwardNeighborStrategy,
+    TraversalNeighborStrategy, UndirectedNeighborStrategy,
+};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use traitgraph::interface::{GraphBase, StaticGraph};
+
+/// A reverse post-order forward DFS in a directed graph.
+pub type ReversePostOrderForwardDfs<Graph> =
+    ReversePostOrderTraversal<Graph, ForwardNeighborStrategy>;
+/// A reverse post-order backward DFS in a directed graph.
+pub type ReversePostOrderBackwardDfs<Graph> =
+    ReversePostOrderTraversal<Graph, BackwardNeighborStrategy>;
+/// A reverse post-order DFS that treats each directed edge as an undirected edge.
+pub type ReversePostOrderUndirectedDfs<Graph> =
+    ReversePostOrderTraversal<Graph, UndirectedNeighborStrategy>;
+
+/// A depth first graph traversal that returns nodes in reverse postorder.
+///
+/// The traversal is generic over the graph implementation and the direction of the search (`NeighborStrategy`).
+///
+/// Since the first node in reverse postorder is only known after the whole search is complete,
+/// this traversal runs a [DfsPostOrderTraversal] on construction and buffers its result.
+/// In a directed acyclic graph, the reverse postorder of a forward search is a topological order.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::traversal::reverse_postorder::ReversePostOrderForwardDfs;
+///
+/// let mut graph = PetGraph::new();
+/// let n0 = graph.add_node(0);
+/// let n1 = graph.add_node(1);
+/// let n2 = graph.add_node(2);
+/// graph.add_edge(n0, n1, ());
+/// graph.add_edge(n1, n2, ());
+/// graph.add_edge(n0, n2, ());
+///
+/// let order: Vec<_> = ReversePostOrderForwardDfs::new(&graph, n0).collect();
+/// assert_eq!(order, [n0, n1, n2]);
+/// ```
+pub struct ReversePostOrderTraversal<Graph: GraphBase, NeighborStrategy> {
+    postorder: Vec<Graph::NodeIndex>,
+    neighbor_strategy: PhantomData<NeighborStrategy>,
+}
+
+impl<Graph: StaticGraph, NeighborStrategy: TraversalNeighborStrategy<Graph>>
+    ReversePostOrderTraversal<Graph, NeighborStrategy>
+{
+    /// Creates a new traversal that operates on the given graph, starting from the given node.
+    pub fn new(graph: &Graph, start: Graph::NodeIndex) -> Self {
+        Self::new_with_starts(graph, [start])
+    }
+
+    /// Creates a new traversal that operates on the given graph, starting from all given nodes in order.
+    /// Each start node that was already visited from a previous start node is ignored.
+    ///
+    /// To traverse the whole graph, pass all nodes of the graph as start nodes.
+    pub fn new_with_starts(
+        graph: &Graph,
+        starts: impl IntoIterator<Item = Graph::NodeIndex>,
+    ) -> Self {
+        let mut traversal = DfsPostOrderTraversal::<
+            Graph,
+            NeighborStrategy,
+            VecDeque<Graph::NodeIndex>,
+        >::new_without_start(graph);
+        let mut postorder = Vec::new();
+        for start in starts {
+            traversal.continue_traversal_from(start);
+            while let Some(node) = traversal.next(graph) {
+                postorder.push(node);
+            }
+        }
+
+        Self {
+            postorder,
+            neighbor_strategy: Default::default(),
+        }
+    }
+}
+
+impl<Graph: StaticGraph, NeighborStrategy> Iterator
+    for ReversePostOrderTraversal<Graph, NeighborStrategy>
+{
+    type Item = Graph::NodeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.postorder.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.postorder.len(), Some(self.postorder.len()))
+    }
+}
+
+impl<Graph: StaticGraph, NeighborStrategy> ExactSizeIterator
+    for ReversePostOrderTraversal<Graph, NeighborStrategy>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::reverse_postorder::ReversePostOrderForwardDfs;
+    use crate::traversal::PostOrderForwardDfs;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::index::GraphIndex;
+    use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_topological_order() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..7).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(3, 1), (1, 0), (3, 5), (5, 0), (6, 2), (2, 4), (6, 4)] {
+            graph.add_edge(n[from], n[to], ());
+        }
+
+        let order: Vec<_> =
+            ReversePostOrderForwardDfs::new_with_starts(&graph, graph.node_indices()).collect();
+        assert_eq!(order.len(), graph.node_count());
+        let mut position = vec![0; graph.node_count()];
+        for (i, node) in order.iter().enumerate() {
+            position[node.as_usize()] = i;
+        }
+        for edge in graph.edge_indices() {
+            let endpoints = graph.edge_endpoints(edge);
+            assert!(
+                position[endpoints.from_node.as_usize()] < position[endpoints.to_node.as_usize()]
+            );
+        }
+
+        let mut postorder = PostOrderForwardDfs::new(&graph, n[3]);
+        let mut expected = Vec::new();
+        while let Some(node) = postorder.next(&graph) {
+            expected.push(node);
+        }
+        expected.reverse();
+        let reverse_postorder = ReversePostOrderForwardDfs::new(&graph, n[3]);
+        assert_eq!(reverse_postorder.len(), 4);
+        assert_eq!(reverse_postorder.collect::<Vec<_>>(), expected);
+    }
+}
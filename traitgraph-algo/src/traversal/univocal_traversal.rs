@@ -2,7 +2,9 @@ use crate::traversal::{
     BackwardNeighborStrategy, ForwardNeighborStrategy, TraversalNeighborStrategy,
 };
 use std::marker::PhantomData;
+use traitgraph::index::GraphIndex;
 use traitgraph::interface::{GraphBase, NavigableGraph, NodeOrEdge, StaticGraph};
+use traitgraph::walks::VecEdgeWalk;
 
 /// An iterator over the univocal extension of a node or edge.
 /// The direction is defined by the `NeighborStrategy`.
@@ -159,9 +161,161 @@ pub fn is_edge_self_bivalent<Graph: StaticGraph>(graph: &Graph, edge_id: Graph::
     backward_iter.any(|e| e == last_element)
 }
 
+/// Returns all maximal univocal walks of the given graph.
+///
+/// The univocal extension of an edge is the walk obtained by following unique out-edges forwards from its head
+/// and unique in-edges backwards from its tail, as computed by the [UnivocalIterator].
+/// An extension stops before it would repeat an edge, such that each walk contains each edge at most once.
+/// If the forward extension of an edge reaches the edge itself, then the extension is a circular walk, which is rotated such that it starts with its edge of minimum index.
+/// The result contains the univocal extensions of all edges that are not a proper subwalk of the univocal extension of another edge,
+/// where circular walks are considered in all rotations.
+/// Each edge of the graph is contained in at least one of the returned walks, and the walks are sorted and free of duplicates.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::traversal::univocal_traversal::maximal_univocal_walks;
+///
+/// let mut graph = PetGraph::new();
+/// let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// let e01 = graph.add_edge(n[0], n[1], ());
+/// let e12 = graph.add_edge(n[1], n[2], ());
+/// let e13 = graph.add_edge(n[1], n[3], ());
+///
+/// assert_eq!(maximal_univocal_walks(&graph), [vec![e01, e12], vec![e01, e13]]);
+/// ```
+pub fn maximal_univocal_walks<Graph: StaticGraph>(graph: &Graph) -> Vec<VecEdgeWalk<Graph>> {
+    let mut in_walk = vec![false; graph.edge_index_bound()];
+    let mut in_circular_walk = vec![false; graph.edge_index_bound()];
+    let mut walks = Vec::new();
+
+    for edge in graph.edge_indices() {
+        if in_circular_walk[edge.as_usize()] {
+            continue;
+        }
+
+        let (walk, is_circular) = univocal_extension(graph, edge, &mut in_walk);
+        if is_circular {
+            for edge in &walk {
+                in_circular_walk[edge.as_usize()] = true;
+            }
+        }
+        walks.push((walk, is_circular));
+    }
+    walks.sort_unstable();
+    walks.dedup();
+
+    // For each edge, the walks that contain it.
+    let mut walks_of_edge = vec![Vec::new(); graph.edge_index_bound()];
+    for (walk_index, (walk, _)) in walks.iter().enumerate() {
+        for edge in walk {
+            walks_of_edge[edge.as_usize()].push(walk_index);
+        }
+    }
+
+    let is_maximal: Vec<_> = walks
+        .iter()
+        .map(|(walk, is_circular)| {
+            walks_of_edge[walk[0].as_usize()]
+                .iter()
+                .map(|&other_index| &walks[other_index])
+                .all(|(other_walk, other_is_circular)| {
+                    other_walk.len() <= walk.len()
+                        || !is_subwalk(walk, *is_circular, other_walk, *other_is_circular)
+                })
+        })
+        .collect();
+    walks
+        .into_iter()
+        .zip(is_maximal)
+        .filter_map(|((walk, _), is_maximal)| is_maximal.then_some(walk))
+        .collect()
+}
+
+/// Computes the univocal extension of the given edge, and returns it together with a flag that is true if it is circular.
+/// The given array must be all `false`, and is all `false` again when this function returns.
+fn univocal_extension<Graph: StaticGraph>(
+    graph: &Graph,
+    edge: Graph::EdgeIndex,
+    in_walk: &mut [bool],
+) -> (VecEdgeWalk<Graph>, bool) {
+    let mut forward = vec![edge];
+    in_walk[edge.as_usize()] = true;
+    let mut is_circular = false;
+    for element in UnivocalIterator::new_forward_without_start(graph, NodeOrEdge::Edge(edge)) {
+        if let NodeOrEdge::Edge(next_edge) = element {
+            if next_edge == edge {
+                is_circular = true;
+                break;
+            } else if in_walk[next_edge.as_usize()] {
+                break;
+            }
+            in_walk[next_edge.as_usize()] = true;
+            forward.push(next_edge);
+        }
+    }
+
+    let mut walk = Vec::new();
+    if is_circular {
+        let min_position = (0..forward.len())
+            .min_by_key(|&position| forward[position])
+            .unwrap();
+        forward.rotate_left(min_position);
+    } else {
+        for element in UnivocalIterator::new_backward_without_start(graph, NodeOrEdge::Edge(edge)) {
+            if let NodeOrEdge::Edge(previous_edge) = element {
+                if in_walk[previous_edge.as_usize()] {
+                    break;
+                }
+                in_walk[previous_edge.as_usize()] = true;
+                walk.push(previous_edge);
+            }
+        }
+        walk.reverse();
+    }
+    walk.extend(forward);
+
+    for edge in &walk {
+        in_walk[edge.as_usize()] = false;
+    }
+    (walk, is_circular)
+}
+
+/// Returns true if `walk` is a subwalk of `other`, where circular walks are considered in all rotations.
+/// Both walks must not repeat an edge.
+fn is_subwalk<EdgeIndex: Eq>(
+    walk: &[EdgeIndex],
+    is_circular: bool,
+    other: &[EdgeIndex],
+    other_is_circular: bool,
+) -> bool {
+    let other_at = |position: usize| {
+        if other_is_circular {
+            other.get(position % other.len())
+        } else {
+            other.get(position)
+        }
+    };
+
+    (0..other.len()).any(|start| {
+        let offset = if is_circular {
+            let Some(offset) = walk.iter().position(|edge| *edge == other[start]) else {
+                return false;
+            };
+            offset
+        } else {
+            0
+        };
+
+        (0..walk.len()).all(|i| other_at(start + i) == Some(&walk[(offset + i) % walk.len()]))
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::traversal::univocal_traversal::is_edge_self_bivalent;
+    use crate::traversal::univocal_traversal::{is_edge_self_bivalent, maximal_univocal_walks};
     use traitgraph::implementation::petgraph_impl::PetGraph;
     use traitgraph::interface::MutableGraphContainer;
 
@@ -208,4 +362,30 @@ mod tests {
         debug_assert!(is_edge_self_bivalent(&graph, e1));
         debug_assert!(is_edge_self_bivalent(&graph, e2));
     }
+
+    #[test]
+    fn test_maximal_univocal_walks_cycles() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+        // A cycle n0 -> n1 -> n2 -> n0 that is entered from n3.
+        let e01 = graph.add_edge(n[0], n[1], ());
+        let e12 = graph.add_edge(n[1], n[2], ());
+        let e20 = graph.add_edge(n[2], n[0], ());
+        let e31 = graph.add_edge(n[3], n[1], ());
+        // An isolated cycle n4 -> n5 -> n4.
+        let e45 = graph.add_edge(n[4], n[5], ());
+        let e54 = graph.add_edge(n[5], n[4], ());
+
+        assert_eq!(
+            maximal_univocal_walks(&graph),
+            // The cycle n0 -> n1 -> n2 -> n0 is a subwalk of the walk entering it.
+            [vec![e31, e12, e20, e01], vec![e45, e54]]
+        );
+
+        // A self-loop.
+        let mut graph = PetGraph::new();
+        let n0 = graph.add_node(());
+        let e00 = graph.add_edge(n0, n0, ());
+        assert_eq!(maximal_univocal_walks(&graph), [vec![e00]]);
+    }
 }
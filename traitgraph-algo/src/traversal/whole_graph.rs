@@ -0,0 +1,196 @@
+use crate::queue::BidirectedQueue;
+use crate::traversal::{
+    ForbiddenSubgraph, NoForbiddenSubgraph, PreOrderTraversal, TraversalNeighborStrategy,
+    TraversalQueueStrategy,
+};
+use traitgraph::interface::{GraphBase, NodeOrEdge, StaticGraph};
+
+/// An item returned by a [WholeGraphTraversal].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum WholeGraphTraversalItem<NodeIndex, EdgeIndex> {
+    /// The traversal restarts from the given node, i.e. the following items belong to a new component.
+    /// This is always followed by the start node itself as [NodeOrEdge::Node].
+    ComponentStart(NodeIndex),
+    /// A node or edge traversed by the underlying preorder traversal.
+    NodeOrEdge(NodeOrEdge<NodeIndex, EdgeIndex>),
+}
+
+/// A preorder traversal that visits all nodes of a graph.
+///
+/// Whenever the underlying [PreOrderTraversal] runs out of nodes, it is continued from the unvisited node with the lowest index.
+/// Each restart is reported with a [WholeGraphTraversalItem::ComponentStart] marker.
+/// In an undirected traversal, the items between two markers are exactly the nodes and edges of a weakly connected component.
+/// In a directed traversal, they are the nodes reachable from the start node that were not visited before, and the traversed edges.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph_algo::traversal::whole_graph::{WholeGraphTraversalItem, UndirectedWholeGraphBfs};
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[2], nodes[0], ());
+///
+/// let component_starts: Vec<_> = UndirectedWholeGraphBfs::new(&graph)
+///     .filter_map(|item| match item {
+///         WholeGraphTraversalItem::ComponentStart(node) => Some(node),
+///         WholeGraphTraversalItem::NodeOrEdge(_) => None,
+///     })
+///     .collect();
+/// assert_eq!(component_starts, [nodes[0], nodes[1], nodes[3]]);
+/// ```
+pub struct WholeGraphTraversal<
+    'a,
+    Graph: GraphBase,
+    NeighborStrategy: 'a + TraversalNeighborStrategy<Graph>,
+    QueueStrategy,
+    Queue: BidirectedQueue<Graph::NodeIndex>,
+> {
+    traversal: PreOrderTraversal<'a, Graph, NeighborStrategy, QueueStrategy, Queue>,
+    next_start_candidate: usize,
+}
+
+/// A forward BFS that visits all nodes of a directed graph.
+pub type ForwardWholeGraphBfs<'a, Graph> = WholeGraphTraversal<
+    'a,
+    Graph,
+    super::ForwardNeighborStrategy,
+    super::BfsQueueStrategy,
+    std::collections::VecDeque<<Graph as GraphBase>::NodeIndex>,
+>;
+/// A BFS that treats each directed edge as an undirected edge and visits all nodes of the graph, one weakly connected component at a time.
+pub type UndirectedWholeGraphBfs<'a, Graph> = WholeGraphTraversal<
+    'a,
+    Graph,
+    super::UndirectedNeighborStrategy,
+    super::BfsQueueStrategy,
+    std::collections::VecDeque<<Graph as GraphBase>::NodeIndex>,
+>;
+
+impl<
+        'a,
+        Graph: StaticGraph,
+        NeighborStrategy: TraversalNeighborStrategy<Graph>,
+        QueueStrategy: TraversalQueueStrategy<Graph, Queue>,
+        Queue: BidirectedQueue<Graph::NodeIndex>,
+    > WholeGraphTraversal<'a, Graph, NeighborStrategy, QueueStrategy, Queue>
+{
+    /// Creates a new traversal that visits all nodes of the given graph.
+    pub fn new(graph: &'a Graph) -> Self {
+        Self::from_traversal(PreOrderTraversal::new_without_start(graph))
+    }
+
+    /// Creates a new traversal that visits all nodes of the graph of the given traversal.
+    /// This allows to configure the underlying traversal, e.g. to record predecessors.
+    ///
+    /// The given traversal should be created with [new_without_start](PreOrderTraversal::new_without_start).
+    pub fn from_traversal(
+        traversal: PreOrderTraversal<'a, Graph, NeighborStrategy, QueueStrategy, Queue>,
+    ) -> Self {
+        Self {
+            traversal,
+            next_start_candidate: 0,
+        }
+    }
+
+    /// Returns the underlying preorder traversal, e.g. to query ranks or predecessors.
+    pub fn traversal(
+        &self,
+    ) -> &PreOrderTraversal<'a, Graph, NeighborStrategy, QueueStrategy, Queue> {
+        &self.traversal
+    }
+
+    /// Advances the traversal, ignoring all nodes and edges forbidden by `forbidden_subgraph`.
+    /// Forbidden nodes are also never used as start nodes of a component.
+    pub fn next_with_forbidden_subgraph<FN: ForbiddenSubgraph<Graph>>(
+        &mut self,
+        forbidden_subgraph: &FN,
+    ) -> Option<WholeGraphTraversalItem<Graph::NodeIndex, Graph::EdgeIndex>> {
+        if let Some(node_or_edge) = self
+            .traversal
+            .next_with_forbidden_subgraph(forbidden_subgraph)
+        {
+            return Some(WholeGraphTraversalItem::NodeOrEdge(node_or_edge));
+        }
+
+        let graph = self.traversal.graph;
+        while self.next_start_candidate < graph.node_index_bound() {
+            let node = Graph::NodeIndex::from(self.next_start_candidate);
+            self.next_start_candidate += 1;
+
+            if graph.contains_node_index(node)
+                && self.traversal.rank_of(node).is_none()
+                && !forbidden_subgraph.is_node_forbidden(node)
+            {
+                self.traversal.continue_traversal_from(node);
+                return Some(WholeGraphTraversalItem::ComponentStart(node));
+            }
+        }
+
+        None
+    }
+}
+
+impl<
+        Graph: StaticGraph,
+        NeighborStrategy: TraversalNeighborStrategy<Graph>,
+        QueueStrategy: TraversalQueueStrategy<Graph, Queue>,
+        Queue: BidirectedQueue<Graph::NodeIndex>,
+    > Iterator for WholeGraphTraversal<'_, Graph, NeighborStrategy, QueueStrategy, Queue>
+{
+    type Item = WholeGraphTraversalItem<Graph::NodeIndex, Graph::EdgeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_forbidden_subgraph(&NoForbiddenSubgraph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traversal::whole_graph::{ForwardWholeGraphBfs, WholeGraphTraversalItem};
+    use crate::traversal::ForbiddenNode;
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::{MutableGraphContainer, NodeOrEdge};
+
+    #[test]
+    fn test_forward_components() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        let e10 = graph.add_edge(n[1], n[0], ());
+        let e34 = graph.add_edge(n[3], n[4], ());
+
+        let items: Vec<_> = ForwardWholeGraphBfs::new(&graph).collect();
+        use NodeOrEdge::{Edge, Node};
+        use WholeGraphTraversalItem::{ComponentStart, NodeOrEdge as Item};
+        assert_eq!(
+            items,
+            [
+                ComponentStart(n[0]),
+                Item(Node(n[0])),
+                ComponentStart(n[1]),
+                Item(Node(n[1])),
+                Item(Edge(e10)),
+                ComponentStart(n[2]),
+                Item(Node(n[2])),
+                ComponentStart(n[3]),
+                Item(Node(n[3])),
+                Item(Edge(e34)),
+                Item(Node(n[4])),
+            ]
+        );
+
+        let mut traversal = ForwardWholeGraphBfs::new(&graph);
+        let forbidden = ForbiddenNode::new(n[3]);
+        let mut starts = Vec::new();
+        while let Some(item) = traversal.next_with_forbidden_subgraph(&forbidden) {
+            if let ComponentStart(node) = item {
+                starts.push(node);
+            }
+        }
+        assert_eq!(starts, [n[0], n[1], n[2], n[4]]);
+        assert_eq!(traversal.traversal().rank_of(n[3]), None);
+        assert_eq!(traversal.traversal().rank_of(n[4]), Some(3.into()));
+    }
+}
@@ -0,0 +1,372 @@
+use crate::traversal::{ForwardNeighborStrategy, TraversalNeighborStrategy};
+use traitgraph::index::GraphIndex;
+use traitgraph::interface::{GraphBase, StaticGraph};
+
+/// The decision of a visitor about how to continue a search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Control {
+    /// Continue the search normally.
+    #[default]
+    Continue,
+    /// Do not explore beyond the current node or edge, but continue the search elsewhere.
+    ///
+    /// Returned from [discover_node](DfsVisitor::discover_node), the neighbors of the node are not explored, and the node is finished immediately.
+    /// Returned from [examine_edge](DfsVisitor::examine_edge) or [tree_edge](DfsVisitor::tree_edge), the edge is not traversed.
+    /// Returned from [start_root](DfsVisitor::start_root), the root is skipped.
+    /// Returned from any other method, it is equivalent to [Continue](Control::Continue).
+    Prune,
+    /// Abort the search immediately.
+    Break,
+}
+
+/// A visitor for a depth-first search.
+///
+/// All methods have a default implementation that does nothing and continues the search,
+/// so implementors only need to override the events they are interested in.
+/// The edge events receive the edge as well as its endpoints in the direction of the traversal,
+/// i.e. `from` is the node whose neighbors are being explored.
+pub trait DfsVisitor<Graph: GraphBase> {
+    /// Called when the search starts a new search tree from the given root.
+    fn start_root(&mut self, _root: Graph::NodeIndex) -> Control {
+        Control::Continue
+    }
+
+    /// Called when a node is visited for the first time.
+    fn discover_node(&mut self, _node: Graph::NodeIndex) -> Control {
+        Control::Continue
+    }
+
+    /// Called for each edge of a discovered node, before it is determined whether the edge leads to a new node.
+    fn examine_edge(
+        &mut self,
+        _edge: Graph::EdgeIndex,
+        _from: Graph::NodeIndex,
+        _to: Graph::NodeIndex,
+    ) -> Control {
+        Control::Continue
+    }
+
+    /// Called for each edge that leads to an undiscovered node, before that node is discovered.
+    fn tree_edge(
+        &mut self,
+        _edge: Graph::EdgeIndex,
+        _from: Graph::NodeIndex,
+        _to: Graph::NodeIndex,
+    ) -> Control {
+        Control::Continue
+    }
+
+    /// Called for each edge that leads to a node that is discovered but not yet finished,
+    /// i.e. an ancestor of `from` in the search tree, or `from` itself for a self-loop.
+    ///
+    /// The graph contains a cycle if and only if a search from all nodes finds a back edge.
+    /// Note that when searching with the [UndirectedNeighborStrategy](crate::traversal::UndirectedNeighborStrategy),
+    /// the tree edge leading to `from` is reported as a back edge when it is examined from the opposite direction.
+    fn back_edge(
+        &mut self,
+        _edge: Graph::EdgeIndex,
+        _from: Graph::NodeIndex,
+        _to: Graph::NodeIndex,
+    ) -> Control {
+        Control::Continue
+    }
+
+    /// Called for each edge that leads to a finished node,
+    /// i.e. either a descendant of `from` in the search tree, or a node in a different branch or search tree.
+    fn forward_or_cross_edge(
+        &mut self,
+        _edge: Graph::EdgeIndex,
+        _from: Graph::NodeIndex,
+        _to: Graph::NodeIndex,
+    ) -> Control {
+        Control::Continue
+    }
+
+    /// Called when all neighbors of a node have been explored.
+    fn finish_node(&mut self, _node: Graph::NodeIndex) -> Control {
+        Control::Continue
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NodeState {
+    Undiscovered,
+    Discovered,
+    Finished,
+}
+
+/// A generic depth-first search that reports its progress to a [DfsVisitor].
+///
+/// The search is generic over the direction of the search (`NeighborStrategy`).
+/// It keeps its state between calls to [visit](DepthFirstSearch::visit), such that nodes discovered by an earlier call are not visited again.
+pub struct DepthFirstSearch<
+    'a,
+    Graph: GraphBase,
+    NeighborStrategy: 'a + TraversalNeighborStrategy<Graph>,
+> {
+    graph: &'a Graph,
+    state: Vec<NodeState>,
+    stack: Vec<(Graph::NodeIndex, NeighborStrategy::Iterator<'a>)>,
+}
+
+impl<'a, Graph: StaticGraph, NeighborStrategy: TraversalNeighborStrategy<Graph>>
+    DepthFirstSearch<'a, Graph, NeighborStrategy>
+{
+    /// Creates a new search on the given graph, where no node has been discovered yet.
+    pub fn new(graph: &'a Graph) -> Self {
+        Self {
+            graph,
+            state: vec![NodeState::Undiscovered; graph.node_index_bound()],
+            stack: Vec::new(),
+        }
+    }
+
+    /// Resets the search such that no node is discovered.
+    pub fn reset(&mut self) {
+        self.state.fill(NodeState::Undiscovered);
+        self.stack.clear();
+    }
+
+    /// Returns true if the given node was discovered by this search.
+    pub fn is_discovered(&self, node: Graph::NodeIndex) -> bool {
+        self.state[node.as_usize()] != NodeState::Undiscovered
+    }
+
+    /// Runs the search from each of the given roots that has not been discovered yet.
+    ///
+    /// Returns [Control::Break] if the visitor aborted the search, and [Control::Continue] otherwise.
+    pub fn visit<Visitor: DfsVisitor<Graph>>(
+        &mut self,
+        roots: impl IntoIterator<Item = Graph::NodeIndex>,
+        visitor: &mut Visitor,
+    ) -> Control {
+        debug_assert!(self.stack.is_empty());
+
+        for root in roots {
+            if self.is_discovered(root) {
+                continue;
+            }
+
+            match visitor.start_root(root) {
+                Control::Continue => {}
+                Control::Prune => continue,
+                Control::Break => return Control::Break,
+            }
+
+            if self.discover(root, visitor) == Control::Break {
+                self.stack.clear();
+                return Control::Break;
+            }
+
+            if self.run(visitor) == Control::Break {
+                self.stack.clear();
+                return Control::Break;
+            }
+        }
+
+        Control::Continue
+    }
+
+    /// Runs the search from all nodes of the graph, in the order of their indices.
+    ///
+    /// Returns [Control::Break] if the visitor aborted the search, and [Control::Continue] otherwise.
+    pub fn visit_all<Visitor: DfsVisitor<Graph>>(&mut self, visitor: &mut Visitor) -> Control {
+        let graph = self.graph;
+        self.visit(graph.node_indices(), visitor)
+    }
+
+    fn discover<Visitor: DfsVisitor<Graph>>(
+        &mut self,
+        node: Graph::NodeIndex,
+        visitor: &mut Visitor,
+    ) -> Control {
+        self.state[node.as_usize()] = NodeState::Discovered;
+        match visitor.discover_node(node) {
+            Control::Continue => {
+                self.stack
+                    .push((node, NeighborStrategy::neighbor_iterator(self.graph, node)));
+                Control::Continue
+            }
+            Control::Prune => {
+                self.state[node.as_usize()] = NodeState::Finished;
+                visitor.finish_node(node)
+            }
+            Control::Break => Control::Break,
+        }
+    }
+
+    fn run<Visitor: DfsVisitor<Graph>>(&mut self, visitor: &mut Visitor) -> Control {
+        while let Some((node, neighbors)) = self.stack.last_mut() {
+            let node = *node;
+            if let Some(neighbor) = neighbors.next() {
+                let (edge, to) = (neighbor.edge_id, neighbor.node_id);
+                match visitor.examine_edge(edge, node, to) {
+                    Control::Continue => {}
+                    Control::Prune => continue,
+                    Control::Break => return Control::Break,
+                }
+
+                match self.state[to.as_usize()] {
+                    NodeState::Discovered => {
+                        if visitor.back_edge(edge, node, to) == Control::Break {
+                            return Control::Break;
+                        }
+                    }
+                    NodeState::Finished => {
+                        if visitor.forward_or_cross_edge(edge, node, to) == Control::Break {
+                            return Control::Break;
+                        }
+                    }
+                    NodeState::Undiscovered => {
+                        match visitor.tree_edge(edge, node, to) {
+                            Control::Continue => {}
+                            Control::Prune => continue,
+                            Control::Break => return Control::Break,
+                        }
+                        if self.discover(to, visitor) == Control::Break {
+                            return Control::Break;
+                        }
+                    }
+                }
+            } else {
+                self.stack.pop();
+                self.state[node.as_usize()] = NodeState::Finished;
+                if visitor.finish_node(node) == Control::Break {
+                    return Control::Break;
+                }
+            }
+        }
+
+        Control::Continue
+    }
+}
+
+/// Runs a forward depth-first search from the given roots, reporting its progress to the given visitor.
+///
+/// Returns [Control::Break] if the visitor aborted the search, and [Control::Continue] otherwise.
+pub fn depth_first_visit<Graph: StaticGraph, Visitor: DfsVisitor<Graph>>(
+    graph: &Graph,
+    roots: impl IntoIterator<Item = Graph::NodeIndex>,
+    visitor: &mut Visitor,
+) -> Control {
+    DepthFirstSearch::<_, ForwardNeighborStrategy>::new(graph).visit(roots, visitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::visitor::{depth_first_visit, Control, DepthFirstSearch, DfsVisitor};
+    use traitgraph::implementation::petgraph_impl::PetGraph;
+    use traitgraph::interface::{GraphBase, MutableGraphContainer};
+
+    type Graph = PetGraph<(), ()>;
+    type NodeIndex = <Graph as GraphBase>::NodeIndex;
+    type EdgeIndex = <Graph as GraphBase>::EdgeIndex;
+
+    #[derive(Default)]
+    struct RecordingVisitor {
+        events: Vec<String>,
+        prune: Option<NodeIndex>,
+        stop: Option<NodeIndex>,
+    }
+
+    impl DfsVisitor<Graph> for RecordingVisitor {
+        fn start_root(&mut self, root: NodeIndex) -> Control {
+            self.events.push(format!("root {root:?}"));
+            Control::Continue
+        }
+
+        fn discover_node(&mut self, node: NodeIndex) -> Control {
+            self.events.push(format!("discover {node:?}"));
+            if Some(node) == self.stop {
+                Control::Break
+            } else if Some(node) == self.prune {
+                Control::Prune
+            } else {
+                Control::Continue
+            }
+        }
+
+        fn back_edge(&mut self, edge: EdgeIndex, _: NodeIndex, _: NodeIndex) -> Control {
+            self.events.push(format!("back {edge:?}"));
+            Control::Continue
+        }
+
+        fn forward_or_cross_edge(
+            &mut self,
+            edge: EdgeIndex,
+            _: NodeIndex,
+            _: NodeIndex,
+        ) -> Control {
+            self.events.push(format!("forward-or-cross {edge:?}"));
+            Control::Continue
+        }
+
+        fn finish_node(&mut self, node: NodeIndex) -> Control {
+            self.events.push(format!("finish {node:?}"));
+            Control::Continue
+        }
+    }
+
+    fn create_graph() -> Graph {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[0], ());
+        graph.add_edge(n[3], n[2], ());
+        graph
+    }
+
+    #[test]
+    fn test_visit_all() {
+        let graph = create_graph();
+        let mut visitor = RecordingVisitor::default();
+        let result = DepthFirstSearch::<_, crate::traversal::ForwardNeighborStrategy>::new(&graph)
+            .visit_all(&mut visitor);
+        debug_assert_eq!(result, Control::Continue);
+        debug_assert_eq!(
+            visitor.events,
+            [
+                "root 0",
+                "discover 0",
+                "discover 1",
+                "discover 2",
+                "back 2",
+                "finish 2",
+                "finish 1",
+                "finish 0",
+                "root 3",
+                "discover 3",
+                "forward-or-cross 3",
+                "finish 3",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prune_and_break() {
+        let graph = create_graph();
+        let mut visitor = RecordingVisitor {
+            prune: Some(1.into()),
+            ..Default::default()
+        };
+        debug_assert_eq!(
+            depth_first_visit(&graph, [0.into()], &mut visitor),
+            Control::Continue
+        );
+        debug_assert_eq!(
+            visitor.events,
+            ["root 0", "discover 0", "discover 1", "finish 1", "finish 0"]
+        );
+
+        let mut visitor = RecordingVisitor {
+            stop: Some(2.into()),
+            ..Default::default()
+        };
+        debug_assert_eq!(
+            depth_first_visit(&graph, [3.into(), 0.into()], &mut visitor),
+            Control::Break
+        );
+        debug_assert_eq!(visitor.events, ["root 3", "discover 3", "discover 2"]);
+    }
+}
@@ -0,0 +1,983 @@
+use crate::index::{GraphIndex, OptionalGraphIndex};
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{
+    Edge, GraphBase, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// The mapping from the indices of a graph to the indices of a copy of it, as returned e.g. by [convert_graph].
+///
+/// Mappings can also be built manually with [insert_node](GraphIndexMapping::insert_node) and [insert_edge](GraphIndexMapping::insert_edge),
+/// e.g. to record the index changes of a custom transformation.
+/// The mappings of consecutive transformations can be combined with [compose](GraphIndexMapping::compose).
+pub struct GraphIndexMapping<Source: GraphBase, Target: GraphBase> {
+    node_map: Vec<Target::OptionalNodeIndex>,
+    edge_map: Vec<Target::OptionalEdgeIndex>,
+    source_graph: PhantomData<fn(&Source)>,
+}
+
+impl<Source: GraphBase, Target: GraphBase> GraphIndexMapping<Source, Target> {
+    /// Creates an empty mapping that maps no node and no edge.
+    pub fn new() -> Self {
+        Self {
+            node_map: Vec::new(),
+            edge_map: Vec::new(),
+            source_graph: PhantomData,
+        }
+    }
+
+    /// Maps the given source node to the given target node, replacing a previous mapping of the source node.
+    pub fn insert_node(
+        &mut self,
+        source_node_id: Source::NodeIndex,
+        target_node_id: Target::NodeIndex,
+    ) {
+        let index = source_node_id.as_usize();
+        if index >= self.node_map.len() {
+            self.node_map
+                .resize(index + 1, Target::OptionalNodeIndex::new_none());
+        }
+        self.node_map[index] = target_node_id.into();
+    }
+
+    /// Maps the given source edge to the given target edge, replacing a previous mapping of the source edge.
+    pub fn insert_edge(
+        &mut self,
+        source_edge_id: Source::EdgeIndex,
+        target_edge_id: Target::EdgeIndex,
+    ) {
+        let index = source_edge_id.as_usize();
+        if index >= self.edge_map.len() {
+            self.edge_map
+                .resize(index + 1, Target::OptionalEdgeIndex::new_none());
+        }
+        self.edge_map[index] = target_edge_id.into();
+    }
+
+    /// Returns the index in the copy of the given node, or `None` if the node was not copied.
+    pub fn node(&self, source_node_id: Source::NodeIndex) -> Option<Target::NodeIndex> {
+        self.node_map
+            .get(source_node_id.as_usize())
+            .and_then(|&node_id| node_id.into())
+    }
+
+    /// Returns the index in the copy of the given edge, or `None` if the edge was not copied.
+    pub fn edge(&self, source_edge_id: Source::EdgeIndex) -> Option<Target::EdgeIndex> {
+        self.edge_map
+            .get(source_edge_id.as_usize())
+            .and_then(|&edge_id| edge_id.into())
+    }
+
+    /// Returns an iterator over all mapped nodes as pairs of source and target index, in ascending order of the source index.
+    pub fn nodes(&self) -> impl '_ + Iterator<Item = (Source::NodeIndex, Target::NodeIndex)> {
+        self.node_map
+            .iter()
+            .enumerate()
+            .filter(|(_, node_id)| node_id.is_some())
+            .map(|(source_node_id, node_id)| (source_node_id.into(), node_id.unwrap()))
+    }
+
+    /// Returns an iterator over all mapped edges as pairs of source and target index, in ascending order of the source index.
+    pub fn edges(&self) -> impl '_ + Iterator<Item = (Source::EdgeIndex, Target::EdgeIndex)> {
+        self.edge_map
+            .iter()
+            .enumerate()
+            .filter(|(_, edge_id)| edge_id.is_some())
+            .map(|(source_edge_id, edge_id)| (source_edge_id.into(), edge_id.unwrap()))
+    }
+
+    /// Returns the mapping in the opposite direction, from the indices of the copy to the indices of the original graph.
+    ///
+    /// If multiple source indices are mapped to the same target index, then the largest of them is used.
+    pub fn inverse(&self) -> GraphIndexMapping<Target, Source> {
+        let mut inverse = GraphIndexMapping::new();
+        for (source_node_id, target_node_id) in self.nodes() {
+            inverse.insert_node(target_node_id, source_node_id);
+        }
+        for (source_edge_id, target_edge_id) in self.edges() {
+            inverse.insert_edge(target_edge_id, source_edge_id);
+        }
+        inverse
+    }
+
+    /// Returns the mapping that first applies this mapping and then the given mapping.
+    ///
+    /// A node or edge is mapped by the result only if it is mapped by this mapping, and its image is mapped by `next`.
+    pub fn compose<Next: GraphBase>(
+        &self,
+        next: &GraphIndexMapping<Target, Next>,
+    ) -> GraphIndexMapping<Source, Next> {
+        GraphIndexMapping {
+            node_map: self
+                .node_map
+                .iter()
+                .map(|&node_id| {
+                    let node_id: Option<Target::NodeIndex> = node_id.into();
+                    node_id.and_then(|node_id| next.node(node_id)).into()
+                })
+                .collect(),
+            edge_map: self
+                .edge_map
+                .iter()
+                .map(|&edge_id| {
+                    let edge_id: Option<Target::EdgeIndex> = edge_id.into();
+                    edge_id.and_then(|edge_id| next.edge(edge_id)).into()
+                })
+                .collect(),
+            source_graph: PhantomData,
+        }
+    }
+}
+
+impl<Source: GraphBase, Target: GraphBase> Default for GraphIndexMapping<Source, Target> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Source: GraphBase, Target: GraphBase> Clone for GraphIndexMapping<Source, Target> {
+    fn clone(&self) -> Self {
+        Self {
+            node_map: self.node_map.clone(),
+            edge_map: self.edge_map.clone(),
+            source_graph: PhantomData,
+        }
+    }
+}
+
+impl<Source: GraphBase, Target: GraphBase> PartialEq for GraphIndexMapping<Source, Target> {
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes().eq(other.nodes()) && self.edges().eq(other.edges())
+    }
+}
+
+impl<Source: GraphBase, Target: GraphBase> Eq for GraphIndexMapping<Source, Target> {}
+
+impl<Source: GraphBase, Target: GraphBase> core::fmt::Debug for GraphIndexMapping<Source, Target> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GraphIndexMapping")
+            .field("node_map", &self.node_map)
+            .field("edge_map", &self.edge_map)
+            .finish()
+    }
+}
+
+/// Conversion of any graph into a graph of this type.
+///
+/// This is implemented for all graphs that implement [MutableGraphContainer] and [Default],
+/// if their node and edge data can be created from the node and edge data of the source graph.
+pub trait FromGraph<Source: ImmutableGraphContainer>: MutableGraphContainer + Sized {
+    /// Creates a copy of the given graph, see [convert_graph].
+    fn from_graph(graph: &Source) -> (Self, GraphIndexMapping<Source, Self>);
+}
+
+impl<Source: ImmutableGraphContainer, Target: MutableGraphContainer + Default> FromGraph<Source>
+    for Target
+where
+    Source::NodeData: Clone,
+    Source::EdgeData: Clone,
+    Target::NodeData: From<Source::NodeData>,
+    Target::EdgeData: From<Source::EdgeData>,
+{
+    fn from_graph(graph: &Source) -> (Self, GraphIndexMapping<Source, Self>) {
+        convert_graph(graph)
+    }
+}
+
+/// Copies the given graph into a new graph of a possibly different type, see [copy_graph_into].
+///
+/// Returns the copy and the mapping from the indices of the given graph to the indices of the copy.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::conversion::convert_graph;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::vec_graph::VecGraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node(1u8);
+/// let b = graph.add_node(2u8);
+/// let e = graph.add_edge(a, b, 'x');
+///
+/// let (copy, mapping): (VecGraph<u32, char>, _) = convert_graph(&graph);
+/// assert_eq!(*copy.node_data(mapping.node(b).unwrap()), 2u32);
+/// assert_eq!(*copy.edge_data(mapping.edge(e).unwrap()), 'x');
+/// # }
+/// ```
+pub fn convert_graph<Source: ImmutableGraphContainer, Target: MutableGraphContainer + Default>(
+    graph: &Source,
+) -> (Target, GraphIndexMapping<Source, Target>)
+where
+    Source::NodeData: Clone,
+    Source::EdgeData: Clone,
+    Target::NodeData: From<Source::NodeData>,
+    Target::EdgeData: From<Source::EdgeData>,
+{
+    let mut target = Target::default();
+    let mapping = copy_graph_into(graph, &mut target);
+    (target, mapping)
+}
+
+/// Adds a copy of all nodes and edges of the given graph to the target graph.
+///
+/// The nodes are added in the order of [`node_indices`](ImmutableGraphContainer::node_indices),
+/// and the edges in the order of [`edge_indices`](ImmutableGraphContainer::edge_indices),
+/// such that parallel edges and self-loops are copied as they are.
+/// Returns the mapping from the indices of the given graph to the indices of the copied nodes and edges in the target graph.
+pub fn copy_graph_into<Source: ImmutableGraphContainer, Target: MutableGraphContainer>(
+    graph: &Source,
+    target: &mut Target,
+) -> GraphIndexMapping<Source, Target>
+where
+    Source::NodeData: Clone,
+    Source::EdgeData: Clone,
+    Target::NodeData: From<Source::NodeData>,
+    Target::EdgeData: From<Source::EdgeData>,
+{
+    let mut node_map = vec![Target::OptionalNodeIndex::new_none(); graph.node_index_bound()];
+    for node_id in graph.node_indices() {
+        node_map[node_id.as_usize()] = target
+            .add_node(graph.node_data(node_id).clone().into())
+            .into();
+    }
+
+    let mut edge_map = vec![Target::OptionalEdgeIndex::new_none(); graph.edge_index_bound()];
+    for edge_id in graph.edge_indices() {
+        let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+        edge_map[edge_id.as_usize()] = target
+            .add_edge(
+                node_map[from_node.as_usize()].unwrap(),
+                node_map[to_node.as_usize()].unwrap(),
+                graph.edge_data(edge_id).clone().into(),
+            )
+            .into();
+    }
+
+    GraphIndexMapping {
+        node_map,
+        edge_map,
+        source_graph: PhantomData,
+    }
+}
+
+/// Copies the nodes and edges of the given subgraph into a new owned graph.
+///
+/// If the new graph indexes its nodes and edges consecutively, as e.g. [PetGraph](crate::implementation::petgraph_impl::PetGraph) does,
+/// then the copy is compacted, i.e. its indices are consecutive and start at zero.
+/// Returns the copy together with the mapping from the indices of the subgraph to the indices of the copy,
+/// and the mapping in the opposite direction.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::conversion::extract_subgraph;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::induced_bit_vector_subgraph::InducedBitVectorSubgraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+/// use traitgraph::interface::subgraph::MutableSubgraph;
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node('a');
+/// let b = graph.add_node('b');
+/// let c = graph.add_node('c');
+/// graph.add_edge(a, b, ());
+/// graph.add_edge(b, c, ());
+///
+/// let mut subgraph = InducedBitVectorSubgraph::new_empty(&graph);
+/// subgraph.enable_node(b);
+/// subgraph.enable_node(c);
+///
+/// let (extracted, forward, backward): (PetGraph<_, _>, _, _) = extract_subgraph(&subgraph);
+/// assert_eq!(extracted.node_count(), 2);
+/// assert_eq!(extracted.edge_count(), 1);
+/// assert_eq!(forward.node(c), Some(1.into()));
+/// assert_eq!(backward.node(0.into()), Some(b));
+/// # }
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn extract_subgraph<
+    Subgraph: SubgraphBase + ImmutableGraphContainer,
+    Target: MutableGraphContainer<NodeData = Subgraph::NodeData, EdgeData = Subgraph::EdgeData> + Default,
+>(
+    subgraph: &Subgraph,
+) -> (
+    Target,
+    GraphIndexMapping<Subgraph, Target>,
+    GraphIndexMapping<Target, Subgraph>,
+)
+where
+    Subgraph::NodeData: Clone,
+    Subgraph::EdgeData: Clone,
+{
+    let (target, mapping) = convert_graph(subgraph);
+    let inverse = mapping.inverse();
+    (target, mapping, inverse)
+}
+
+/// Merges the two given graphs into a single graph that contains all their nodes and edges.
+///
+/// Returns the merged graph together with the mappings from the indices of each given graph to the indices in the merged graph.
+/// See [disjoint_union_all] for details.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::conversion::disjoint_union;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+///
+/// let mut first = PetGraph::new();
+/// let a = first.add_node("a");
+/// let mut second = PetGraph::new();
+/// let b = second.add_node("b");
+/// let c = second.add_node("c");
+/// second.add_edge(b, c, ());
+///
+/// let (union, mappings) = disjoint_union(first, second);
+/// assert_eq!(union.node_count(), 3);
+/// assert_eq!(*union.node_data(mappings[0].node(a).unwrap()), "a");
+/// assert!(union.contains_edge_between(mappings[1].node(b).unwrap(), mappings[1].node(c).unwrap()));
+/// # }
+/// ```
+pub fn disjoint_union<Graph: MutableGraphContainer>(
+    first: Graph,
+    second: Graph,
+) -> (Graph, Vec<GraphIndexMapping<Graph, Graph>>) {
+    disjoint_union_all([first, second]).expect("two graphs were given")
+}
+
+/// Merges the given graphs into a single graph that contains all their nodes and edges.
+///
+/// Returns the merged graph together with one mapping per given graph, in the same order,
+/// that translates the indices of the given graph to the indices in the merged graph.
+/// Returns `None` if no graphs are given.
+///
+/// The first graph is reused as the merged graph, so its indices stay the same.
+/// The data of the other graphs is moved into it without cloning, by removing their edges and nodes in descending order of their indices.
+/// This requires that removing the node or edge with the highest index does not change the indices of the remaining nodes or edges,
+/// which holds for all graph types in this crate.
+pub fn disjoint_union_all<Graph: MutableGraphContainer>(
+    graphs: impl IntoIterator<Item = Graph>,
+) -> Option<(Graph, Vec<GraphIndexMapping<Graph, Graph>>)> {
+    let mut graphs = graphs.into_iter();
+    let mut result = graphs.next()?;
+
+    let mut node_map = vec![Graph::OptionalNodeIndex::new_none(); result.node_index_bound()];
+    for node_id in result.node_indices() {
+        node_map[node_id.as_usize()] = node_id.into();
+    }
+    let mut edge_map = vec![Graph::OptionalEdgeIndex::new_none(); result.edge_index_bound()];
+    for edge_id in result.edge_indices() {
+        edge_map[edge_id.as_usize()] = edge_id.into();
+    }
+    let mut mappings = vec![GraphIndexMapping {
+        node_map,
+        edge_map,
+        source_graph: PhantomData,
+    }];
+
+    for graph in graphs {
+        mappings.push(move_graph_into(graph, &mut result));
+    }
+    Some((result, mappings))
+}
+
+/// Moves all nodes and edges of `graph` into `target`, see [disjoint_union_all].
+fn move_graph_into<Graph: MutableGraphContainer>(
+    graph: Graph,
+    target: &mut Graph,
+) -> GraphIndexMapping<Graph, Graph> {
+    let mut node_order: Vec<_> = graph.node_indices().collect();
+    node_order.sort_unstable();
+    let mut edge_order: Vec<_> = graph.edge_indices().collect();
+    edge_order.sort_unstable();
+    move_graph_into_ordered(graph, target, &node_order, &edge_order)
+}
+
+/// Moves all nodes and edges of `graph` into `target`, adding them in the given orders.
+///
+/// The data is moved out of `graph` by removing its edges and nodes in descending order of their indices, see [disjoint_union_all].
+fn move_graph_into_ordered<Graph: MutableGraphContainer>(
+    mut graph: Graph,
+    target: &mut Graph,
+    node_order: &[Graph::NodeIndex],
+    edge_order: &[Graph::EdgeIndex],
+) -> GraphIndexMapping<Graph, Graph> {
+    debug_assert_eq!(node_order.len(), graph.node_count());
+    debug_assert_eq!(edge_order.len(), graph.edge_count());
+    let mut node_ids: Vec<_> = graph.node_indices().collect();
+    node_ids.sort_unstable();
+    let mut edge_ids: Vec<_> = graph.edge_indices().collect();
+    edge_ids.sort_unstable();
+
+    let mut edges: Vec<_> = (0..graph.edge_index_bound()).map(|_| None).collect();
+    for &edge_id in edge_ids.iter().rev() {
+        let endpoints = graph.edge_endpoints(edge_id);
+        edges[edge_id.as_usize()] = Some((endpoints, graph.remove_edge(edge_id).unwrap()));
+    }
+    let mut node_data: Vec<_> = (0..graph.node_index_bound()).map(|_| None).collect();
+    for &node_id in node_ids.iter().rev() {
+        node_data[node_id.as_usize()] = graph.remove_node(node_id);
+    }
+
+    let mut node_map = vec![Graph::OptionalNodeIndex::new_none(); node_data.len()];
+    let mut edge_map = vec![Graph::OptionalEdgeIndex::new_none(); edges.len()];
+    target.reserve_nodes(node_order.len());
+    for &node_id in node_order {
+        let node_data = node_data[node_id.as_usize()].take().unwrap();
+        node_map[node_id.as_usize()] = target.add_node(node_data).into();
+    }
+    target.reserve_edges(edge_order.len());
+    for &edge_id in edge_order {
+        let (Edge { from_node, to_node }, edge_data) = edges[edge_id.as_usize()].take().unwrap();
+        edge_map[edge_id.as_usize()] = target
+            .add_edge(
+                node_map[from_node.as_usize()].unwrap(),
+                node_map[to_node.as_usize()].unwrap(),
+                edge_data,
+            )
+            .into();
+    }
+
+    GraphIndexMapping {
+        node_map,
+        edge_map,
+        source_graph: PhantomData,
+    }
+}
+
+/// Returns the mapping that renumbers the nodes and edges of the given graph to be consecutive, keeping their relative order.
+///
+/// The graph is not modified, so this also works for graphs that cannot be rebuilt, like subgraphs.
+/// The mapped indices can then be used e.g. to store data for the nodes of a subgraph in a vector of length [`node_count`](ImmutableGraphContainer::node_count)
+/// instead of [`node_index_bound`](ImmutableGraphContainer::node_index_bound).
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::conversion::compact_index_mapping;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::induced_bit_vector_subgraph::InducedBitVectorSubgraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph::interface::subgraph::MutableSubgraph;
+///
+/// let mut graph = PetGraph::new();
+/// let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(n[1], n[3], ());
+///
+/// let mut subgraph = InducedBitVectorSubgraph::new_empty(&graph);
+/// subgraph.enable_node(n[1]);
+/// subgraph.enable_node(n[3]);
+///
+/// let mapping = compact_index_mapping(&subgraph);
+/// assert_eq!(mapping.node(n[3]), Some(1.into()));
+/// assert_eq!(mapping.node(n[2]), None);
+/// # }
+/// ```
+pub fn compact_index_mapping<Graph: ImmutableGraphContainer>(
+    graph: &Graph,
+) -> GraphIndexMapping<Graph, Graph> {
+    let mut node_ids: Vec<_> = graph.node_indices().collect();
+    node_ids.sort_unstable();
+    let mut edge_ids: Vec<_> = graph.edge_indices().collect();
+    edge_ids.sort_unstable();
+
+    let mut mapping = GraphIndexMapping::new();
+    for (index, node_id) in node_ids.into_iter().enumerate() {
+        mapping.insert_node(node_id, index.into());
+    }
+    for (index, edge_id) in edge_ids.into_iter().enumerate() {
+        mapping.insert_edge(edge_id, index.into());
+    }
+    mapping
+}
+
+/// Renumbers the nodes and edges of the given graph to be consecutive, keeping their relative order.
+///
+/// This removes the gaps that are left in the index space by graphs with stable indices after removing nodes or edges.
+/// If the indices are already consecutive, then the graph is not modified.
+/// Otherwise it is rebuilt by moving its nodes and edges into a new graph, see [disjoint_union_all] for the requirements on the graph type.
+/// Returns the mapping from the old to the new indices, which is the same as returned by [compact_index_mapping].
+pub fn compact<Graph: MutableGraphContainer + Default>(
+    graph: &mut Graph,
+) -> GraphIndexMapping<Graph, Graph> {
+    if graph.node_index_bound() == graph.node_count()
+        && graph.edge_index_bound() == graph.edge_count()
+    {
+        return compact_index_mapping(graph);
+    }
+
+    let mut node_order: Vec<_> = graph.node_indices().collect();
+    node_order.sort_unstable();
+    let mut edge_order: Vec<_> = graph.edge_indices().collect();
+    edge_order.sort_unstable();
+    let source = core::mem::take(graph);
+    move_graph_into_ordered(source, graph, &node_order, &edge_order)
+}
+
+/// Reorders the edges of the given graph into a canonical order, keeping the order of the nodes.
+///
+/// See [normalize_by_key] for details.
+pub fn normalize<Graph: MutableGraphContainer + Default>(
+    graph: &mut Graph,
+) -> GraphIndexMapping<Graph, Graph> {
+    normalize_by_key(graph, |_, node_id| node_id, |_, _| ())
+}
+
+/// Reorders the nodes and edges of the given graph into a canonical order.
+///
+/// The nodes are sorted by `node_key`, and the edges are sorted by the new positions of their tails, then the new positions of their heads, and then by `edge_key`.
+/// Ties are broken by the original indices.
+/// If the keys are derived from the node and edge data such that there are no ties,
+/// then graphs that differ only in the order in which their nodes and edges were inserted are normalised into the same graph.
+///
+/// The graph is rebuilt by moving its nodes and edges into a new graph in the canonical order, see [disjoint_union_all] for the requirements on the graph type.
+/// Returns the permutation applied, i.e. the mapping from the old to the new indices.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::conversion::normalize_by_key;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// let mut graph = PetGraph::new();
+/// let b = graph.add_node('b');
+/// let a = graph.add_node('a');
+/// graph.add_edge(b, a, 1);
+/// graph.add_edge(a, b, 2);
+///
+/// let mapping = normalize_by_key(
+///     &mut graph,
+///     |graph, node_id| *graph.node_data(node_id),
+///     |graph, edge_id| *graph.edge_data(edge_id),
+/// );
+/// assert_eq!(mapping.node(a), Some(0.into()));
+/// assert_eq!(*graph.node_data(0.into()), 'a');
+/// assert_eq!(*graph.edge_data(0.into()), 2);
+/// # }
+/// ```
+pub fn normalize_by_key<Graph: MutableGraphContainer + Default, NodeKey: Ord, EdgeKey: Ord>(
+    graph: &mut Graph,
+    mut node_key: impl FnMut(&Graph, Graph::NodeIndex) -> NodeKey,
+    mut edge_key: impl FnMut(&Graph, Graph::EdgeIndex) -> EdgeKey,
+) -> GraphIndexMapping<Graph, Graph> {
+    let mut node_order: Vec<_> = graph.node_indices().collect();
+    node_order.sort_unstable();
+    let node_keys: Vec<_> = node_order
+        .iter()
+        .map(|&node_id| node_key(graph, node_id))
+        .collect();
+    let mut node_positions: Vec<_> = (0..node_order.len()).collect();
+    node_positions.sort_by(|&a, &b| node_keys[a].cmp(&node_keys[b]));
+    let node_order: Vec<_> = node_positions
+        .into_iter()
+        .map(|position| node_order[position])
+        .collect();
+
+    let mut node_rank = vec![0; graph.node_index_bound()];
+    for (rank, node_id) in node_order.iter().enumerate() {
+        node_rank[node_id.as_usize()] = rank;
+    }
+    let mut edges: Vec<_> = graph
+        .edge_indices()
+        .map(|edge_id| {
+            let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+            (
+                node_rank[from_node.as_usize()],
+                node_rank[to_node.as_usize()],
+                edge_key(graph, edge_id),
+                edge_id,
+            )
+        })
+        .collect();
+    edges.sort_unstable();
+    let edge_order: Vec<_> = edges
+        .into_iter()
+        .map(|(_, _, _, edge_id)| edge_id)
+        .collect();
+
+    let source = core::mem::take(graph);
+    move_graph_into_ordered(source, graph, &node_order, &edge_order)
+}
+
+/// Copies the given graph into a new graph in which all parallel edges are collapsed into a single edge.
+///
+/// The nodes are added to the new graph in the order of [`node_indices`](crate::interface::ImmutableGraphContainer::node_indices),
+/// so if both graphs index their nodes consecutively, then the node indices are preserved.
+/// For each pair of nodes connected by at least one edge, a single edge is added,
+/// whose data is the data of the first of the parallel edges, merged with the data of each further parallel edge using `reduce`.
+/// The edges are added grouped by their tail, and for the same tail in the order in which their first parallel edge is returned by
+/// [`out_neighbors`](NavigableGraph::out_neighbors).
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::conversion::collapse_parallel_edges;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// graph.add_edge(a, b, 5);
+/// graph.add_edge(a, b, 3);
+///
+/// let simple: PetGraph<_, _> = collapse_parallel_edges(&graph, |weight, other| *weight = (*weight).min(*other));
+/// assert_eq!(simple.edge_count(), 1);
+/// assert_eq!(*simple.edge_data(0.into()), 3);
+/// # }
+/// ```
+pub fn collapse_parallel_edges<
+    Source: NavigableGraph,
+    Target: MutableGraphContainer<NodeData = Source::NodeData, EdgeData = Source::EdgeData> + Default,
+>(
+    graph: &Source,
+    mut reduce: impl FnMut(&mut Source::EdgeData, &Source::EdgeData),
+) -> Target
+where
+    Source::NodeData: Clone,
+    Source::EdgeData: Clone,
+{
+    let mut result = Target::default();
+    let mut node_map = vec![None; graph.node_index_bound()];
+    for node_id in graph.node_indices() {
+        node_map[node_id.as_usize()] = Some(result.add_node(graph.node_data(node_id).clone()));
+    }
+
+    // The position in `collapsed_edges` of the edge to each node, or `usize::MAX` if there is none yet.
+    let mut positions = vec![usize::MAX; graph.node_index_bound()];
+    let mut collapsed_edges: Vec<(Source::NodeIndex, Source::EdgeData)> = Vec::new();
+    for from_node in graph.node_indices() {
+        for neighbor in graph.out_neighbors(from_node) {
+            let position = &mut positions[neighbor.node_id.as_usize()];
+            let edge_data = graph.edge_data(neighbor.edge_id);
+            if *position == usize::MAX {
+                *position = collapsed_edges.len();
+                collapsed_edges.push((neighbor.node_id, edge_data.clone()));
+            } else {
+                reduce(&mut collapsed_edges[*position].1, edge_data);
+            }
+        }
+
+        let from = node_map[from_node.as_usize()].unwrap();
+        for (to_node, edge_data) in collapsed_edges.drain(..) {
+            positions[to_node.as_usize()] = usize::MAX;
+            result.add_edge(from, node_map[to_node.as_usize()].unwrap(), edge_data);
+        }
+    }
+
+    result
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{
+        collapse_parallel_edges, compact, disjoint_union_all, extract_subgraph, normalize,
+        normalize_by_key, FromGraph, GraphIndexMapping,
+    };
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::stable_graph::StableGraph;
+    use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+    use crate::interface::subgraph::MutableSubgraph;
+    use crate::interface::{Edge, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_convert_multigraph() {
+        let mut graph = StableGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = [(0, 1), (0, 1), (1, 1), (3, 0), (1, 3)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (from, to))| graph.add_edge(n[from], n[to], i))
+            .collect();
+        graph.remove_node(n[2]);
+        graph.remove_edge(e[3]);
+
+        let (copy, mapping) = PetGraph::<i64, usize>::from_graph(&graph);
+        debug_assert_eq!(copy.node_count(), 3);
+        debug_assert_eq!(copy.edge_count(), 4);
+        debug_assert_eq!(mapping.node(n[2]), None);
+        debug_assert_eq!(mapping.node(n[3]), Some(2.into()));
+        debug_assert_eq!(mapping.edge(e[3]), None);
+        for edge_id in graph.edge_indices() {
+            let copied_edge_id = mapping.edge(edge_id).unwrap();
+            let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+            debug_assert_eq!(
+                copy.edge_endpoints(copied_edge_id),
+                Edge {
+                    from_node: mapping.node(from_node).unwrap(),
+                    to_node: mapping.node(to_node).unwrap(),
+                }
+            );
+            debug_assert_eq!(copy.edge_data(copied_edge_id), graph.edge_data(edge_id));
+        }
+        debug_assert_eq!(copy.edge_count_between(0.into(), 1.into()), 2);
+    }
+
+    #[test]
+    fn test_compose_mappings() {
+        type Graph = PetGraph<(), ()>;
+        let mut first = GraphIndexMapping::<Graph, Graph>::new();
+        first.insert_node(0.into(), 2.into());
+        first.insert_node(3.into(), 0.into());
+        first.insert_node(4.into(), 1.into());
+        first.insert_edge(1.into(), 0.into());
+
+        let mut second = GraphIndexMapping::<Graph, Graph>::new();
+        second.insert_node(0.into(), 5.into());
+        second.insert_node(2.into(), 6.into());
+        second.insert_edge(0.into(), 3.into());
+
+        let composed = first.compose(&second);
+        debug_assert_eq!(
+            composed.nodes().collect::<Vec<_>>(),
+            [(0.into(), 6.into()), (3.into(), 5.into())]
+        );
+        debug_assert_eq!(composed.node(4.into()), None);
+        debug_assert_eq!(composed.edge(1.into()), Some(3.into()));
+
+        let inverse = composed.inverse();
+        debug_assert_eq!(inverse.node(5.into()), Some(3.into()));
+        debug_assert_eq!(inverse.inverse(), composed);
+        debug_assert_eq!(
+            first.compose(&first.inverse()).node(4.into()),
+            Some(4.into())
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut graph = StableGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = [(0, 1), (1, 4), (4, 3), (3, 0), (2, 4)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (from, to))| graph.add_edge(n[from], n[to], i))
+            .collect();
+        graph.remove_node(n[2]);
+        graph.remove_edge(e[1]);
+        debug_assert_eq!(graph.node_index_bound(), 5);
+
+        let mapping = compact(&mut graph);
+        debug_assert_eq!(graph.node_index_bound(), 4);
+        debug_assert_eq!(graph.edge_index_bound(), 3);
+        debug_assert_eq!(mapping.node(n[4]), Some(3.into()));
+        debug_assert_eq!(mapping.node(n[2]), None);
+        debug_assert_eq!(mapping.edge(e[3]), Some(2.into()));
+        debug_assert_eq!(
+            graph
+                .node_indices()
+                .map(|node_id| *graph.node_data(node_id))
+                .collect::<Vec<_>>(),
+            [0, 1, 3, 4]
+        );
+        debug_assert_eq!(
+            graph.edge_endpoints(1.into()),
+            Edge {
+                from_node: 3.into(),
+                to_node: 2.into()
+            }
+        );
+
+        // Already consecutive indices are kept.
+        let unchanged = compact(&mut graph);
+        debug_assert_eq!(unchanged.node(3.into()), Some(3.into()));
+        debug_assert_eq!(*graph.edge_data(2.into()), 3);
+    }
+
+    #[test]
+    fn test_extract_subgraph() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = [(0, 1), (1, 3), (3, 4), (4, 1), (1, 3)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (from, to))| graph.add_edge(n[from], n[to], i))
+            .collect();
+
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        for node_id in [n[1], n[3], n[4]] {
+            subgraph.enable_node(node_id);
+        }
+        for edge_id in [e[1], e[2], e[4]] {
+            subgraph.enable_edge(edge_id);
+        }
+
+        let (extracted, forward, backward): (PetGraph<_, _>, _, _) = extract_subgraph(&subgraph);
+        debug_assert_eq!(extracted.node_count(), 3);
+        debug_assert_eq!(extracted.edge_count(), 3);
+        debug_assert_eq!(
+            extracted
+                .node_indices()
+                .map(|node_id| *extracted.node_data(node_id))
+                .collect::<Vec<_>>(),
+            [1, 3, 4]
+        );
+        debug_assert_eq!(forward.node(n[0]), None);
+        debug_assert_eq!(forward.node(n[4]), Some(2.into()));
+        debug_assert_eq!(forward.edge(e[3]), None);
+        debug_assert_eq!(forward.edge(e[4]), Some(2.into()));
+        for node_id in extracted.node_indices() {
+            debug_assert_eq!(forward.node(backward.node(node_id).unwrap()), Some(node_id));
+        }
+        for edge_id in extracted.edge_indices() {
+            let original_edge_id = backward.edge(edge_id).unwrap();
+            debug_assert_eq!(
+                extracted.edge_data(edge_id),
+                graph.edge_data(original_edge_id)
+            );
+        }
+        debug_assert_eq!(extracted.edge_count_between(0.into(), 1.into()), 2);
+    }
+
+    #[test]
+    fn test_disjoint_union_all() {
+        let graphs: Vec<_> = (0..3)
+            .map(|offset| {
+                let mut graph = PetGraph::new();
+                let n: Vec<_> = (0..3).map(|i| graph.add_node(offset * 10 + i)).collect();
+                graph.add_edge(n[0], n[1], offset * 10);
+                graph.add_edge(n[1], n[2], offset * 10 + 1);
+                graph.add_edge(n[1], n[2], offset * 10 + 2);
+                graph
+            })
+            .collect();
+        let originals = graphs.clone();
+
+        let (union, mappings) = disjoint_union_all(graphs).unwrap();
+        debug_assert_eq!(union.node_count(), 9);
+        debug_assert_eq!(union.edge_count(), 9);
+        debug_assert_eq!(mappings.len(), 3);
+        for (graph, mapping) in originals.iter().zip(&mappings) {
+            for node_id in graph.node_indices() {
+                debug_assert_eq!(
+                    union.node_data(mapping.node(node_id).unwrap()),
+                    graph.node_data(node_id)
+                );
+            }
+            for edge_id in graph.edge_indices() {
+                let union_edge_id = mapping.edge(edge_id).unwrap();
+                let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+                debug_assert_eq!(union.edge_data(union_edge_id), graph.edge_data(edge_id));
+                debug_assert_eq!(
+                    union.edge_endpoints(union_edge_id),
+                    Edge {
+                        from_node: mapping.node(from_node).unwrap(),
+                        to_node: mapping.node(to_node).unwrap(),
+                    }
+                );
+            }
+        }
+        debug_assert_eq!(mappings[0].node(2.into()), Some(2.into()));
+        debug_assert!(disjoint_union_all(Vec::<PetGraph<(), ()>>::new()).is_none());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let build = |edges: &[(usize, usize, char)], nodes: &[char]| {
+            let mut graph = PetGraph::new();
+            graph.extend_nodes(nodes.iter().copied());
+            graph.extend_edges(
+                edges
+                    .iter()
+                    .map(|&(from, to, data)| (from.into(), to.into(), data)),
+            );
+            graph
+        };
+        let mut first = build(
+            &[(0, 1, 'x'), (1, 2, 'y'), (0, 1, 'w'), (2, 0, 'z')],
+            &['a', 'b', 'c'],
+        );
+        let mut second = build(
+            &[(0, 1, 'z'), (1, 2, 'w'), (2, 0, 'y'), (1, 2, 'x')],
+            &['c', 'a', 'b'],
+        );
+
+        let first_mapping = normalize_by_key(
+            &mut first,
+            |graph, node_id| *graph.node_data(node_id),
+            |graph, edge_id| *graph.edge_data(edge_id),
+        );
+        normalize_by_key(
+            &mut second,
+            |graph, node_id| *graph.node_data(node_id),
+            |graph, edge_id| *graph.edge_data(edge_id),
+        );
+        let parts = |graph: &PetGraph<char, char>| {
+            graph
+                .edge_references()
+                .map(|(_, Edge { from_node, to_node }, &data)| {
+                    (*graph.node_data(from_node), *graph.node_data(to_node), data)
+                })
+                .collect::<Vec<_>>()
+        };
+        debug_assert_eq!(parts(&first), parts(&second));
+        debug_assert_eq!(
+            parts(&first),
+            [
+                ('a', 'b', 'w'),
+                ('a', 'b', 'x'),
+                ('b', 'c', 'y'),
+                ('c', 'a', 'z')
+            ]
+        );
+        debug_assert_eq!(first_mapping.edge(0.into()), Some(1.into()));
+        debug_assert_eq!(first_mapping.node(2.into()), Some(2.into()));
+
+        let mapping = normalize(&mut second);
+        debug_assert_eq!(mapping.edge(3.into()), Some(3.into()));
+        debug_assert_eq!(*second.node_data(0.into()), 'a');
+    }
+
+    #[test]
+    fn test_collapse_parallel_edges() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 4);
+        graph.add_edge(n[0], n[2], 7);
+        graph.add_edge(n[0], n[1], 2);
+        graph.add_edge(n[1], n[1], 3);
+        graph.add_edge(n[1], n[1], 1);
+        graph.add_edge(n[2], n[0], 6);
+        graph.add_edge(n[0], n[1], 5);
+
+        let simple: PetGraph<_, _> =
+            collapse_parallel_edges(&graph, |weight, other| *weight = (*weight).min(*other));
+        debug_assert_eq!(simple.node_count(), 3);
+        debug_assert_eq!(simple.edge_count(), 4);
+        debug_assert_eq!(*simple.node_data(n[2]), 2);
+        for from in &n {
+            for to in &n {
+                debug_assert_eq!(
+                    simple.edge_count_between(*from, *to),
+                    usize::from(graph.contains_edge_between(*from, *to))
+                );
+                if let Some(edge_id) = simple.edges_between(*from, *to).next() {
+                    debug_assert_eq!(
+                        Some(*simple.edge_data(edge_id)),
+                        graph
+                            .edges_between(*from, *to)
+                            .map(|edge_id| *graph.edge_data(edge_id))
+                            .min()
+                    );
+                }
+            }
+        }
+
+        let sums: PetGraph<_, _> =
+            collapse_parallel_edges(&graph, |weight, other| *weight += *other);
+        let edge_id = sums.edges_between(n[0], n[1]).next().unwrap();
+        debug_assert_eq!(*sums.edge_data(edge_id), 11);
+    }
+}
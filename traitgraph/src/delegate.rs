@@ -0,0 +1,176 @@
+/// Implements [GraphBase](crate::interface::GraphBase), [ImmutableGraphContainer](crate::interface::ImmutableGraphContainer)
+/// and [NavigableGraph](crate::interface::NavigableGraph) for a wrapper type by forwarding to a field containing a graph.
+///
+/// The macro takes the generic parameters of the impl blocks in square brackets, followed by the wrapper type,
+/// and after `=>` the name of the field that contains the wrapped graph together with its type.
+/// Additional where clauses can be appended, and are added to all impl blocks.
+/// Each trait is implemented only if the wrapped graph implements it.
+///
+/// Traits that need custom behaviour, like [MutableGraphContainer](crate::interface::MutableGraphContainer), need to be implemented manually.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::delegate_graph;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{GraphBase, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+///
+/// struct Roads<Graph> {
+///     graph: Graph,
+///     name: String,
+/// }
+///
+/// delegate_graph!(impl[Graph] Roads<Graph> => graph: Graph);
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// graph.add_edge(a, b, 5);
+///
+/// let roads = Roads { graph, name: "roads".to_string() };
+/// assert_eq!(roads.node_count(), 2);
+/// assert!(roads.contains_edge_between(a, b));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! delegate_graph {
+    (impl[$($generics:tt)*] $wrapper:ty => $field:tt: $inner:ty) => {
+        $crate::delegate_graph!(impl[$($generics)*] $wrapper => $field: $inner where);
+    };
+    (impl[$($generics:tt)*] $wrapper:ty => $field:tt: $inner:ty where $($bounds:tt)*) => {
+        impl<$($generics)*> $crate::interface::GraphBase for $wrapper
+        where
+            $inner: $crate::interface::GraphBase,
+            $($bounds)*
+        {
+            type NodeData = <$inner as $crate::interface::GraphBase>::NodeData;
+            type EdgeData = <$inner as $crate::interface::GraphBase>::EdgeData;
+            type OptionalNodeIndex = <$inner as $crate::interface::GraphBase>::OptionalNodeIndex;
+            type OptionalEdgeIndex = <$inner as $crate::interface::GraphBase>::OptionalEdgeIndex;
+            type NodeIndex = <$inner as $crate::interface::GraphBase>::NodeIndex;
+            type EdgeIndex = <$inner as $crate::interface::GraphBase>::EdgeIndex;
+        }
+
+        impl<$($generics)*> $crate::interface::ImmutableGraphContainer for $wrapper
+        where
+            $inner: $crate::interface::ImmutableGraphContainer,
+            $($bounds)*
+        {
+            type NodeIndices<'delegate>
+                = <$inner as $crate::interface::ImmutableGraphContainer>::NodeIndices<'delegate>
+            where
+                Self: 'delegate;
+            type EdgeIndices<'delegate>
+                = <$inner as $crate::interface::ImmutableGraphContainer>::EdgeIndices<'delegate>
+            where
+                Self: 'delegate;
+            type EdgeReferences<'delegate>
+                = <$inner as $crate::interface::ImmutableGraphContainer>::EdgeReferences<'delegate>
+            where
+                Self: 'delegate;
+            type NodeIndicesCopied =
+                <$inner as $crate::interface::ImmutableGraphContainer>::NodeIndicesCopied;
+            type EdgeIndicesCopied =
+                <$inner as $crate::interface::ImmutableGraphContainer>::EdgeIndicesCopied;
+
+            fn node_indices(&self) -> Self::NodeIndices<'_> {
+                self.$field.node_indices()
+            }
+
+            fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+                self.$field.edge_indices()
+            }
+
+            fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+                self.$field.node_indices_copied()
+            }
+
+            fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+                self.$field.edge_indices_copied()
+            }
+
+            fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+                self.$field.contains_node_index(node_id)
+            }
+
+            fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+                self.$field.contains_edge_index(edge_id)
+            }
+
+            fn node_count(&self) -> usize {
+                self.$field.node_count()
+            }
+
+            fn edge_count(&self) -> usize {
+                self.$field.edge_count()
+            }
+
+            fn node_index_bound(&self) -> usize {
+                self.$field.node_index_bound()
+            }
+
+            fn edge_index_bound(&self) -> usize {
+                self.$field.edge_index_bound()
+            }
+
+            fn generation(&self) -> Option<u64> {
+                self.$field.generation()
+            }
+
+            fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+                self.$field.node_data(node_id)
+            }
+
+            fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+                self.$field.edge_data(edge_id)
+            }
+
+            fn edge_endpoints(
+                &self,
+                edge_id: Self::EdgeIndex,
+            ) -> $crate::interface::Edge<Self::NodeIndex> {
+                self.$field.edge_endpoints(edge_id)
+            }
+
+            fn edge_references(&self) -> Self::EdgeReferences<'_> {
+                self.$field.edge_references()
+            }
+        }
+
+        impl<$($generics)*> $crate::interface::NavigableGraph for $wrapper
+        where
+            $inner: $crate::interface::NavigableGraph,
+            $($bounds)*
+        {
+            type OutNeighbors<'delegate>
+                = <$inner as $crate::interface::NavigableGraph>::OutNeighbors<'delegate>
+            where
+                Self: 'delegate;
+            type InNeighbors<'delegate>
+                = <$inner as $crate::interface::NavigableGraph>::InNeighbors<'delegate>
+            where
+                Self: 'delegate;
+            type EdgesBetween<'delegate>
+                = <$inner as $crate::interface::NavigableGraph>::EdgesBetween<'delegate>
+            where
+                Self: 'delegate;
+
+            fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+                self.$field.out_neighbors(node_id)
+            }
+
+            fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+                self.$field.in_neighbors(node_id)
+            }
+
+            fn edges_between(
+                &self,
+                from_node_id: Self::NodeIndex,
+                to_node_id: Self::NodeIndex,
+            ) -> Self::EdgesBetween<'_> {
+                self.$field.edges_between(from_node_id, to_node_id)
+            }
+        }
+    };
+}
@@ -0,0 +1,88 @@
+use crate::index::GraphIndex;
+use crate::interface::{Edge, ImmutableGraphContainer};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+
+/// A wrapper around a reference to a graph whose [Debug] implementation renders the graph as an adjacency listing.
+///
+/// Each node is printed on its own line with its index and data,
+/// followed by one indented line per outgoing edge with the index of its head, the index of the edge and the edge data.
+/// Nodes and edges are printed in the order of [`node_indices`](ImmutableGraphContainer::node_indices)
+/// and [`edge_indices`](ImmutableGraphContainer::edge_indices).
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::format::DebugGraph;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node('a');
+/// let b = graph.add_node('b');
+/// graph.add_edge(a, b, 5);
+/// graph.add_edge(b, b, 7);
+///
+/// assert_eq!(
+///     format!("{:?}", DebugGraph::new(&graph)),
+///     "graph with 2 nodes and 2 edges\n0: 'a'\n    -> 1 via 0: 5\n1: 'b'\n    -> 1 via 1: 7\n"
+/// );
+/// # }
+/// ```
+pub struct DebugGraph<'a, Graph> {
+    graph: &'a Graph,
+}
+
+impl<'a, Graph> DebugGraph<'a, Graph> {
+    /// Wraps the given graph for printing.
+    pub fn new(graph: &'a Graph) -> Self {
+        Self { graph }
+    }
+}
+
+impl<Graph: ImmutableGraphContainer> Debug for DebugGraph<'_, Graph>
+where
+    Graph::NodeData: Debug,
+    Graph::EdgeData: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let graph = self.graph;
+        writeln!(
+            f,
+            "graph with {} nodes and {} edges",
+            graph.node_count(),
+            graph.edge_count()
+        )?;
+
+        let mut out_edges: Vec<Vec<_>> =
+            (0..graph.node_index_bound()).map(|_| Vec::new()).collect();
+        for edge_id in graph.edge_indices() {
+            let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+            out_edges[from_node.as_usize()].push((edge_id, to_node));
+        }
+
+        for node_id in graph.node_indices() {
+            writeln!(f, "{node_id:?}: {:?}", graph.node_data(node_id))?;
+            for &(edge_id, to_node) in &out_edges[node_id.as_usize()] {
+                writeln!(
+                    f,
+                    "    -> {to_node:?} via {edge_id:?}: {:?}",
+                    graph.edge_data(edge_id)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renders the given graph as an adjacency listing, see [DebugGraph].
+pub fn format_graph<Graph: ImmutableGraphContainer>(graph: &Graph) -> String
+where
+    Graph::NodeData: Debug,
+    Graph::EdgeData: Debug,
+{
+    format!("{:?}", DebugGraph::new(graph))
+}
@@ -0,0 +1,555 @@
+use crate::implementation::generation::GraphGeneration;
+use crate::index::{GraphIndex, GraphIndices};
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{
+    Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, MutableGraphContainer,
+    NavigableGraph, Neighbor,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// Marks the absence of an edge in the matrix or at the end of a list of parallel edges.
+const NO_EDGE: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+struct MatrixEdge<EdgeData> {
+    data: EdgeData,
+    from_node: usize,
+    to_node: usize,
+    /// The next edge with the same endpoints.
+    next_parallel_edge: usize,
+}
+
+/// A graph that stores its edges in a dense adjacency matrix, optimised for dense graphs.
+///
+/// Each cell of the matrix stores the first of the edges between a pair of nodes, and parallel edges are linked from there.
+/// Hence, [contains_edge_between](NavigableGraph::contains_edge_between) and [edges_between](NavigableGraph::edges_between) take constant time
+/// (plus the number of parallel edges), while iterating over the neighbors of a node takes time linear in the number of nodes.
+/// The matrix requires space quadratic in the number of nodes, and adding nodes may need to reallocate it.
+///
+/// Removals behave like in [PetGraph](crate::implementation::petgraph_impl::PetGraph):
+/// when a node or edge is removed, the node or edge with the highest index is moved into its place.
+#[derive(Debug, Clone)]
+pub struct AdjacencyMatrixGraph<NodeData, EdgeData> {
+    node_data: Vec<NodeData>,
+    edges: Vec<MatrixEdge<EdgeData>>,
+    /// The matrix in row-major order, with a row length of `stride`.
+    matrix: Vec<usize>,
+    stride: usize,
+    generation: GraphGeneration,
+}
+
+impl<NodeData, EdgeData> AdjacencyMatrixGraph<NodeData, EdgeData> {
+    /// Creates a new empty graph.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new empty graph with a matrix large enough for the given amount of nodes.
+    pub fn with_capacity(node_capacity: usize) -> Self {
+        Self {
+            node_data: Vec::with_capacity(node_capacity),
+            edges: Vec::new(),
+            matrix: vec![NO_EDGE; node_capacity * node_capacity],
+            stride: node_capacity,
+            generation: GraphGeneration::new(),
+        }
+    }
+
+    fn cell(&self, from_node: usize, to_node: usize) -> usize {
+        from_node * self.stride + to_node
+    }
+
+    /// Grows the matrix such that it has space for at least `node_count` nodes.
+    fn reserve_matrix(&mut self, node_count: usize) {
+        if node_count <= self.stride {
+            return;
+        }
+
+        self.resize_matrix(node_count.max(2 * self.stride));
+    }
+
+    /// Moves the matrix into a new allocation with the given stride, which must be at least the amount of nodes.
+    fn resize_matrix(&mut self, stride: usize) {
+        debug_assert!(stride >= self.node_data.len());
+        let mut matrix = vec![NO_EDGE; stride * stride];
+        for row in 0..self.node_data.len() {
+            let old_row = &self.matrix[row * self.stride..row * self.stride + self.node_data.len()];
+            matrix[row * stride..row * stride + self.node_data.len()].copy_from_slice(old_row);
+        }
+        self.matrix = matrix;
+        self.stride = stride;
+    }
+
+    /// Returns a mutable reference to the pointer to the given edge, which is either a matrix cell or the link of a parallel edge.
+    fn pointer_to_edge(&mut self, edge_id: usize) -> &mut usize {
+        let cell = self.cell(self.edges[edge_id].from_node, self.edges[edge_id].to_node);
+        if self.matrix[cell] == edge_id {
+            return &mut self.matrix[cell];
+        }
+
+        let mut current = self.matrix[cell];
+        while self.edges[current].next_parallel_edge != edge_id {
+            current = self.edges[current].next_parallel_edge;
+        }
+        &mut self.edges[current].next_parallel_edge
+    }
+
+    fn edges_in_cell(&self, cell: usize) -> impl '_ + Iterator<Item = usize> {
+        let mut current = self.matrix[cell];
+        core::iter::from_fn(move || {
+            (current != NO_EDGE).then(|| {
+                let edge_id = current;
+                current = self.edges[current].next_parallel_edge;
+                edge_id
+            })
+        })
+    }
+}
+
+impl<NodeData, EdgeData> Default for AdjacencyMatrixGraph<NodeData, EdgeData> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<NodeData, EdgeData> GraphBase for AdjacencyMatrixGraph<NodeData, EdgeData> {
+    type NodeData = NodeData;
+    type EdgeData = EdgeData;
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<usize>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<usize>;
+    type NodeIndex = crate::index::NodeIndex<usize>;
+    type EdgeIndex = crate::index::EdgeIndex<usize>;
+}
+
+impl<NodeData, EdgeData> ImmutableGraphContainer for AdjacencyMatrixGraph<NodeData, EdgeData> {
+    type NodeIndices<'a>
+        = GraphIndices<Self::NodeIndex, Self::OptionalNodeIndex>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = GraphIndices<Self::EdgeIndex, Self::OptionalEdgeIndex>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+
+    type NodeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::NodeIndex, <Self as GraphBase>::OptionalNodeIndex>;
+    type EdgeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::EdgeIndex, <Self as GraphBase>::OptionalEdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        GraphIndices::from((0, self.node_count()))
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        GraphIndices::from((0, self.edge_count()))
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        GraphIndices::from((0, self.node_count()))
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        GraphIndices::from((0, self.edge_count()))
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        node_id.as_usize() < self.node_count()
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        edge_id.as_usize() < self.edge_count()
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_data.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn generation(&self) -> Option<u64> {
+        Some(self.generation.get())
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        &self.node_data[node_id.as_usize()]
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        &self.edges[edge_id.as_usize()].data
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        let edge = &self.edges[edge_id.as_usize()];
+        Edge {
+            from_node: edge.from_node.into(),
+            to_node: edge.to_node.into(),
+        }
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<NodeData, EdgeData> MutableGraphContainer for AdjacencyMatrixGraph<NodeData, EdgeData> {
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        &mut self.node_data[node_id.as_usize()]
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        &mut self.edges[edge_id.as_usize()].data
+    }
+
+    fn add_node(&mut self, node_data: Self::NodeData) -> Self::NodeIndex {
+        let node_id = self.node_data.len();
+        self.reserve_matrix(node_id + 1);
+        self.node_data.push(node_data);
+        self.generation.advance();
+        node_id.into()
+    }
+
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: Self::EdgeData,
+    ) -> Self::EdgeIndex {
+        let (from_node, to_node) = (from.as_usize(), to.as_usize());
+        debug_assert!(from_node < self.node_count() && to_node < self.node_count());
+        let edge_id = self.edges.len();
+        let cell = self.cell(from_node, to_node);
+        self.edges.push(MatrixEdge {
+            data: edge_data,
+            from_node,
+            to_node,
+            next_parallel_edge: self.matrix[cell],
+        });
+        self.matrix[cell] = edge_id;
+        self.generation.advance();
+        edge_id.into()
+    }
+
+    /// Reserves space for `additional` more nodes, growing the matrix if necessary.
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.node_data.reserve(additional);
+        self.reserve_matrix(self.node_data.len() + additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.edges.reserve(additional);
+    }
+
+    /// Shrinks the matrix to the amount of nodes, and the capacity of the edges to the amount of edges.
+    fn shrink_to_fit(&mut self) {
+        if self.stride > self.node_data.len() {
+            self.resize_matrix(self.node_data.len());
+        }
+        self.node_data.shrink_to_fit();
+        self.edges.shrink_to_fit();
+    }
+
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData> {
+        let node_id = node_id.as_usize();
+        let node_count = self.node_count();
+        if node_id >= node_count {
+            return None;
+        }
+
+        // Removing the incident edges in descending order ensures that no other incident edge is moved.
+        let mut incident_edges: Vec<_> = (0..node_count)
+            .flat_map(|other_node| {
+                self.edges_in_cell(self.cell(node_id, other_node))
+                    .chain(self.edges_in_cell(self.cell(other_node, node_id)))
+            })
+            .collect();
+        incident_edges.sort_unstable();
+        incident_edges.dedup();
+        for edge_id in incident_edges.into_iter().rev() {
+            self.remove_edge(edge_id.into());
+        }
+
+        // Move the row and column of the last node into the place of the removed node.
+        let last_node_id = node_count - 1;
+        if node_id != last_node_id {
+            // Moving all rows before all columns moves a self-loop of the last node correctly.
+            for other_node in 0..node_count {
+                let (from_cell, to_cell) = (
+                    self.cell(last_node_id, other_node),
+                    self.cell(node_id, other_node),
+                );
+                self.matrix[to_cell] = core::mem::replace(&mut self.matrix[from_cell], NO_EDGE);
+            }
+            for other_node in 0..node_count {
+                let (from_cell, to_cell) = (
+                    self.cell(other_node, last_node_id),
+                    self.cell(other_node, node_id),
+                );
+                self.matrix[to_cell] = core::mem::replace(&mut self.matrix[from_cell], NO_EDGE);
+            }
+            for edge in &mut self.edges {
+                if edge.from_node == last_node_id {
+                    edge.from_node = node_id;
+                }
+                if edge.to_node == last_node_id {
+                    edge.to_node = node_id;
+                }
+            }
+        }
+
+        self.generation.advance();
+        Some(self.node_data.swap_remove(node_id))
+    }
+
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<Self::EdgeData> {
+        let edge_id = edge_id.as_usize();
+        if edge_id >= self.edges.len() {
+            return None;
+        }
+
+        let next_parallel_edge = self.edges[edge_id].next_parallel_edge;
+        *self.pointer_to_edge(edge_id) = next_parallel_edge;
+
+        let last_edge_id = self.edges.len() - 1;
+        if edge_id != last_edge_id {
+            *self.pointer_to_edge(last_edge_id) = edge_id;
+        }
+        self.generation.advance();
+        Some(self.edges.swap_remove(edge_id).data)
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        edge_ids.windows(2).for_each(|w| debug_assert!(w[0] < w[1]));
+
+        for edge_id in edge_ids.iter().rev() {
+            self.remove_edge(*edge_id);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.node_data.clear();
+        self.edges.clear();
+        self.matrix.fill(NO_EDGE);
+        self.generation.advance();
+    }
+}
+
+/// An iterator over the neighbors of a node in an [AdjacencyMatrixGraph].
+pub struct AdjacencyMatrixNeighbors<'a, NodeData, EdgeData> {
+    graph: &'a AdjacencyMatrixGraph<NodeData, EdgeData>,
+    node_id: usize,
+    /// The next neighbor whose cell is visited.
+    next_neighbor_id: usize,
+    /// The next edge in the current cell.
+    next_edge_id: usize,
+    outgoing: bool,
+}
+
+impl<NodeData, EdgeData> Iterator for AdjacencyMatrixNeighbors<'_, NodeData, EdgeData> {
+    type Item = Neighbor<
+        <AdjacencyMatrixGraph<NodeData, EdgeData> as GraphBase>::NodeIndex,
+        <AdjacencyMatrixGraph<NodeData, EdgeData> as GraphBase>::EdgeIndex,
+    >;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_edge_id == NO_EDGE {
+            if self.next_neighbor_id >= self.graph.node_count() {
+                return None;
+            }
+            let cell = if self.outgoing {
+                self.graph.cell(self.node_id, self.next_neighbor_id)
+            } else {
+                self.graph.cell(self.next_neighbor_id, self.node_id)
+            };
+            self.next_edge_id = self.graph.matrix[cell];
+            self.next_neighbor_id += 1;
+        }
+
+        let edge_id = self.next_edge_id;
+        self.next_edge_id = self.graph.edges[edge_id].next_parallel_edge;
+        Some(Neighbor {
+            edge_id: edge_id.into(),
+            node_id: (self.next_neighbor_id - 1).into(),
+        })
+    }
+}
+
+/// An iterator over the edges between two nodes in an [AdjacencyMatrixGraph].
+pub struct AdjacencyMatrixEdgesBetween<'a, EdgeData, EdgeIndex> {
+    edges: &'a [MatrixEdge<EdgeData>],
+    next_edge_id: usize,
+    edge_index_type: PhantomData<EdgeIndex>,
+}
+
+impl<EdgeData, EdgeIndex: From<usize>> Iterator
+    for AdjacencyMatrixEdgesBetween<'_, EdgeData, EdgeIndex>
+{
+    type Item = EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.next_edge_id != NO_EDGE).then(|| {
+            let edge_id = self.next_edge_id;
+            self.next_edge_id = self.edges[edge_id].next_parallel_edge;
+            edge_id.into()
+        })
+    }
+}
+
+impl<NodeData, EdgeData> NavigableGraph for AdjacencyMatrixGraph<NodeData, EdgeData> {
+    type OutNeighbors<'a>
+        = AdjacencyMatrixNeighbors<'a, NodeData, EdgeData>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = AdjacencyMatrixNeighbors<'a, NodeData, EdgeData>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = AdjacencyMatrixEdgesBetween<'a, EdgeData, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        debug_assert!(self.contains_node_index(node_id));
+        AdjacencyMatrixNeighbors {
+            graph: self,
+            node_id: node_id.as_usize(),
+            next_neighbor_id: 0,
+            next_edge_id: NO_EDGE,
+            outgoing: true,
+        }
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        debug_assert!(self.contains_node_index(node_id));
+        AdjacencyMatrixNeighbors {
+            graph: self,
+            node_id: node_id.as_usize(),
+            next_neighbor_id: 0,
+            next_edge_id: NO_EDGE,
+            outgoing: false,
+        }
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        AdjacencyMatrixEdgesBetween {
+            edges: &self.edges,
+            next_edge_id: self.matrix[self.cell(from_node_id.as_usize(), to_node_id.as_usize())],
+            edge_index_type: PhantomData,
+        }
+    }
+
+    fn contains_edge_between(&self, from: Self::NodeIndex, to: Self::NodeIndex) -> bool {
+        self.matrix[self.cell(from.as_usize(), to.as_usize())] != NO_EDGE
+    }
+}
+
+impl<NodeData, EdgeData> SubgraphBase for AdjacencyMatrixGraph<NodeData, EdgeData> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::AdjacencyMatrixGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::index::GraphIndex;
+    use crate::interface::{
+        DynamicGraph, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph,
+    };
+
+    fn sorted_edges<Graph: DynamicGraph<EdgeData = usize>>(
+        graph: &Graph,
+    ) -> Vec<(usize, usize, usize)> {
+        let mut edges: Vec<_> = graph
+            .edge_indices()
+            .map(|edge_id| {
+                let endpoints = graph.edge_endpoints(edge_id);
+                (
+                    *graph.edge_data(edge_id),
+                    endpoints.from_node.as_usize(),
+                    endpoints.to_node.as_usize(),
+                )
+            })
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn test_adjacency_matrix_graph() {
+        let mut graph = AdjacencyMatrixGraph::new();
+        let mut petgraph = PetGraph::new();
+        for i in 0..5 {
+            graph.add_node(i);
+            petgraph.add_node(i);
+        }
+        for (data, (from, to)) in [
+            (0, 1),
+            (1, 2),
+            (0, 1),
+            (2, 2),
+            (3, 4),
+            (4, 0),
+            (2, 0),
+            (4, 4),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            graph.add_edge(from.into(), to.into(), data);
+            petgraph.add_edge(from.into(), to.into(), data);
+        }
+
+        debug_assert!(graph.contains_edge_between(0.into(), 1.into()));
+        debug_assert!(!graph.contains_edge_between(1.into(), 0.into()));
+        debug_assert_eq!(graph.edge_count_between(0.into(), 1.into()), 2);
+        let mut out_neighbors: Vec<_> = graph
+            .out_neighbors(2.into())
+            .map(|neighbor| (neighbor.node_id.as_usize(), neighbor.edge_id.as_usize()))
+            .collect();
+        out_neighbors.sort_unstable();
+        debug_assert_eq!(out_neighbors, [(0, 6), (2, 3)]);
+        debug_assert_eq!(graph.in_degree(0.into()), 2);
+
+        graph.remove_edge(0.into());
+        petgraph.remove_edge(0.into());
+        graph.remove_node(1.into());
+        petgraph.remove_node(1.into());
+        debug_assert_eq!(graph.node_count(), petgraph.node_count());
+        debug_assert_eq!(sorted_edges(&graph), sorted_edges(&petgraph));
+        for node_id in graph.node_indices() {
+            debug_assert_eq!(graph.node_data(node_id), petgraph.node_data(node_id));
+            debug_assert_eq!(graph.out_degree(node_id), petgraph.out_degree(node_id));
+            debug_assert_eq!(graph.in_degree(node_id), petgraph.in_degree(node_id));
+        }
+        debug_assert!(graph.contains_edge_between(1.into(), 0.into()));
+        debug_assert!(graph.contains_edge_between(1.into(), 1.into()));
+
+        graph.reserve_nodes(20);
+        debug_assert!(graph.stride >= 24);
+        debug_assert_eq!(sorted_edges(&graph), sorted_edges(&petgraph));
+        graph.shrink_to_fit();
+        debug_assert_eq!(graph.stride, 4);
+        debug_assert_eq!(graph.matrix.len(), 16);
+        debug_assert_eq!(sorted_edges(&graph), sorted_edges(&petgraph));
+        debug_assert_eq!(graph.edge_count_between(1.into(), 1.into()), 1);
+
+        graph.clear();
+        debug_assert!(graph.is_empty());
+    }
+}
@@ -0,0 +1,258 @@
+use crate::index::GraphIndex;
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{GraphBase, ImmutableGraphContainer, MutableGraphContainer};
+use alloc::vec::Vec;
+
+/// One of the two sides of a [BipartiteGraph].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BipartiteSide {
+    /// The left side.
+    Left,
+    /// The right side.
+    Right,
+}
+
+impl BipartiteSide {
+    /// Returns the other side.
+    pub fn opposite(self) -> Self {
+        match self {
+            BipartiteSide::Left => BipartiteSide::Right,
+            BipartiteSide::Right => BipartiteSide::Left,
+        }
+    }
+}
+
+/// A wrapper around a graph that assigns each node to one of two sides, such that all edges connect nodes of different sides.
+///
+/// The sides are stored in a vector indexed by the node indices,
+/// and are kept consistent when removing nodes under the same assumptions as for [LabeledGraph](crate::implementation::labeled_graph::LabeledGraph):
+/// the wrapped graph either moves the node with the highest index into the place of a removed node,
+/// or it does not change the indices of the remaining nodes at all.
+///
+/// Since nodes cannot be added without a side, this type does not implement [MutableGraphContainer].
+/// Instead, it offers methods with the same names that respect the partition.
+#[derive(Debug, Clone)]
+pub struct BipartiteGraph<Graph> {
+    graph: Graph,
+    sides: Vec<BipartiteSide>,
+}
+
+impl<Graph: ImmutableGraphContainer> BipartiteGraph<Graph> {
+    /// Wraps the given graph, assigning each node the side returned by `side`.
+    ///
+    /// Panics if an edge connects two nodes on the same side.
+    pub fn new(graph: Graph, mut side: impl FnMut(Graph::NodeIndex) -> BipartiteSide) -> Self {
+        let mut sides = Vec::new();
+        sides.resize(graph.node_index_bound(), BipartiteSide::Left);
+        for node_id in graph.node_indices() {
+            sides[node_id.as_usize()] = side(node_id);
+        }
+        let result = Self { graph, sides };
+        for edge_id in result.graph.edge_indices() {
+            let endpoints = result.graph.edge_endpoints(edge_id);
+            assert_ne!(
+                result.side(endpoints.from_node),
+                result.side(endpoints.to_node),
+                "edge {edge_id:?} does not cross the partition"
+            );
+        }
+        result
+    }
+
+    /// Returns the side of the given node.
+    pub fn side(&self, node_id: Graph::NodeIndex) -> BipartiteSide {
+        debug_assert!(self.graph.contains_node_index(node_id));
+        self.sides[node_id.as_usize()]
+    }
+
+    /// Returns an iterator over the nodes on the left side.
+    pub fn left_nodes(&self) -> impl '_ + Iterator<Item = Graph::NodeIndex> {
+        self.nodes_on_side(BipartiteSide::Left)
+    }
+
+    /// Returns an iterator over the nodes on the right side.
+    pub fn right_nodes(&self) -> impl '_ + Iterator<Item = Graph::NodeIndex> {
+        self.nodes_on_side(BipartiteSide::Right)
+    }
+
+    /// Returns an iterator over the nodes on the given side.
+    pub fn nodes_on_side(
+        &self,
+        side: BipartiteSide,
+    ) -> impl '_ + Iterator<Item = Graph::NodeIndex> {
+        self.graph
+            .node_indices()
+            .filter(move |&node_id| self.side(node_id) == side)
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns the wrapped graph, discarding the partition.
+    pub fn into_inner(self) -> Graph {
+        self.graph
+    }
+}
+
+impl<Graph: MutableGraphContainer> BipartiteGraph<Graph> {
+    /// Adds a new node on the left side.
+    pub fn add_left_node(&mut self, node_data: Graph::NodeData) -> Graph::NodeIndex {
+        self.add_node(node_data, BipartiteSide::Left)
+    }
+
+    /// Adds a new node on the right side.
+    pub fn add_right_node(&mut self, node_data: Graph::NodeData) -> Graph::NodeIndex {
+        self.add_node(node_data, BipartiteSide::Right)
+    }
+
+    /// Adds a new node on the given side.
+    pub fn add_node(
+        &mut self,
+        node_data: Graph::NodeData,
+        side: BipartiteSide,
+    ) -> Graph::NodeIndex {
+        let node_id = self.graph.add_node(node_data);
+        if node_id.as_usize() >= self.sides.len() {
+            self.sides
+                .resize(node_id.as_usize() + 1, BipartiteSide::Left);
+        }
+        self.sides[node_id.as_usize()] = side;
+        node_id
+    }
+
+    /// Adds a new edge between the given nodes.
+    ///
+    /// Panics if both nodes are on the same side.
+    pub fn add_edge(
+        &mut self,
+        from: Graph::NodeIndex,
+        to: Graph::NodeIndex,
+        edge_data: Graph::EdgeData,
+    ) -> Graph::EdgeIndex {
+        assert_ne!(
+            self.side(from),
+            self.side(to),
+            "an edge must connect nodes on different sides"
+        );
+        self.graph.add_edge(from, to, edge_data)
+    }
+
+    /// Returns a mutable reference to the node data associated with the given node id.
+    pub fn node_data_mut(&mut self, node_id: Graph::NodeIndex) -> &mut Graph::NodeData {
+        self.graph.node_data_mut(node_id)
+    }
+
+    /// Returns a mutable reference to the edge data associated with the given edge id.
+    pub fn edge_data_mut(&mut self, edge_id: Graph::EdgeIndex) -> &mut Graph::EdgeData {
+        self.graph.edge_data_mut(edge_id)
+    }
+
+    /// Removes the node with the given id from the graph, together with all its incident edges.
+    pub fn remove_node(&mut self, node_id: Graph::NodeIndex) -> Option<Graph::NodeData> {
+        let last_node_id = self.graph.node_indices().last();
+        let node_data = self.graph.remove_node(node_id)?;
+
+        // The last node may have been moved into the place of the removed node.
+        if let Some(last_node_id) = last_node_id {
+            if last_node_id != node_id
+                && self.graph.contains_node_index(node_id)
+                && !self.graph.contains_node_index(last_node_id)
+            {
+                self.sides[node_id.as_usize()] = self.sides[last_node_id.as_usize()];
+            }
+        }
+        self.sides.truncate(self.graph.node_index_bound());
+
+        Some(node_data)
+    }
+
+    /// Removes the edge with the given id from the graph.
+    pub fn remove_edge(&mut self, edge_id: Graph::EdgeIndex) -> Option<Graph::EdgeData> {
+        self.graph.remove_edge(edge_id)
+    }
+
+    /// Removes all nodes and edges from the graph.
+    pub fn clear(&mut self) {
+        self.graph.clear();
+        self.sides.clear();
+    }
+}
+
+impl<Graph: Default> Default for BipartiteGraph<Graph> {
+    fn default() -> Self {
+        Self {
+            graph: Graph::default(),
+            sides: Vec::new(),
+        }
+    }
+}
+
+delegate_graph!(impl[Graph] BipartiteGraph<Graph> => graph: Graph);
+
+impl<Graph: GraphBase> SubgraphBase for BipartiteGraph<Graph> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{BipartiteGraph, BipartiteSide};
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_partition_tracking() {
+        let mut graph = BipartiteGraph::<PetGraph<char, ()>>::default();
+        let a = graph.add_left_node('a');
+        let b = graph.add_left_node('b');
+        let x = graph.add_right_node('x');
+        let y = graph.add_right_node('y');
+        graph.add_edge(a, x, ());
+        graph.add_edge(y, b, ());
+        graph.add_edge(b, x, ());
+
+        debug_assert_eq!(graph.left_nodes().collect::<Vec<_>>(), [a, b]);
+        debug_assert_eq!(graph.right_nodes().collect::<Vec<_>>(), [x, y]);
+        debug_assert_eq!(graph.side(y), BipartiteSide::Right);
+        debug_assert!(graph.contains_edge_between(y, b));
+
+        // y is moved into the place of a.
+        debug_assert_eq!(graph.remove_node(a), Some('a'));
+        debug_assert_eq!(*graph.node_data(a), 'y');
+        debug_assert_eq!(graph.side(a), BipartiteSide::Right);
+        debug_assert_eq!(graph.left_nodes().collect::<Vec<_>>(), [b]);
+        debug_assert_eq!(graph.right_nodes().count(), 2);
+        debug_assert_eq!(graph.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_new() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[3], n[2], ());
+
+        let graph = BipartiteGraph::new(graph, |node_id| {
+            if node_id == n[0] || node_id == n[2] {
+                BipartiteSide::Left
+            } else {
+                BipartiteSide::Right
+            }
+        });
+        debug_assert_eq!(graph.left_nodes().collect::<Vec<_>>(), [n[0], n[2]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_edge_within_side() {
+        let mut graph = BipartiteGraph::<PetGraph<(), ()>>::default();
+        let a = graph.add_left_node(());
+        let b = graph.add_left_node(());
+        graph.add_edge(a, b, ());
+    }
+}
@@ -0,0 +1,255 @@
+use crate::implementation::csr_graph::CsrGraph;
+use crate::index::{AtomicIndexAllocator, GraphIndex};
+use crate::interface::{GraphBase, MutableGraphContainer};
+use std::sync::Mutex;
+
+/// The default amount of shards of a [ConcurrentGraphBuilder].
+const DEFAULT_SHARD_COUNT: usize = 64;
+
+/// A shard of nodes, each stored with its index.
+type NodeShard<NodeData> = Mutex<Vec<(usize, NodeData)>>;
+/// A shard of edges, each stored with its index and its endpoints.
+type EdgeShard<EdgeData> = Mutex<Vec<(usize, usize, usize, EdgeData)>>;
+
+/// A builder that allows to add nodes and edges to a graph from multiple threads in parallel.
+///
+/// Node and edge indices are assigned consecutively by an [AtomicIndexAllocator],
+/// and the nodes and edges are stored in shards that are each protected by a lock.
+/// Hence, threads only contend when they add to the same shard at the same time.
+///
+/// After construction, the builder is frozen into a graph with [freeze](Self::freeze),
+/// which preserves the indices returned by [add_node](Self::add_node) and [add_edge](Self::add_edge),
+/// or into a [CsrGraph] with [into_csr_graph](Self::into_csr_graph).
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::concurrent_graph_builder::ConcurrentGraphBuilder;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::ImmutableGraphContainer;
+///
+/// let builder = ConcurrentGraphBuilder::new();
+/// std::thread::scope(|scope| {
+///     for thread in 0..4 {
+///         let builder = &builder;
+///         scope.spawn(move || {
+///             let a = builder.add_node(thread);
+///             let b = builder.add_node(thread);
+///             builder.add_edge(a, b, ());
+///         });
+///     }
+/// });
+///
+/// let graph: PetGraph<_, _> = builder.freeze();
+/// assert_eq!(graph.node_count(), 8);
+/// assert_eq!(graph.edge_count(), 4);
+/// ```
+#[derive(Debug)]
+pub struct ConcurrentGraphBuilder<NodeData, EdgeData> {
+    indices: AtomicIndexAllocator,
+    node_shards: Vec<NodeShard<NodeData>>,
+    edge_shards: Vec<EdgeShard<EdgeData>>,
+}
+
+impl<NodeData, EdgeData> ConcurrentGraphBuilder<NodeData, EdgeData> {
+    /// Creates a new empty builder.
+    pub fn new() -> Self {
+        Self::with_shard_count(DEFAULT_SHARD_COUNT)
+    }
+
+    /// Creates a new empty builder with the given amount of shards.
+    ///
+    /// More shards reduce lock contention when many threads add nodes and edges at the same time.
+    pub fn with_shard_count(shard_count: usize) -> Self {
+        assert!(shard_count > 0, "a builder needs at least one shard");
+        Self {
+            indices: AtomicIndexAllocator::new(),
+            node_shards: (0..shard_count).map(|_| Mutex::default()).collect(),
+            edge_shards: (0..shard_count).map(|_| Mutex::default()).collect(),
+        }
+    }
+
+    /// Adds a new node with the given data and returns its index.
+    pub fn add_node(&self, node_data: NodeData) -> crate::index::NodeIndex<usize> {
+        let node_id = self.indices.allocate_node();
+        self.node_shards[node_id.as_usize() % self.node_shards.len()]
+            .lock()
+            .unwrap()
+            .push((node_id.as_usize(), node_data));
+        node_id
+    }
+
+    /// Adds a new edge with the given data and returns its index.
+    ///
+    /// The endpoints must have been returned by [add_node](Self::add_node).
+    pub fn add_edge(
+        &self,
+        from: crate::index::NodeIndex<usize>,
+        to: crate::index::NodeIndex<usize>,
+        edge_data: EdgeData,
+    ) -> crate::index::EdgeIndex<usize> {
+        debug_assert!(from.as_usize() < self.node_count() && to.as_usize() < self.node_count());
+        let edge_id = self.indices.allocate_edge();
+        self.edge_shards[edge_id.as_usize() % self.edge_shards.len()]
+            .lock()
+            .unwrap()
+            .push((
+                edge_id.as_usize(),
+                from.as_usize(),
+                to.as_usize(),
+                edge_data,
+            ));
+        edge_id
+    }
+
+    /// Returns the amount of nodes added so far.
+    pub fn node_count(&self) -> usize {
+        self.indices.node_count()
+    }
+
+    /// Returns the amount of edges added so far.
+    pub fn edge_count(&self) -> usize {
+        self.indices.edge_count()
+    }
+
+    /// Converts the builder into a graph, preserving the node and edge indices.
+    ///
+    /// The nodes and edges are added to an empty graph in the order of their indices,
+    /// so the indices are preserved if the graph assigns consecutive indices on insertion, as all graphs of this crate do.
+    pub fn freeze<
+        Graph: MutableGraphContainer<
+                NodeData = NodeData,
+                EdgeData = EdgeData,
+                NodeIndex = crate::index::NodeIndex<usize>,
+                EdgeIndex = crate::index::EdgeIndex<usize>,
+            > + Default,
+    >(
+        self,
+    ) -> Graph {
+        let (node_data, edges) = self.into_sorted_parts();
+        let mut graph = Graph::default();
+        for (node_id, node_data) in node_data.into_iter().enumerate() {
+            let added_node_id = graph.add_node(node_data);
+            debug_assert_eq!(added_node_id.as_usize(), node_id);
+        }
+        for (edge_id, (from, to, edge_data)) in edges.into_iter().enumerate() {
+            let added_edge_id = graph.add_edge(from.into(), to.into(), edge_data);
+            debug_assert_eq!(added_edge_id.as_usize(), edge_id);
+        }
+        graph
+    }
+
+    /// Converts the builder into a [CsrGraph].
+    ///
+    /// The node indices are preserved, while the edge indices are reassigned as described in [CsrGraph::from_edges].
+    pub fn into_csr_graph(self) -> CsrGraph<NodeData, EdgeData> {
+        let (node_data, edges) = self.into_sorted_parts();
+        CsrGraph::from_edges(node_data, edges)
+    }
+
+    /// Returns the node data and the edges, each ordered by their index.
+    fn into_sorted_parts(self) -> (Vec<NodeData>, Vec<(usize, usize, EdgeData)>) {
+        let node_count = self.indices.node_count();
+        let edge_count = self.indices.edge_count();
+
+        let mut node_data: Vec<_> = (0..node_count).map(|_| None).collect();
+        for shard in self.node_shards {
+            for (node_id, data) in shard.into_inner().unwrap() {
+                node_data[node_id] = Some(data);
+            }
+        }
+        let mut edges: Vec<_> = (0..edge_count).map(|_| None).collect();
+        for shard in self.edge_shards {
+            for (edge_id, from, to, data) in shard.into_inner().unwrap() {
+                edges[edge_id] = Some((from, to, data));
+            }
+        }
+
+        (
+            node_data.into_iter().map(Option::unwrap).collect(),
+            edges.into_iter().map(Option::unwrap).collect(),
+        )
+    }
+}
+
+impl<NodeData, EdgeData> Default for ConcurrentGraphBuilder<NodeData, EdgeData> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<NodeData, EdgeData> GraphBase for ConcurrentGraphBuilder<NodeData, EdgeData> {
+    type NodeData = NodeData;
+    type EdgeData = EdgeData;
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<usize>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<usize>;
+    type NodeIndex = crate::index::NodeIndex<usize>;
+    type EdgeIndex = crate::index::EdgeIndex<usize>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConcurrentGraphBuilder;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::index::GraphIndex;
+    use crate::interface::{ImmutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_parallel_construction() {
+        let builder = ConcurrentGraphBuilder::with_shard_count(3);
+        let edges = std::sync::Mutex::new(Vec::new());
+        std::thread::scope(|scope| {
+            for thread in 0..8 {
+                let (builder, edges) = (&builder, &edges);
+                scope.spawn(move || {
+                    let nodes: Vec<_> =
+                        (0..10).map(|i| builder.add_node(thread * 10 + i)).collect();
+                    for window in nodes.windows(2) {
+                        let edge_id = builder.add_edge(window[0], window[1], thread);
+                        edges.lock().unwrap().push((edge_id, window[0], window[1]));
+                    }
+                });
+            }
+        });
+        debug_assert_eq!(builder.node_count(), 80);
+        debug_assert_eq!(builder.edge_count(), 72);
+
+        let graph: PetGraph<usize, usize> = builder.freeze();
+        for (edge_id, from, to) in edges.into_inner().unwrap() {
+            let endpoints = graph.edge_endpoints(edge_id);
+            debug_assert_eq!((endpoints.from_node, endpoints.to_node), (from, to));
+            debug_assert_eq!(
+                *graph.node_data(from) / 10,
+                *graph.node_data(to) / 10,
+                "edges connect nodes of the same thread"
+            );
+            debug_assert_eq!(*graph.edge_data(edge_id), *graph.node_data(from) / 10);
+        }
+        for node in graph.node_indices() {
+            debug_assert!(graph.out_degree(node) <= 1);
+            debug_assert_eq!(
+                graph.out_degree(node) + graph.in_degree(node),
+                if *graph.node_data(node) % 10 == 0 || *graph.node_data(node) % 10 == 9 {
+                    1
+                } else {
+                    2
+                }
+            );
+            debug_assert!(node.as_usize() < 80);
+        }
+    }
+
+    #[test]
+    fn test_into_csr_graph() {
+        let builder = ConcurrentGraphBuilder::new();
+        let a = builder.add_node('a');
+        let b = builder.add_node('b');
+        builder.add_edge(b, a, 0);
+        builder.add_edge(a, b, 1);
+
+        let graph = builder.into_csr_graph();
+        debug_assert_eq!(*graph.node_data(b), 'b');
+        debug_assert_eq!(*graph.edge_data(0.into()), 1);
+        debug_assert!(graph.contains_edge_between(b, a));
+    }
+}
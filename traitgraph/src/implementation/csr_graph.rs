@@ -0,0 +1,467 @@
+use crate::index::{GraphIndex, GraphIndices};
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{Edge, GraphBase, ImmutableGraphContainer, NavigableGraph, Neighbor};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A static graph that stores its adjacency in compressed sparse row (CSR) layout.
+///
+/// The outgoing edges of each node are stored consecutively, and the edge indices are assigned in this order.
+/// This makes iterating over the outgoing neighbors of a node a linear scan over contiguous memory.
+/// Additionally, the incoming edges of each node are stored consecutively in a second array.
+///
+/// The graph cannot be modified after construction.
+/// When constructing it from another graph, node indices are preserved, while edge indices are reassigned.
+#[derive(Debug, Clone)]
+pub struct CsrGraph<NodeData, EdgeData> {
+    node_data: Vec<NodeData>,
+    /// The outgoing edges of node `i` are `out_offsets[i]..out_offsets[i + 1]`.
+    out_offsets: Vec<usize>,
+    edge_sources: Vec<usize>,
+    edge_targets: Vec<usize>,
+    edge_data: Vec<EdgeData>,
+    /// The incoming edges of node `i` are `in_edges[in_offsets[i]..in_offsets[i + 1]]`.
+    in_offsets: Vec<usize>,
+    in_edges: Vec<usize>,
+}
+
+impl<NodeData, EdgeData> CsrGraph<NodeData, EdgeData> {
+    /// Creates a graph with the given nodes and edges.
+    ///
+    /// The nodes get the indices of their position in `node_data`.
+    /// The edges are given as triples of tail, head and data.
+    /// They get indices ordered by their tail, where edges with the same tail keep their relative order.
+    pub fn from_edges(
+        node_data: Vec<NodeData>,
+        edges: impl IntoIterator<Item = (usize, usize, EdgeData)>,
+    ) -> Self {
+        let node_count = node_data.len();
+        let mut edges: Vec<_> = edges.into_iter().collect();
+        // Stable, so edges with the same tail keep their relative order.
+        edges.sort_by_key(|(from, _, _)| *from);
+
+        let mut out_offsets = vec![0; node_count + 1];
+        let mut in_offsets = vec![0; node_count + 1];
+        let mut edge_sources = Vec::with_capacity(edges.len());
+        let mut edge_targets = Vec::with_capacity(edges.len());
+        let mut edge_data = Vec::with_capacity(edges.len());
+        for (from, to, data) in edges {
+            debug_assert!(from < node_count && to < node_count);
+            out_offsets[from + 1] += 1;
+            in_offsets[to + 1] += 1;
+            edge_sources.push(from);
+            edge_targets.push(to);
+            edge_data.push(data);
+        }
+        for node in 0..node_count {
+            out_offsets[node + 1] += out_offsets[node];
+            in_offsets[node + 1] += in_offsets[node];
+        }
+
+        let mut in_edges = vec![0; edge_targets.len()];
+        let mut in_positions = in_offsets.clone();
+        for (edge, &to) in edge_targets.iter().enumerate() {
+            in_edges[in_positions[to]] = edge;
+            in_positions[to] += 1;
+        }
+
+        Self {
+            node_data,
+            out_offsets,
+            edge_sources,
+            edge_targets,
+            edge_data,
+            in_offsets,
+            in_edges,
+        }
+    }
+
+    /// Creates a copy of the given graph.
+    ///
+    /// The node indices of the given graph must be consecutive, and are preserved.
+    /// The edge indices are reassigned ordered by their tail, where edges with the same tail keep the order of their original indices.
+    ///
+    /// Panics if the node indices of the given graph are not consecutive.
+    /// Graphs that leave removed indices vacant can be made consecutive with [compact](crate::conversion::compact) first.
+    pub fn from_graph<Graph: ImmutableGraphContainer<NodeData = NodeData, EdgeData = EdgeData>>(
+        graph: &Graph,
+    ) -> Self
+    where
+        NodeData: Clone,
+        EdgeData: Clone,
+    {
+        let node_data = graph
+            .node_indices()
+            .enumerate()
+            .map(|(expected_node_id, node_id)| {
+                assert_eq!(
+                    expected_node_id,
+                    node_id.as_usize(),
+                    "the node indices of the graph are not consecutive"
+                );
+                graph.node_data(node_id).clone()
+            })
+            .collect();
+        let edges = graph.edge_indices().map(|edge_id| {
+            let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+            (
+                from_node.as_usize(),
+                to_node.as_usize(),
+                graph.edge_data(edge_id).clone(),
+            )
+        });
+        Self::from_edges(node_data, edges)
+    }
+
+    pub(crate) fn out_edge_range(&self, node_id: usize) -> Range<usize> {
+        self.out_offsets[node_id]..self.out_offsets[node_id + 1]
+    }
+
+    pub(crate) fn edge_targets(&self) -> &[usize] {
+        &self.edge_targets
+    }
+}
+
+impl<NodeData, EdgeData> Default for CsrGraph<NodeData, EdgeData> {
+    fn default() -> Self {
+        Self::from_edges(Vec::new(), [])
+    }
+}
+
+impl<NodeData, EdgeData> GraphBase for CsrGraph<NodeData, EdgeData> {
+    type NodeData = NodeData;
+    type EdgeData = EdgeData;
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<usize>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<usize>;
+    type NodeIndex = crate::index::NodeIndex<usize>;
+    type EdgeIndex = crate::index::EdgeIndex<usize>;
+}
+
+impl<NodeData, EdgeData> ImmutableGraphContainer for CsrGraph<NodeData, EdgeData> {
+    type NodeIndices<'a>
+        = GraphIndices<Self::NodeIndex, Self::OptionalNodeIndex>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = GraphIndices<Self::EdgeIndex, Self::OptionalEdgeIndex>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = CsrEdgeReferences<'a, EdgeData, Self::NodeIndex, Self::EdgeIndex>
+    where
+        Self: 'a;
+
+    type NodeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::NodeIndex, <Self as GraphBase>::OptionalNodeIndex>;
+    type EdgeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::EdgeIndex, <Self as GraphBase>::OptionalEdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        GraphIndices::from((0, self.node_count()))
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        GraphIndices::from((0, self.edge_count()))
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        GraphIndices::from((0, self.node_count()))
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        GraphIndices::from((0, self.edge_count()))
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        node_id.as_usize() < self.node_count()
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        edge_id.as_usize() < self.edge_count()
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_data.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_data.len()
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        &self.node_data[node_id.as_usize()]
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        &self.edge_data[edge_id.as_usize()]
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        Edge {
+            from_node: self.edge_sources[edge_id.as_usize()].into(),
+            to_node: self.edge_targets[edge_id.as_usize()].into(),
+        }
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        CsrEdgeReferences {
+            edges: self
+                .edge_sources
+                .iter()
+                .zip(&self.edge_targets)
+                .zip(&self.edge_data)
+                .enumerate(),
+            index_types: core::marker::PhantomData,
+        }
+    }
+}
+
+/// An iterator over the edges of a [CsrGraph], as returned by [`edge_references`](ImmutableGraphContainer::edge_references).
+pub struct CsrEdgeReferences<'a, EdgeData, NodeIndex, EdgeIndex> {
+    #[allow(clippy::type_complexity)]
+    edges: core::iter::Enumerate<
+        core::iter::Zip<
+            core::iter::Zip<core::slice::Iter<'a, usize>, core::slice::Iter<'a, usize>>,
+            core::slice::Iter<'a, EdgeData>,
+        >,
+    >,
+    index_types: core::marker::PhantomData<(NodeIndex, EdgeIndex)>,
+}
+
+impl<'a, EdgeData, NodeIndex: From<usize>, EdgeIndex: From<usize>> Iterator
+    for CsrEdgeReferences<'a, EdgeData, NodeIndex, EdgeIndex>
+{
+    type Item = (EdgeIndex, Edge<NodeIndex>, &'a EdgeData);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edges
+            .next()
+            .map(|(edge, ((&from_node, &to_node), data))| {
+                (
+                    edge.into(),
+                    Edge {
+                        from_node: from_node.into(),
+                        to_node: to_node.into(),
+                    },
+                    data,
+                )
+            })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+/// An iterator over the outgoing neighbors of a node in a [CsrGraph].
+pub struct CsrOutNeighbors<'a, NodeIndex, EdgeIndex> {
+    edges: Range<usize>,
+    edge_targets: &'a [usize],
+    index_types: core::marker::PhantomData<(NodeIndex, EdgeIndex)>,
+}
+
+impl<NodeIndex: From<usize>, EdgeIndex: From<usize>> Iterator
+    for CsrOutNeighbors<'_, NodeIndex, EdgeIndex>
+{
+    type Item = Neighbor<NodeIndex, EdgeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edges.next().map(|edge| Neighbor {
+            edge_id: edge.into(),
+            node_id: self.edge_targets[edge].into(),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+/// An iterator over the incoming neighbors of a node in a [CsrGraph].
+pub struct CsrInNeighbors<'a, NodeIndex, EdgeIndex> {
+    edges: core::slice::Iter<'a, usize>,
+    edge_sources: &'a [usize],
+    index_types: core::marker::PhantomData<(NodeIndex, EdgeIndex)>,
+}
+
+impl<NodeIndex: From<usize>, EdgeIndex: From<usize>> Iterator
+    for CsrInNeighbors<'_, NodeIndex, EdgeIndex>
+{
+    type Item = Neighbor<NodeIndex, EdgeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edges.next().map(|&edge| Neighbor {
+            edge_id: edge.into(),
+            node_id: self.edge_sources[edge].into(),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+/// An iterator over the edges between two nodes in a [CsrGraph].
+pub struct CsrEdgesBetween<'a, EdgeIndex> {
+    edges: Range<usize>,
+    edge_targets: &'a [usize],
+    to_node: usize,
+    edge_index_type: core::marker::PhantomData<EdgeIndex>,
+}
+
+impl<EdgeIndex: From<usize>> Iterator for CsrEdgesBetween<'_, EdgeIndex> {
+    type Item = EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let edge_targets = self.edge_targets;
+        let to_node = self.to_node;
+        self.edges
+            .find(|&edge| edge_targets[edge] == to_node)
+            .map(Into::into)
+    }
+}
+
+impl<NodeData, EdgeData> NavigableGraph for CsrGraph<NodeData, EdgeData> {
+    type OutNeighbors<'a>
+        = CsrOutNeighbors<'a, <Self as GraphBase>::NodeIndex, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = CsrInNeighbors<'a, <Self as GraphBase>::NodeIndex, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = CsrEdgesBetween<'a, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        debug_assert!(self.contains_node_index(node_id));
+        CsrOutNeighbors {
+            edges: self.out_edge_range(node_id.as_usize()),
+            edge_targets: &self.edge_targets,
+            index_types: Default::default(),
+        }
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        debug_assert!(self.contains_node_index(node_id));
+        let node_id = node_id.as_usize();
+        CsrInNeighbors {
+            edges: self.in_edges[self.in_offsets[node_id]..self.in_offsets[node_id + 1]].iter(),
+            edge_sources: &self.edge_sources,
+            index_types: Default::default(),
+        }
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        debug_assert!(self.contains_node_index(from_node_id));
+        CsrEdgesBetween {
+            edges: self.out_edge_range(from_node_id.as_usize()),
+            edge_targets: &self.edge_targets,
+            to_node: to_node_id.as_usize(),
+            edge_index_type: Default::default(),
+        }
+    }
+
+    fn out_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.out_edge_range(node_id.as_usize()).len()
+    }
+
+    fn in_degree(&self, node_id: Self::NodeIndex) -> usize {
+        let node_id = node_id.as_usize();
+        self.in_offsets[node_id + 1] - self.in_offsets[node_id]
+    }
+}
+
+impl<NodeData, EdgeData> SubgraphBase for CsrGraph<NodeData, EdgeData> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::CsrGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::stable_graph::StableGraph;
+    use crate::interface::{
+        Edge, EdgeReferences, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph,
+    };
+
+    #[test]
+    fn test_from_graph() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[2], n[0], 'a');
+        graph.add_edge(n[0], n[1], 'b');
+        graph.add_edge(n[0], n[2], 'c');
+        graph.add_edge(n[2], n[0], 'd');
+        graph.add_edge(n[3], n[3], 'e');
+
+        let csr = CsrGraph::from_graph(&graph);
+        debug_assert_eq!(csr.node_count(), 4);
+        debug_assert_eq!(csr.edge_count(), 5);
+        debug_assert_eq!(*csr.node_data(n[2]), 2);
+        let edge_data: Vec<_> = csr
+            .edge_indices()
+            .map(|edge_id| *csr.edge_data(edge_id))
+            .collect();
+        debug_assert_eq!(edge_data, ['b', 'c', 'a', 'd', 'e']);
+        debug_assert_eq!(
+            csr.edge_endpoints(2.into()),
+            Edge {
+                from_node: n[2],
+                to_node: n[0]
+            }
+        );
+
+        let out_neighbors: Vec<_> = csr
+            .out_neighbors(n[0])
+            .map(|neighbor| (neighbor.edge_id, neighbor.node_id))
+            .collect();
+        debug_assert_eq!(out_neighbors, [(0.into(), n[1]), (1.into(), n[2])]);
+        let in_neighbors: Vec<_> = csr
+            .in_neighbors(n[0])
+            .map(|neighbor| (neighbor.edge_id, neighbor.node_id))
+            .collect();
+        debug_assert_eq!(in_neighbors, [(2.into(), n[2]), (3.into(), n[2])]);
+        debug_assert_eq!(
+            csr.edges_between(n[2], n[0]).collect::<Vec<_>>(),
+            [2.into(), 3.into()]
+        );
+        debug_assert_eq!(csr.edges_between(n[1], n[0]).count(), 0);
+        debug_assert_eq!(csr.out_degree(n[3]), 1);
+        debug_assert_eq!(csr.in_degree(n[3]), 1);
+        debug_assert_eq!(csr.in_degree(n[1]), 1);
+        debug_assert_eq!(csr.out_degree(n[1]), 0);
+        debug_assert_eq!(
+            csr.edge_references().collect::<Vec<_>>(),
+            EdgeReferences::new(&csr).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the node indices of the graph are not consecutive")]
+    fn test_from_graph_with_vacant_indices() {
+        let mut graph = StableGraph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[2], ());
+        graph.remove_node(n[1]);
+        CsrGraph::from_graph(&graph);
+    }
+
+    #[test]
+    fn test_empty() {
+        let csr = CsrGraph::<(), ()>::default();
+        debug_assert!(csr.is_empty());
+        debug_assert_eq!(csr.edge_count(), 0);
+    }
+}
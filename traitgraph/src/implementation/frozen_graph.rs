@@ -0,0 +1,345 @@
+use crate::implementation::csr_graph::{CsrGraph, CsrInNeighbors, CsrOutNeighbors};
+use crate::index::{GraphIndex, GraphIndices};
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// An immutable graph whose adjacency lists are sorted by the opposite endpoint.
+///
+/// The graph is stored like a [CsrGraph], but the outgoing edges of each node are additionally sorted by their head.
+/// Hence, [edges_between](NavigableGraph::edges_between), [contains_edge_between](NavigableGraph::contains_edge_between)
+/// and [edge_count_between](NavigableGraph::edge_count_between) are binary searches that run in `O(log d)` time,
+/// where `d` is the out-degree of the tail.
+/// The incoming edges of each node are sorted by their tail.
+///
+/// Node indices are preserved when freezing a graph, while edge indices are reassigned ordered by tail and head.
+/// Parallel edges keep the order of their original indices.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::implementation::frozen_graph::Freeze;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{MutableGraphContainer, NavigableGraph};
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.add_edge(a, c, ());
+/// graph.add_edge(a, b, ());
+///
+/// let frozen = graph.freeze();
+/// assert!(frozen.contains_edge_between(a, b));
+/// assert!(!frozen.contains_edge_between(b, c));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrozenGraph<NodeData, EdgeData> {
+    graph: CsrGraph<NodeData, EdgeData>,
+}
+
+impl<NodeData, EdgeData> FrozenGraph<NodeData, EdgeData> {
+    /// Creates a graph with the given nodes and edges.
+    ///
+    /// The nodes get the indices of their position in `node_data`.
+    /// The edges are given as triples of tail, head and data.
+    /// They get indices ordered by their tail and head, where parallel edges keep their relative order.
+    pub fn from_edges(
+        node_data: Vec<NodeData>,
+        edges: impl IntoIterator<Item = (usize, usize, EdgeData)>,
+    ) -> Self {
+        let mut edges: Vec<_> = edges.into_iter().collect();
+        // Stable, so parallel edges keep their relative order.
+        edges.sort_by_key(|(from, to, _)| (*from, *to));
+        Self {
+            graph: CsrGraph::from_edges(node_data, edges),
+        }
+    }
+
+    /// Creates a frozen copy of the given graph.
+    ///
+    /// The node indices of the given graph must be consecutive, and are preserved.
+    /// The edge indices are reassigned ordered by their tail and head, where parallel edges keep the order of their original indices.
+    ///
+    /// Panics if the node indices of the given graph are not consecutive.
+    /// Graphs that leave removed indices vacant can be made consecutive with [compact](crate::conversion::compact) first.
+    pub fn from_graph<Graph: ImmutableGraphContainer<NodeData = NodeData, EdgeData = EdgeData>>(
+        graph: &Graph,
+    ) -> Self
+    where
+        NodeData: Clone,
+        EdgeData: Clone,
+    {
+        let node_data = graph
+            .node_indices()
+            .enumerate()
+            .map(|(expected_node_id, node_id)| {
+                assert_eq!(
+                    expected_node_id,
+                    node_id.as_usize(),
+                    "the node indices of the graph are not consecutive"
+                );
+                graph.node_data(node_id).clone()
+            })
+            .collect();
+        let edges = graph.edge_indices().map(|edge_id| {
+            let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+            (
+                from_node.as_usize(),
+                to_node.as_usize(),
+                graph.edge_data(edge_id).clone(),
+            )
+        });
+        Self::from_edges(node_data, edges)
+    }
+
+    /// Returns the underlying [CsrGraph].
+    pub fn as_csr_graph(&self) -> &CsrGraph<NodeData, EdgeData> {
+        &self.graph
+    }
+
+    /// Returns the underlying [CsrGraph].
+    pub fn into_csr_graph(self) -> CsrGraph<NodeData, EdgeData> {
+        self.graph
+    }
+
+    /// Returns the range of indices of the edges from `from_node` to `to_node`.
+    fn edge_range_between(&self, from_node: usize, to_node: usize) -> Range<usize> {
+        let edges = self.graph.out_edge_range(from_node);
+        let targets = &self.graph.edge_targets()[edges.clone()];
+        let start = targets.partition_point(|&target| target < to_node);
+        let end = start + targets[start..].partition_point(|&target| target == to_node);
+        edges.start + start..edges.start + end
+    }
+}
+
+/// Conversion of a graph into a [FrozenGraph].
+pub trait Freeze: ImmutableGraphContainer + Sized {
+    /// Creates a frozen copy of this graph, see [FrozenGraph::from_graph].
+    fn freeze(&self) -> FrozenGraph<Self::NodeData, Self::EdgeData>
+    where
+        Self::NodeData: Clone,
+        Self::EdgeData: Clone,
+    {
+        FrozenGraph::from_graph(self)
+    }
+}
+
+impl<Graph: ImmutableGraphContainer> Freeze for Graph {}
+
+impl<NodeData, EdgeData> Default for FrozenGraph<NodeData, EdgeData> {
+    fn default() -> Self {
+        Self {
+            graph: Default::default(),
+        }
+    }
+}
+
+impl<NodeData, EdgeData> GraphBase for FrozenGraph<NodeData, EdgeData> {
+    type NodeData = NodeData;
+    type EdgeData = EdgeData;
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<usize>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<usize>;
+    type NodeIndex = crate::index::NodeIndex<usize>;
+    type EdgeIndex = crate::index::EdgeIndex<usize>;
+}
+
+impl<NodeData, EdgeData> ImmutableGraphContainer for FrozenGraph<NodeData, EdgeData> {
+    type NodeIndices<'a>
+        = GraphIndices<Self::NodeIndex, Self::OptionalNodeIndex>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = GraphIndices<Self::EdgeIndex, Self::OptionalEdgeIndex>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+
+    type NodeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::NodeIndex, <Self as GraphBase>::OptionalNodeIndex>;
+    type EdgeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::EdgeIndex, <Self as GraphBase>::OptionalEdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        self.graph.node_indices()
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        self.graph.edge_indices()
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        self.graph.node_indices_copied()
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        self.graph.edge_indices_copied()
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        self.graph.contains_node_index(node_id)
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        self.graph.contains_edge_index(edge_id)
+    }
+
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        self.graph.node_data(node_id)
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        self.graph.edge_data(edge_id)
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        self.graph.edge_endpoints(edge_id)
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<NodeData, EdgeData> NavigableGraph for FrozenGraph<NodeData, EdgeData> {
+    type OutNeighbors<'a>
+        = CsrOutNeighbors<'a, <Self as GraphBase>::NodeIndex, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = CsrInNeighbors<'a, <Self as GraphBase>::NodeIndex, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = GraphIndices<<Self as GraphBase>::EdgeIndex, <Self as GraphBase>::OptionalEdgeIndex>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        self.graph.out_neighbors(node_id)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        self.graph.in_neighbors(node_id)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        debug_assert!(self.contains_node_index(from_node_id));
+        let edges = self.edge_range_between(from_node_id.as_usize(), to_node_id.as_usize());
+        GraphIndices::from((edges.start, edges.end))
+    }
+
+    fn contains_edge_between(&self, from: Self::NodeIndex, to: Self::NodeIndex) -> bool {
+        !self
+            .edge_range_between(from.as_usize(), to.as_usize())
+            .is_empty()
+    }
+
+    fn edge_count_between(&self, from: Self::NodeIndex, to: Self::NodeIndex) -> usize {
+        self.edge_range_between(from.as_usize(), to.as_usize())
+            .len()
+    }
+
+    fn out_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.graph.out_degree(node_id)
+    }
+
+    fn in_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.graph.in_degree(node_id)
+    }
+}
+
+impl<NodeData, EdgeData> SubgraphBase for FrozenGraph<NodeData, EdgeData> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::Freeze;
+    use crate::implementation::frozen_graph::FrozenGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::stable_graph::StableGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_sorted_adjacency() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[3], 'a');
+        graph.add_edge(n[0], n[1], 'b');
+        graph.add_edge(n[2], n[0], 'c');
+        graph.add_edge(n[0], n[3], 'd');
+        graph.add_edge(n[1], n[0], 'e');
+        graph.add_edge(n[0], n[2], 'f');
+
+        let frozen = graph.freeze();
+        debug_assert_eq!(frozen.node_count(), 4);
+        debug_assert_eq!(frozen.edge_count(), 6);
+        let edge_data: Vec<_> = frozen
+            .edge_indices()
+            .map(|edge_id| *frozen.edge_data(edge_id))
+            .collect();
+        debug_assert_eq!(edge_data, ['b', 'f', 'a', 'd', 'e', 'c']);
+        debug_assert_eq!(
+            frozen
+                .in_neighbors(n[0])
+                .map(|neighbor| neighbor.node_id)
+                .collect::<Vec<_>>(),
+            [n[1], n[2]]
+        );
+
+        for from in &n {
+            for to in &n {
+                debug_assert_eq!(
+                    frozen.edges_between(*from, *to).count(),
+                    graph.edges_between(*from, *to).count()
+                );
+                debug_assert_eq!(
+                    frozen.contains_edge_between(*from, *to),
+                    graph.contains_edge_between(*from, *to)
+                );
+                for edge_id in frozen.edges_between(*from, *to) {
+                    let endpoints = frozen.edge_endpoints(edge_id);
+                    debug_assert_eq!((endpoints.from_node, endpoints.to_node), (*from, *to));
+                }
+            }
+        }
+        debug_assert_eq!(frozen.edge_count_between(n[0], n[3]), 2);
+        debug_assert_eq!(
+            frozen.edges_between(n[0], n[3]).collect::<Vec<_>>(),
+            [2.into(), 3.into()]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the node indices of the graph are not consecutive")]
+    fn test_from_graph_with_vacant_indices() {
+        let mut graph = StableGraph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[2], n[0], ());
+        graph.remove_node(n[1]);
+        FrozenGraph::from_graph(&graph);
+    }
+}
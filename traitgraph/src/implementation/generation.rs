@@ -0,0 +1,114 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The amount of graph generations that were created so far.
+static GRAPH_GENERATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// The [generation](crate::interface::ImmutableGraphContainer::generation) of a graph.
+///
+/// Each new generation starts at a value drawn from a global counter, such that different graphs are unlikely to share a generation.
+/// The upper half of the value identifies the graph, and the lower half counts its mutations.
+/// Hence, generations of different graphs only collide after more than `u32::MAX` mutations, or when more than `u32::MAX` graphs were created.
+///
+/// Cloning a generation keeps its value, since the clone of a graph has the same indices as the graph.
+/// A default generation is a new generation, such that graphs that skip their generation when deserialising still get a unique one.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct GraphGeneration(u64);
+
+impl GraphGeneration {
+    /// Creates a new generation that differs from the generations of all other graphs.
+    pub(crate) fn new() -> Self {
+        let graph_id = GRAPH_GENERATION_COUNT.fetch_add(1, Ordering::Relaxed) as u64;
+        Self(graph_id << 32)
+    }
+
+    /// Advances the generation after nodes or edges were added or removed.
+    pub(crate) fn advance(&mut self) {
+        self.0 = self.0.wrapping_add(1);
+    }
+
+    /// Returns the value of the generation.
+    pub(crate) fn get(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for GraphGeneration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphGeneration;
+    use crate::implementation::adjacency_matrix_graph::AdjacencyMatrixGraph;
+    use crate::implementation::slab_graph::SlabGraph;
+    use crate::implementation::stable_graph::StableGraph;
+    use crate::implementation::vec_graph::VecGraph;
+    use crate::interface::MutableGraphContainer;
+    use alloc::vec::Vec;
+
+    /// Checks that the generation of a graph changes on every structural mutation, and differs from the one of a new graph.
+    fn check_generation<Graph: MutableGraphContainer<NodeData = u8, EdgeData = u8> + Default>() {
+        let mut graph = Graph::default();
+        let mut generations = Vec::new();
+        generations.push(Graph::default().generation());
+        generations.push(graph.generation());
+        let n0 = graph.add_node(0);
+        generations.push(graph.generation());
+        let n1 = graph.add_node(1);
+        generations.push(graph.generation());
+        let edge = graph.add_edge(n0, n1, 2);
+        generations.push(graph.generation());
+
+        // Mutating data does not change the structure of the graph.
+        *graph.node_data_mut(n0) = 3;
+        *graph.edge_data_mut(edge) = 4;
+        debug_assert_eq!(graph.generation(), generations[4]);
+
+        graph.remove_edge(edge);
+        generations.push(graph.generation());
+        graph.remove_node(n1);
+        generations.push(graph.generation());
+        graph.clear();
+        generations.push(graph.generation());
+
+        debug_assert!(generations.iter().all(Option::is_some));
+        for (index, generation) in generations.iter().enumerate() {
+            debug_assert!(!generations[index + 1..].contains(generation));
+        }
+    }
+
+    #[test]
+    fn test_backend_generations() {
+        check_generation::<AdjacencyMatrixGraph<_, _>>();
+        check_generation::<SlabGraph<_, _>>();
+        check_generation::<StableGraph<_, _>>();
+        check_generation::<VecGraph<_, _>>();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_std_backend_generations() {
+        use crate::implementation::hash_graph::HashGraph;
+        use crate::implementation::petgraph_impl::PetGraph;
+        use crate::implementation::stable_petgraph_impl::StablePetGraph;
+
+        check_generation::<HashGraph<_, _>>();
+        check_generation::<PetGraph<_, _>>();
+        check_generation::<StablePetGraph<_, _>>();
+    }
+
+    #[test]
+    fn test_unique_generations() {
+        let mut first = GraphGeneration::new();
+        let second = GraphGeneration::default();
+        debug_assert_ne!(first, second);
+
+        let initial = first;
+        first.advance();
+        debug_assert_ne!(first, initial);
+        debug_assert_ne!(first, second);
+        debug_assert_eq!(first.get(), initial.get() + 1);
+    }
+}
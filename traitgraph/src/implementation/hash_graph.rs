@@ -0,0 +1,240 @@
+use crate::implementation::stable_graph::StableGraph;
+use crate::index::GraphIndex;
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{GraphBase, ImmutableGraphContainer, MutableGraphContainer};
+use std::collections::hash_map::{self, HashMap};
+
+/// A graph whose nodes are identified by arbitrary, sparse external identifiers.
+///
+/// Nodes are inserted with their external identifier via [`insert_node`](HashGraph::insert_node),
+/// and can be looked up by it via [`node_index`](HashGraph::node_index).
+/// Internally, each node gets a dense node index in the order the nodes were inserted,
+/// such that algorithms that allocate arrays indexed by node indices only allocate memory proportional to the amount of inserted nodes.
+/// Nodes added via [`add_node`](MutableGraphContainer::add_node) get the external identifier after the highest one used so far,
+/// and edges get consecutive indices in the order they were added.
+///
+/// The nodes and edges are stored in a [StableGraph], and only the external identifiers are kept in a hash map.
+/// Hence, removals never change the indices of other nodes or edges, indices of removed nodes and edges are not reused,
+/// and node and edge indices are iterated in ascending order.
+#[derive(Debug, Clone)]
+pub struct HashGraph<NodeData, EdgeData> {
+    graph: StableGraph<NodeData, EdgeData>,
+    /// Maps external identifiers to node indices.
+    node_indices: HashMap<usize, usize>,
+    /// Maps node indices to external identifiers, including those of removed nodes.
+    external_ids: Vec<usize>,
+    /// The external identifier of the next node added via `add_node`, or `None` if `usize::MAX` was used already.
+    next_external_id: Option<usize>,
+}
+
+impl<NodeData, EdgeData> HashGraph<NodeData, EdgeData> {
+    /// Creates a new empty graph.
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    /// Creates a new empty graph with space for the given amount of nodes and edges.
+    pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> Self {
+        Self {
+            graph: StableGraph::with_capacity(node_capacity, edge_capacity),
+            node_indices: HashMap::with_capacity(node_capacity),
+            external_ids: Vec::with_capacity(node_capacity),
+            next_external_id: Some(0),
+        }
+    }
+
+    /// Inserts a node with the given external identifier, and returns its node index.
+    ///
+    /// If a node with this identifier exists already, then its data is replaced and returned, while its index and edges are kept.
+    pub fn insert_node(
+        &mut self,
+        external_id: usize,
+        node_data: NodeData,
+    ) -> (<Self as GraphBase>::NodeIndex, Option<NodeData>) {
+        match self.node_indices.entry(external_id) {
+            hash_map::Entry::Occupied(entry) => {
+                let node_id = (*entry.get()).into();
+                let node_data = std::mem::replace(self.graph.node_data_mut(node_id), node_data);
+                (node_id, Some(node_data))
+            }
+            hash_map::Entry::Vacant(entry) => {
+                let node_id = self.graph.add_node(node_data);
+                debug_assert_eq!(node_id.as_usize(), self.external_ids.len());
+                entry.insert(node_id.as_usize());
+                self.external_ids.push(external_id);
+                // Once `usize::MAX` was used, there is no identifier after the highest one anymore.
+                self.next_external_id = match (self.next_external_id, external_id.checked_add(1)) {
+                    (Some(next_external_id), Some(after_external_id)) => {
+                        Some(next_external_id.max(after_external_id))
+                    }
+                    _ => None,
+                };
+                (node_id, None)
+            }
+        }
+    }
+
+    /// Returns the node index of the node with the given external identifier, or `None` if there is no such node.
+    pub fn node_index(&self, external_id: usize) -> Option<<Self as GraphBase>::NodeIndex> {
+        self.node_indices
+            .get(&external_id)
+            .map(|&node_id| node_id.into())
+    }
+
+    /// Returns the external identifier of the given node.
+    pub fn external_id(&self, node_id: <Self as GraphBase>::NodeIndex) -> usize {
+        assert!(
+            self.contains_node_index(node_id),
+            "node {node_id:?} does not exist"
+        );
+        self.external_ids[node_id.as_usize()]
+    }
+}
+
+impl<NodeData, EdgeData> Default for HashGraph<NodeData, EdgeData> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+delegate_graph!(impl[NodeData, EdgeData] HashGraph<NodeData, EdgeData> => graph: StableGraph<NodeData, EdgeData>);
+
+impl<NodeData, EdgeData> MutableGraphContainer for HashGraph<NodeData, EdgeData> {
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        self.graph.node_data_mut(node_id)
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        self.graph.edge_data_mut(edge_id)
+    }
+
+    /// Adds a node with the external identifier after the highest one used so far.
+    ///
+    /// Panics if a node with external identifier `usize::MAX` was inserted before.
+    fn add_node(&mut self, node_data: Self::NodeData) -> Self::NodeIndex {
+        let external_id = self
+            .next_external_id
+            .expect("the external identifier usize::MAX was used already");
+        self.insert_node(external_id, node_data).0
+    }
+
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: Self::EdgeData,
+    ) -> Self::EdgeIndex {
+        self.graph.add_edge(from, to, edge_data)
+    }
+
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.graph.reserve_nodes(additional);
+        self.node_indices.reserve(additional);
+        self.external_ids.reserve(additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.graph.reserve_edges(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.graph.shrink_to_fit();
+        self.node_indices.shrink_to_fit();
+        self.external_ids.shrink_to_fit();
+    }
+
+    /// Removes the node with the given id from the graph, together with all its incident edges.
+    /// The ids of all other nodes and edges stay the same.
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData> {
+        let node_data = self.graph.remove_node(node_id)?;
+        self.node_indices
+            .remove(&self.external_ids[node_id.as_usize()]);
+        Some(node_data)
+    }
+
+    /// Removes the edge with the given id from the graph.
+    /// The ids of all other edges stay the same.
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<Self::EdgeData> {
+        self.graph.remove_edge(edge_id)
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        self.graph.remove_edges_sorted(edge_ids);
+    }
+
+    fn clear(&mut self) {
+        self.graph.clear();
+        self.node_indices.clear();
+        self.external_ids.clear();
+        self.next_external_id = Some(0);
+    }
+}
+
+impl<NodeData, EdgeData> SubgraphBase for HashGraph<NodeData, EdgeData> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HashGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_sparse_indices() {
+        let mut graph = HashGraph::new();
+        let (a, data) = graph.insert_node(1_000_000_000_000, 'a');
+        debug_assert_eq!(data, None);
+        let (b, data) = graph.insert_node(17, 'b');
+        debug_assert_eq!(data, None);
+        let c = graph.add_node('c');
+        debug_assert_eq!(graph.external_id(c), 1_000_000_000_001);
+        debug_assert_eq!(graph.node_index(17), Some(b));
+        debug_assert_eq!(graph.node_index(18), None);
+        debug_assert_eq!(graph.node_index_bound(), 3);
+
+        let ab = graph.add_edge(a, b, 0);
+        let bc = graph.add_edge(b, c, 1);
+        let cc = graph.add_edge(c, c, 2);
+        debug_assert_eq!(graph.node_count(), 3);
+        debug_assert_eq!(graph.edge_count(), 3);
+        debug_assert_eq!(graph.insert_node(17, 'B'), (b, Some('b')));
+        debug_assert_eq!(*graph.node_data(b), 'B');
+        debug_assert_eq!(graph.out_degree(b), 1);
+
+        // Indices are iterated in insertion order, independently of the external identifiers.
+        debug_assert_eq!(graph.node_indices().collect::<Vec<_>>(), [a, b, c]);
+        debug_assert_eq!(graph.edge_indices().collect::<Vec<_>>(), [ab, bc, cc]);
+        debug_assert_eq!(graph.edges_between(a, b).collect::<Vec<_>>(), [ab]);
+        debug_assert_eq!(
+            graph.in_neighbors(c).map(|n| n.edge_id).collect::<Vec<_>>(),
+            [bc, cc]
+        );
+
+        debug_assert_eq!(graph.remove_node(c), Some('c'));
+        debug_assert!(!graph.contains_edge_index(bc));
+        debug_assert!(!graph.contains_edge_index(cc));
+        debug_assert!(graph.contains_edge_index(ab));
+        debug_assert_eq!(graph.out_degree(b), 0);
+        debug_assert_eq!(graph.edge_count(), 1);
+        debug_assert_eq!(graph.node_index(1_000_000_000_001), None);
+
+        // Removed indices are not reused.
+        let (c, _) = graph.insert_node(1_000_000_000_001, 'C');
+        debug_assert_eq!(c, 3.into());
+        debug_assert_eq!(graph.node_index_bound(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_maximum_external_id() {
+        let mut graph = HashGraph::<(), ()>::new();
+        let (node, _) = graph.insert_node(usize::MAX, ());
+        debug_assert_eq!(graph.external_id(node), usize::MAX);
+        debug_assert_eq!(graph.node_index_bound(), 1);
+        graph.add_node(());
+    }
+}
@@ -0,0 +1,240 @@
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{GraphBase, ImmutableGraphContainer, MutableGraphContainer};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A wrapper around a graph that maintains an index from node data to node indices.
+///
+/// The index is kept consistent through all operations of [MutableGraphContainer].
+/// It assumes that the wrapped graph removes nodes by moving the node with the highest index into the place of the removed node,
+/// as done e.g. by [PetGraph](crate::implementation::petgraph_impl::PetGraph),
+/// or that it does not change the indices of the remaining nodes at all.
+///
+/// Node data is expected to be unique.
+/// If multiple nodes have the same data, then the index points to the most recently added one.
+#[derive(Debug, Clone)]
+pub struct LabeledGraph<Graph: GraphBase> {
+    graph: Graph,
+    index: HashMap<Graph::NodeData, Graph::NodeIndex>,
+    /// Nodes whose data was borrowed mutably and that are not yet reflected in the index.
+    modified_nodes: Vec<Graph::NodeIndex>,
+}
+
+impl<Graph: ImmutableGraphContainer> LabeledGraph<Graph>
+where
+    Graph::NodeData: Hash + Eq + Clone,
+{
+    /// Wraps the given graph, indexing all of its nodes.
+    pub fn new(graph: Graph) -> Self {
+        let index = graph
+            .node_indices()
+            .map(|node_id| (graph.node_data(node_id).clone(), node_id))
+            .collect();
+        Self {
+            graph,
+            index,
+            modified_nodes: Vec::new(),
+        }
+    }
+
+    /// Returns the index of the node with the given data, or `None` if there is no such node.
+    pub fn node_by_data<Label: Hash + Eq + ?Sized>(&self, data: &Label) -> Option<Graph::NodeIndex>
+    where
+        Graph::NodeData: Borrow<Label>,
+    {
+        self.modified_nodes
+            .iter()
+            .rev()
+            .copied()
+            .find(|&node_id| self.graph.node_data(node_id).borrow() == data)
+            .or_else(|| self.index.get(data).copied())
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns the wrapped graph, discarding the index.
+    pub fn into_inner(self) -> Graph {
+        self.graph
+    }
+
+    fn update_modified_nodes(&mut self) {
+        for node_id in self.modified_nodes.drain(..) {
+            self.index
+                .insert(self.graph.node_data(node_id).clone(), node_id);
+        }
+    }
+}
+
+impl<Graph: MutableGraphContainer> LabeledGraph<Graph>
+where
+    Graph::NodeData: Hash + Eq + Clone,
+{
+    /// Returns the index of the node with the given data, adding a new node if there is no such node.
+    pub fn get_or_add_node(&mut self, data: Graph::NodeData) -> Graph::NodeIndex {
+        if let Some(node_id) = self.node_by_data(&data) {
+            node_id
+        } else {
+            self.add_node(data)
+        }
+    }
+}
+
+impl<Graph: ImmutableGraphContainer + Default> Default for LabeledGraph<Graph>
+where
+    Graph::NodeData: Hash + Eq + Clone,
+{
+    fn default() -> Self {
+        Self::new(Graph::default())
+    }
+}
+
+impl<Graph: MutableGraphContainer> MutableGraphContainer for LabeledGraph<Graph>
+where
+    Graph::NodeData: Hash + Eq + Clone,
+{
+    /// Returns a mutable reference to the node data associated with the given node id.
+    ///
+    /// The index is updated lazily with the modified data.
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        self.update_modified_nodes();
+        let node_data = self.graph.node_data(node_id);
+        if self.index.get(node_data) == Some(&node_id) {
+            self.index.remove(node_data);
+        }
+        self.modified_nodes.push(node_id);
+        self.graph.node_data_mut(node_id)
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        self.graph.edge_data_mut(edge_id)
+    }
+
+    fn add_node(&mut self, node_data: Self::NodeData) -> Self::NodeIndex {
+        self.update_modified_nodes();
+        let node_id = self.graph.add_node(node_data.clone());
+        self.index.insert(node_data, node_id);
+        node_id
+    }
+
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: Self::EdgeData,
+    ) -> Self::EdgeIndex {
+        self.graph.add_edge(from, to, edge_data)
+    }
+
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.graph.reserve_nodes(additional);
+        self.index.reserve(additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.graph.reserve_edges(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.graph.shrink_to_fit();
+        self.index.shrink_to_fit();
+    }
+
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData> {
+        self.update_modified_nodes();
+        let last_node_id = self.graph.node_indices().last();
+        let node_data = self.graph.remove_node(node_id)?;
+
+        if self.index.get(&node_data) == Some(&node_id) {
+            self.index.remove(&node_data);
+        }
+
+        // The last node may have been moved into the place of the removed node.
+        if let Some(last_node_id) = last_node_id {
+            if last_node_id != node_id && self.graph.contains_node_index(node_id) {
+                let moved_node_data = self.graph.node_data(node_id);
+                if let Some(indexed_node_id) = self.index.get_mut(moved_node_data) {
+                    if *indexed_node_id == last_node_id {
+                        *indexed_node_id = node_id;
+                    }
+                }
+            }
+        }
+
+        Some(node_data)
+    }
+
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<Self::EdgeData> {
+        self.graph.remove_edge(edge_id)
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        self.graph.remove_edges_sorted(edge_ids)
+    }
+
+    fn clear(&mut self) {
+        self.graph.clear();
+        self.index.clear();
+        self.modified_nodes.clear();
+    }
+}
+
+delegate_graph!(impl[Graph: GraphBase] LabeledGraph<Graph> => graph: Graph);
+
+impl<Graph: GraphBase> SubgraphBase for LabeledGraph<Graph> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LabeledGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_node_by_data() {
+        let mut graph = LabeledGraph::<PetGraph<String, ()>>::default();
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        let c = graph.add_node("c".to_string());
+        graph.add_edge(a, b, ());
+        graph.add_edge(b, c, ());
+        debug_assert_eq!(graph.node_by_data("a"), Some(a));
+        debug_assert_eq!(graph.node_by_data("c"), Some(c));
+        debug_assert_eq!(graph.node_by_data("d"), None);
+        debug_assert_eq!(graph.get_or_add_node("b".to_string()), b);
+        debug_assert_eq!(graph.node_count(), 3);
+
+        // Removing a moves c into its place.
+        debug_assert_eq!(graph.remove_node(a), Some("a".to_string()));
+        debug_assert_eq!(graph.node_by_data("a"), None);
+        debug_assert_eq!(graph.node_by_data("b"), Some(b));
+        debug_assert_eq!(graph.node_by_data("c"), Some(a));
+        debug_assert!(graph.contains_edge_between(b, a));
+
+        *graph.node_data_mut(b) = "d".to_string();
+        debug_assert_eq!(graph.node_by_data("b"), None);
+        debug_assert_eq!(graph.node_by_data("d"), Some(b));
+        let e = graph.add_node("e".to_string());
+        debug_assert_eq!(graph.node_by_data("b"), None);
+        debug_assert_eq!(graph.node_by_data("d"), Some(b));
+        debug_assert_eq!(graph.node_by_data("e"), Some(e));
+    }
+
+    #[test]
+    fn test_new_indexes_existing_nodes() {
+        let mut graph = PetGraph::<_, ()>::new();
+        let a = graph.add_node(1);
+        let b = graph.add_node(2);
+        let graph = LabeledGraph::new(graph);
+        debug_assert_eq!(graph.node_by_data(&1), Some(a));
+        debug_assert_eq!(graph.node_by_data(&2), Some(b));
+    }
+}
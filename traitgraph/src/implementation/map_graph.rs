@@ -0,0 +1,245 @@
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use core::marker::PhantomData;
+
+/// A view of a graph that presents its node and edge data through two mapping functions.
+///
+/// The view does not copy the graph, and maps the data lazily whenever it is accessed.
+/// Since [`node_data`](ImmutableGraphContainer::node_data) and [`edge_data`](ImmutableGraphContainer::edge_data) return references,
+/// the mapping functions project a reference to the original data to a reference into it, e.g. to one of its fields.
+/// Indices and adjacency are the same as in the wrapped graph.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::implementation::map_graph::MapGraph;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// struct Road {
+///     name: String,
+///     length: u32,
+/// }
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let road = graph.add_edge(a, b, Road { name: "main".to_string(), length: 5 });
+///
+/// let lengths = MapGraph::new(&graph, |node: &()| node, |road: &Road| &road.length);
+/// assert_eq!(*lengths.edge_data(road), 5);
+/// # }
+/// ```
+pub struct MapGraph<'a, Graph, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData> {
+    graph: &'a Graph,
+    node_map: NodeMap,
+    edge_map: EdgeMap,
+    mapped_data: PhantomData<(MappedNodeData, MappedEdgeData)>,
+}
+
+impl<'a, Graph: GraphBase, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData>
+    MapGraph<'a, Graph, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData>
+where
+    NodeMap: Fn(&Graph::NodeData) -> &MappedNodeData,
+    EdgeMap: Fn(&Graph::EdgeData) -> &MappedEdgeData,
+{
+    /// Creates a view of the given graph that maps its node data with `node_map` and its edge data with `edge_map`.
+    pub fn new(graph: &'a Graph, node_map: NodeMap, edge_map: EdgeMap) -> Self {
+        Self {
+            graph,
+            node_map,
+            edge_map,
+            mapped_data: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &'a Graph {
+        self.graph
+    }
+}
+
+impl<Graph: GraphBase, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData> GraphBase
+    for MapGraph<'_, Graph, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData>
+{
+    type NodeData = MappedNodeData;
+    type EdgeData = MappedEdgeData;
+    type OptionalNodeIndex = Graph::OptionalNodeIndex;
+    type OptionalEdgeIndex = Graph::OptionalEdgeIndex;
+    type NodeIndex = Graph::NodeIndex;
+    type EdgeIndex = Graph::EdgeIndex;
+}
+
+impl<Graph: ImmutableGraphContainer, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData>
+    ImmutableGraphContainer
+    for MapGraph<'_, Graph, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData>
+where
+    NodeMap: Fn(&Graph::NodeData) -> &MappedNodeData,
+    EdgeMap: Fn(&Graph::EdgeData) -> &MappedEdgeData,
+{
+    type NodeIndices<'a>
+        = Graph::NodeIndices<'a>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = Graph::EdgeIndices<'a>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+    type NodeIndicesCopied = Graph::NodeIndicesCopied;
+    type EdgeIndicesCopied = Graph::EdgeIndicesCopied;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        self.graph.node_indices()
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        self.graph.edge_indices()
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        self.graph.node_indices_copied()
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        self.graph.edge_indices_copied()
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        self.graph.contains_node_index(node_id)
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        self.graph.contains_edge_index(edge_id)
+    }
+
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.graph.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.graph.edge_index_bound()
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        (self.node_map)(self.graph.node_data(node_id))
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        (self.edge_map)(self.graph.edge_data(edge_id))
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        self.graph.edge_endpoints(edge_id)
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<Graph: NavigableGraph, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData> NavigableGraph
+    for MapGraph<'_, Graph, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData>
+where
+    NodeMap: Fn(&Graph::NodeData) -> &MappedNodeData,
+    EdgeMap: Fn(&Graph::EdgeData) -> &MappedEdgeData,
+{
+    type OutNeighbors<'a>
+        = Graph::OutNeighbors<'a>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = Graph::InNeighbors<'a>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = Graph::EdgesBetween<'a>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        self.graph.out_neighbors(node_id)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        self.graph.in_neighbors(node_id)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        self.graph.edges_between(from_node_id, to_node_id)
+    }
+
+    fn contains_edge_between(&self, from: Self::NodeIndex, to: Self::NodeIndex) -> bool {
+        self.graph.contains_edge_between(from, to)
+    }
+
+    fn edge_count_between(&self, from: Self::NodeIndex, to: Self::NodeIndex) -> usize {
+        self.graph.edge_count_between(from, to)
+    }
+
+    fn out_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.graph.out_degree(node_id)
+    }
+
+    fn in_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.graph.in_degree(node_id)
+    }
+}
+
+impl<Graph: GraphBase, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData> SubgraphBase
+    for MapGraph<'_, Graph, NodeMap, EdgeMap, MappedNodeData, MappedEdgeData>
+{
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::MapGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_field_projection() {
+        let mut graph = PetGraph::new();
+        let a = graph.add_node(("a", 1));
+        let b = graph.add_node(("b", 2));
+        let e = graph.add_edge(a, b, (3.5, 'x'));
+
+        let mapped = MapGraph::new(
+            &graph,
+            |node: &(&str, i32)| &node.1,
+            |edge: &(f64, char)| &edge.1,
+        );
+        debug_assert_eq!(*mapped.node_data(b), 2);
+        debug_assert_eq!(*mapped.edge_data(e), 'x');
+        debug_assert_eq!(mapped.node_count(), 2);
+        debug_assert!(mapped.contains_edge_between(a, b));
+        debug_assert_eq!(
+            mapped
+                .out_neighbors(a)
+                .map(|neighbor| *mapped.node_data(neighbor.node_id))
+                .collect::<Vec<_>>(),
+            [2]
+        );
+    }
+}
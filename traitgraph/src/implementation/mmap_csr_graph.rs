@@ -0,0 +1,554 @@
+use crate::index::{GraphIndex, GraphIndices};
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{
+    Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph, Neighbor,
+};
+use memmap2::Mmap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::ops::Range;
+use std::path::Path;
+
+/// The first eight bytes of a file written by [MmapCsrGraph::write].
+const MAGIC: &[u8; 8] = b"TGCSR\0\0\x01";
+/// The size of the header in words: magic, node count and edge count.
+const HEADER_WORDS: usize = 3;
+const WORD_SIZE: usize = std::mem::size_of::<u64>();
+
+/// A read-only graph that memory-maps its adjacency from a file in compressed sparse row (CSR) layout.
+///
+/// The operating system loads the parts of the file that are accessed on demand,
+/// so the graph may be larger than the available memory.
+/// The layout is the same as that of [CsrGraph](crate::implementation::csr_graph::CsrGraph):
+/// the outgoing edges of each node have consecutive indices, and the incoming edges of each node are listed in a second array.
+///
+/// The graph stores no node or edge data.
+/// Data can be stored separately in arrays indexed by the node and edge indices, which are consecutive.
+///
+/// # File format
+///
+/// The file consists of little-endian `u64` words:
+/// a magic number, the node count `n`, the edge count `m`,
+/// the out-offsets (`n + 1` words), the edge tails (`m` words), the edge heads (`m` words),
+/// the in-offsets (`n + 1` words) and the incoming edges of each node (`m` words).
+#[derive(Debug)]
+pub struct MmapCsrGraph {
+    mmap: Mmap,
+    node_count: usize,
+    edge_count: usize,
+}
+
+impl MmapCsrGraph {
+    /// Memory-maps the graph stored in the given file.
+    ///
+    /// Returns an error if the file cannot be mapped or is not a valid graph file.
+    /// The whole file is read once to check that all offsets and indices are in range,
+    /// such that a corrupt file cannot cause out-of-bounds accesses later on.
+    /// The file must not be modified while it is mapped.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // Safety: the caller is responsible for not modifying the file while it is mapped, as documented.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+        if mmap.len() < HEADER_WORDS * WORD_SIZE || &mmap[..WORD_SIZE] != MAGIC {
+            return Err(invalid("not a CSR graph file"));
+        }
+
+        let mut graph = Self {
+            mmap,
+            node_count: 0,
+            edge_count: 0,
+        };
+        graph.node_count = usize::try_from(graph.word(1))
+            .map_err(|_| invalid("node count does not fit into usize"))?;
+        graph.edge_count = usize::try_from(graph.word(2))
+            .map_err(|_| invalid("edge count does not fit into usize"))?;
+        let expected_bytes = graph
+            .node_count
+            .checked_add(1)
+            .and_then(|offsets| offsets.checked_mul(2))
+            .zip(graph.edge_count.checked_mul(3))
+            .and_then(|(offset_words, edge_words)| offset_words.checked_add(edge_words))
+            .and_then(|words| words.checked_add(HEADER_WORDS))
+            .and_then(|words| words.checked_mul(WORD_SIZE));
+        if expected_bytes != Some(graph.mmap.len()) {
+            return Err(invalid("file size does not match node and edge count"));
+        }
+
+        graph.validate_offsets(graph.out_offsets_start())?;
+        graph.validate_offsets(graph.in_offsets_start())?;
+        graph.validate_entries(
+            graph.edge_sources_start(),
+            graph.edge_count,
+            graph.node_count,
+        )?;
+        graph.validate_entries(
+            graph.edge_targets_start(),
+            graph.edge_count,
+            graph.node_count,
+        )?;
+        graph.validate_entries(graph.in_edges_start(), graph.edge_count, graph.edge_count)?;
+        Ok(graph)
+    }
+
+    /// Checks that the offset array starting at word `array_start` starts at zero,
+    /// is monotonically increasing and ends at the edge count.
+    fn validate_offsets(&self, array_start: usize) -> io::Result<()> {
+        let mut previous = 0;
+        for index in 0..=self.node_count {
+            let offset = self.word(array_start + index);
+            if offset < previous || (index == 0 && offset != 0) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "edge offsets are not monotonically increasing from zero",
+                ));
+            }
+            previous = offset;
+        }
+        if previous != self.edge_count as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "edge offsets do not end at the edge count",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that the `len` entries of the array starting at word `array_start` are less than `bound`.
+    fn validate_entries(&self, array_start: usize, len: usize, bound: usize) -> io::Result<()> {
+        if (array_start..array_start + len).any(|index| self.word(index) >= bound as u64) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "node or edge index out of range",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Writes the given graph into a file in the format read by [open](Self::open).
+    ///
+    /// The node indices of the given graph must be consecutive, and are preserved.
+    /// The edge indices are reassigned ordered by their tail, where edges with the same tail keep the order of their original indices.
+    /// Node and edge data are not written.
+    pub fn write<Graph: ImmutableGraphContainer>(
+        graph: &Graph,
+        path: impl AsRef<Path>,
+    ) -> io::Result<()> {
+        let node_count = graph.node_count();
+        let mut edges: Vec<_> = graph
+            .edge_indices()
+            .map(|edge_id| {
+                let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+                debug_assert!(from_node.as_usize() < node_count && to_node.as_usize() < node_count);
+                (from_node.as_usize(), to_node.as_usize())
+            })
+            .collect();
+        // Stable, so edges with the same tail keep their relative order.
+        edges.sort_by_key(|&(from, _)| from);
+
+        let mut out_offsets = vec![0; node_count + 1];
+        let mut in_offsets = vec![0; node_count + 1];
+        for &(from, to) in &edges {
+            out_offsets[from + 1] += 1;
+            in_offsets[to + 1] += 1;
+        }
+        for node in 0..node_count {
+            out_offsets[node + 1] += out_offsets[node];
+            in_offsets[node + 1] += in_offsets[node];
+        }
+        let mut in_edges = vec![0; edges.len()];
+        let mut in_positions = in_offsets.clone();
+        for (edge, &(_, to)) in edges.iter().enumerate() {
+            in_edges[in_positions[to]] = edge;
+            in_positions[to] += 1;
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        let words = [node_count, edges.len()]
+            .into_iter()
+            .chain(out_offsets)
+            .chain(edges.iter().map(|&(from, _)| from))
+            .chain(edges.iter().map(|&(_, to)| to))
+            .chain(in_offsets)
+            .chain(in_edges);
+        for word in words {
+            writer.write_all(&(word as u64).to_le_bytes())?;
+        }
+        writer.flush()
+    }
+
+    /// Reads the word at the given position of the file.
+    fn word(&self, index: usize) -> u64 {
+        let offset = index * WORD_SIZE;
+        u64::from_le_bytes(self.mmap[offset..offset + WORD_SIZE].try_into().unwrap())
+    }
+
+    /// Reads the word at the given position of the array starting at word `array_start`.
+    fn array_entry(&self, array_start: usize, index: usize) -> usize {
+        self.word(array_start + index) as usize
+    }
+
+    fn out_offsets_start(&self) -> usize {
+        HEADER_WORDS
+    }
+
+    fn edge_sources_start(&self) -> usize {
+        self.out_offsets_start() + self.node_count + 1
+    }
+
+    fn edge_targets_start(&self) -> usize {
+        self.edge_sources_start() + self.edge_count
+    }
+
+    fn in_offsets_start(&self) -> usize {
+        self.edge_targets_start() + self.edge_count
+    }
+
+    fn in_edges_start(&self) -> usize {
+        self.in_offsets_start() + self.node_count + 1
+    }
+
+    fn out_edge_range(&self, node_id: usize) -> Range<usize> {
+        let start = self.out_offsets_start();
+        self.array_entry(start, node_id)..self.array_entry(start, node_id + 1)
+    }
+
+    fn in_edge_range(&self, node_id: usize) -> Range<usize> {
+        let start = self.in_offsets_start();
+        self.array_entry(start, node_id)..self.array_entry(start, node_id + 1)
+    }
+
+    fn edge_source(&self, edge_id: usize) -> usize {
+        self.array_entry(self.edge_sources_start(), edge_id)
+    }
+
+    fn edge_target(&self, edge_id: usize) -> usize {
+        self.array_entry(self.edge_targets_start(), edge_id)
+    }
+}
+
+impl GraphBase for MmapCsrGraph {
+    type NodeData = ();
+    type EdgeData = ();
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<usize>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<usize>;
+    type NodeIndex = crate::index::NodeIndex<usize>;
+    type EdgeIndex = crate::index::EdgeIndex<usize>;
+}
+
+impl ImmutableGraphContainer for MmapCsrGraph {
+    type NodeIndices<'a>
+        = GraphIndices<Self::NodeIndex, Self::OptionalNodeIndex>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = GraphIndices<Self::EdgeIndex, Self::OptionalEdgeIndex>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+
+    type NodeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::NodeIndex, <Self as GraphBase>::OptionalNodeIndex>;
+    type EdgeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::EdgeIndex, <Self as GraphBase>::OptionalEdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        GraphIndices::from((0, self.node_count()))
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        GraphIndices::from((0, self.edge_count()))
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        GraphIndices::from((0, self.node_count()))
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        GraphIndices::from((0, self.edge_count()))
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        node_id.as_usize() < self.node_count()
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        edge_id.as_usize() < self.edge_count()
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        debug_assert!(self.contains_node_index(node_id));
+        &()
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        debug_assert!(self.contains_edge_index(edge_id));
+        &()
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        Edge {
+            from_node: self.edge_source(edge_id.as_usize()).into(),
+            to_node: self.edge_target(edge_id.as_usize()).into(),
+        }
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+/// An iterator over the outgoing neighbors of a node in an [MmapCsrGraph].
+pub struct MmapCsrOutNeighbors<'a, NodeIndex, EdgeIndex> {
+    graph: &'a MmapCsrGraph,
+    edges: Range<usize>,
+    index_types: std::marker::PhantomData<(NodeIndex, EdgeIndex)>,
+}
+
+impl<NodeIndex: From<usize>, EdgeIndex: From<usize>> Iterator
+    for MmapCsrOutNeighbors<'_, NodeIndex, EdgeIndex>
+{
+    type Item = Neighbor<NodeIndex, EdgeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edges.next().map(|edge_id| Neighbor {
+            edge_id: edge_id.into(),
+            node_id: self.graph.edge_target(edge_id).into(),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+/// An iterator over the incoming neighbors of a node in an [MmapCsrGraph].
+pub struct MmapCsrInNeighbors<'a, NodeIndex, EdgeIndex> {
+    graph: &'a MmapCsrGraph,
+    positions: Range<usize>,
+    index_types: std::marker::PhantomData<(NodeIndex, EdgeIndex)>,
+}
+
+impl<NodeIndex: From<usize>, EdgeIndex: From<usize>> Iterator
+    for MmapCsrInNeighbors<'_, NodeIndex, EdgeIndex>
+{
+    type Item = Neighbor<NodeIndex, EdgeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.positions.next().map(|position| {
+            let edge_id = self
+                .graph
+                .array_entry(self.graph.in_edges_start(), position);
+            Neighbor {
+                edge_id: edge_id.into(),
+                node_id: self.graph.edge_source(edge_id).into(),
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+/// An iterator over the edges between two nodes in an [MmapCsrGraph].
+pub struct MmapCsrEdgesBetween<'a, EdgeIndex> {
+    graph: &'a MmapCsrGraph,
+    edges: Range<usize>,
+    to_node: usize,
+    edge_index_type: std::marker::PhantomData<EdgeIndex>,
+}
+
+impl<EdgeIndex: From<usize>> Iterator for MmapCsrEdgesBetween<'_, EdgeIndex> {
+    type Item = EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (graph, to_node) = (self.graph, self.to_node);
+        self.edges
+            .find(|&edge_id| graph.edge_target(edge_id) == to_node)
+            .map(Into::into)
+    }
+}
+
+impl NavigableGraph for MmapCsrGraph {
+    type OutNeighbors<'a>
+        = MmapCsrOutNeighbors<'a, <Self as GraphBase>::NodeIndex, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = MmapCsrInNeighbors<'a, <Self as GraphBase>::NodeIndex, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = MmapCsrEdgesBetween<'a, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        debug_assert!(self.contains_node_index(node_id));
+        MmapCsrOutNeighbors {
+            graph: self,
+            edges: self.out_edge_range(node_id.as_usize()),
+            index_types: Default::default(),
+        }
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        debug_assert!(self.contains_node_index(node_id));
+        MmapCsrInNeighbors {
+            graph: self,
+            positions: self.in_edge_range(node_id.as_usize()),
+            index_types: Default::default(),
+        }
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        debug_assert!(self.contains_node_index(from_node_id));
+        debug_assert!(self.contains_node_index(to_node_id));
+        MmapCsrEdgesBetween {
+            graph: self,
+            edges: self.out_edge_range(from_node_id.as_usize()),
+            to_node: to_node_id.as_usize(),
+            edge_index_type: Default::default(),
+        }
+    }
+
+    fn out_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.out_edge_range(node_id.as_usize()).len()
+    }
+
+    fn in_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.in_edge_range(node_id.as_usize()).len()
+    }
+}
+
+impl SubgraphBase for MmapCsrGraph {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MmapCsrGraph;
+    use crate::implementation::csr_graph::CsrGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_same_adjacency_as_csr_graph() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        for (from, to) in [(2, 0), (0, 1), (1, 2), (2, 2), (0, 1), (3, 0)] {
+            graph.add_edge(n[from], n[to], ());
+        }
+
+        let path = std::env::temp_dir().join(format!(
+            "traitgraph_test_mmap_csr_graph_{}.bin",
+            std::process::id()
+        ));
+        MmapCsrGraph::write(&graph, &path).unwrap();
+        let mmap_graph = MmapCsrGraph::open(&path).unwrap();
+        let csr_graph = CsrGraph::from_graph(&graph);
+
+        debug_assert_eq!(mmap_graph.node_count(), 4);
+        debug_assert_eq!(mmap_graph.edge_count(), 6);
+        for node in mmap_graph.node_indices() {
+            debug_assert_eq!(
+                mmap_graph.out_neighbors(node).collect::<Vec<_>>(),
+                csr_graph.out_neighbors(node).collect::<Vec<_>>()
+            );
+            debug_assert_eq!(
+                mmap_graph.in_neighbors(node).collect::<Vec<_>>(),
+                csr_graph.in_neighbors(node).collect::<Vec<_>>()
+            );
+        }
+        for edge in mmap_graph.edge_indices() {
+            debug_assert_eq!(
+                mmap_graph.edge_endpoints(edge),
+                csr_graph.edge_endpoints(edge)
+            );
+        }
+        debug_assert_eq!(mmap_graph.edge_count_between(n[0], n[1]), 2);
+        debug_assert_eq!(mmap_graph.in_degree(n[2]), 2);
+
+        std::fs::write(&path, b"not a graph").unwrap();
+        debug_assert!(MmapCsrGraph::open(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_files() {
+        let path = std::env::temp_dir().join(format!(
+            "traitgraph_test_mmap_csr_graph_corrupt_{}.bin",
+            std::process::id()
+        ));
+        let write_words = |words: &[u64]| {
+            let mut bytes = super::MAGIC.to_vec();
+            for word in words {
+                bytes.extend_from_slice(&word.to_le_bytes());
+            }
+            std::fs::write(&path, bytes).unwrap();
+        };
+        let assert_invalid = || {
+            let error = MmapCsrGraph::open(&path).unwrap_err();
+            debug_assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        };
+
+        // Two nodes and one edge from node 0 to node 1.
+        let valid = [2, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0];
+        write_words(&valid);
+        debug_assert_eq!(MmapCsrGraph::open(&path).unwrap().edge_count(), 1);
+
+        // Header words whose size computation overflows.
+        write_words(&[u64::MAX, 1]);
+        assert_invalid();
+        write_words(&[0, u64::MAX / 3 + 1]);
+        assert_invalid();
+
+        // Out-offsets that decrease.
+        let mut words = valid;
+        words[3] = 2;
+        write_words(&words);
+        assert_invalid();
+
+        // In-offsets that do not end at the edge count.
+        let mut words = valid;
+        words[9] = 0;
+        write_words(&words);
+        assert_invalid();
+
+        // An edge target that is not a node.
+        let mut words = valid;
+        words[6] = 2;
+        write_words(&words);
+        assert_invalid();
+
+        // An incoming edge that is not an edge.
+        let mut words = valid;
+        words[10] = 1;
+        write_words(&words);
+        assert_invalid();
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
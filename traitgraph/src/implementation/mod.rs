@@ -1,4 +1,48 @@
+/// A graph implementation based on a dense adjacency matrix.
+pub mod adjacency_matrix_graph;
+/// A graph wrapper that partitions the nodes into two sides, such that all edges cross the partition.
+pub mod bipartite_graph;
+/// A builder that allows to construct a graph from multiple threads in parallel.
+#[cfg(feature = "std")]
+pub mod concurrent_graph_builder;
+/// A static graph implementation storing its adjacency in compressed sparse row layout.
+pub mod csr_graph;
+/// An immutable graph implementation with sorted adjacency lists.
+pub mod frozen_graph;
+/// The generation counter shared by the mutable graph implementations.
+mod generation;
+/// A graph implementation supporting sparse external node identifiers.
+#[cfg(feature = "std")]
+pub mod hash_graph;
+/// A graph wrapper that maintains an index from node data to node indices.
+#[cfg(feature = "std")]
+pub mod labeled_graph;
+/// A view of a graph that maps its node and edge data lazily.
+pub mod map_graph;
+/// A read-only graph implementation that memory-maps its adjacency from a file.
+#[cfg(feature = "mmap")]
+pub mod mmap_csr_graph;
+/// A graph wrapper that notifies observers about mutations.
+pub mod observed_graph;
 /// A graph implementation based on the `petgraph` crate.
+#[cfg(feature = "std")]
 pub mod petgraph_impl;
+/// A graph wrapper that records all mutations such that they can be undone.
+pub mod recording_graph;
+/// A view of a graph with the direction of all edges reversed.
+pub mod reversed_graph;
+/// A graph wrapper that allows any graph to serve as the root of subgraphs.
+pub mod root_graph;
+/// A graph implementation that reuses the slots of removed nodes and edges.
+pub mod slab_graph;
+/// A graph implementation whose indices are not changed by removals.
+pub mod stable_graph;
+/// A graph implementation based on the stable graph of the `petgraph` crate.
+#[cfg(feature = "std")]
+pub mod stable_petgraph_impl;
 /// Various implementations of subgraphs.
 pub mod subgraphs;
+/// A graph wrapper that ignores the direction of the edges of a directed graph.
+pub mod undirected_graph;
+/// A graph implementation based on plain vectors, without external dependencies.
+pub mod vec_graph;
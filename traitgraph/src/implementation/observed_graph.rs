@@ -0,0 +1,431 @@
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{DynamicGraph, Edge, GraphBase, MutableGraphContainer};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// A listener for mutations of an [ObservedGraph].
+///
+/// All methods have a default implementation that ignores the event.
+/// Each method receives the graph in the state after the mutation.
+///
+/// When a node or edge is removed, the graph may move another node or edge into its place.
+/// This is reported by a call to [node_moved](Self::node_moved) or [edge_moved](Self::edge_moved) directly after the removal.
+/// When a node is removed, the removal of its incident edges is reported before the removal of the node itself.
+pub trait GraphObserver<Graph: GraphBase> {
+    /// Called after a node was added.
+    fn node_added(&mut self, _graph: &Graph, _node_id: Graph::NodeIndex) {}
+
+    /// Called after an edge was added.
+    fn edge_added(&mut self, _graph: &Graph, _edge_id: Graph::EdgeIndex) {}
+
+    /// Called after a node was removed.
+    fn node_removed(
+        &mut self,
+        _graph: &Graph,
+        _node_id: Graph::NodeIndex,
+        _node_data: &Graph::NodeData,
+    ) {
+    }
+
+    /// Called after an edge was removed.
+    fn edge_removed(
+        &mut self,
+        _graph: &Graph,
+        _edge_id: Graph::EdgeIndex,
+        _endpoints: &Edge<Graph::NodeIndex>,
+        _edge_data: &Graph::EdgeData,
+    ) {
+    }
+
+    /// Called after the removal of a node caused the node at index `from` to be moved to index `to`.
+    fn node_moved(&mut self, _graph: &Graph, _from: Graph::NodeIndex, _to: Graph::NodeIndex) {}
+
+    /// Called after the removal of an edge caused the edge at index `from` to be moved to index `to`.
+    fn edge_moved(&mut self, _graph: &Graph, _from: Graph::EdgeIndex, _to: Graph::EdgeIndex) {}
+
+    /// Called before a mutable reference to the data of the given node is handed out.
+    fn node_data_accessed_mut(&mut self, _graph: &Graph, _node_id: Graph::NodeIndex) {}
+
+    /// Called before a mutable reference to the data of the given edge is handed out.
+    fn edge_data_accessed_mut(&mut self, _graph: &Graph, _edge_id: Graph::EdgeIndex) {}
+
+    /// Called after all nodes and edges were removed by [clear](MutableGraphContainer::clear).
+    fn cleared(&mut self, _graph: &Graph) {}
+}
+
+impl<Graph: GraphBase> GraphObserver<Graph> for () {}
+
+macro_rules! impl_graph_observer_for_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<Graph: GraphBase, $($name: GraphObserver<Graph>),+> GraphObserver<Graph> for ($($name,)+) {
+            fn node_added(&mut self, graph: &Graph, node_id: Graph::NodeIndex) {
+                $(self.$index.node_added(graph, node_id);)+
+            }
+
+            fn edge_added(&mut self, graph: &Graph, edge_id: Graph::EdgeIndex) {
+                $(self.$index.edge_added(graph, edge_id);)+
+            }
+
+            fn node_removed(
+                &mut self,
+                graph: &Graph,
+                node_id: Graph::NodeIndex,
+                node_data: &Graph::NodeData,
+            ) {
+                $(self.$index.node_removed(graph, node_id, node_data);)+
+            }
+
+            fn edge_removed(
+                &mut self,
+                graph: &Graph,
+                edge_id: Graph::EdgeIndex,
+                endpoints: &Edge<Graph::NodeIndex>,
+                edge_data: &Graph::EdgeData,
+            ) {
+                $(self.$index.edge_removed(graph, edge_id, endpoints, edge_data);)+
+            }
+
+            fn node_moved(&mut self, graph: &Graph, from: Graph::NodeIndex, to: Graph::NodeIndex) {
+                $(self.$index.node_moved(graph, from, to);)+
+            }
+
+            fn edge_moved(&mut self, graph: &Graph, from: Graph::EdgeIndex, to: Graph::EdgeIndex) {
+                $(self.$index.edge_moved(graph, from, to);)+
+            }
+
+            fn node_data_accessed_mut(&mut self, graph: &Graph, node_id: Graph::NodeIndex) {
+                $(self.$index.node_data_accessed_mut(graph, node_id);)+
+            }
+
+            fn edge_data_accessed_mut(&mut self, graph: &Graph, edge_id: Graph::EdgeIndex) {
+                $(self.$index.edge_data_accessed_mut(graph, edge_id);)+
+            }
+
+            fn cleared(&mut self, graph: &Graph) {
+                $(self.$index.cleared(graph);)+
+            }
+        }
+    };
+}
+
+impl_graph_observer_for_tuple!(A 0);
+impl_graph_observer_for_tuple!(A 0, B 1);
+impl_graph_observer_for_tuple!(A 0, B 1, C 2);
+impl_graph_observer_for_tuple!(A 0, B 1, C 2, D 3);
+
+impl<Graph: GraphBase> GraphObserver<Graph> for Vec<Box<dyn GraphObserver<Graph>>> {
+    fn node_added(&mut self, graph: &Graph, node_id: Graph::NodeIndex) {
+        self.iter_mut()
+            .for_each(|observer| observer.node_added(graph, node_id));
+    }
+
+    fn edge_added(&mut self, graph: &Graph, edge_id: Graph::EdgeIndex) {
+        self.iter_mut()
+            .for_each(|observer| observer.edge_added(graph, edge_id));
+    }
+
+    fn node_removed(
+        &mut self,
+        graph: &Graph,
+        node_id: Graph::NodeIndex,
+        node_data: &Graph::NodeData,
+    ) {
+        self.iter_mut()
+            .for_each(|observer| observer.node_removed(graph, node_id, node_data));
+    }
+
+    fn edge_removed(
+        &mut self,
+        graph: &Graph,
+        edge_id: Graph::EdgeIndex,
+        endpoints: &Edge<Graph::NodeIndex>,
+        edge_data: &Graph::EdgeData,
+    ) {
+        self.iter_mut()
+            .for_each(|observer| observer.edge_removed(graph, edge_id, endpoints, edge_data));
+    }
+
+    fn node_moved(&mut self, graph: &Graph, from: Graph::NodeIndex, to: Graph::NodeIndex) {
+        self.iter_mut()
+            .for_each(|observer| observer.node_moved(graph, from, to));
+    }
+
+    fn edge_moved(&mut self, graph: &Graph, from: Graph::EdgeIndex, to: Graph::EdgeIndex) {
+        self.iter_mut()
+            .for_each(|observer| observer.edge_moved(graph, from, to));
+    }
+
+    fn node_data_accessed_mut(&mut self, graph: &Graph, node_id: Graph::NodeIndex) {
+        self.iter_mut()
+            .for_each(|observer| observer.node_data_accessed_mut(graph, node_id));
+    }
+
+    fn edge_data_accessed_mut(&mut self, graph: &Graph, edge_id: Graph::EdgeIndex) {
+        self.iter_mut()
+            .for_each(|observer| observer.edge_data_accessed_mut(graph, edge_id));
+    }
+
+    fn cleared(&mut self, graph: &Graph) {
+        self.iter_mut().for_each(|observer| observer.cleared(graph));
+    }
+}
+
+/// A wrapper around a graph that notifies an observer about all mutations.
+///
+/// Auxiliary structures like degree caches or indices can implement [GraphObserver] to stay synchronised with the graph.
+/// Multiple observers can be combined using tuples or a `Vec<Box<dyn GraphObserver<Graph>>>`.
+///
+/// Moves of nodes and edges are detected assuming that the wrapped graph removes nodes and edges by moving the node or edge with the highest index into the place of the removed one,
+/// as done e.g. by [PetGraph](crate::implementation::petgraph_impl::PetGraph),
+/// or that it does not change the indices of the remaining nodes and edges at all.
+#[derive(Debug, Clone, Default)]
+pub struct ObservedGraph<Graph, Observer> {
+    graph: Graph,
+    observer: Observer,
+}
+
+impl<Graph: GraphBase, Observer: GraphObserver<Graph>> ObservedGraph<Graph, Observer> {
+    /// Wraps the given graph, notifying the given observer about all future mutations.
+    pub fn new(graph: Graph, observer: Observer) -> Self {
+        Self { graph, observer }
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns a reference to the observer.
+    pub fn observer(&self) -> &Observer {
+        &self.observer
+    }
+
+    /// Returns a mutable reference to the observer.
+    pub fn observer_mut(&mut self) -> &mut Observer {
+        &mut self.observer
+    }
+
+    /// Returns the wrapped graph and the observer.
+    pub fn into_parts(self) -> (Graph, Observer) {
+        (self.graph, self.observer)
+    }
+}
+
+impl<Graph: DynamicGraph, Observer: GraphObserver<Graph>> ObservedGraph<Graph, Observer> {
+    fn remove_edge_and_notify(&mut self, edge_id: Graph::EdgeIndex) -> Option<Graph::EdgeData> {
+        let last_edge_id = self.graph.edge_indices().last();
+        let endpoints = self.graph.edge_endpoints(edge_id);
+        let edge_data = self.graph.remove_edge(edge_id)?;
+        self.observer
+            .edge_removed(&self.graph, edge_id, &endpoints, &edge_data);
+
+        if let Some(last_edge_id) = last_edge_id {
+            if last_edge_id != edge_id && self.graph.contains_edge_index(edge_id) {
+                self.observer.edge_moved(&self.graph, last_edge_id, edge_id);
+            }
+        }
+
+        Some(edge_data)
+    }
+}
+
+impl<Graph: DynamicGraph, Observer: GraphObserver<Graph>> MutableGraphContainer
+    for ObservedGraph<Graph, Observer>
+{
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        self.observer.node_data_accessed_mut(&self.graph, node_id);
+        self.graph.node_data_mut(node_id)
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        self.observer.edge_data_accessed_mut(&self.graph, edge_id);
+        self.graph.edge_data_mut(edge_id)
+    }
+
+    fn add_node(&mut self, node_data: Self::NodeData) -> Self::NodeIndex {
+        let node_id = self.graph.add_node(node_data);
+        self.observer.node_added(&self.graph, node_id);
+        node_id
+    }
+
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: Self::EdgeData,
+    ) -> Self::EdgeIndex {
+        let edge_id = self.graph.add_edge(from, to, edge_data);
+        self.observer.edge_added(&self.graph, edge_id);
+        edge_id
+    }
+
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.graph.reserve_nodes(additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.graph.reserve_edges(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.graph.shrink_to_fit();
+    }
+
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData> {
+        if !self.graph.contains_node_index(node_id) {
+            return None;
+        }
+
+        // Remove incident edges first such that their removal is reported.
+        // Removing them in descending order ensures that no other incident edge is moved.
+        let mut incident_edges: Vec<_> = self
+            .graph
+            .out_neighbors(node_id)
+            .chain(self.graph.in_neighbors(node_id))
+            .map(|neighbor| neighbor.edge_id)
+            .collect();
+        incident_edges.sort_unstable();
+        incident_edges.dedup();
+        for edge_id in incident_edges.into_iter().rev() {
+            self.remove_edge_and_notify(edge_id);
+        }
+
+        let last_node_id = self.graph.node_indices().last();
+        let node_data = self.graph.remove_node(node_id)?;
+        self.observer.node_removed(&self.graph, node_id, &node_data);
+
+        if let Some(last_node_id) = last_node_id {
+            if last_node_id != node_id && self.graph.contains_node_index(node_id) {
+                self.observer.node_moved(&self.graph, last_node_id, node_id);
+            }
+        }
+
+        Some(node_data)
+    }
+
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<Self::EdgeData> {
+        if !self.graph.contains_edge_index(edge_id) {
+            return None;
+        }
+        self.remove_edge_and_notify(edge_id)
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        edge_ids.windows(2).for_each(|w| debug_assert!(w[0] < w[1]));
+
+        for edge_id in edge_ids.iter().rev() {
+            self.remove_edge_and_notify(*edge_id);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.graph.clear();
+        self.observer.cleared(&self.graph);
+    }
+}
+
+delegate_graph!(impl[Graph, Observer] ObservedGraph<Graph, Observer> => graph: Graph);
+
+impl<Graph: GraphBase, Observer> SubgraphBase for ObservedGraph<Graph, Observer> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{GraphObserver, ObservedGraph};
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::index::GraphIndex;
+    use crate::interface::{
+        Edge, GraphBase, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph,
+    };
+
+    type Graph = PetGraph<(), ()>;
+    type NodeIndex = <Graph as GraphBase>::NodeIndex;
+    type EdgeIndex = <Graph as GraphBase>::EdgeIndex;
+
+    /// Caches the out-degree of each node.
+    #[derive(Default)]
+    struct OutDegreeCache(Vec<usize>);
+
+    impl GraphObserver<Graph> for OutDegreeCache {
+        fn node_added(&mut self, _: &Graph, node_id: NodeIndex) {
+            debug_assert_eq!(node_id.as_usize(), self.0.len());
+            self.0.push(0);
+        }
+
+        fn edge_added(&mut self, graph: &Graph, edge_id: EdgeIndex) {
+            self.0[graph.edge_endpoints(edge_id).from_node.as_usize()] += 1;
+        }
+
+        fn node_removed(&mut self, _: &Graph, node_id: NodeIndex, _: &()) {
+            self.0.swap_remove(node_id.as_usize());
+        }
+
+        fn edge_removed(&mut self, _: &Graph, _: EdgeIndex, endpoints: &Edge<NodeIndex>, _: &()) {
+            self.0[endpoints.from_node.as_usize()] -= 1;
+        }
+
+        fn cleared(&mut self, _: &Graph) {
+            self.0.clear();
+        }
+    }
+
+    #[derive(Default)]
+    struct EventCounter(usize);
+
+    impl GraphObserver<Graph> for EventCounter {
+        fn node_moved(&mut self, _: &Graph, _: NodeIndex, _: NodeIndex) {
+            self.0 += 1;
+        }
+
+        fn edge_moved(&mut self, _: &Graph, _: EdgeIndex, _: EdgeIndex) {
+            self.0 += 1;
+        }
+    }
+
+    fn assert_cache_is_correct(graph: &ObservedGraph<Graph, (OutDegreeCache, EventCounter)>) {
+        let expected: Vec<_> = graph
+            .node_indices()
+            .map(|node_id| graph.out_degree(node_id))
+            .collect();
+        debug_assert_eq!(graph.observer().0 .0, expected);
+    }
+
+    #[test]
+    fn test_degree_cache() {
+        let mut graph = ObservedGraph::new(Graph::new(), Default::default());
+        let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[0], n[2], ());
+        graph.add_edge(n[1], n[1], ());
+        graph.add_edge(n[3], n[0], ());
+        graph.add_edge(n[3], n[1], ());
+        assert_cache_is_correct(&graph);
+
+        graph.remove_edge(1.into());
+        assert_cache_is_correct(&graph);
+        debug_assert_eq!(graph.observer().1 .0, 1);
+
+        graph.remove_node(n[0]);
+        assert_cache_is_correct(&graph);
+        graph.remove_node(n[1]);
+        assert_cache_is_correct(&graph);
+        graph.clear();
+        assert_cache_is_correct(&graph);
+    }
+
+    #[test]
+    fn test_boxed_observers() {
+        let observers: Vec<Box<dyn GraphObserver<Graph>>> =
+            vec![Box::new(OutDegreeCache::default()), Box::new(())];
+        let mut graph = ObservedGraph::new(Graph::new(), observers);
+        let n0 = graph.add_node(());
+        graph.add_edge(n0, n0, ());
+        graph.remove_node(n0);
+        debug_assert!(graph.is_empty());
+    }
+}
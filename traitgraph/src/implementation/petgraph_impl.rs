@@ -1,37 +1,149 @@
-use crate::index::{GraphIndex, GraphIndices};
+use crate::implementation::generation::GraphGeneration;
+use crate::index::{GraphIndex, GraphIndices, OptionalGraphIndex};
 use crate::interface::{
-    Edge, GraphBase, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph, Neighbor,
+    AdjacencyListStep, Edge, GraphBase, ImmutableGraphContainer, MutableGraphContainer,
+    NavigableGraph, Neighbor, NeighborsMut,
 };
 use num_traits::{PrimInt, ToPrimitive};
 use petgraph::graph::{DiGraph, Edges, EdgesConnecting};
 use petgraph::visit::EdgeRef;
 use petgraph::{Directed, Direction};
-use std::iter::Map;
+use std::hash::Hash;
+use std::iter::{Enumerate, Map};
 
 use crate::interface::subgraph::SubgraphBase;
 pub use petgraph;
 
+/// An adapter implementing the `visit` traits of petgraph for any graph implementing our traits.
+pub mod visit_adapter;
+
+/// The integer types that can be used as indices of a [PetGraph].
+///
+/// This is implemented for `u8`, `u16`, `u32` and `usize`.
+/// The maximum value of the type is reserved, so a graph can contain at most `max_value() - 1` nodes and edges.
+pub trait PetGraphIndexType: petgraph::graph::IndexType + PrimInt + Hash {}
+
+impl<IndexType: petgraph::graph::IndexType + PrimInt + Hash> PetGraphIndexType for IndexType {}
+
 /// A wrapper around the [petgraph::graph::Graph] type replacing its methods with implementations of our traits.
+///
+/// The integer type used to store node and edge indices can be chosen with `IndexType`.
+/// It defaults to `usize`, but e.g. `u32` halves the memory used for indices on 64-bit machines, see [PetGraph32].
+///
+/// The graph tracks its [generation](ImmutableGraphContainer::generation), which is not serialised.
+/// A deserialised graph gets a new generation.
 #[derive(Debug, Clone)]
-pub struct PetGraph<NodeData, EdgeData>(DiGraph<NodeData, EdgeData, usize>);
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PetGraph<NodeData, EdgeData, IndexType: PetGraphIndexType = usize>(
+    DiGraph<NodeData, EdgeData, IndexType>,
+    #[cfg_attr(feature = "serde", serde(skip))] GraphGeneration,
+);
+
+/// A [PetGraph] that uses `u32` indices, which suffices for graphs with less than `u32::MAX` nodes and edges.
+pub type PetGraph32<NodeData, EdgeData> = PetGraph<NodeData, EdgeData, u32>;
+
+/// A [PetGraph] that uses `u16` indices, which suffices for graphs with less than `u16::MAX` nodes and edges.
+pub type PetGraph16<NodeData, EdgeData> = PetGraph<NodeData, EdgeData, u16>;
 
 impl<NodeData, EdgeData> PetGraph<NodeData, EdgeData> {
     /// Create a new graph implemented using the `petgraph::graph::Graph` type.
+    ///
+    /// Graphs with a different index type can be created with [Default], e.g. `PetGraph32::default()`.
     pub fn new() -> PetGraph<NodeData, EdgeData> {
-        PetGraph(DiGraph::<NodeData, EdgeData, usize>::default())
+        PetGraph(
+            DiGraph::<NodeData, EdgeData, usize>::default(),
+            GraphGeneration::new(),
+        )
+    }
+}
+
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> PetGraph<NodeData, EdgeData, IndexType> {
+    /// Create a new graph with space for the given amount of nodes and edges.
+    pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> Self {
+        PetGraph(
+            DiGraph::with_capacity(node_capacity, edge_capacity),
+            GraphGeneration::new(),
+        )
+    }
+
+    /// Advances the generation of the graph after nodes or edges were added or removed.
+    fn next_generation(&mut self) {
+        self.1.advance();
+    }
+
+    /// Walks the adjacency list of the given node in the given direction without collecting it first.
+    fn neighbors_mut(
+        &mut self,
+        node_id: <Self as GraphBase>::NodeIndex,
+        direction: Direction,
+    ) -> NeighborsMut<'_, Self> {
+        debug_assert!(self.contains_node_index(node_id));
+        let first_edge =
+            Self::optional_edge_index(self.0.raw_nodes()[node_id.as_usize()].next_edge(direction));
+        let step: AdjacencyListStep<Self> = match direction {
+            Direction::Outgoing => |graph, edge_id| {
+                let edge = &graph.0.raw_edges()[edge_id.as_usize()];
+                let neighbor = Neighbor {
+                    edge_id,
+                    node_id: edge.target().index().into(),
+                };
+                (
+                    neighbor,
+                    Self::optional_edge_index(edge.next_edge(Direction::Outgoing)),
+                )
+            },
+            Direction::Incoming => |graph, edge_id| {
+                let edge = &graph.0.raw_edges()[edge_id.as_usize()];
+                let neighbor = Neighbor {
+                    edge_id,
+                    node_id: edge.source().index().into(),
+                };
+                (
+                    neighbor,
+                    Self::optional_edge_index(edge.next_edge(Direction::Incoming)),
+                )
+            },
+        };
+        NeighborsMut::from_adjacency_list(self, first_edge, step)
+    }
+
+    /// Converts the end marker of petgraph's adjacency lists into `None`.
+    fn optional_edge_index(
+        edge_id: petgraph::graph::EdgeIndex<IndexType>,
+    ) -> <Self as GraphBase>::OptionalEdgeIndex {
+        if edge_id == petgraph::graph::EdgeIndex::end() {
+            OptionalGraphIndex::new_none()
+        } else {
+            edge_id.index().into()
+        }
     }
 }
 
-impl<NodeData, EdgeData> GraphBase for PetGraph<NodeData, EdgeData> {
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> GraphBase
+    for PetGraph<NodeData, EdgeData, IndexType>
+{
     type NodeData = NodeData;
     type EdgeData = EdgeData;
-    type OptionalNodeIndex = crate::index::OptionalNodeIndex<usize>;
-    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<usize>;
-    type NodeIndex = crate::index::NodeIndex<usize>;
-    type EdgeIndex = crate::index::EdgeIndex<usize>;
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<IndexType>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<IndexType>;
+    type NodeIndex = crate::index::NodeIndex<IndexType>;
+    type EdgeIndex = crate::index::EdgeIndex<IndexType>;
 }
 
-impl<NodeData, EdgeData> ImmutableGraphContainer for PetGraph<NodeData, EdgeData> {
+type PetgraphEdgeReferenceTranslator<'a, EdgeData, IndexType, NodeIndex, EdgeIndex> = Map<
+    Enumerate<core::slice::Iter<'a, petgraph::graph::Edge<EdgeData, IndexType>>>,
+    fn(
+        (usize, &'a petgraph::graph::Edge<EdgeData, IndexType>),
+    ) -> (EdgeIndex, Edge<NodeIndex>, &'a EdgeData),
+>;
+
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> ImmutableGraphContainer
+    for PetGraph<NodeData, EdgeData, IndexType>
+{
     type NodeIndices<'a>
         = GraphIndices<Self::NodeIndex, Self::OptionalNodeIndex>
     where
@@ -40,6 +152,16 @@ impl<NodeData, EdgeData> ImmutableGraphContainer for PetGraph<NodeData, EdgeData
         = GraphIndices<Self::EdgeIndex, Self::OptionalEdgeIndex>
     where
         Self: 'a;
+    type EdgeReferences<'a>
+        = PetgraphEdgeReferenceTranslator<
+        'a,
+        EdgeData,
+        IndexType,
+        <Self as GraphBase>::NodeIndex,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        Self: 'a;
 
     type NodeIndicesCopied =
         GraphIndices<<Self as GraphBase>::NodeIndex, <Self as GraphBase>::OptionalNodeIndex>;
@@ -77,6 +199,10 @@ impl<NodeData, EdgeData> ImmutableGraphContainer for PetGraph<NodeData, EdgeData
         self.0.edge_count()
     }
 
+    fn generation(&self) -> Option<u64> {
+        Some(self.1.get())
+    }
+
     fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
         self.0.node_weight(node_id.into()).unwrap()
     }
@@ -92,9 +218,29 @@ impl<NodeData, EdgeData> ImmutableGraphContainer for PetGraph<NodeData, EdgeData
             to_node: endpoints.1.index().into(),
         }
     }
+
+    /// Iterates over the edges directly, without looking up each edge by its index.
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        self.0
+            .raw_edges()
+            .iter()
+            .enumerate()
+            .map(|(edge_id, edge)| {
+                (
+                    edge_id.into(),
+                    Edge {
+                        from_node: edge.source().index().into(),
+                        to_node: edge.target().index().into(),
+                    },
+                    &edge.weight,
+                )
+            })
+    }
 }
 
-impl<NodeData, EdgeData> MutableGraphContainer for PetGraph<NodeData, EdgeData> {
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> MutableGraphContainer
+    for PetGraph<NodeData, EdgeData, IndexType>
+{
     fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
         self.0.node_weight_mut(node_id.into()).unwrap()
     }
@@ -104,26 +250,92 @@ impl<NodeData, EdgeData> MutableGraphContainer for PetGraph<NodeData, EdgeData>
     }
 
     fn add_node(&mut self, node_data: NodeData) -> Self::NodeIndex {
+        self.next_generation();
         self.0.add_node(node_data).index().into()
     }
 
+    fn add_nodes(
+        &mut self,
+        node_data: impl IntoIterator<Item = Self::NodeData>,
+    ) -> Vec<Self::NodeIndex> {
+        self.next_generation();
+        let node_data = node_data.into_iter();
+        self.0.reserve_nodes(node_data.size_hint().0);
+        node_data
+            .map(|node_data| self.0.add_node(node_data).index().into())
+            .collect()
+    }
+
     fn add_edge(
         &mut self,
         from: Self::NodeIndex,
         to: Self::NodeIndex,
         edge_data: EdgeData,
     ) -> Self::EdgeIndex {
+        self.next_generation();
         self.0
             .add_edge(from.into(), to.into(), edge_data)
             .index()
             .into()
     }
 
+    fn add_edges(
+        &mut self,
+        edges: impl IntoIterator<Item = (Self::NodeIndex, Self::NodeIndex, Self::EdgeData)>,
+    ) -> Vec<Self::EdgeIndex> {
+        self.next_generation();
+        let edges = edges.into_iter();
+        self.0.reserve_edges(edges.size_hint().0);
+        edges
+            .map(|(from, to, edge_data)| {
+                self.0
+                    .add_edge(from.into(), to.into(), edge_data)
+                    .index()
+                    .into()
+            })
+            .collect()
+    }
+
+    fn extend_nodes(&mut self, node_data: impl IntoIterator<Item = Self::NodeData>) {
+        self.next_generation();
+        let node_data = node_data.into_iter();
+        self.0.reserve_nodes(node_data.size_hint().0);
+        node_data.for_each(|node_data| {
+            self.0.add_node(node_data);
+        });
+    }
+
+    fn extend_edges(
+        &mut self,
+        edges: impl IntoIterator<Item = (Self::NodeIndex, Self::NodeIndex, Self::EdgeData)>,
+    ) {
+        self.next_generation();
+        let edges = edges.into_iter();
+        self.0.reserve_edges(edges.size_hint().0);
+        edges.for_each(|(from, to, edge_data)| {
+            self.0.add_edge(from.into(), to.into(), edge_data);
+        });
+    }
+
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.0.reserve_nodes(additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.0.reserve_edges(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
     fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<NodeData> {
+        self.next_generation();
         self.0.remove_node(node_id.into())
     }
 
     fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<EdgeData> {
+        self.next_generation();
         self.0.remove_edge(edge_id.into())
     }
 
@@ -135,12 +347,75 @@ impl<NodeData, EdgeData> MutableGraphContainer for PetGraph<NodeData, EdgeData>
         }
     }
 
+    /// Removes all nodes for which `predicate` returns false, together with their incident edges.
+    ///
+    /// Unlike repeated calls to [`remove_node`](Self::remove_node), this rebuilds the graph in a single pass.
+    /// The remaining nodes and edges keep their relative order and are indexed consecutively.
+    fn retain_nodes(&mut self, mut predicate: impl FnMut(&Self, Self::NodeIndex) -> bool) {
+        let retained: Vec<_> = self
+            .node_indices()
+            .map(|node_id| predicate(self, node_id))
+            .collect();
+        if retained.iter().all(|&retained| retained) {
+            return;
+        }
+
+        let (nodes, edges) = std::mem::take(&mut self.0).into_nodes_edges();
+        let mut node_map = vec![None; nodes.len()];
+        let mut graph = DiGraph::with_capacity(nodes.len(), edges.len());
+        for (node_index, node) in nodes.into_iter().enumerate() {
+            if retained[node_index] {
+                node_map[node_index] = Some(graph.add_node(node.weight));
+            }
+        }
+        for edge in edges {
+            if let (Some(from), Some(to)) = (
+                node_map[edge.source().index()],
+                node_map[edge.target().index()],
+            ) {
+                graph.add_edge(from, to, edge.weight);
+            }
+        }
+        self.0 = graph;
+        self.next_generation();
+    }
+
+    /// Removes all edges for which `predicate` returns false.
+    ///
+    /// Unlike repeated calls to [`remove_edge`](Self::remove_edge), this rebuilds the edges of the graph in a single pass.
+    /// The remaining edges keep their relative order and are indexed consecutively.
+    fn retain_edges(&mut self, mut predicate: impl FnMut(&Self, Self::EdgeIndex) -> bool) {
+        let retained: Vec<_> = self
+            .edge_indices()
+            .map(|edge_id| predicate(self, edge_id))
+            .collect();
+        if retained.iter().all(|&retained| retained) {
+            return;
+        }
+
+        let (nodes, edges) = std::mem::take(&mut self.0).into_nodes_edges();
+        let mut graph = DiGraph::with_capacity(nodes.len(), edges.len());
+        for node in nodes {
+            graph.add_node(node.weight);
+        }
+        for (edge, retained) in edges.into_iter().zip(retained) {
+            if retained {
+                graph.add_edge(edge.source(), edge.target(), edge.weight);
+            }
+        }
+        self.0 = graph;
+        self.next_generation();
+    }
+
     fn clear(&mut self) {
+        self.next_generation();
         self.0.clear();
     }
 }
 
-impl<NodeData, EdgeData> SubgraphBase for PetGraph<NodeData, EdgeData> {
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> SubgraphBase
+    for PetGraph<NodeData, EdgeData, IndexType>
+{
     type RootGraph = Self;
 
     fn root(&self) -> &Self::RootGraph {
@@ -148,21 +423,24 @@ impl<NodeData, EdgeData> SubgraphBase for PetGraph<NodeData, EdgeData> {
     }
 }
 
-type PetgraphNeighborTranslator<'a, EdgeData, NodeIndex, EdgeIndex> = Map<
-    Edges<'a, EdgeData, Directed, usize>,
-    fn(petgraph::graph::EdgeReference<'a, EdgeData, usize>) -> Neighbor<NodeIndex, EdgeIndex>,
+type PetgraphNeighborTranslator<'a, EdgeData, IndexType, NodeIndex, EdgeIndex> = Map<
+    Edges<'a, EdgeData, Directed, IndexType>,
+    fn(petgraph::graph::EdgeReference<'a, EdgeData, IndexType>) -> Neighbor<NodeIndex, EdgeIndex>,
 >;
 
-type PetgraphRestrictedNeighborTranslator<'a, EdgeData, EdgeIndex> = Map<
-    EdgesConnecting<'a, EdgeData, Directed, usize>,
-    fn(petgraph::graph::EdgeReference<'a, EdgeData, usize>) -> EdgeIndex,
+type PetgraphRestrictedNeighborTranslator<'a, EdgeData, IndexType, EdgeIndex> = Map<
+    EdgesConnecting<'a, EdgeData, Directed, IndexType>,
+    fn(petgraph::graph::EdgeReference<'a, EdgeData, IndexType>) -> EdgeIndex,
 >;
 
-impl<NodeData, EdgeData> NavigableGraph for PetGraph<NodeData, EdgeData> {
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> NavigableGraph
+    for PetGraph<NodeData, EdgeData, IndexType>
+{
     type OutNeighbors<'a>
         = PetgraphNeighborTranslator<
         'a,
         EdgeData,
+        IndexType,
         <Self as GraphBase>::NodeIndex,
         <Self as GraphBase>::EdgeIndex,
     >
@@ -173,6 +451,7 @@ impl<NodeData, EdgeData> NavigableGraph for PetGraph<NodeData, EdgeData> {
         = PetgraphNeighborTranslator<
         'a,
         EdgeData,
+        IndexType,
         <Self as GraphBase>::NodeIndex,
         <Self as GraphBase>::EdgeIndex,
     >
@@ -180,7 +459,12 @@ impl<NodeData, EdgeData> NavigableGraph for PetGraph<NodeData, EdgeData> {
         NodeData: 'a,
         EdgeData: 'a;
     type EdgesBetween<'a>
-        = PetgraphRestrictedNeighborTranslator<'a, EdgeData, <Self as GraphBase>::EdgeIndex>
+        = PetgraphRestrictedNeighborTranslator<
+        'a,
+        EdgeData,
+        IndexType,
+        <Self as GraphBase>::EdgeIndex,
+    >
     where
         NodeData: 'a,
         EdgeData: 'a;
@@ -205,6 +489,14 @@ impl<NodeData, EdgeData> NavigableGraph for PetGraph<NodeData, EdgeData> {
             })
     }
 
+    fn out_neighbors_mut(&mut self, node_id: Self::NodeIndex) -> NeighborsMut<'_, Self> {
+        self.neighbors_mut(node_id, Direction::Outgoing)
+    }
+
+    fn in_neighbors_mut(&mut self, node_id: Self::NodeIndex) -> NeighborsMut<'_, Self> {
+        self.neighbors_mut(node_id, Direction::Incoming)
+    }
+
     fn edges_between(
         &self,
         from_node_id: <Self as GraphBase>::NodeIndex,
@@ -245,7 +537,9 @@ impl<IndexType: PrimInt + ToPrimitive + petgraph::graph::IndexType>
     }
 }
 
-impl<NodeData: PartialEq, EdgeData: PartialEq> PartialEq for PetGraph<NodeData, EdgeData> {
+impl<NodeData: PartialEq, EdgeData: PartialEq, IndexType: PetGraphIndexType> PartialEq
+    for PetGraph<NodeData, EdgeData, IndexType>
+{
     fn eq(&self, other: &Self) -> bool {
         self.node_count() == other.node_count()
             || self.edge_count() == other.edge_count()
@@ -261,10 +555,198 @@ impl<NodeData: PartialEq, EdgeData: PartialEq> PartialEq for PetGraph<NodeData,
     }
 }
 
-impl<NodeData: Eq, EdgeData: Eq> Eq for PetGraph<NodeData, EdgeData> {}
+impl<NodeData: Eq, EdgeData: Eq, IndexType: PetGraphIndexType> Eq
+    for PetGraph<NodeData, EdgeData, IndexType>
+{
+}
 
-impl<NodeData, EdgeData> Default for PetGraph<NodeData, EdgeData> {
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> Default
+    for PetGraph<NodeData, EdgeData, IndexType>
+{
     fn default() -> Self {
-        Self(Default::default())
+        Self(Default::default(), GraphGeneration::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::petgraph_impl::{PetGraph, PetGraph16, PetGraph32};
+    use crate::implementation::vec_graph::VecGraph;
+    use crate::index::GraphIndex;
+    use crate::interface::{
+        GraphBase, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph,
+    };
+
+    #[test]
+    fn test_capacity_management() {
+        let mut graph = PetGraph::<(), ()>::new();
+        graph.reserve(10, 20);
+        let (node_capacity, edge_capacity) = graph.0.capacity();
+        debug_assert!(node_capacity >= 10);
+        debug_assert!(edge_capacity >= 20);
+
+        let node = graph.add_node(());
+        graph.add_edge(node, node, ());
+        graph.shrink_to_fit();
+        let (node_capacity, edge_capacity) = graph.0.capacity();
+        debug_assert!(node_capacity < 10);
+        debug_assert!(edge_capacity < 20);
+        debug_assert_eq!(graph.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_generation() {
+        let mut graph = PetGraph::new();
+        let mut generations = vec![graph.generation()];
+        let n0 = graph.add_node(0);
+        generations.push(graph.generation());
+        let n1 = graph.add_nodes([1, 2])[0];
+        generations.push(graph.generation());
+        let edge = graph.add_edge(n0, n1, 3);
+        generations.push(graph.generation());
+
+        // Mutating data does not change the structure of the graph.
+        *graph.node_data_mut(n0) = 4;
+        *graph.edge_data_mut(edge) = 5;
+        debug_assert_eq!(graph.generation(), generations[3]);
+
+        graph.remove_edge(edge);
+        generations.push(graph.generation());
+        graph.retain_nodes(|_, node_id| node_id != n1);
+        generations.push(graph.generation());
+        graph.clear();
+        generations.push(graph.generation());
+
+        debug_assert!(generations.iter().all(Option::is_some));
+        for (index, generation) in generations.iter().enumerate() {
+            debug_assert!(!generations[index + 1..].contains(generation));
+        }
+        debug_assert_eq!(graph.clone().generation(), graph.generation());
+    }
+
+    #[test]
+    fn test_small_indices() {
+        debug_assert_eq!(
+            std::mem::size_of::<<PetGraph16<(), ()> as GraphBase>::OptionalNodeIndex>(),
+            2
+        );
+
+        let mut graph = PetGraph16::default();
+        graph.extend_nodes(0..1000);
+        graph.extend_edges((1..1000).map(|i| ((i - 1).into(), i.into(), i)));
+        debug_assert_eq!(graph.node_count(), 1000);
+        debug_assert_eq!(graph.out_degree(998.into()), 1);
+
+        let mut graph = PetGraph::<_, (), u8>::default();
+        graph.extend_nodes(0..254);
+        debug_assert_eq!(graph.node_indices().next_back().unwrap().as_usize(), 253);
+    }
+
+    #[test]
+    fn test_u32_indices() {
+        debug_assert_eq!(
+            std::mem::size_of::<<PetGraph32<(), ()> as GraphBase>::NodeIndex>(),
+            4
+        );
+        debug_assert_eq!(
+            std::mem::size_of::<<PetGraph32<(), ()> as GraphBase>::OptionalEdgeIndex>(),
+            4
+        );
+
+        let mut graph = PetGraph32::default();
+        let n0 = graph.add_node('a');
+        let n1 = graph.add_node('b');
+        let n2 = graph.add_node('c');
+        let e0 = graph.add_edge(n0, n1, 0);
+        let e1 = graph.add_edge(n1, n2, 1);
+        graph.add_edge(n2, n0, 2);
+
+        debug_assert_eq!(
+            graph
+                .out_neighbors(n1)
+                .map(|n| n.node_id)
+                .collect::<Vec<_>>(),
+            [n2]
+        );
+        debug_assert_eq!(graph.edges_between(n0, n1).collect::<Vec<_>>(), [e0]);
+
+        graph.remove_node(n0);
+        debug_assert_eq!(graph.node_count(), 2);
+        debug_assert_eq!(graph.edge_count(), 1);
+        debug_assert_eq!(*graph.node_data(n0), 'c');
+        debug_assert_eq!(*graph.edge_data(e0), 1);
+        debug_assert_eq!(graph.edge_indices().collect::<Vec<_>>(), [e0]);
+        debug_assert!(!graph.contains_edge_index(e1));
+    }
+
+    #[test]
+    fn test_retain() {
+        fn build_and_retain<
+            Graph: Default + MutableGraphContainer<NodeData = usize, EdgeData = (usize, usize)>,
+        >() -> Graph {
+            let mut graph = Graph::default();
+            graph.extend_nodes(0..10);
+            for i in 0..10 {
+                for j in [1, 3, 3] {
+                    graph.add_edge(i.into(), ((i + j) % 10).into(), (i, j));
+                }
+            }
+            graph.retain_nodes(|graph, node_id| *graph.node_data(node_id) % 3 != 1);
+            graph.retain_edges(|graph, edge_id| {
+                let &(i, j) = graph.edge_data(edge_id);
+                i + j != 5
+            });
+            graph
+        }
+
+        let graph: PetGraph<_, _> = build_and_retain();
+        let reference: VecGraph<_, _> = build_and_retain();
+        debug_assert_eq!(
+            graph
+                .node_indices()
+                .map(|node_id| *graph.node_data(node_id))
+                .collect::<Vec<_>>(),
+            [0, 2, 3, 5, 6, 8, 9]
+        );
+        debug_assert_eq!(graph.edge_count(), 14);
+
+        let mut edges: Vec<_> = graph.edge_indices().map(|e| *graph.edge_data(e)).collect();
+        let mut reference_edges: Vec<_> = reference
+            .edge_indices()
+            .map(|e| *reference.edge_data(e))
+            .collect();
+        edges.sort_unstable();
+        reference_edges.sort_unstable();
+        debug_assert_eq!(edges, reference_edges);
+        for edge_id in graph.edge_indices() {
+            let (i, j) = *graph.edge_data(edge_id);
+            let endpoints = graph.edge_endpoints(edge_id);
+            debug_assert_eq!(*graph.node_data(endpoints.from_node), i);
+            debug_assert_eq!(*graph.node_data(endpoints.to_node), (i + j) % 10);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let mut graph = PetGraph32::default();
+        let n0 = graph.add_node("a".to_string());
+        let n1 = graph.add_node("b".to_string());
+        let e0 = graph.add_edge(n0, n1, 1.5);
+        graph.add_edge(n1, n1, 2.5);
+
+        let json = serde_json::to_string(&graph).unwrap();
+        let deserialised: PetGraph32<String, f64> = serde_json::from_str(&json).unwrap();
+        debug_assert_eq!(deserialised.node_count(), 2);
+        debug_assert_eq!(deserialised.node_data(n1), "b");
+        debug_assert_eq!(*deserialised.edge_data(e0), 1.5);
+        debug_assert!(deserialised.contains_edge_between(n1, n1));
+
+        let json = serde_json::to_string(&[e0]).unwrap();
+        debug_assert_eq!(json, "[0]");
+        debug_assert_eq!(
+            serde_json::from_str::<Vec<crate::index::EdgeIndex<u32>>>(&json).unwrap(),
+            [e0]
+        );
     }
 }
@@ -0,0 +1,428 @@
+use crate::index::GraphIndex;
+use crate::interface::{Edge, GraphBase, ImmutableGraphContainer, Neighbor, StaticGraph};
+use bitvec::bitvec;
+use bitvec::vec::BitVec;
+use petgraph::visit::{
+    Data, EdgeCount, EdgeIndexable, EdgeRef, GraphProp, GraphRef, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeCount, NodeIndexable, VisitMap, Visitable,
+};
+use petgraph::{Directed, Direction};
+use std::iter::Map;
+use std::marker::PhantomData;
+
+/// A wrapper around a reference to any graph implementing our traits that implements the `visit` traits of petgraph.
+///
+/// This allows to run the algorithms from [petgraph::algo] on graph implementations other than [PetGraph](super::PetGraph),
+/// including subgraphs.
+pub struct PetgraphVisitAdapter<'a, Graph>(&'a Graph);
+
+impl<'a, Graph> PetgraphVisitAdapter<'a, Graph> {
+    /// Wrap the given graph.
+    pub fn new(graph: &'a Graph) -> Self {
+        Self(graph)
+    }
+
+    /// Returns the wrapped graph.
+    pub fn graph(&self) -> &'a Graph {
+        self.0
+    }
+}
+
+impl<Graph> Clone for PetgraphVisitAdapter<'_, Graph> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Graph> Copy for PetgraphVisitAdapter<'_, Graph> {}
+
+impl<Graph: GraphBase> petgraph::visit::GraphBase for PetgraphVisitAdapter<'_, Graph> {
+    type EdgeId = Graph::EdgeIndex;
+    type NodeId = Graph::NodeIndex;
+}
+
+impl<Graph: GraphBase> GraphRef for PetgraphVisitAdapter<'_, Graph> {}
+
+impl<Graph: GraphBase> GraphProp for PetgraphVisitAdapter<'_, Graph> {
+    type EdgeType = Directed;
+}
+
+impl<Graph: GraphBase> Data for PetgraphVisitAdapter<'_, Graph> {
+    type NodeWeight = Graph::NodeData;
+    type EdgeWeight = Graph::EdgeData;
+}
+
+impl<Graph: ImmutableGraphContainer> NodeCount for PetgraphVisitAdapter<'_, Graph> {
+    fn node_count(&self) -> usize {
+        self.0.node_count()
+    }
+}
+
+impl<Graph: ImmutableGraphContainer> EdgeCount for PetgraphVisitAdapter<'_, Graph> {
+    fn edge_count(&self) -> usize {
+        self.0.edge_count()
+    }
+}
+
+impl<Graph: ImmutableGraphContainer> NodeIndexable for PetgraphVisitAdapter<'_, Graph> {
+    fn node_bound(&self) -> usize {
+        self.0.node_index_bound()
+    }
+
+    fn to_index(&self, node: Self::NodeId) -> usize {
+        node.as_usize()
+    }
+
+    fn from_index(&self, index: usize) -> Self::NodeId {
+        index.into()
+    }
+}
+
+impl<Graph: ImmutableGraphContainer> EdgeIndexable for PetgraphVisitAdapter<'_, Graph> {
+    fn edge_bound(&self) -> usize {
+        self.0.edge_index_bound()
+    }
+
+    fn to_index(&self, edge: Self::EdgeId) -> usize {
+        edge.as_usize()
+    }
+
+    fn from_index(&self, index: usize) -> Self::EdgeId {
+        index.into()
+    }
+}
+
+/// A visit map for the [PetgraphVisitAdapter] based on a bitvector.
+pub struct PetgraphVisitMap<Graph> {
+    visited: BitVec,
+    graph: PhantomData<Graph>,
+}
+
+impl<Graph: GraphBase> VisitMap<Graph::NodeIndex> for PetgraphVisitMap<Graph> {
+    fn visit(&mut self, node: Graph::NodeIndex) -> bool {
+        let index = node.as_usize();
+        if index >= self.visited.len() {
+            self.visited.resize(index + 1, false);
+        }
+        !self.visited.replace(index, true)
+    }
+
+    fn is_visited(&self, node: &Graph::NodeIndex) -> bool {
+        self.visited
+            .get(node.as_usize())
+            .map(|visited| *visited)
+            .unwrap_or(false)
+    }
+}
+
+impl<Graph: ImmutableGraphContainer> Visitable for PetgraphVisitAdapter<'_, Graph> {
+    type Map = PetgraphVisitMap<Graph>;
+
+    fn visit_map(&self) -> Self::Map {
+        PetgraphVisitMap {
+            visited: bitvec![0; self.node_bound()],
+            graph: Default::default(),
+        }
+    }
+
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.visited.clear();
+        map.visited.resize(self.node_bound(), false);
+    }
+}
+
+/// An edge reference as used by the [PetgraphVisitAdapter].
+pub struct PetgraphVisitEdgeReference<'a, Graph: GraphBase> {
+    edge_id: Graph::EdgeIndex,
+    from_node: Graph::NodeIndex,
+    to_node: Graph::NodeIndex,
+    data: &'a Graph::EdgeData,
+}
+
+impl<Graph: GraphBase> Clone for PetgraphVisitEdgeReference<'_, Graph> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Graph: GraphBase> Copy for PetgraphVisitEdgeReference<'_, Graph> {}
+
+impl<Graph: GraphBase> EdgeRef for PetgraphVisitEdgeReference<'_, Graph> {
+    type NodeId = Graph::NodeIndex;
+    type EdgeId = Graph::EdgeIndex;
+    type Weight = Graph::EdgeData;
+
+    fn source(&self) -> Self::NodeId {
+        self.from_node
+    }
+
+    fn target(&self) -> Self::NodeId {
+        self.to_node
+    }
+
+    fn weight(&self) -> &Self::Weight {
+        self.data
+    }
+
+    fn id(&self) -> Self::EdgeId {
+        self.edge_id
+    }
+}
+
+impl<'a, Graph: ImmutableGraphContainer> PetgraphVisitAdapter<'a, Graph> {
+    fn edge_reference(&self, edge_id: Graph::EdgeIndex) -> PetgraphVisitEdgeReference<'a, Graph> {
+        let Edge { from_node, to_node } = self.0.edge_endpoints(edge_id);
+        PetgraphVisitEdgeReference {
+            edge_id,
+            from_node,
+            to_node,
+            data: self.0.edge_data(edge_id),
+        }
+    }
+}
+
+/// An iterator over the neighbors of a node in either direction.
+pub enum DirectedNeighbors<OutNeighbors, InNeighbors> {
+    /// Iterate over the outgoing neighbors.
+    Outgoing(OutNeighbors),
+    /// Iterate over the incoming neighbors.
+    Incoming(InNeighbors),
+}
+
+impl<Item, OutNeighbors: Iterator<Item = Item>, InNeighbors: Iterator<Item = Item>> Iterator
+    for DirectedNeighbors<OutNeighbors, InNeighbors>
+{
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            DirectedNeighbors::Outgoing(iterator) => iterator.next(),
+            DirectedNeighbors::Incoming(iterator) => iterator.next(),
+        }
+    }
+}
+
+type NeighborNodes<'a, Graph, Neighbors> = Map<
+    Neighbors,
+    fn(
+        Neighbor<<Graph as GraphBase>::NodeIndex, <Graph as GraphBase>::EdgeIndex>,
+    ) -> <Graph as GraphBase>::NodeIndex,
+>;
+
+fn neighbor_node_id<Graph: GraphBase>(
+    neighbor: Neighbor<Graph::NodeIndex, Graph::EdgeIndex>,
+) -> Graph::NodeIndex {
+    neighbor.node_id
+}
+
+fn neighbor_edge_id<Graph: GraphBase>(
+    neighbor: Neighbor<Graph::NodeIndex, Graph::EdgeIndex>,
+) -> Graph::EdgeIndex {
+    neighbor.edge_id
+}
+
+/// An iterator over edge references as used by the [PetgraphVisitAdapter].
+pub struct PetgraphVisitEdges<'a, Graph: GraphBase, Iterator> {
+    adapter: PetgraphVisitAdapter<'a, Graph>,
+    iterator: Iterator,
+}
+
+impl<'a, Graph: ImmutableGraphContainer, EdgeIndices: Iterator<Item = Graph::EdgeIndex>> Iterator
+    for PetgraphVisitEdges<'a, Graph, EdgeIndices>
+{
+    type Item = PetgraphVisitEdgeReference<'a, Graph>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator
+            .next()
+            .map(|edge_id| self.adapter.edge_reference(edge_id))
+    }
+}
+
+impl<'a, Graph: StaticGraph> IntoNeighbors for PetgraphVisitAdapter<'a, Graph> {
+    type Neighbors = NeighborNodes<'a, Graph, Graph::OutNeighbors<'a>>;
+
+    fn neighbors(self, node: Self::NodeId) -> Self::Neighbors {
+        self.0
+            .out_neighbors(node)
+            .map(neighbor_node_id::<Graph> as _)
+    }
+}
+
+impl<'a, Graph: StaticGraph> IntoNeighborsDirected for PetgraphVisitAdapter<'a, Graph> {
+    type NeighborsDirected = DirectedNeighbors<
+        NeighborNodes<'a, Graph, Graph::OutNeighbors<'a>>,
+        NeighborNodes<'a, Graph, Graph::InNeighbors<'a>>,
+    >;
+
+    fn neighbors_directed(
+        self,
+        node: Self::NodeId,
+        direction: Direction,
+    ) -> Self::NeighborsDirected {
+        match direction {
+            Direction::Outgoing => DirectedNeighbors::Outgoing(
+                self.0
+                    .out_neighbors(node)
+                    .map(neighbor_node_id::<Graph> as _),
+            ),
+            Direction::Incoming => DirectedNeighbors::Incoming(
+                self.0
+                    .in_neighbors(node)
+                    .map(neighbor_node_id::<Graph> as _),
+            ),
+        }
+    }
+}
+
+impl<'a, Graph: ImmutableGraphContainer> IntoNodeIdentifiers for PetgraphVisitAdapter<'a, Graph> {
+    type NodeIdentifiers = Graph::NodeIndices<'a>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.0.node_indices()
+    }
+}
+
+/// An iterator over node references as used by the [PetgraphVisitAdapter].
+pub struct PetgraphVisitNodes<'a, Graph: ImmutableGraphContainer> {
+    graph: &'a Graph,
+    iterator: Graph::NodeIndices<'a>,
+}
+
+impl<'a, Graph: ImmutableGraphContainer> Iterator for PetgraphVisitNodes<'a, Graph> {
+    type Item = (Graph::NodeIndex, &'a Graph::NodeData);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator
+            .next()
+            .map(|node_id| (node_id, self.graph.node_data(node_id)))
+    }
+}
+
+impl<'a, Graph: ImmutableGraphContainer> IntoNodeReferences for PetgraphVisitAdapter<'a, Graph> {
+    type NodeRef = (Graph::NodeIndex, &'a Graph::NodeData);
+    type NodeReferences = PetgraphVisitNodes<'a, Graph>;
+
+    fn node_references(self) -> Self::NodeReferences {
+        PetgraphVisitNodes {
+            graph: self.0,
+            iterator: self.0.node_indices(),
+        }
+    }
+}
+
+impl<'a, Graph: ImmutableGraphContainer> IntoEdgeReferences for PetgraphVisitAdapter<'a, Graph> {
+    type EdgeRef = PetgraphVisitEdgeReference<'a, Graph>;
+    type EdgeReferences = PetgraphVisitEdges<'a, Graph, Graph::EdgeIndices<'a>>;
+
+    fn edge_references(self) -> Self::EdgeReferences {
+        PetgraphVisitEdges {
+            adapter: self,
+            iterator: self.0.edge_indices(),
+        }
+    }
+}
+
+type NeighborEdges<'a, Graph, Neighbors> = Map<
+    Neighbors,
+    fn(
+        Neighbor<<Graph as GraphBase>::NodeIndex, <Graph as GraphBase>::EdgeIndex>,
+    ) -> <Graph as GraphBase>::EdgeIndex,
+>;
+
+impl<'a, Graph: StaticGraph> IntoEdges for PetgraphVisitAdapter<'a, Graph> {
+    type Edges = PetgraphVisitEdges<'a, Graph, NeighborEdges<'a, Graph, Graph::OutNeighbors<'a>>>;
+
+    fn edges(self, node: Self::NodeId) -> Self::Edges {
+        PetgraphVisitEdges {
+            adapter: self,
+            iterator: self
+                .0
+                .out_neighbors(node)
+                .map(neighbor_edge_id::<Graph> as _),
+        }
+    }
+}
+
+impl<'a, Graph: StaticGraph> IntoEdgesDirected for PetgraphVisitAdapter<'a, Graph> {
+    type EdgesDirected = PetgraphVisitEdges<
+        'a,
+        Graph,
+        DirectedNeighbors<
+            NeighborEdges<'a, Graph, Graph::OutNeighbors<'a>>,
+            NeighborEdges<'a, Graph, Graph::InNeighbors<'a>>,
+        >,
+    >;
+
+    fn edges_directed(self, node: Self::NodeId, direction: Direction) -> Self::EdgesDirected {
+        let iterator = match direction {
+            Direction::Outgoing => DirectedNeighbors::Outgoing(
+                self.0
+                    .out_neighbors(node)
+                    .map(neighbor_edge_id::<Graph> as _),
+            ),
+            Direction::Incoming => DirectedNeighbors::Incoming(
+                self.0
+                    .in_neighbors(node)
+                    .map(neighbor_edge_id::<Graph> as _),
+            ),
+        };
+        PetgraphVisitEdges {
+            adapter: self,
+            iterator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PetgraphVisitAdapter;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+    use crate::interface::subgraph::MutableSubgraph;
+    use crate::interface::MutableGraphContainer;
+
+    #[test]
+    fn test_petgraph_algorithms_on_subgraph() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        let e0 = graph.add_edge(n[0], n[1], 3);
+        let e1 = graph.add_edge(n[1], n[2], 4);
+        let e2 = graph.add_edge(n[0], n[2], 10);
+        let e3 = graph.add_edge(n[2], n[0], 1);
+        graph.add_edge(n[3], n[4], 1);
+
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        for node in &n[0..3] {
+            subgraph.enable_node(*node);
+        }
+        for edge in [e0, e1, e2] {
+            subgraph.enable_edge(edge);
+        }
+        let adapter = PetgraphVisitAdapter::new(&subgraph);
+
+        let order = petgraph::algo::toposort(adapter, None).unwrap();
+        assert_eq!(order, n[0..3].to_vec());
+
+        let distances = petgraph::algo::dijkstra(adapter, n[0], None, |edge| {
+            *petgraph::visit::EdgeRef::weight(&edge)
+        });
+        assert_eq!(distances[&n[2]], 7);
+        assert!(!distances.contains_key(&n[3]));
+
+        subgraph.enable_edge(e3);
+        let adapter = PetgraphVisitAdapter::new(&subgraph);
+        assert!(petgraph::algo::toposort(adapter, None).is_err());
+        assert_eq!(petgraph::algo::kosaraju_scc(adapter).len(), 1);
+        assert!(petgraph::algo::has_path_connecting(
+            adapter, n[2], n[1], None
+        ));
+        assert!(!petgraph::algo::has_path_connecting(
+            PetgraphVisitAdapter::new(&graph),
+            n[4],
+            n[3],
+            None
+        ));
+    }
+}
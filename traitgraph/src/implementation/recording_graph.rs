@@ -0,0 +1,466 @@
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{DynamicGraph, Edge, GraphBase, MutableGraphContainer};
+use alloc::vec::Vec;
+
+/// A single mutation recorded by a [RecordingGraph].
+#[derive(Debug, Clone)]
+enum Mutation<NodeIndex, EdgeIndex, NodeData, EdgeData> {
+    AddNode {
+        node_id: NodeIndex,
+    },
+    AddEdge {
+        edge_id: EdgeIndex,
+    },
+    RemoveNode {
+        node_id: NodeIndex,
+        node_data: NodeData,
+    },
+    RemoveEdge {
+        edge_id: EdgeIndex,
+        endpoints: Edge<NodeIndex>,
+        edge_data: EdgeData,
+    },
+    ChangeNodeData {
+        node_id: NodeIndex,
+        node_data: NodeData,
+    },
+    ChangeEdgeData {
+        edge_id: EdgeIndex,
+        edge_data: EdgeData,
+    },
+}
+
+type GraphMutation<Graph> = Mutation<
+    <Graph as GraphBase>::NodeIndex,
+    <Graph as GraphBase>::EdgeIndex,
+    <Graph as GraphBase>::NodeData,
+    <Graph as GraphBase>::EdgeData,
+>;
+
+/// A checkpoint in the history of a [RecordingGraph], created by [RecordingGraph::checkpoint].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Checkpoint(usize);
+
+/// A wrapper around a graph that records all mutations, such that they can be undone.
+///
+/// Undoing a mutation restores the indices and data of all nodes and edges exactly,
+/// however the order of the neighbors of a node may differ.
+/// It assumes that the wrapped graph removes nodes and edges by moving the node or edge with the highest index into the place of the removed one,
+/// as done e.g. by [PetGraph](crate::implementation::petgraph_impl::PetGraph),
+/// or that it does not change the indices of the remaining nodes and edges at all.
+///
+/// Since undoing a mutation needs the old data, node and edge data are cloned when they are removed or borrowed mutably.
+pub struct RecordingGraph<Graph: GraphBase> {
+    graph: Graph,
+    mutations: Vec<GraphMutation<Graph>>,
+    /// The index into `mutations` at which each recorded operation starts.
+    operations: Vec<usize>,
+}
+
+impl<Graph: GraphBase + Clone> Clone for RecordingGraph<Graph>
+where
+    Graph::NodeData: Clone,
+    Graph::EdgeData: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph.clone(),
+            mutations: self.mutations.clone(),
+            operations: self.operations.clone(),
+        }
+    }
+}
+
+impl<Graph: GraphBase + core::fmt::Debug> core::fmt::Debug for RecordingGraph<Graph>
+where
+    Graph::NodeData: core::fmt::Debug,
+    Graph::EdgeData: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RecordingGraph")
+            .field("graph", &self.graph)
+            .field("mutations", &self.mutations)
+            .field("operations", &self.operations)
+            .finish()
+    }
+}
+
+impl<Graph: GraphBase> RecordingGraph<Graph> {
+    /// Wraps the given graph with an empty history.
+    pub fn new(graph: Graph) -> Self {
+        Self {
+            graph,
+            mutations: Vec::new(),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns the wrapped graph, discarding the history.
+    pub fn into_inner(self) -> Graph {
+        self.graph
+    }
+
+    /// Returns the amount of recorded operations that can be undone.
+    pub fn history_len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Discards the history, such that the current state cannot be rolled back anymore.
+    pub fn clear_history(&mut self) {
+        self.mutations.clear();
+        self.operations.clear();
+    }
+
+    /// Returns a checkpoint of the current state that can be restored with [rollback_to](Self::rollback_to).
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.operations.len())
+    }
+
+    fn begin_operation(&mut self) {
+        self.operations.push(self.mutations.len());
+    }
+}
+
+impl<Graph: DynamicGraph> RecordingGraph<Graph>
+where
+    Graph::NodeData: Clone,
+    Graph::EdgeData: Clone,
+{
+    /// Undoes the last `n` recorded operations, or all of them if fewer were recorded.
+    pub fn undo(&mut self, n: usize) {
+        for _ in 0..n {
+            let Some(start) = self.operations.pop() else {
+                return;
+            };
+            while self.mutations.len() > start {
+                let mutation = self.mutations.pop().unwrap();
+                self.undo_mutation(mutation);
+            }
+        }
+    }
+
+    /// Undoes all operations recorded after the given checkpoint was created.
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) {
+        debug_assert!(
+            checkpoint.0 <= self.operations.len(),
+            "The checkpoint was already rolled back"
+        );
+        self.undo(self.operations.len().saturating_sub(checkpoint.0));
+    }
+
+    fn undo_mutation(&mut self, mutation: GraphMutation<Graph>) {
+        match mutation {
+            Mutation::AddNode { node_id } => {
+                debug_assert_eq!(self.graph.node_indices().last(), Some(node_id));
+                self.graph.remove_node(node_id);
+            }
+            Mutation::AddEdge { edge_id } => {
+                debug_assert_eq!(self.graph.edge_indices().last(), Some(edge_id));
+                self.graph.remove_edge(edge_id);
+            }
+            Mutation::RemoveNode { node_id, node_data } => {
+                let added_node_id = self.graph.add_node(node_data);
+                if added_node_id != node_id {
+                    // The node at `added_node_id` was moved to `node_id` when removing the node, so we move it back.
+                    let moved_node_data = self.graph.node_data(node_id).clone();
+                    let node_data = core::mem::replace(
+                        self.graph.node_data_mut(added_node_id),
+                        moved_node_data,
+                    );
+                    *self.graph.node_data_mut(node_id) = node_data;
+
+                    let mut incident_edges: Vec<_> = self
+                        .graph
+                        .out_neighbors(node_id)
+                        .chain(self.graph.in_neighbors(node_id))
+                        .map(|neighbor| neighbor.edge_id)
+                        .collect();
+                    incident_edges.sort_unstable();
+                    incident_edges.dedup();
+                    for edge_id in incident_edges {
+                        let Edge { from_node, to_node } = self.graph.edge_endpoints(edge_id);
+                        let replace = |n| if n == node_id { added_node_id } else { n };
+                        self.replace_edge(edge_id, replace(from_node), replace(to_node));
+                    }
+                }
+            }
+            Mutation::RemoveEdge {
+                edge_id,
+                endpoints: Edge { from_node, to_node },
+                edge_data,
+            } => {
+                let added_edge_id = self.graph.add_edge(from_node, to_node, edge_data);
+                if added_edge_id != edge_id {
+                    self.swap_edge_with_last(edge_id);
+                }
+            }
+            Mutation::ChangeNodeData { node_id, node_data } => {
+                *self.graph.node_data_mut(node_id) = node_data;
+            }
+            Mutation::ChangeEdgeData { edge_id, edge_data } => {
+                *self.graph.edge_data_mut(edge_id) = edge_data;
+            }
+        }
+    }
+
+    /// Changes the endpoints of the given edge without changing the indices of any edges.
+    fn replace_edge(
+        &mut self,
+        edge_id: Graph::EdgeIndex,
+        from_node: Graph::NodeIndex,
+        to_node: Graph::NodeIndex,
+    ) {
+        let edge_data = self.graph.remove_edge(edge_id).unwrap();
+        let added_edge_id = self.graph.add_edge(from_node, to_node, edge_data);
+        if added_edge_id != edge_id {
+            self.swap_edge_with_last(edge_id);
+        }
+    }
+
+    /// Moves the edge at the given index to the last index, and the edge at the last index to the given index.
+    fn swap_edge_with_last(&mut self, edge_id: Graph::EdgeIndex) {
+        let Edge { from_node, to_node } = self.graph.edge_endpoints(edge_id);
+        let edge_data = self.graph.remove_edge(edge_id).unwrap();
+        self.graph.add_edge(from_node, to_node, edge_data);
+    }
+}
+
+impl<Graph: DynamicGraph> MutableGraphContainer for RecordingGraph<Graph>
+where
+    Graph::NodeData: Clone,
+    Graph::EdgeData: Clone,
+{
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        self.begin_operation();
+        self.mutations.push(Mutation::ChangeNodeData {
+            node_id,
+            node_data: self.graph.node_data(node_id).clone(),
+        });
+        self.graph.node_data_mut(node_id)
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        self.begin_operation();
+        self.mutations.push(Mutation::ChangeEdgeData {
+            edge_id,
+            edge_data: self.graph.edge_data(edge_id).clone(),
+        });
+        self.graph.edge_data_mut(edge_id)
+    }
+
+    fn add_node(&mut self, node_data: Self::NodeData) -> Self::NodeIndex {
+        self.begin_operation();
+        let node_id = self.graph.add_node(node_data);
+        self.mutations.push(Mutation::AddNode { node_id });
+        node_id
+    }
+
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: Self::EdgeData,
+    ) -> Self::EdgeIndex {
+        self.begin_operation();
+        let edge_id = self.graph.add_edge(from, to, edge_data);
+        self.mutations.push(Mutation::AddEdge { edge_id });
+        edge_id
+    }
+
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.graph.reserve_nodes(additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.graph.reserve_edges(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.graph.shrink_to_fit();
+    }
+
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData> {
+        if !self.graph.contains_node_index(node_id) {
+            return None;
+        }
+        self.begin_operation();
+
+        // Remove incident edges first such that their removal is recorded.
+        // Removing them in descending order ensures that no other incident edge is moved.
+        let mut incident_edges: Vec<_> = self
+            .graph
+            .out_neighbors(node_id)
+            .chain(self.graph.in_neighbors(node_id))
+            .map(|neighbor| neighbor.edge_id)
+            .collect();
+        incident_edges.sort_unstable();
+        incident_edges.dedup();
+        for edge_id in incident_edges.into_iter().rev() {
+            self.record_remove_edge(edge_id);
+        }
+
+        let node_data = self.graph.remove_node(node_id)?;
+        self.mutations.push(Mutation::RemoveNode {
+            node_id,
+            node_data: node_data.clone(),
+        });
+        Some(node_data)
+    }
+
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<Self::EdgeData> {
+        if !self.graph.contains_edge_index(edge_id) {
+            return None;
+        }
+        self.begin_operation();
+        self.record_remove_edge(edge_id)
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        edge_ids.windows(2).for_each(|w| debug_assert!(w[0] < w[1]));
+        self.begin_operation();
+
+        for edge_id in edge_ids.iter().rev() {
+            self.record_remove_edge(*edge_id);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.begin_operation();
+        let edge_ids: Vec<_> = self.graph.edge_indices().collect();
+        for edge_id in edge_ids.into_iter().rev() {
+            self.record_remove_edge(edge_id);
+        }
+        let node_ids: Vec<_> = self.graph.node_indices().collect();
+        for node_id in node_ids.into_iter().rev() {
+            let node_data = self.graph.remove_node(node_id).unwrap();
+            self.mutations
+                .push(Mutation::RemoveNode { node_id, node_data });
+        }
+    }
+}
+
+impl<Graph: DynamicGraph> RecordingGraph<Graph>
+where
+    Graph::EdgeData: Clone,
+{
+    fn record_remove_edge(&mut self, edge_id: Graph::EdgeIndex) -> Option<Graph::EdgeData> {
+        let endpoints = self.graph.edge_endpoints(edge_id);
+        let edge_data = self.graph.remove_edge(edge_id)?;
+        self.mutations.push(Mutation::RemoveEdge {
+            edge_id,
+            endpoints,
+            edge_data: edge_data.clone(),
+        });
+        Some(edge_data)
+    }
+}
+
+delegate_graph!(impl[Graph: GraphBase] RecordingGraph<Graph> => graph: Graph);
+
+impl<Graph: GraphBase> SubgraphBase for RecordingGraph<Graph> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::RecordingGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::index::GraphIndex;
+    use crate::interface::{Edge, ImmutableGraphContainer, MutableGraphContainer};
+
+    type Snapshot = (Vec<i32>, Vec<(usize, usize, i32)>);
+
+    fn snapshot<Graph: ImmutableGraphContainer<NodeData = i32, EdgeData = i32>>(
+        graph: &Graph,
+    ) -> Snapshot {
+        (
+            graph
+                .node_indices()
+                .map(|node_id| *graph.node_data(node_id))
+                .collect(),
+            graph
+                .edge_indices()
+                .map(|edge_id| {
+                    let Edge { from_node, to_node } = graph.edge_endpoints(edge_id);
+                    (
+                        from_node.as_usize(),
+                        to_node.as_usize(),
+                        *graph.edge_data(edge_id),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn create_graph() -> RecordingGraph<PetGraph<i32, i32>> {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        for (i, (from, to)) in [
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 0),
+            (1, 1),
+            (4, 2),
+            (0, 4),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            graph.add_edge(n[from], n[to], 10 + i as i32);
+        }
+        RecordingGraph::new(graph)
+    }
+
+    #[test]
+    fn test_undo_single_operations() {
+        let mut graph = create_graph();
+        let original = snapshot(&graph);
+
+        graph.remove_node(1.into());
+        graph.remove_edge(0.into());
+        *graph.node_data_mut(2.into()) = 20;
+        *graph.edge_data_mut(1.into()) = 30;
+        let n = graph.add_node(5);
+        graph.add_edge(n, 0.into(), 40);
+        debug_assert_eq!(graph.history_len(), 6);
+
+        graph.undo(1);
+        debug_assert_eq!(graph.edge_count(), 4);
+        graph.undo(5);
+        debug_assert_eq!(snapshot(&graph), original);
+        debug_assert_eq!(graph.history_len(), 0);
+    }
+
+    #[test]
+    fn test_rollback_to_checkpoint() {
+        let mut graph = create_graph();
+        let original = snapshot(&graph);
+        let checkpoint = graph.checkpoint();
+
+        graph.remove_node(0.into());
+        let intermediate = snapshot(&graph);
+        let intermediate_checkpoint = graph.checkpoint();
+        graph.remove_node(2.into());
+        graph.remove_edges_sorted(&[0.into(), 1.into()]);
+        graph.remove_node(0.into());
+
+        graph.rollback_to(intermediate_checkpoint);
+        debug_assert_eq!(snapshot(&graph), intermediate);
+
+        graph.clear();
+        debug_assert!(graph.is_empty());
+        graph.rollback_to(checkpoint);
+        debug_assert_eq!(snapshot(&graph), original);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+
+/// A view of a graph with the direction of all edges reversed.
+///
+/// The view does not copy the graph.
+/// The outgoing neighbors of a node in the view are its incoming neighbors in the wrapped graph and vice versa,
+/// and [`edge_endpoints`](ImmutableGraphContainer::edge_endpoints) returns the endpoints swapped.
+/// Node and edge indices and data are the same as in the wrapped graph.
+///
+/// This allows to run forward algorithms on the transposed graph without materialising it.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::reversed_graph::ReversedGraph;
+/// use traitgraph::interface::{MutableGraphContainer, NavigableGraph};
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// graph.add_edge(a, b, ());
+///
+/// let reversed = ReversedGraph::new(&graph);
+/// assert!(reversed.contains_edge_between(b, a));
+/// assert!(!reversed.contains_edge_between(a, b));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ReversedGraph<'a, Graph> {
+    graph: &'a Graph,
+}
+
+impl<'a, Graph> ReversedGraph<'a, Graph> {
+    /// Creates a reversed view of the given graph.
+    pub fn new(graph: &'a Graph) -> Self {
+        Self { graph }
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &'a Graph {
+        self.graph
+    }
+}
+
+impl<Graph> Clone for ReversedGraph<'_, Graph> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Graph> Copy for ReversedGraph<'_, Graph> {}
+
+impl<Graph: GraphBase> GraphBase for ReversedGraph<'_, Graph> {
+    type NodeData = Graph::NodeData;
+    type EdgeData = Graph::EdgeData;
+    type OptionalNodeIndex = Graph::OptionalNodeIndex;
+    type OptionalEdgeIndex = Graph::OptionalEdgeIndex;
+    type NodeIndex = Graph::NodeIndex;
+    type EdgeIndex = Graph::EdgeIndex;
+}
+
+impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for ReversedGraph<'_, Graph> {
+    type NodeIndices<'a>
+        = Graph::NodeIndices<'a>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = Graph::EdgeIndices<'a>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+    type NodeIndicesCopied = Graph::NodeIndicesCopied;
+    type EdgeIndicesCopied = Graph::EdgeIndicesCopied;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        self.graph.node_indices()
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        self.graph.edge_indices()
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        self.graph.node_indices_copied()
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        self.graph.edge_indices_copied()
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        self.graph.contains_node_index(node_id)
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        self.graph.contains_edge_index(edge_id)
+    }
+
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.graph.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.graph.edge_index_bound()
+    }
+
+    fn generation(&self) -> Option<u64> {
+        self.graph.generation()
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        self.graph.node_data(node_id)
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        self.graph.edge_data(edge_id)
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        let Edge { from_node, to_node } = self.graph.edge_endpoints(edge_id);
+        Edge {
+            from_node: to_node,
+            to_node: from_node,
+        }
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<Graph: NavigableGraph> NavigableGraph for ReversedGraph<'_, Graph> {
+    type OutNeighbors<'a>
+        = Graph::InNeighbors<'a>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = Graph::OutNeighbors<'a>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = Graph::EdgesBetween<'a>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        self.graph.in_neighbors(node_id)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        self.graph.out_neighbors(node_id)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        self.graph.edges_between(to_node_id, from_node_id)
+    }
+
+    fn contains_edge_between(&self, from: Self::NodeIndex, to: Self::NodeIndex) -> bool {
+        self.graph.contains_edge_between(to, from)
+    }
+
+    fn edge_count_between(&self, from: Self::NodeIndex, to: Self::NodeIndex) -> usize {
+        self.graph.edge_count_between(to, from)
+    }
+
+    fn out_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.graph.in_degree(node_id)
+    }
+
+    fn in_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.graph.out_degree(node_id)
+    }
+}
+
+impl<Graph: GraphBase> SubgraphBase for ReversedGraph<'_, Graph> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::ReversedGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::interface::{Edge, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_reversed_adjacency() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        let e0 = graph.add_edge(n[0], n[1], 'a');
+        let e1 = graph.add_edge(n[0], n[2], 'b');
+        graph.add_edge(n[2], n[1], 'c');
+
+        let reversed = ReversedGraph::new(&graph);
+        debug_assert_eq!(reversed.node_count(), 3);
+        debug_assert_eq!(reversed.edge_count(), 3);
+        debug_assert_eq!(*reversed.edge_data(e1), 'b');
+        debug_assert_eq!(
+            reversed.edge_endpoints(e0),
+            Edge {
+                from_node: n[1],
+                to_node: n[0]
+            }
+        );
+        debug_assert_eq!(reversed.out_degree(n[0]), 0);
+        debug_assert_eq!(reversed.in_degree(n[0]), 2);
+        debug_assert_eq!(
+            reversed
+                .out_neighbors(n[1])
+                .map(|neighbor| neighbor.node_id)
+                .collect::<std::collections::BTreeSet<_>>(),
+            [n[0], n[2]].into()
+        );
+        debug_assert_eq!(reversed.edges_between(n[1], n[0]).collect::<Vec<_>>(), [e0]);
+        debug_assert!(!reversed.contains_edge_between(n[0], n[1]));
+
+        let twice = ReversedGraph::new(&reversed);
+        debug_assert!(twice.contains_edge_between(n[0], n[1]));
+    }
+}
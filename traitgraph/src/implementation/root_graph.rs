@@ -0,0 +1,70 @@
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::GraphBase;
+
+/// A wrapper that allows any graph to serve as the root of subgraphs.
+///
+/// Subgraphs like [BitVectorSubgraph](super::subgraphs::bit_vector_subgraph::BitVectorSubgraph) require their parent to implement [SubgraphBase].
+/// The graph implementations of this crate do so already, but custom graph types may not.
+/// This wrapper forwards [ImmutableGraphContainer](crate::interface::ImmutableGraphContainer) and [NavigableGraph](crate::interface::NavigableGraph)
+/// to the wrapped graph and implements [SubgraphBase] with itself as root.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::delegate_graph;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::root_graph::RootGraph;
+/// use traitgraph::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+/// use traitgraph::interface::subgraph::MutableSubgraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// struct Roads<Graph> {
+///     graph: Graph,
+/// }
+///
+/// delegate_graph!(impl[Graph] Roads<Graph> => graph: Graph);
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// graph.add_edge(a, b, 5);
+///
+/// let roads = RootGraph::new(Roads { graph });
+/// let mut subgraph = BitVectorSubgraph::new_empty(&roads);
+/// subgraph.enable_node(b);
+/// assert_eq!(subgraph.node_count(), 1);
+/// assert_eq!(roads.graph().graph.node_count(), 2);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RootGraph<Graph> {
+    graph: Graph,
+}
+
+impl<Graph> RootGraph<Graph> {
+    /// Wraps the given graph.
+    pub fn new(graph: Graph) -> Self {
+        Self { graph }
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns the wrapped graph.
+    pub fn into_inner(self) -> Graph {
+        self.graph
+    }
+}
+
+delegate_graph!(impl[Graph] RootGraph<Graph> => graph: Graph);
+
+impl<Graph: GraphBase> SubgraphBase for RootGraph<Graph> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
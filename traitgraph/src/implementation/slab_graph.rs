@@ -0,0 +1,654 @@
+use crate::implementation::generation::GraphGeneration;
+use crate::index::{EdgeIndex, GraphIndex, NodeIndex};
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{
+    Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, MutableGraphContainer,
+    NavigableGraph, Neighbor,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+enum Slot<T> {
+    Occupied(T),
+    Vacant { next_vacant: Option<usize> },
+}
+
+impl<T> Slot<T> {
+    fn as_ref(&self) -> Option<&T> {
+        match self {
+            Slot::Occupied(value) => Some(value),
+            Slot::Vacant { .. } => None,
+        }
+    }
+
+    fn as_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Slot::Occupied(value) => Some(value),
+            Slot::Vacant { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SlabGraphNode<NodeData> {
+    data: NodeData,
+    out_edges: Vec<usize>,
+    in_edges: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct SlabGraphEdge<EdgeData> {
+    data: EdgeData,
+    from_node: usize,
+    to_node: usize,
+    /// The position of this edge in the `out_edges` of `from_node`.
+    out_position: usize,
+    /// The position of this edge in the `in_edges` of `to_node`.
+    in_position: usize,
+}
+
+/// A slab of slots with a free list of vacant slots.
+#[derive(Debug, Clone)]
+struct Slab<T> {
+    slots: Vec<Slot<T>>,
+    first_vacant: Option<usize>,
+    len: usize,
+}
+
+impl<T> Slab<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            first_vacant: None,
+            len: 0,
+        }
+    }
+
+    /// Reserves space for `additional` more values, taking into account the vacant slots.
+    fn reserve(&mut self, additional: usize) {
+        let vacant = self.slots.len() - self.len;
+        self.slots.reserve(additional.saturating_sub(vacant));
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.slots.shrink_to_fit();
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.slots.get(index).and_then(Slot::as_ref)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.slots.get_mut(index).and_then(Slot::as_mut)
+    }
+
+    /// Inserts the value into the most recently vacated slot, or into a new slot if there is none.
+    fn insert(&mut self, value: T) -> usize {
+        self.len += 1;
+        if let Some(index) = self.first_vacant {
+            let Slot::Vacant { next_vacant } = self.slots[index] else {
+                unreachable!("free list contains an occupied slot");
+            };
+            self.first_vacant = next_vacant;
+            self.slots[index] = Slot::Occupied(value);
+            index
+        } else {
+            self.slots.push(Slot::Occupied(value));
+            self.slots.len() - 1
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        let slot = self.slots.get_mut(index)?;
+        slot.as_ref()?;
+        let vacant = Slot::Vacant {
+            next_vacant: self.first_vacant,
+        };
+        let Slot::Occupied(value) = core::mem::replace(slot, vacant) else {
+            unreachable!("slot was checked to be occupied");
+        };
+        self.first_vacant = Some(index);
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn clear(&mut self) {
+        self.slots.clear();
+        self.first_vacant = None;
+        self.len = 0;
+    }
+}
+
+/// A graph that stores its nodes and edges in slabs, optimised for frequent insertions and removals.
+///
+/// Removing a node or an edge leaves a vacant slot behind that is reused by the next insertion,
+/// so the memory used by the graph does not grow under churn.
+/// Removing an edge takes constant time, and removing a node takes time linear in its degree.
+/// Removals never change the indices of other nodes and edges, but an index of a removed node or edge
+/// may refer to a different node or edge after later insertions.
+///
+/// The indices of this graph are not necessarily consecutive,
+/// and vectors indexed by node or edge indices need to have length [`node_index_bound`](ImmutableGraphContainer::node_index_bound)
+/// or [`edge_index_bound`](ImmutableGraphContainer::edge_index_bound).
+/// The order of the neighbors of a node may change when an incident edge is removed.
+/// The amount of vacant slots can be queried with [`fragmentation`](SlabGraph::fragmentation).
+#[derive(Debug, Clone)]
+pub struct SlabGraph<NodeData, EdgeData> {
+    nodes: Slab<SlabGraphNode<NodeData>>,
+    edges: Slab<SlabGraphEdge<EdgeData>>,
+    generation: GraphGeneration,
+}
+
+/// Statistics about the slot usage of a [SlabGraph], as returned by [`SlabGraph::fragmentation`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SlabGraphFragmentation {
+    /// The total amount of node slots, i.e. the node index bound.
+    pub node_slots: usize,
+    /// The amount of node slots that are vacant and will be reused by the next node insertions.
+    pub vacant_node_slots: usize,
+    /// The total amount of edge slots, i.e. the edge index bound.
+    pub edge_slots: usize,
+    /// The amount of edge slots that are vacant and will be reused by the next edge insertions.
+    pub vacant_edge_slots: usize,
+}
+
+impl SlabGraphFragmentation {
+    /// Returns the fraction of node slots that are vacant, or zero if there are no node slots.
+    pub fn node_fragmentation(&self) -> f64 {
+        fraction(self.vacant_node_slots, self.node_slots)
+    }
+
+    /// Returns the fraction of edge slots that are vacant, or zero if there are no edge slots.
+    pub fn edge_fragmentation(&self) -> f64 {
+        fraction(self.vacant_edge_slots, self.edge_slots)
+    }
+}
+
+fn fraction(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+impl<NodeData, EdgeData> SlabGraph<NodeData, EdgeData> {
+    /// Creates a new empty graph.
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    /// Creates a new empty graph with space for the given amount of nodes and edges.
+    pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> Self {
+        Self {
+            nodes: Slab::with_capacity(node_capacity),
+            edges: Slab::with_capacity(edge_capacity),
+            generation: GraphGeneration::new(),
+        }
+    }
+
+    /// Returns statistics about the vacant slots of this graph.
+    pub fn fragmentation(&self) -> SlabGraphFragmentation {
+        SlabGraphFragmentation {
+            node_slots: self.nodes.slots.len(),
+            vacant_node_slots: self.nodes.slots.len() - self.nodes.len,
+            edge_slots: self.edges.slots.len(),
+            vacant_edge_slots: self.edges.slots.len() - self.edges.len,
+        }
+    }
+
+    fn node(&self, node_id: usize) -> &SlabGraphNode<NodeData> {
+        self.nodes
+            .get(node_id)
+            .unwrap_or_else(|| panic!("node {node_id} does not exist"))
+    }
+
+    fn node_mut(&mut self, node_id: usize) -> &mut SlabGraphNode<NodeData> {
+        self.nodes
+            .get_mut(node_id)
+            .unwrap_or_else(|| panic!("node {node_id} does not exist"))
+    }
+
+    fn edge(&self, edge_id: usize) -> &SlabGraphEdge<EdgeData> {
+        self.edges
+            .get(edge_id)
+            .unwrap_or_else(|| panic!("edge {edge_id} does not exist"))
+    }
+}
+
+impl<NodeData, EdgeData> Default for SlabGraph<NodeData, EdgeData> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<NodeData, EdgeData> GraphBase for SlabGraph<NodeData, EdgeData> {
+    type NodeData = NodeData;
+    type EdgeData = EdgeData;
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<usize>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<usize>;
+    type NodeIndex = NodeIndex<usize>;
+    type EdgeIndex = EdgeIndex<usize>;
+}
+
+/// An iterator over the node indices of a [SlabGraph].
+pub struct SlabGraphNodeIndices<'a, NodeData, NodeIndex> {
+    slots: core::iter::Enumerate<core::slice::Iter<'a, Slot<SlabGraphNode<NodeData>>>>,
+    index_type: PhantomData<NodeIndex>,
+}
+
+impl<NodeData, NodeIndex: From<usize>> Iterator for SlabGraphNodeIndices<'_, NodeData, NodeIndex> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots
+            .find(|(_, slot)| slot.as_ref().is_some())
+            .map(|(index, _)| index.into())
+    }
+}
+
+/// An iterator over the edge indices of a [SlabGraph].
+pub struct SlabGraphEdgeIndices<'a, EdgeData, EdgeIndex> {
+    slots: core::iter::Enumerate<core::slice::Iter<'a, Slot<SlabGraphEdge<EdgeData>>>>,
+    index_type: PhantomData<EdgeIndex>,
+}
+
+impl<EdgeData, EdgeIndex: From<usize>> Iterator for SlabGraphEdgeIndices<'_, EdgeData, EdgeIndex> {
+    type Item = EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots
+            .find(|(_, slot)| slot.as_ref().is_some())
+            .map(|(index, _)| index.into())
+    }
+}
+
+impl<NodeData, EdgeData> ImmutableGraphContainer for SlabGraph<NodeData, EdgeData> {
+    type NodeIndices<'a>
+        = SlabGraphNodeIndices<'a, NodeData, Self::NodeIndex>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = SlabGraphEdgeIndices<'a, EdgeData, Self::EdgeIndex>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+
+    type NodeIndicesCopied = alloc::vec::IntoIter<<Self as GraphBase>::NodeIndex>;
+    type EdgeIndicesCopied = alloc::vec::IntoIter<<Self as GraphBase>::EdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        SlabGraphNodeIndices {
+            slots: self.nodes.slots.iter().enumerate(),
+            index_type: PhantomData,
+        }
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        SlabGraphEdgeIndices {
+            slots: self.edges.slots.iter().enumerate(),
+            index_type: PhantomData,
+        }
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        self.node_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        self.edge_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        self.nodes.get(node_id.as_usize()).is_some()
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        self.edges.get(edge_id.as_usize()).is_some()
+    }
+
+    fn node_count(&self) -> usize {
+        self.nodes.len
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges.len
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.nodes.slots.len()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.edges.slots.len()
+    }
+
+    fn generation(&self) -> Option<u64> {
+        Some(self.generation.get())
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        &self.node(node_id.as_usize()).data
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        &self.edge(edge_id.as_usize()).data
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        let edge = self.edge(edge_id.as_usize());
+        Edge {
+            from_node: edge.from_node.into(),
+            to_node: edge.to_node.into(),
+        }
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<NodeData, EdgeData> MutableGraphContainer for SlabGraph<NodeData, EdgeData> {
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        &mut self.node_mut(node_id.as_usize()).data
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        &mut self
+            .edges
+            .get_mut(edge_id.as_usize())
+            .unwrap_or_else(|| panic!("edge {edge_id:?} does not exist"))
+            .data
+    }
+
+    /// Adds a new node, reusing the most recently vacated node slot if there is one.
+    fn add_node(&mut self, node_data: Self::NodeData) -> Self::NodeIndex {
+        self.generation.advance();
+        self.nodes
+            .insert(SlabGraphNode {
+                data: node_data,
+                out_edges: Vec::new(),
+                in_edges: Vec::new(),
+            })
+            .into()
+    }
+
+    /// Adds a new edge, reusing the most recently vacated edge slot if there is one.
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: Self::EdgeData,
+    ) -> Self::EdgeIndex {
+        let (from_node, to_node) = (from.as_usize(), to.as_usize());
+        debug_assert!(self.contains_node_index(from) && self.contains_node_index(to));
+        let out_position = self.node(from_node).out_edges.len();
+        let in_position = self.node(to_node).in_edges.len();
+        let edge_id = self.edges.insert(SlabGraphEdge {
+            data: edge_data,
+            from_node,
+            to_node,
+            out_position,
+            in_position,
+        });
+        self.node_mut(from_node).out_edges.push(edge_id);
+        self.node_mut(to_node).in_edges.push(edge_id);
+        self.generation.advance();
+        edge_id.into()
+    }
+
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.edges.reserve(additional);
+    }
+
+    /// Shrinks the capacity of the slabs to their amount of slots.
+    /// Vacant slots are kept, so the capacity may remain larger than the amount of nodes and edges.
+    fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+    }
+
+    /// Removes the node with the given id from the graph, together with all its incident edges.
+    /// This takes time linear in the degree of the node.
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData> {
+        let node_id = node_id.as_usize();
+        self.nodes.get(node_id)?;
+        while let Some(&edge_id) = self.node(node_id).out_edges.last() {
+            self.remove_edge(edge_id.into());
+        }
+        while let Some(&edge_id) = self.node(node_id).in_edges.last() {
+            self.remove_edge(edge_id.into());
+        }
+        self.generation.advance();
+        self.nodes.remove(node_id).map(|node| node.data)
+    }
+
+    /// Removes the edge with the given id from the graph in constant time.
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<Self::EdgeData> {
+        let edge = self.edges.remove(edge_id.as_usize())?;
+
+        let out_edges = &mut self.nodes.get_mut(edge.from_node).unwrap().out_edges;
+        out_edges.swap_remove(edge.out_position);
+        if let Some(&moved_edge_id) = out_edges.get(edge.out_position) {
+            self.edges.get_mut(moved_edge_id).unwrap().out_position = edge.out_position;
+        }
+        let in_edges = &mut self.nodes.get_mut(edge.to_node).unwrap().in_edges;
+        in_edges.swap_remove(edge.in_position);
+        if let Some(&moved_edge_id) = in_edges.get(edge.in_position) {
+            self.edges.get_mut(moved_edge_id).unwrap().in_position = edge.in_position;
+        }
+
+        self.generation.advance();
+        Some(edge.data)
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        edge_ids.windows(2).for_each(|w| debug_assert!(w[0] < w[1]));
+
+        for edge_id in edge_ids {
+            self.remove_edge(*edge_id);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+        self.generation.advance();
+    }
+}
+
+/// An iterator over the neighbors of a node in a [SlabGraph].
+pub struct SlabGraphNeighbors<'a, EdgeData, NodeIndex, EdgeIndex> {
+    edge_ids: core::slice::Iter<'a, usize>,
+    edges: &'a [Slot<SlabGraphEdge<EdgeData>>],
+    outgoing: bool,
+    index_types: PhantomData<(NodeIndex, EdgeIndex)>,
+}
+
+impl<EdgeData, NodeIndex: From<usize>, EdgeIndex: From<usize>> Iterator
+    for SlabGraphNeighbors<'_, EdgeData, NodeIndex, EdgeIndex>
+{
+    type Item = Neighbor<NodeIndex, EdgeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edge_ids.next().map(|&edge_id| {
+            let edge = self.edges[edge_id].as_ref().unwrap();
+            Neighbor {
+                edge_id: edge_id.into(),
+                node_id: if self.outgoing {
+                    edge.to_node
+                } else {
+                    edge.from_node
+                }
+                .into(),
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edge_ids.size_hint()
+    }
+}
+
+/// An iterator over the edges between two nodes in a [SlabGraph].
+pub struct SlabGraphEdgesBetween<'a, EdgeData, EdgeIndex> {
+    edge_ids: core::slice::Iter<'a, usize>,
+    edges: &'a [Slot<SlabGraphEdge<EdgeData>>],
+    to_node: usize,
+    edge_index_type: PhantomData<EdgeIndex>,
+}
+
+impl<EdgeData, EdgeIndex: From<usize>> Iterator for SlabGraphEdgesBetween<'_, EdgeData, EdgeIndex> {
+    type Item = EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (edges, to_node) = (self.edges, self.to_node);
+        self.edge_ids
+            .find(|&&edge_id| edges[edge_id].as_ref().unwrap().to_node == to_node)
+            .map(|&edge_id| edge_id.into())
+    }
+}
+
+impl<NodeData, EdgeData> NavigableGraph for SlabGraph<NodeData, EdgeData> {
+    type OutNeighbors<'a>
+        = SlabGraphNeighbors<
+        'a,
+        EdgeData,
+        <Self as GraphBase>::NodeIndex,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = SlabGraphNeighbors<
+        'a,
+        EdgeData,
+        <Self as GraphBase>::NodeIndex,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = SlabGraphEdgesBetween<'a, EdgeData, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        SlabGraphNeighbors {
+            edge_ids: self.node(node_id.as_usize()).out_edges.iter(),
+            edges: &self.edges.slots,
+            outgoing: true,
+            index_types: PhantomData,
+        }
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        SlabGraphNeighbors {
+            edge_ids: self.node(node_id.as_usize()).in_edges.iter(),
+            edges: &self.edges.slots,
+            outgoing: false,
+            index_types: PhantomData,
+        }
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        SlabGraphEdgesBetween {
+            edge_ids: self.node(from_node_id.as_usize()).out_edges.iter(),
+            edges: &self.edges.slots,
+            to_node: to_node_id.as_usize(),
+            edge_index_type: PhantomData,
+        }
+    }
+
+    fn out_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.node(node_id.as_usize()).out_edges.len()
+    }
+
+    fn in_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.node(node_id.as_usize()).in_edges.len()
+    }
+}
+
+impl<NodeData, EdgeData> SubgraphBase for SlabGraph<NodeData, EdgeData> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SlabGraph, SlabGraphFragmentation};
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_slot_reuse() {
+        let mut graph = SlabGraph::new();
+        let n: Vec<_> = ['a', 'b', 'c', 'd'].map(|data| graph.add_node(data)).into();
+        let e01 = graph.add_edge(n[0], n[1], 0);
+        let e12 = graph.add_edge(n[1], n[2], 1);
+        let e22 = graph.add_edge(n[2], n[2], 2);
+        let e23 = graph.add_edge(n[2], n[3], 3);
+        let e30 = graph.add_edge(n[3], n[0], 4);
+        let e13 = graph.add_edge(n[1], n[3], 5);
+
+        debug_assert_eq!(graph.remove_node(n[2]), Some('c'));
+        debug_assert_eq!(graph.remove_node(n[2]), None);
+        debug_assert_eq!(graph.node_count(), 3);
+        debug_assert_eq!(graph.edge_count(), 3);
+        debug_assert_eq!(graph.edge_indices().collect::<Vec<_>>(), [e01, e30, e13]);
+        debug_assert_eq!(
+            graph
+                .out_neighbors(n[1])
+                .map(|neighbor| neighbor.node_id)
+                .collect::<Vec<_>>(),
+            [n[3]]
+        );
+        debug_assert_eq!(graph.in_degree(n[3]), 1);
+        debug_assert_eq!(
+            graph.fragmentation(),
+            SlabGraphFragmentation {
+                node_slots: 4,
+                vacant_node_slots: 1,
+                edge_slots: 6,
+                vacant_edge_slots: 3,
+            }
+        );
+        debug_assert_eq!(graph.fragmentation().edge_fragmentation(), 0.5);
+
+        let e = graph.add_node('e');
+        debug_assert_eq!(e, n[2]);
+        let reused_edges: Vec<_> = (0..4).map(|i| graph.add_edge(e, n[i % 2], i)).collect();
+        debug_assert!([e12, e22, e23].contains(&reused_edges[0]));
+        debug_assert_eq!(reused_edges[3], 6.into());
+        debug_assert_eq!(graph.fragmentation().vacant_edge_slots, 0);
+        debug_assert_eq!(graph.edge_count_between(e, n[0]), 2);
+
+        debug_assert_eq!(graph.remove_edge(e01), Some(0));
+        debug_assert_eq!(graph.remove_edge(e01), None);
+        debug_assert_eq!(graph.in_degree(n[1]), 2);
+        for edge_id in graph.edge_indices() {
+            let endpoints = graph.edge_endpoints(edge_id);
+            debug_assert!(graph
+                .out_neighbors(endpoints.from_node)
+                .any(|neighbor| neighbor.edge_id == edge_id));
+            debug_assert!(graph
+                .in_neighbors(endpoints.to_node)
+                .any(|neighbor| neighbor.edge_id == edge_id));
+        }
+    }
+}
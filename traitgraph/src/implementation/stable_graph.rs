@@ -0,0 +1,562 @@
+use crate::conversion::{compact_index_mapping, GraphIndexMapping};
+use crate::implementation::generation::GraphGeneration;
+use crate::index::{EdgeIndex, GraphIndex, NodeIndex};
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{
+    Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, MutableGraphContainer,
+    NavigableGraph, Neighbor,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+struct StableGraphNode<NodeData> {
+    data: NodeData,
+    out_edges: Vec<usize>,
+    in_edges: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct StableGraphEdge<EdgeData> {
+    data: EdgeData,
+    from_node: usize,
+    to_node: usize,
+}
+
+/// A graph whose node and edge indices stay valid until the node or edge is removed.
+///
+/// Removed nodes and edges leave a vacant slot behind, so removals never change the indices of other nodes and edges.
+/// Vacant slots are not reused by later insertions, hence an index of a removed node or edge never refers to a different node or edge.
+/// As a consequence, the indices of this graph are not consecutive,
+/// and vectors indexed by node or edge indices need to have length [`node_index_bound`](ImmutableGraphContainer::node_index_bound)
+/// or [`edge_index_bound`](ImmutableGraphContainer::edge_index_bound).
+///
+/// The space of vacant slots is only reclaimed by [`compact`](StableGraph::compact),
+/// which renumbers the nodes and edges and returns a mapping from old to new indices.
+#[derive(Debug, Clone)]
+pub struct StableGraph<NodeData, EdgeData> {
+    nodes: Vec<Option<StableGraphNode<NodeData>>>,
+    edges: Vec<Option<StableGraphEdge<EdgeData>>>,
+    node_count: usize,
+    edge_count: usize,
+    generation: GraphGeneration,
+}
+
+impl<NodeData, EdgeData> StableGraph<NodeData, EdgeData> {
+    /// Creates a new empty graph.
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    /// Creates a new empty graph with space for the given amount of nodes and edges.
+    pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(node_capacity),
+            edges: Vec::with_capacity(edge_capacity),
+            node_count: 0,
+            edge_count: 0,
+            generation: GraphGeneration::new(),
+        }
+    }
+
+    /// Returns the amount of vacant node slots, i.e. the amount of node slots that [`compact`](Self::compact) would reclaim.
+    pub fn vacant_node_count(&self) -> usize {
+        self.nodes.len() - self.node_count
+    }
+
+    /// Returns the amount of vacant edge slots, i.e. the amount of edge slots that [`compact`](Self::compact) would reclaim.
+    pub fn vacant_edge_count(&self) -> usize {
+        self.edges.len() - self.edge_count
+    }
+
+    /// Removes all vacant slots, such that the nodes and edges are indexed consecutively again.
+    ///
+    /// The relative order of the remaining nodes and edges is preserved.
+    /// All previously obtained indices are invalidated, and can be translated with the returned mapping,
+    /// which is the same as returned by [compact_index_mapping] before the compaction.
+    pub fn compact(&mut self) -> GraphIndexMapping<Self, Self> {
+        let mapping = compact_index_mapping(self);
+
+        self.nodes.retain(Option::is_some);
+        self.edges.retain(Option::is_some);
+        for node in self.nodes.iter_mut().flatten() {
+            for edge_id in node.out_edges.iter_mut().chain(node.in_edges.iter_mut()) {
+                *edge_id = mapping.edge((*edge_id).into()).unwrap().as_usize();
+            }
+        }
+        for edge in self.edges.iter_mut().flatten() {
+            edge.from_node = mapping.node(edge.from_node.into()).unwrap().as_usize();
+            edge.to_node = mapping.node(edge.to_node.into()).unwrap().as_usize();
+        }
+        self.generation.advance();
+
+        mapping
+    }
+
+    fn node(&self, node_id: usize) -> &StableGraphNode<NodeData> {
+        self.nodes[node_id]
+            .as_ref()
+            .unwrap_or_else(|| panic!("node {node_id} was removed"))
+    }
+
+    fn edge(&self, edge_id: usize) -> &StableGraphEdge<EdgeData> {
+        self.edges[edge_id]
+            .as_ref()
+            .unwrap_or_else(|| panic!("edge {edge_id} was removed"))
+    }
+}
+
+impl<NodeData, EdgeData> Default for StableGraph<NodeData, EdgeData> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<NodeData, EdgeData> GraphBase for StableGraph<NodeData, EdgeData> {
+    type NodeData = NodeData;
+    type EdgeData = EdgeData;
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<usize>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<usize>;
+    type NodeIndex = NodeIndex<usize>;
+    type EdgeIndex = EdgeIndex<usize>;
+}
+
+/// An iterator over the node indices of a [StableGraph].
+pub struct StableGraphNodeIndices<'a, NodeData, NodeIndex> {
+    slots: core::iter::Enumerate<core::slice::Iter<'a, Option<StableGraphNode<NodeData>>>>,
+    index_type: PhantomData<NodeIndex>,
+}
+
+impl<NodeData, NodeIndex: From<usize>> Iterator
+    for StableGraphNodeIndices<'_, NodeData, NodeIndex>
+{
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots
+            .find(|(_, slot)| slot.is_some())
+            .map(|(index, _)| index.into())
+    }
+}
+
+/// An iterator over the edge indices of a [StableGraph].
+pub struct StableGraphEdgeIndices<'a, EdgeData, EdgeIndex> {
+    slots: core::iter::Enumerate<core::slice::Iter<'a, Option<StableGraphEdge<EdgeData>>>>,
+    index_type: PhantomData<EdgeIndex>,
+}
+
+impl<EdgeData, EdgeIndex: From<usize>> Iterator
+    for StableGraphEdgeIndices<'_, EdgeData, EdgeIndex>
+{
+    type Item = EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots
+            .find(|(_, slot)| slot.is_some())
+            .map(|(index, _)| index.into())
+    }
+}
+
+impl<NodeData, EdgeData> ImmutableGraphContainer for StableGraph<NodeData, EdgeData> {
+    type NodeIndices<'a>
+        = StableGraphNodeIndices<'a, NodeData, Self::NodeIndex>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = StableGraphEdgeIndices<'a, EdgeData, Self::EdgeIndex>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+
+    type NodeIndicesCopied = alloc::vec::IntoIter<<Self as GraphBase>::NodeIndex>;
+    type EdgeIndicesCopied = alloc::vec::IntoIter<<Self as GraphBase>::EdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        StableGraphNodeIndices {
+            slots: self.nodes.iter().enumerate(),
+            index_type: PhantomData,
+        }
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        StableGraphEdgeIndices {
+            slots: self.edges.iter().enumerate(),
+            index_type: PhantomData,
+        }
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        self.node_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        self.edge_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        matches!(self.nodes.get(node_id.as_usize()), Some(Some(_)))
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        matches!(self.edges.get(edge_id.as_usize()), Some(Some(_)))
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn generation(&self) -> Option<u64> {
+        Some(self.generation.get())
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        &self.node(node_id.as_usize()).data
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        &self.edge(edge_id.as_usize()).data
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        let edge = self.edge(edge_id.as_usize());
+        Edge {
+            from_node: edge.from_node.into(),
+            to_node: edge.to_node.into(),
+        }
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<NodeData, EdgeData> MutableGraphContainer for StableGraph<NodeData, EdgeData> {
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        &mut self.nodes[node_id.as_usize()]
+            .as_mut()
+            .unwrap_or_else(|| panic!("node {node_id:?} was removed"))
+            .data
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        &mut self.edges[edge_id.as_usize()]
+            .as_mut()
+            .unwrap_or_else(|| panic!("edge {edge_id:?} was removed"))
+            .data
+    }
+
+    fn add_node(&mut self, node_data: Self::NodeData) -> Self::NodeIndex {
+        let node_id = self.nodes.len();
+        self.nodes.push(Some(StableGraphNode {
+            data: node_data,
+            out_edges: Vec::new(),
+            in_edges: Vec::new(),
+        }));
+        self.node_count += 1;
+        self.generation.advance();
+        node_id.into()
+    }
+
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: Self::EdgeData,
+    ) -> Self::EdgeIndex {
+        let (from_node, to_node) = (from.as_usize(), to.as_usize());
+        debug_assert!(self.contains_node_index(from) && self.contains_node_index(to));
+        let edge_id = self.edges.len();
+        self.edges.push(Some(StableGraphEdge {
+            data: edge_data,
+            from_node,
+            to_node,
+        }));
+        self.nodes[from_node]
+            .as_mut()
+            .unwrap()
+            .out_edges
+            .push(edge_id);
+        self.nodes[to_node].as_mut().unwrap().in_edges.push(edge_id);
+        self.edge_count += 1;
+        self.generation.advance();
+        edge_id.into()
+    }
+
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.edges.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+    }
+
+    /// Removes the node with the given id from the graph, together with all its incident edges.
+    /// The ids of all other nodes and edges stay the same.
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData> {
+        let node = self.nodes.get(node_id.as_usize())?.as_ref()?;
+        let incident_edges: Vec<_> = node
+            .out_edges
+            .iter()
+            .chain(node.in_edges.iter())
+            .copied()
+            .collect();
+        for edge_id in incident_edges {
+            // Self-loops are listed twice, so the second removal does nothing.
+            self.remove_edge(edge_id.into());
+        }
+
+        let node = self.nodes[node_id.as_usize()].take().unwrap();
+        self.node_count -= 1;
+        self.generation.advance();
+        Some(node.data)
+    }
+
+    /// Removes the edge with the given id from the graph.
+    /// The ids of all other edges stay the same.
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<Self::EdgeData> {
+        let edge_id = edge_id.as_usize();
+        let edge = self.edges.get_mut(edge_id)?.take()?;
+
+        let out_edges = &mut self.nodes[edge.from_node].as_mut().unwrap().out_edges;
+        out_edges.remove(out_edges.iter().position(|&e| e == edge_id).unwrap());
+        let in_edges = &mut self.nodes[edge.to_node].as_mut().unwrap().in_edges;
+        in_edges.remove(in_edges.iter().position(|&e| e == edge_id).unwrap());
+
+        self.edge_count -= 1;
+        self.generation.advance();
+        Some(edge.data)
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        edge_ids.windows(2).for_each(|w| debug_assert!(w[0] < w[1]));
+
+        for edge_id in edge_ids {
+            self.remove_edge(*edge_id);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+        self.node_count = 0;
+        self.edge_count = 0;
+        self.generation.advance();
+    }
+}
+
+/// An iterator over the neighbors of a node in a [StableGraph].
+pub struct StableGraphNeighbors<'a, EdgeData, NodeIndex, EdgeIndex> {
+    edge_ids: core::slice::Iter<'a, usize>,
+    edges: &'a [Option<StableGraphEdge<EdgeData>>],
+    outgoing: bool,
+    index_types: PhantomData<(NodeIndex, EdgeIndex)>,
+}
+
+impl<EdgeData, NodeIndex: From<usize>, EdgeIndex: From<usize>> Iterator
+    for StableGraphNeighbors<'_, EdgeData, NodeIndex, EdgeIndex>
+{
+    type Item = Neighbor<NodeIndex, EdgeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edge_ids.next().map(|&edge_id| {
+            let edge = self.edges[edge_id].as_ref().unwrap();
+            Neighbor {
+                edge_id: edge_id.into(),
+                node_id: if self.outgoing {
+                    edge.to_node
+                } else {
+                    edge.from_node
+                }
+                .into(),
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edge_ids.size_hint()
+    }
+}
+
+/// An iterator over the edges between two nodes in a [StableGraph].
+pub struct StableGraphEdgesBetween<'a, EdgeData, EdgeIndex> {
+    edge_ids: core::slice::Iter<'a, usize>,
+    edges: &'a [Option<StableGraphEdge<EdgeData>>],
+    to_node: usize,
+    edge_index_type: PhantomData<EdgeIndex>,
+}
+
+impl<EdgeData, EdgeIndex: From<usize>> Iterator
+    for StableGraphEdgesBetween<'_, EdgeData, EdgeIndex>
+{
+    type Item = EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (edges, to_node) = (self.edges, self.to_node);
+        self.edge_ids
+            .find(|&&edge_id| edges[edge_id].as_ref().unwrap().to_node == to_node)
+            .map(|&edge_id| edge_id.into())
+    }
+}
+
+impl<NodeData, EdgeData> NavigableGraph for StableGraph<NodeData, EdgeData> {
+    type OutNeighbors<'a>
+        = StableGraphNeighbors<
+        'a,
+        EdgeData,
+        <Self as GraphBase>::NodeIndex,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = StableGraphNeighbors<
+        'a,
+        EdgeData,
+        <Self as GraphBase>::NodeIndex,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = StableGraphEdgesBetween<'a, EdgeData, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        StableGraphNeighbors {
+            edge_ids: self.node(node_id.as_usize()).out_edges.iter(),
+            edges: &self.edges,
+            outgoing: true,
+            index_types: PhantomData,
+        }
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        StableGraphNeighbors {
+            edge_ids: self.node(node_id.as_usize()).in_edges.iter(),
+            edges: &self.edges,
+            outgoing: false,
+            index_types: PhantomData,
+        }
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        StableGraphEdgesBetween {
+            edge_ids: self.node(from_node_id.as_usize()).out_edges.iter(),
+            edges: &self.edges,
+            to_node: to_node_id.as_usize(),
+            edge_index_type: PhantomData,
+        }
+    }
+
+    fn out_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.node(node_id.as_usize()).out_edges.len()
+    }
+
+    fn in_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.node(node_id.as_usize()).in_edges.len()
+    }
+}
+
+impl<NodeData, EdgeData> SubgraphBase for StableGraph<NodeData, EdgeData> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StableGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_removal_keeps_indices() {
+        let mut graph = StableGraph::new();
+        let n: Vec<_> = ['a', 'b', 'c', 'd'].map(|data| graph.add_node(data)).into();
+        let e01 = graph.add_edge(n[0], n[1], 0);
+        let e12 = graph.add_edge(n[1], n[2], 1);
+        let e22 = graph.add_edge(n[2], n[2], 2);
+        let e23 = graph.add_edge(n[2], n[3], 3);
+        let e30 = graph.add_edge(n[3], n[0], 4);
+
+        debug_assert_eq!(graph.remove_edge(e12), Some(1));
+        debug_assert_eq!(graph.remove_edge(e12), None);
+        debug_assert_eq!(graph.remove_node(n[2]), Some('c'));
+        debug_assert_eq!(graph.remove_node(n[2]), None);
+
+        debug_assert_eq!(graph.node_count(), 3);
+        debug_assert_eq!(graph.edge_count(), 2);
+        debug_assert_eq!(graph.node_index_bound(), 4);
+        debug_assert_eq!(graph.edge_index_bound(), 5);
+        debug_assert!(!graph.contains_edge_index(e22));
+        debug_assert!(!graph.contains_edge_index(e23));
+        debug_assert_eq!(graph.node_indices().collect::<Vec<_>>(), [n[0], n[1], n[3]]);
+        debug_assert_eq!(graph.edge_indices().collect::<Vec<_>>(), [e01, e30]);
+        debug_assert_eq!(*graph.node_data(n[3]), 'd');
+        debug_assert_eq!(graph.out_degree(n[1]), 0);
+        debug_assert_eq!(graph.in_degree(n[3]), 0);
+
+        let e = graph.add_edge(n[3], n[1], 5);
+        debug_assert_eq!(e, 5.into());
+        debug_assert!(graph.contains_edge_between(n[3], n[1]));
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut graph = StableGraph::new();
+        let n: Vec<_> = ['a', 'b', 'c', 'd'].map(|data| graph.add_node(data)).into();
+        let e01 = graph.add_edge(n[0], n[1], 0);
+        let e12 = graph.add_edge(n[1], n[2], 1);
+        let e31 = graph.add_edge(n[3], n[1], 2);
+        let e33 = graph.add_edge(n[3], n[3], 3);
+        graph.remove_node(n[2]);
+        graph.remove_edge(e01);
+        debug_assert_eq!(graph.vacant_node_count(), 1);
+        debug_assert_eq!(graph.vacant_edge_count(), 2);
+
+        let compaction = graph.compact();
+        debug_assert_eq!(graph.vacant_node_count(), 0);
+        debug_assert_eq!(graph.vacant_edge_count(), 0);
+        debug_assert_eq!(compaction.node(n[2]), None);
+        debug_assert_eq!(compaction.edge(e01), None);
+        debug_assert_eq!(compaction.edge(e12), None);
+        debug_assert_eq!(compaction.nodes().count(), graph.node_count());
+
+        let b = compaction.node(n[1]).unwrap();
+        let d = compaction.node(n[3]).unwrap();
+        debug_assert_eq!(d, 2.into());
+        debug_assert_eq!(*graph.node_data(d), 'd');
+        debug_assert_eq!(compaction.edge(e31), Some(0.into()));
+        debug_assert_eq!(compaction.edge(e33), Some(1.into()));
+        debug_assert_eq!(graph.edges_between(d, b).collect::<Vec<_>>(), [0.into()]);
+        debug_assert_eq!(graph.in_degree(d), 1);
+        debug_assert_eq!(graph.edge_index_bound(), graph.edge_count());
+    }
+}
@@ -0,0 +1,448 @@
+use crate::implementation::generation::GraphGeneration;
+use crate::implementation::petgraph_impl::PetGraphIndexType;
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{
+    Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, MutableGraphContainer,
+    NavigableGraph, Neighbor,
+};
+use petgraph::graph::{DiGraph, EdgeIndex as PetgraphEdgeIndex, NodeIndex as PetgraphNodeIndex};
+use petgraph::stable_graph::{EdgeIndices, Edges, EdgesConnecting, NodeIndices, StableDiGraph};
+use petgraph::visit::{EdgeIndexable, EdgeRef, NodeIndexable};
+use petgraph::{Directed, Direction};
+use std::iter::Map;
+
+/// A wrapper around the [petgraph::stable_graph::StableGraph] type replacing its methods with implementations of our traits.
+///
+/// Unlike [PetGraph](crate::implementation::petgraph_impl::PetGraph), removing nodes and edges does not change the indices of the remaining nodes and edges.
+/// Hence, the indices of this graph are not necessarily consecutive,
+/// and [node_index_bound](ImmutableGraphContainer::node_index_bound) and [edge_index_bound](ImmutableGraphContainer::edge_index_bound)
+/// may be larger than the amount of nodes and edges.
+/// Vacant indices are reused by later insertions, as in the underlying petgraph type.
+#[derive(Debug, Clone)]
+pub struct StablePetGraph<NodeData, EdgeData, IndexType: PetGraphIndexType = usize>(
+    StableDiGraph<NodeData, EdgeData, IndexType>,
+    GraphGeneration,
+);
+
+impl<NodeData, EdgeData> StablePetGraph<NodeData, EdgeData> {
+    /// Create a new graph implemented using the `petgraph::stable_graph::StableGraph` type.
+    pub fn new() -> StablePetGraph<NodeData, EdgeData> {
+        StablePetGraph(
+            StableDiGraph::<NodeData, EdgeData, usize>::default(),
+            GraphGeneration::new(),
+        )
+    }
+}
+
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType>
+    StablePetGraph<NodeData, EdgeData, IndexType>
+{
+    /// Create a new graph with space for the given amount of nodes and edges.
+    pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> Self {
+        StablePetGraph(
+            StableDiGraph::with_capacity(node_capacity, edge_capacity),
+            GraphGeneration::new(),
+        )
+    }
+
+    /// Returns true if some node or edge index below the respective index bound is vacant.
+    fn has_vacant_indices(&self) -> bool {
+        self.0.node_count() < self.0.node_bound() || self.0.edge_count() < self.0.edge_bound()
+    }
+
+    /// Moves all nodes and edges into a new petgraph graph with the given capacities.
+    ///
+    /// Petgraph cannot change the capacity of a stable graph, and a new stable graph can only be built without vacant indices.
+    /// Hence, this keeps all node and edge indices only if the graph has no vacant indices.
+    fn reallocate(&mut self, node_capacity: usize, edge_capacity: usize) {
+        debug_assert!(!self.has_vacant_indices());
+        let graph = std::mem::replace(
+            &mut self.0,
+            StableDiGraph::with_capacity(node_capacity, edge_capacity),
+        );
+        let (nodes, edges) = DiGraph::from(graph).into_nodes_edges();
+        for node in nodes {
+            self.0.add_node(node.weight);
+        }
+        for edge in edges {
+            self.0.add_edge(edge.source(), edge.target(), edge.weight);
+        }
+    }
+}
+
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> GraphBase
+    for StablePetGraph<NodeData, EdgeData, IndexType>
+{
+    type NodeData = NodeData;
+    type EdgeData = EdgeData;
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<IndexType>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<IndexType>;
+    type NodeIndex = crate::index::NodeIndex<IndexType>;
+    type EdgeIndex = crate::index::EdgeIndex<IndexType>;
+}
+
+type StablePetgraphNodeIndexTranslator<'a, NodeData, IndexType, NodeIndex> = Map<
+    NodeIndices<'a, NodeData, IndexType>,
+    fn(petgraph::stable_graph::NodeIndex<IndexType>) -> NodeIndex,
+>;
+
+type StablePetgraphEdgeIndexTranslator<'a, EdgeData, IndexType, EdgeIndex> = Map<
+    EdgeIndices<'a, EdgeData, IndexType>,
+    fn(petgraph::stable_graph::EdgeIndex<IndexType>) -> EdgeIndex,
+>;
+
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> ImmutableGraphContainer
+    for StablePetGraph<NodeData, EdgeData, IndexType>
+{
+    type NodeIndices<'a>
+        = StablePetgraphNodeIndexTranslator<'a, NodeData, IndexType, Self::NodeIndex>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = StablePetgraphEdgeIndexTranslator<'a, EdgeData, IndexType, Self::EdgeIndex>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+
+    type NodeIndicesCopied = std::vec::IntoIter<<Self as GraphBase>::NodeIndex>;
+    type EdgeIndicesCopied = std::vec::IntoIter<<Self as GraphBase>::EdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        self.0.node_indices().map(|node_id| node_id.index().into())
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        self.0.edge_indices().map(|edge_id| edge_id.index().into())
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        self.node_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        self.edge_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        self.0.contains_node(node_id.into())
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        self.0.edge_weight(edge_id.into()).is_some()
+    }
+
+    fn node_count(&self) -> usize {
+        self.0.node_count()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.0.edge_count()
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.0.node_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.0.edge_bound()
+    }
+
+    fn generation(&self) -> Option<u64> {
+        Some(self.1.get())
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        self.0.node_weight(node_id.into()).unwrap()
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        self.0.edge_weight(edge_id.into()).unwrap()
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        let endpoints = self.0.edge_endpoints(edge_id.into()).unwrap();
+        Edge {
+            from_node: endpoints.0.index().into(),
+            to_node: endpoints.1.index().into(),
+        }
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> MutableGraphContainer
+    for StablePetGraph<NodeData, EdgeData, IndexType>
+{
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        self.0.node_weight_mut(node_id.into()).unwrap()
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        self.0.edge_weight_mut(edge_id.into()).unwrap()
+    }
+
+    fn add_node(&mut self, node_data: NodeData) -> Self::NodeIndex {
+        self.1.advance();
+        self.0.add_node(node_data).index().into()
+    }
+
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: EdgeData,
+    ) -> Self::EdgeIndex {
+        self.1.advance();
+        self.0
+            .add_edge(from.into(), to.into(), edge_data)
+            .index()
+            .into()
+    }
+
+    /// Reserves capacity for at least `additional` more nodes, taking into account the vacant node indices.
+    ///
+    /// Petgraph cannot grow a stable graph in place, so the graph is moved into a larger one.
+    /// This is only possible if the graph has no vacant node or edge indices, otherwise, this does nothing.
+    fn reserve_nodes(&mut self, additional: usize) {
+        let (node_capacity, edge_capacity) = self.0.capacity();
+        let required = self.0.node_count() + additional;
+        if required > node_capacity && !self.has_vacant_indices() {
+            self.reallocate(required.max(2 * node_capacity), edge_capacity);
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more edges, taking into account the vacant edge indices.
+    ///
+    /// Petgraph cannot grow a stable graph in place, so the graph is moved into a larger one.
+    /// This is only possible if the graph has no vacant node or edge indices, otherwise, this does nothing.
+    fn reserve_edges(&mut self, additional: usize) {
+        let (node_capacity, edge_capacity) = self.0.capacity();
+        let required = self.0.edge_count() + additional;
+        if required > edge_capacity && !self.has_vacant_indices() {
+            self.reallocate(node_capacity, required.max(2 * edge_capacity));
+        }
+    }
+
+    /// Shrinks the capacity of the graph to its index bounds, keeping all node and edge indices.
+    fn shrink_to_fit(&mut self) {
+        // Petgraph's filter_map keeps all indices and allocates exactly for the index bounds, but only borrows the data.
+        // Hence, the structure of the graph is copied first, and then the data is moved over.
+        let structure = self.0.filter_map(|_, _| Some(()), |_, _| Some(()));
+        let mut edge_data: Vec<_> = (0..self.0.edge_bound())
+            .map(|edge_id| self.0.remove_edge(PetgraphEdgeIndex::new(edge_id)))
+            .collect();
+        let mut node_data: Vec<_> = (0..self.0.node_bound())
+            .map(|node_id| self.0.remove_node(PetgraphNodeIndex::new(node_id)))
+            .collect();
+        self.0 = structure.filter_map(
+            |node_id, ()| node_data[node_id.index()].take(),
+            |edge_id, ()| edge_data[edge_id.index()].take(),
+        );
+    }
+
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<NodeData> {
+        self.1.advance();
+        self.0.remove_node(node_id.into())
+    }
+
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<EdgeData> {
+        self.1.advance();
+        self.0.remove_edge(edge_id.into())
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        edge_ids.windows(2).for_each(|w| debug_assert!(w[0] < w[1]));
+
+        for edge_id in edge_ids {
+            self.remove_edge(*edge_id);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+        self.1.advance();
+    }
+}
+
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> SubgraphBase
+    for StablePetGraph<NodeData, EdgeData, IndexType>
+{
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+type StablePetgraphNeighborTranslator<'a, EdgeData, IndexType, NodeIndex, EdgeIndex> = Map<
+    Edges<'a, EdgeData, Directed, IndexType>,
+    fn(
+        petgraph::stable_graph::EdgeReference<'a, EdgeData, IndexType>,
+    ) -> Neighbor<NodeIndex, EdgeIndex>,
+>;
+
+type StablePetgraphRestrictedNeighborTranslator<'a, EdgeData, IndexType, EdgeIndex> = Map<
+    EdgesConnecting<'a, EdgeData, Directed, IndexType>,
+    fn(petgraph::stable_graph::EdgeReference<'a, EdgeData, IndexType>) -> EdgeIndex,
+>;
+
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> NavigableGraph
+    for StablePetGraph<NodeData, EdgeData, IndexType>
+{
+    type OutNeighbors<'a>
+        = StablePetgraphNeighborTranslator<
+        'a,
+        EdgeData,
+        IndexType,
+        <Self as GraphBase>::NodeIndex,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        NodeData: 'a,
+        EdgeData: 'a;
+    type InNeighbors<'a>
+        = StablePetgraphNeighborTranslator<
+        'a,
+        EdgeData,
+        IndexType,
+        <Self as GraphBase>::NodeIndex,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        NodeData: 'a,
+        EdgeData: 'a;
+    type EdgesBetween<'a>
+        = StablePetgraphRestrictedNeighborTranslator<
+        'a,
+        EdgeData,
+        IndexType,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        NodeData: 'a,
+        EdgeData: 'a;
+
+    fn out_neighbors(&self, node_id: <Self as GraphBase>::NodeIndex) -> Self::OutNeighbors<'_> {
+        debug_assert!(self.contains_node_index(node_id));
+        self.0
+            .edges_directed(node_id.into(), Direction::Outgoing)
+            .map(|edge| Neighbor {
+                edge_id: <Self as GraphBase>::EdgeIndex::from(edge.id().index()),
+                node_id: <Self as GraphBase>::NodeIndex::from(edge.target().index()),
+            })
+    }
+
+    fn in_neighbors(&self, node_id: <Self as GraphBase>::NodeIndex) -> Self::InNeighbors<'_> {
+        debug_assert!(self.contains_node_index(node_id));
+        self.0
+            .edges_directed(node_id.into(), Direction::Incoming)
+            .map(|edge| Neighbor {
+                edge_id: <Self as GraphBase>::EdgeIndex::from(edge.id().index()),
+                node_id: <Self as GraphBase>::NodeIndex::from(edge.source().index()),
+            })
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: <Self as GraphBase>::NodeIndex,
+        to_node_id: <Self as GraphBase>::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        debug_assert!(self.contains_node_index(from_node_id));
+        debug_assert!(self.contains_node_index(to_node_id));
+        self.0
+            .edges_connecting(from_node_id.into(), to_node_id.into())
+            .map(|edge| <Self as GraphBase>::EdgeIndex::from(edge.id().index()))
+    }
+
+    fn contains_edge_between(&self, from: Self::NodeIndex, to: Self::NodeIndex) -> bool {
+        self.0.contains_edge(from.into(), to.into())
+    }
+
+    fn edge_count_between(&self, from: Self::NodeIndex, to: Self::NodeIndex) -> usize {
+        self.0.edges_connecting(from.into(), to.into()).count()
+    }
+}
+
+impl<NodeData, EdgeData, IndexType: PetGraphIndexType> Default
+    for StablePetGraph<NodeData, EdgeData, IndexType>
+{
+    fn default() -> Self {
+        Self(Default::default(), GraphGeneration::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::stable_petgraph_impl::StablePetGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_removal_keeps_indices() {
+        let mut graph = StablePetGraph::new();
+        let n0 = graph.add_node('a');
+        let n1 = graph.add_node('b');
+        let n2 = graph.add_node('c');
+        let e0 = graph.add_edge(n0, n1, 0);
+        let e1 = graph.add_edge(n1, n2, 1);
+        let e2 = graph.add_edge(n2, n0, 2);
+
+        graph.remove_node(n0);
+        debug_assert_eq!(graph.node_count(), 2);
+        debug_assert_eq!(graph.edge_count(), 1);
+        debug_assert_eq!(graph.node_index_bound(), 3);
+        debug_assert_eq!(graph.edge_index_bound(), 2);
+        debug_assert!(!graph.contains_node_index(n0));
+        debug_assert!(!graph.contains_edge_index(e0));
+        debug_assert!(!graph.contains_edge_index(e2));
+        debug_assert_eq!(*graph.node_data(n2), 'c');
+        debug_assert_eq!(*graph.edge_data(e1), 1);
+        debug_assert_eq!(graph.node_indices().collect::<Vec<_>>(), [n1, n2]);
+        debug_assert_eq!(graph.edge_indices().collect::<Vec<_>>(), [e1]);
+        debug_assert_eq!(
+            graph
+                .in_neighbors(n2)
+                .map(|n| (n.node_id, n.edge_id))
+                .collect::<Vec<_>>(),
+            [(n1, e1)]
+        );
+        debug_assert_eq!(graph.out_neighbors(n2).count(), 0);
+        debug_assert!(graph.contains_edge_between(n1, n2));
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut graph = StablePetGraph::new();
+        let n0 = graph.add_node('a');
+        let n1 = graph.add_node('b');
+        let n2 = graph.add_node('c');
+        let e0 = graph.add_edge(n0, n1, 0);
+        let e1 = graph.add_edge(n1, n2, 1);
+        let e2 = graph.add_edge(n2, n1, 2);
+
+        graph.reserve(10, 20);
+        debug_assert!(graph.0.capacity().0 >= 13);
+        debug_assert!(graph.0.capacity().1 >= 23);
+        debug_assert_eq!(graph.node_indices().collect::<Vec<_>>(), [n0, n1, n2]);
+        debug_assert_eq!(graph.edge_indices().collect::<Vec<_>>(), [e0, e1, e2]);
+        debug_assert_eq!(graph.edge_endpoints(e2).from_node, n2);
+
+        graph.remove_node(n0);
+        graph.shrink_to_fit();
+        debug_assert_eq!(graph.0.capacity(), (3, 3));
+        debug_assert_eq!(graph.node_index_bound(), 3);
+        debug_assert_eq!(graph.node_indices().collect::<Vec<_>>(), [n1, n2]);
+        debug_assert_eq!(graph.edge_indices().collect::<Vec<_>>(), [e1, e2]);
+        debug_assert_eq!(*graph.node_data(n2), 'c');
+        debug_assert_eq!(*graph.edge_data(e2), 2);
+        debug_assert!(graph.contains_edge_between(n2, n1));
+
+        // The vacant indices are still reused.
+        debug_assert_eq!(graph.add_node('d'), n0);
+        debug_assert_eq!(graph.add_edge(n0, n1, 3), e0);
+    }
+}
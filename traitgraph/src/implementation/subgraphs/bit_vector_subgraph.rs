@@ -1,68 +1,148 @@
 use crate::implementation::subgraphs::filter_iterators::{
     FilterEdgeIndexIterator, FilterNeighborIterator,
 };
+use crate::implementation::subgraphs::induced_incremental_subgraph::FilterNodeIndexIterator;
 use crate::index::GraphIndex;
-use crate::interface::subgraph::{EmptyConstructibleSubgraph, MutableSubgraph, SubgraphBase};
-use crate::interface::{Edge, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use crate::interface::subgraph::{
+    EmptyConstructibleSubgraph, MutableSubgraph, SubgraphBase, SubgraphRelations,
+};
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::vec::Vec;
 use bitvec::bitvec;
+use bitvec::field::BitField;
+use bitvec::slice::BitSlice;
 use bitvec::vec::BitVec;
-
-/// A subgraph that stores the presence or absence of a node or edge using bitvectors.
-pub struct BitVectorSubgraph<'a, Graph> {
-    parent_graph: &'a Graph,
+use core::ops::Deref;
+
+/// A subgraph that stores the presence or absence of a node or edge using bitvectors, and holds its parent graph through any pointer.
+///
+/// The amount of present nodes and edges is tracked, so [node_count](ImmutableGraphContainer::node_count)
+/// and [edge_count](ImmutableGraphContainer::edge_count) take constant time.
+///
+/// The parent graph is held through any pointer that dereferences to it.
+/// Usually this is a reference, for which the alias [BitVectorSubgraph] exists, but with a shared pointer like [Arc](alloc::sync::Arc),
+/// the subgraph does not borrow its parent, so it can be stored in `'static` structures,
+/// and moved to other threads if the pointer allows it.
+/// The parent graph cannot be mutated while it is shared, so the subgraph stays valid.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use std::sync::Arc;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::bit_vector_subgraph::GenericBitVectorSubgraph;
+/// use traitgraph::interface::subgraph::MutableSubgraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// let mut graph = PetGraph::<_, ()>::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// let graph = Arc::new(graph);
+///
+/// let handles: Vec<_> = nodes
+///     .chunks(2)
+///     .map(|chunk| {
+///         let mut subgraph = GenericBitVectorSubgraph::new_empty(Arc::clone(&graph));
+///         chunk.iter().for_each(|&node_id| subgraph.enable_node(node_id));
+///         std::thread::spawn(move || {
+///             subgraph
+///                 .node_indices()
+///                 .map(|node_id| *subgraph.node_data(node_id))
+///                 .sum::<i32>()
+///         })
+///     })
+///     .collect();
+/// let sums: Vec<_> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+/// assert_eq!(sums, [1, 5]);
+/// # }
+/// ```
+pub struct GenericBitVectorSubgraph<GraphRef> {
+    parent_graph: GraphRef,
     present_nodes: BitVec,
     present_edges: BitVec,
+    node_count: usize,
+    edge_count: usize,
 }
 
-impl<'a, Graph: SubgraphBase> BitVectorSubgraph<'a, Graph>
+/// A subgraph that stores the presence or absence of a node or edge using bitvectors, and borrows its parent graph.
+pub type BitVectorSubgraph<'a, Graph> = GenericBitVectorSubgraph<&'a Graph>;
+
+impl<GraphRef: Deref> GenericBitVectorSubgraph<GraphRef>
 where
-    Graph::RootGraph: ImmutableGraphContainer,
+    GraphRef::Target: SubgraphBase,
+    <GraphRef::Target as SubgraphBase>::RootGraph: ImmutableGraphContainer,
 {
     /// Constructs a new instance decorating the given graph.
     /// The subgraph is initialised empty.
-    pub fn new_empty(parent_graph: &'a Graph) -> Self {
+    pub fn new_empty(parent_graph: GraphRef) -> Self {
+        let present_nodes = bitvec![0; parent_graph.root().node_index_bound()];
+        let present_edges = bitvec![0; parent_graph.root().edge_index_bound()];
         Self {
             parent_graph,
-            present_nodes: bitvec![0; parent_graph.root().node_count()],
-            present_edges: bitvec![0; parent_graph.root().edge_count()],
+            present_nodes,
+            present_edges,
+            node_count: 0,
+            edge_count: 0,
         }
     }
 }
 
-impl<Graph: GraphBase> GraphBase for BitVectorSubgraph<'_, Graph> {
-    type NodeData = Graph::NodeData;
-    type EdgeData = Graph::EdgeData;
-    type OptionalNodeIndex = Graph::OptionalNodeIndex;
-    type OptionalEdgeIndex = Graph::OptionalEdgeIndex;
-    type NodeIndex = Graph::NodeIndex;
-    type EdgeIndex = Graph::EdgeIndex;
+impl<GraphRef> GenericBitVectorSubgraph<GraphRef> {
+    /// Returns the pointer to the parent graph of this subgraph.
+    pub fn parent_graph(&self) -> &GraphRef {
+        &self.parent_graph
+    }
+}
+
+impl<GraphRef: Deref> GraphBase for GenericBitVectorSubgraph<GraphRef>
+where
+    GraphRef::Target: GraphBase,
+{
+    type NodeData = <GraphRef::Target as GraphBase>::NodeData;
+    type EdgeData = <GraphRef::Target as GraphBase>::EdgeData;
+    type OptionalNodeIndex = <GraphRef::Target as GraphBase>::OptionalNodeIndex;
+    type OptionalEdgeIndex = <GraphRef::Target as GraphBase>::OptionalEdgeIndex;
+    type NodeIndex = <GraphRef::Target as GraphBase>::NodeIndex;
+    type EdgeIndex = <GraphRef::Target as GraphBase>::EdgeIndex;
 }
 
-impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for BitVectorSubgraph<'_, Graph> {
+impl<GraphRef: Deref> ImmutableGraphContainer for GenericBitVectorSubgraph<GraphRef>
+where
+    GraphRef::Target: ImmutableGraphContainer,
+{
     type NodeIndices<'a>
-        = std::iter::Filter<Graph::NodeIndices<'a>, Box<dyn 'a + Fn(&Graph::NodeIndex) -> bool>>
+        = FilterNodeIndexIterator<
+        'a,
+        Self::NodeIndex,
+        Self::OptionalNodeIndex,
+        <GraphRef::Target as ImmutableGraphContainer>::NodeIndices<'a>,
+        Self,
+    >
     where
-        Self: 'a,
-        Graph: 'a;
+        Self: 'a;
     type EdgeIndices<'a>
-        = std::iter::Filter<Graph::EdgeIndices<'a>, Box<dyn 'a + Fn(&Graph::EdgeIndex) -> bool>>
+        = FilterEdgeIndexIterator<
+        'a,
+        <GraphRef::Target as ImmutableGraphContainer>::EdgeIndices<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
     where
-        Self: 'a,
-        Graph: 'a;
+        Self: 'a;
+    type NodeIndicesCopied = alloc::vec::IntoIter<Self::NodeIndex>;
+    type EdgeIndicesCopied = alloc::vec::IntoIter<Self::EdgeIndex>;
 
     fn node_indices(&self) -> Self::NodeIndices<'_> {
-        self.parent_graph
-            .node_indices()
-            .filter(Box::new(|&node_index| self.contains_node_index(node_index)))
+        FilterNodeIndexIterator::new(self.parent_graph.node_indices(), self)
     }
 
     fn edge_indices(&self) -> Self::EdgeIndices<'_> {
-        self.parent_graph
-            .edge_indices()
-            .filter(Box::new(|&edge_index| self.contains_edge_index(edge_index)))
+        FilterEdgeIndexIterator::new(self.parent_graph.edge_indices(), self)
     }
-    type NodeIndicesCopied = std::vec::IntoIter<Graph::NodeIndex>;
-    type EdgeIndicesCopied = std::vec::IntoIter<Graph::EdgeIndex>;
+
     fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
         self.node_indices().collect::<Vec<_>>().into_iter()
     }
@@ -88,11 +168,19 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for BitVectorSubgra
     }
 
     fn node_count(&self) -> usize {
-        self.node_indices().count()
+        self.node_count
     }
 
     fn edge_count(&self) -> usize {
-        self.edge_indices().count()
+        self.edge_count
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.parent_graph.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.parent_graph.edge_index_bound()
     }
 
     fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
@@ -109,19 +197,26 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for BitVectorSubgra
         debug_assert!(self.contains_edge_index(edge_id));
         self.parent_graph.edge_endpoints(edge_id)
     }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
 }
 
-impl<Graph: NavigableGraph> NavigableGraph for BitVectorSubgraph<'_, Graph> {
+impl<GraphRef: Deref> NavigableGraph for GenericBitVectorSubgraph<GraphRef>
+where
+    GraphRef::Target: NavigableGraph,
+{
     type OutNeighbors<'a>
-        = FilterNeighborIterator<'a, <Graph as NavigableGraph>::OutNeighbors<'a>, Self>
+        = FilterNeighborIterator<'a, <GraphRef::Target as NavigableGraph>::OutNeighbors<'a>, Self>
     where
         Self: 'a;
     type InNeighbors<'a>
-        = FilterNeighborIterator<'a, <Graph as NavigableGraph>::InNeighbors<'a>, Self>
+        = FilterNeighborIterator<'a, <GraphRef::Target as NavigableGraph>::InNeighbors<'a>, Self>
     where
         Self: 'a;
     type EdgesBetween<'a>
-        = FilterEdgeIndexIterator<'a, <Graph as NavigableGraph>::EdgesBetween<'a>, Self>
+        = FilterEdgeIndexIterator<'a, <GraphRef::Target as NavigableGraph>::EdgesBetween<'a>, Self>
     where
         Self: 'a;
 
@@ -145,33 +240,40 @@ impl<Graph: NavigableGraph> NavigableGraph for BitVectorSubgraph<'_, Graph> {
     }
 }
 
-impl<Graph: SubgraphBase> SubgraphBase for BitVectorSubgraph<'_, Graph> {
-    type RootGraph = Graph::RootGraph;
+impl<GraphRef: Deref> SubgraphBase for GenericBitVectorSubgraph<GraphRef>
+where
+    GraphRef::Target: SubgraphBase,
+{
+    type RootGraph = <GraphRef::Target as SubgraphBase>::RootGraph;
 
     fn root(&self) -> &Self::RootGraph {
         self.parent_graph.root()
     }
 }
 
-impl<Graph: ImmutableGraphContainer + SubgraphBase> MutableSubgraph for BitVectorSubgraph<'_, Graph>
+impl<GraphRef: Deref> MutableSubgraph for GenericBitVectorSubgraph<GraphRef>
 where
-    Self: GraphBase<
-        NodeIndex = <Graph as GraphBase>::NodeIndex,
-        EdgeIndex = <Graph as GraphBase>::EdgeIndex,
-    >,
+    GraphRef::Target: ImmutableGraphContainer + SubgraphBase,
 {
     fn clear(&mut self) {
         self.present_nodes.fill(false);
         self.present_edges.fill(false);
+        self.node_count = 0;
+        self.edge_count = 0;
     }
 
     fn fill(&mut self) {
-        self.parent_graph
-            .node_indices()
-            .for_each(|node_index| self.enable_node(node_index));
-        self.parent_graph
-            .edge_indices()
-            .for_each(|edge_index| self.enable_edge(edge_index));
+        let parent_graph = &*self.parent_graph;
+        for node_index in parent_graph.node_indices() {
+            if !self.present_nodes.replace(node_index.as_usize(), true) {
+                self.node_count += 1;
+            }
+        }
+        for edge_index in parent_graph.edge_indices() {
+            if !self.present_edges.replace(edge_index.as_usize(), true) {
+                self.edge_count += 1;
+            }
+        }
     }
 
     fn enable_node(
@@ -179,7 +281,9 @@ where
         node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
     ) {
         debug_assert!(self.parent_graph.contains_node_index(node_index));
-        self.present_nodes.set(node_index.as_usize(), true);
+        if !self.present_nodes.replace(node_index.as_usize(), true) {
+            self.node_count += 1;
+        }
     }
 
     fn enable_edge(
@@ -187,7 +291,9 @@ where
         edge_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::EdgeIndex,
     ) {
         debug_assert!(self.parent_graph.contains_edge_index(edge_index));
-        self.present_edges.set(edge_index.as_usize(), true);
+        if !self.present_edges.replace(edge_index.as_usize(), true) {
+            self.edge_count += 1;
+        }
     }
 
     fn disable_node(
@@ -195,7 +301,9 @@ where
         node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
     ) {
         debug_assert!(self.parent_graph.contains_node_index(node_index));
-        self.present_nodes.set(node_index.as_usize(), false);
+        if self.present_nodes.replace(node_index.as_usize(), false) {
+            self.node_count -= 1;
+        }
     }
 
     fn disable_edge(
@@ -203,28 +311,70 @@ where
         edge_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::EdgeIndex,
     ) {
         debug_assert!(self.parent_graph.contains_edge_index(edge_index));
-        self.present_edges.set(edge_index.as_usize(), false);
+        if self.present_edges.replace(edge_index.as_usize(), false) {
+            self.edge_count -= 1;
+        }
+    }
+}
+
+/// Returns true if all bits set in `subset` are also set in `superset`, comparing word by word.
+pub(super) fn is_bit_subset(subset: &BitSlice, superset: &BitSlice) -> bool {
+    debug_assert_eq!(subset.len(), superset.len());
+    subset
+        .chunks(usize::BITS as usize)
+        .zip(superset.chunks(usize::BITS as usize))
+        .all(|(subset, superset)| subset.load_le::<usize>() & !superset.load_le::<usize>() == 0)
+}
+
+/// Returns true if no bit is set in both `a` and `b`, comparing word by word.
+pub(super) fn is_bit_disjoint(a: &BitSlice, b: &BitSlice) -> bool {
+    debug_assert_eq!(a.len(), b.len());
+    a.chunks(usize::BITS as usize)
+        .zip(b.chunks(usize::BITS as usize))
+        .all(|(a, b)| a.load_le::<usize>() & b.load_le::<usize>() == 0)
+}
+
+impl<GraphRef: Deref> SubgraphRelations for GenericBitVectorSubgraph<GraphRef>
+where
+    GraphRef::Target: SubgraphBase,
+{
+    fn is_subset_of(&self, other: &Self) -> bool {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        self.node_count <= other.node_count
+            && self.edge_count <= other.edge_count
+            && is_bit_subset(&self.present_nodes, &other.present_nodes)
+            && is_bit_subset(&self.present_edges, &other.present_edges)
+    }
+
+    fn is_disjoint_from(&self, other: &Self) -> bool {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        is_bit_disjoint(&self.present_nodes, &other.present_nodes)
+            && is_bit_disjoint(&self.present_edges, &other.present_edges)
     }
 }
 
 impl<'a, Graph: ImmutableGraphContainer + SubgraphBase> EmptyConstructibleSubgraph<'a>
-    for BitVectorSubgraph<'a, Graph>
+    for GenericBitVectorSubgraph<&'a Graph>
 where
     Self: SubgraphBase<RootGraph = Graph>,
 {
     fn new_empty(root_graph: &'a <Self as SubgraphBase>::RootGraph) -> Self {
         Self {
             parent_graph: root_graph,
-            present_nodes: bitvec![0; root_graph.node_count()],
-            present_edges: bitvec![0; root_graph.edge_count()],
+            present_nodes: bitvec![0; root_graph.node_index_bound()],
+            present_edges: bitvec![0; root_graph.edge_index_bound()],
+            node_count: 0,
+            edge_count: 0,
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use crate::implementation::petgraph_impl::PetGraph;
-    use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+    use crate::implementation::subgraphs::bit_vector_subgraph::{
+        BitVectorSubgraph, GenericBitVectorSubgraph,
+    };
     use crate::interface::subgraph::MutableSubgraph;
     use crate::interface::{ImmutableGraphContainer, MutableGraphContainer};
     use bitvec::bitvec;
@@ -234,7 +384,7 @@ mod tests {
         let bv = bitvec![0; 12];
         assert_eq!(bv.len(), 12);
         assert_eq!(bv.iter_ones().sum::<usize>(), 0);
-        assert_eq!(bv.iter_zeros().sum::<usize>(), (0..12).sum());
+        assert_eq!(bv.iter_zeros().sum::<usize>(), (0..12).sum::<usize>());
     }
 
     #[test]
@@ -264,4 +414,134 @@ mod tests {
         assert!(subgraph.node_indices().next().is_none());
         assert!(subgraph.edge_indices().next().is_none());
     }
+
+    #[test]
+    fn test_counts() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..10).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..9)
+            .map(|i| graph.add_edge(n[i], n[i + 1], i + 100))
+            .collect();
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        assert_eq!(subgraph.node_count(), 0);
+        assert_eq!(subgraph.edge_count(), 0);
+
+        subgraph.enable_node(n[2]);
+        subgraph.enable_node(n[2]);
+        subgraph.enable_node(n[5]);
+        subgraph.enable_edge(e[2]);
+        subgraph.disable_edge(e[3]);
+        assert_eq!(subgraph.node_count(), 2);
+        assert_eq!(subgraph.edge_count(), 1);
+
+        subgraph.disable_node(n[2]);
+        subgraph.disable_node(n[2]);
+        assert_eq!(subgraph.node_count(), subgraph.node_indices().count());
+        assert_eq!(subgraph.node_count(), 1);
+
+        subgraph.fill();
+        assert_eq!(subgraph.node_count(), 10);
+        assert_eq!(subgraph.edge_count(), 9);
+        subgraph.clear();
+        assert_eq!(subgraph.node_count(), 0);
+        assert_eq!(subgraph.edge_count(), 0);
+    }
+
+    #[test]
+    fn test_shared_parent_graph() {
+        use crate::interface::NavigableGraph;
+        use std::rc::Rc;
+
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..3)
+            .map(|i| graph.add_edge(n[i], n[i + 1], i + 100))
+            .collect();
+        let graph = Rc::new(graph);
+
+        let mut first = GenericBitVectorSubgraph::new_empty(Rc::clone(&graph));
+        let mut second = GenericBitVectorSubgraph::new_empty(Rc::clone(&graph));
+        first.enable_node(n[0]);
+        first.enable_node(n[1]);
+        first.enable_edge(e[0]);
+        second.fill();
+        second.disable_edge(e[1]);
+        assert_eq!(Rc::strong_count(&graph), 3);
+
+        assert_eq!(first.node_indices().collect::<Vec<_>>(), n[0..2].to_vec());
+        assert_eq!(first.edge_count(), 1);
+        assert_eq!(first.edge_data(e[0]), &100);
+        assert_eq!(second.node_count(), 4);
+        assert_eq!(second.edge_count(), 2);
+        assert!(second.out_neighbors(n[1]).next().is_none());
+        assert_eq!(second.out_neighbors(n[2]).next().unwrap().node_id, n[3]);
+
+        // The subgraphs keep the graph alive on their own.
+        drop(graph);
+        assert_eq!(Rc::strong_count(first.parent_graph()), 2);
+        drop(second);
+        assert_eq!(Rc::strong_count(first.parent_graph()), 1);
+        assert_eq!(first.node_data(n[1]), &1);
+    }
+
+    #[test]
+    fn test_shared_parent_graph_across_threads() {
+        use std::sync::Arc;
+
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        for i in 0..5 {
+            graph.add_edge(n[i], n[i + 1], ());
+        }
+        let graph = Arc::new(graph);
+
+        let mut subgraph = GenericBitVectorSubgraph::new_empty(Arc::clone(&graph));
+        subgraph.fill();
+        subgraph.disable_node(n[0]);
+        let handle = std::thread::spawn(move || {
+            (
+                subgraph.node_count(),
+                subgraph.edge_count(),
+                subgraph
+                    .node_indices()
+                    .map(|node| *subgraph.node_data(node))
+                    .sum::<i32>(),
+            )
+        });
+        assert_eq!(handle.join().unwrap(), (5, 5, 15));
+        assert_eq!(Arc::strong_count(&graph), 1);
+    }
+
+    #[test]
+    fn test_relations() {
+        use crate::interface::subgraph::SubgraphRelations;
+
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..200).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..199)
+            .map(|i| graph.add_edge(n[i], n[i + 1], ()))
+            .collect();
+        let mut small = BitVectorSubgraph::new_empty(&graph);
+        let mut large = BitVectorSubgraph::new_empty(&graph);
+        let mut other = BitVectorSubgraph::new_empty(&graph);
+        for i in [3, 64, 150, 199] {
+            small.enable_node(n[i]);
+        }
+        small.enable_edge(e[150]);
+        large.fill();
+        other.enable_node(n[198]);
+
+        assert!(small.is_subset_of(&large));
+        assert!(large.is_superset_of(&small));
+        assert!(!large.is_subset_of(&small));
+        assert!(small.is_subset_of(&small));
+        assert!(small.is_disjoint_from(&other));
+        assert!(!large.is_disjoint_from(&other));
+
+        other.enable_edge(e[150]);
+        assert!(!small.is_disjoint_from(&other));
+        assert!(!other.is_subset_of(&small));
+        large.disable_edge(e[150]);
+        assert!(!small.is_subset_of(&large));
+    }
 }
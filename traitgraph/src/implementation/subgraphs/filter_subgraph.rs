@@ -0,0 +1,215 @@
+use crate::implementation::subgraphs::filter_iterators::{
+    FilterEdgeIndexIterator, FilterNeighborIterator,
+};
+use crate::implementation::subgraphs::induced_incremental_subgraph::FilterNodeIndexIterator;
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::vec::Vec;
+
+/// A subgraph defined by two predicates over the node and edge indices of its parent graph.
+///
+/// A node is contained if it is contained in the parent graph and the node predicate holds for it.
+/// An edge is contained if it is contained in the parent graph, the edge predicate holds for it and both its endpoints are contained.
+/// The predicates are evaluated lazily on every access, so constructing this subgraph takes constant time and memory.
+/// This makes it suitable for one-shot traversals over a filtered view of a large graph,
+/// but repeated queries may be faster on a materialised subgraph like a [BitVectorSubgraph](super::bit_vector_subgraph::BitVectorSubgraph).
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::filter_subgraph::FilterSubgraph;
+/// use traitgraph::index::GraphIndex;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// graph.add_edge(nodes[0], nodes[1], 5);
+/// graph.add_edge(nodes[1], nodes[2], 3);
+/// graph.add_edge(nodes[1], nodes[3], 7);
+///
+/// let subgraph = FilterSubgraph::new(
+///     &graph,
+///     |node_id| node_id.as_usize() != 2,
+///     |edge_id| *graph.edge_data(edge_id) > 4,
+/// );
+/// assert_eq!(subgraph.node_count(), 3);
+/// assert_eq!(subgraph.edge_count(), 2);
+/// assert_eq!(subgraph.out_neighbors(nodes[1]).count(), 1);
+/// # }
+/// ```
+pub struct FilterSubgraph<'a, Graph, NodeFilter, EdgeFilter> {
+    parent_graph: &'a Graph,
+    node_filter: NodeFilter,
+    edge_filter: EdgeFilter,
+}
+
+impl<
+        'a,
+        Graph: GraphBase,
+        NodeFilter: Fn(Graph::NodeIndex) -> bool,
+        EdgeFilter: Fn(Graph::EdgeIndex) -> bool,
+    > FilterSubgraph<'a, Graph, NodeFilter, EdgeFilter>
+{
+    /// Constructs a new instance decorating the given graph,
+    /// containing the nodes and edges for which the respective filter returns true.
+    pub fn new(parent_graph: &'a Graph, node_filter: NodeFilter, edge_filter: EdgeFilter) -> Self {
+        Self {
+            parent_graph,
+            node_filter,
+            edge_filter,
+        }
+    }
+}
+
+impl<Graph: GraphBase, NodeFilter, EdgeFilter> GraphBase
+    for FilterSubgraph<'_, Graph, NodeFilter, EdgeFilter>
+{
+    type NodeData = Graph::NodeData;
+    type EdgeData = Graph::EdgeData;
+    type OptionalNodeIndex = Graph::OptionalNodeIndex;
+    type OptionalEdgeIndex = Graph::OptionalEdgeIndex;
+    type NodeIndex = Graph::NodeIndex;
+    type EdgeIndex = Graph::EdgeIndex;
+}
+
+impl<Graph: SubgraphBase, NodeFilter, EdgeFilter> SubgraphBase
+    for FilterSubgraph<'_, Graph, NodeFilter, EdgeFilter>
+{
+    type RootGraph = Graph::RootGraph;
+
+    fn root(&self) -> &Self::RootGraph {
+        self.parent_graph.root()
+    }
+}
+
+impl<
+        Graph: ImmutableGraphContainer,
+        NodeFilter: Fn(Graph::NodeIndex) -> bool,
+        EdgeFilter: Fn(Graph::EdgeIndex) -> bool,
+    > ImmutableGraphContainer for FilterSubgraph<'_, Graph, NodeFilter, EdgeFilter>
+{
+    type NodeIndices<'a>
+        = FilterNodeIndexIterator<
+        'a,
+        Self::NodeIndex,
+        Self::OptionalNodeIndex,
+        Graph::NodeIndices<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = FilterEdgeIndexIterator<'a, Graph::EdgeIndices<'a>, Self>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+    type NodeIndicesCopied = alloc::vec::IntoIter<Graph::NodeIndex>;
+    type EdgeIndicesCopied = alloc::vec::IntoIter<Graph::EdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        FilterNodeIndexIterator::new(self.parent_graph.node_indices(), self)
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        FilterEdgeIndexIterator::new(self.parent_graph.edge_indices(), self)
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        self.node_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        self.edge_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        self.parent_graph.contains_node_index(node_id) && (self.node_filter)(node_id)
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        if !self.parent_graph.contains_edge_index(edge_id) || !(self.edge_filter)(edge_id) {
+            return false;
+        }
+        let Edge { from_node, to_node } = self.parent_graph.edge_endpoints(edge_id);
+        self.contains_node_index(from_node) && self.contains_node_index(to_node)
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_indices().count()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_indices().count()
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.parent_graph.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.parent_graph.edge_index_bound()
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        debug_assert!(self.contains_node_index(node_id));
+        self.parent_graph.node_data(node_id)
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.parent_graph.edge_data(edge_id)
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.parent_graph.edge_endpoints(edge_id)
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<
+        Graph: NavigableGraph,
+        NodeFilter: Fn(Graph::NodeIndex) -> bool,
+        EdgeFilter: Fn(Graph::EdgeIndex) -> bool,
+    > NavigableGraph for FilterSubgraph<'_, Graph, NodeFilter, EdgeFilter>
+{
+    type OutNeighbors<'a>
+        = FilterNeighborIterator<'a, <Graph as NavigableGraph>::OutNeighbors<'a>, Self>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = FilterNeighborIterator<'a, <Graph as NavigableGraph>::InNeighbors<'a>, Self>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = FilterEdgeIndexIterator<'a, <Graph as NavigableGraph>::EdgesBetween<'a>, Self>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        FilterNeighborIterator::new(self.parent_graph.out_neighbors(node_id), self)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        FilterNeighborIterator::new(self.parent_graph.in_neighbors(node_id), self)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        FilterEdgeIndexIterator::new(
+            self.parent_graph.edges_between(from_node_id, to_node_id),
+            self,
+        )
+    }
+}
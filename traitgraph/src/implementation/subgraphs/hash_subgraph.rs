@@ -0,0 +1,308 @@
+use crate::implementation::subgraphs::filter_iterators::{
+    FilterEdgeIndexIterator, FilterNeighborIterator,
+};
+use crate::interface::subgraph::{
+    EmptyConstructibleSubgraph, MutableSubgraph, SubgraphBase, SubgraphRelations,
+};
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use std::collections::HashSet;
+
+/// A subgraph that stores the present nodes and edges in hash sets.
+///
+/// In contrast to the [BitVectorSubgraph](super::bit_vector_subgraph::BitVectorSubgraph),
+/// the memory used by this subgraph is proportional to the amount of present nodes and edges and not to the size of the root graph.
+/// Iterating over the nodes or edges sorts the present indices, so it takes time `O(k log k)` for `k` present nodes or edges,
+/// independent of the size of the parent graph.
+pub struct HashSubgraph<'a, Graph: GraphBase> {
+    parent_graph: &'a Graph,
+    present_nodes: HashSet<Graph::NodeIndex>,
+    present_edges: HashSet<Graph::EdgeIndex>,
+}
+
+impl<'a, Graph: GraphBase> HashSubgraph<'a, Graph> {
+    /// Constructs a new instance decorating the given graph.
+    /// The subgraph is initialised empty.
+    pub fn new_empty(parent_graph: &'a Graph) -> Self {
+        Self {
+            parent_graph,
+            present_nodes: Default::default(),
+            present_edges: Default::default(),
+        }
+    }
+}
+
+impl<Graph: GraphBase> GraphBase for HashSubgraph<'_, Graph> {
+    type NodeData = Graph::NodeData;
+    type EdgeData = Graph::EdgeData;
+    type OptionalNodeIndex = Graph::OptionalNodeIndex;
+    type OptionalEdgeIndex = Graph::OptionalEdgeIndex;
+    type NodeIndex = Graph::NodeIndex;
+    type EdgeIndex = Graph::EdgeIndex;
+}
+
+impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for HashSubgraph<'_, Graph> {
+    type NodeIndices<'a>
+        = std::vec::IntoIter<Graph::NodeIndex>
+    where
+        Self: 'a,
+        Graph: 'a;
+    type EdgeIndices<'a>
+        = std::vec::IntoIter<Graph::EdgeIndex>
+    where
+        Self: 'a,
+        Graph: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a,
+        Graph: 'a;
+    type NodeIndicesCopied = std::vec::IntoIter<Graph::NodeIndex>;
+    type EdgeIndicesCopied = std::vec::IntoIter<Graph::EdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        self.node_indices_copied()
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        self.edge_indices_copied()
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        let mut node_indices: Vec<_> = self.present_nodes.iter().copied().collect();
+        node_indices.sort_unstable();
+        node_indices.into_iter()
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        let mut edge_indices: Vec<_> = self.present_edges.iter().copied().collect();
+        edge_indices.sort_unstable();
+        edge_indices.into_iter()
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        self.present_nodes.contains(&node_id)
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        self.present_edges.contains(&edge_id)
+    }
+
+    fn node_count(&self) -> usize {
+        self.present_nodes.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.present_edges.len()
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.parent_graph.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.parent_graph.edge_index_bound()
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        debug_assert!(self.contains_node_index(node_id));
+        self.parent_graph.node_data(node_id)
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.parent_graph.edge_data(edge_id)
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.parent_graph.edge_endpoints(edge_id)
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<Graph: NavigableGraph> NavigableGraph for HashSubgraph<'_, Graph> {
+    type OutNeighbors<'a>
+        = FilterNeighborIterator<'a, <Graph as NavigableGraph>::OutNeighbors<'a>, Self>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = FilterNeighborIterator<'a, <Graph as NavigableGraph>::InNeighbors<'a>, Self>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = FilterEdgeIndexIterator<'a, <Graph as NavigableGraph>::EdgesBetween<'a>, Self>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        FilterNeighborIterator::new(self.parent_graph.out_neighbors(node_id), self)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        FilterNeighborIterator::new(self.parent_graph.in_neighbors(node_id), self)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        FilterEdgeIndexIterator::new(
+            self.parent_graph.edges_between(from_node_id, to_node_id),
+            self,
+        )
+    }
+}
+
+impl<Graph: SubgraphBase> SubgraphBase for HashSubgraph<'_, Graph> {
+    type RootGraph = Graph::RootGraph;
+
+    fn root(&self) -> &Self::RootGraph {
+        self.parent_graph.root()
+    }
+}
+
+impl<Graph: ImmutableGraphContainer + SubgraphBase> MutableSubgraph for HashSubgraph<'_, Graph>
+where
+    Self: GraphBase<
+        NodeIndex = <Graph as GraphBase>::NodeIndex,
+        EdgeIndex = <Graph as GraphBase>::EdgeIndex,
+    >,
+{
+    fn clear(&mut self) {
+        self.present_nodes.clear();
+        self.present_edges.clear();
+    }
+
+    fn fill(&mut self) {
+        self.present_nodes.extend(self.parent_graph.node_indices());
+        self.present_edges.extend(self.parent_graph.edge_indices());
+    }
+
+    fn enable_node(
+        &mut self,
+        node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
+    ) {
+        debug_assert!(self.parent_graph.contains_node_index(node_index));
+        self.present_nodes.insert(node_index);
+    }
+
+    fn enable_edge(
+        &mut self,
+        edge_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::EdgeIndex,
+    ) {
+        debug_assert!(self.parent_graph.contains_edge_index(edge_index));
+        self.present_edges.insert(edge_index);
+    }
+
+    fn disable_node(
+        &mut self,
+        node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
+    ) {
+        debug_assert!(self.parent_graph.contains_node_index(node_index));
+        self.present_nodes.remove(&node_index);
+    }
+
+    fn disable_edge(
+        &mut self,
+        edge_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::EdgeIndex,
+    ) {
+        debug_assert!(self.parent_graph.contains_edge_index(edge_index));
+        self.present_edges.remove(&edge_index);
+    }
+}
+
+impl<Graph: SubgraphBase> SubgraphRelations for HashSubgraph<'_, Graph> {
+    fn is_subset_of(&self, other: &Self) -> bool {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        self.present_nodes.is_subset(&other.present_nodes)
+            && self.present_edges.is_subset(&other.present_edges)
+    }
+
+    fn is_disjoint_from(&self, other: &Self) -> bool {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        self.present_nodes.is_disjoint(&other.present_nodes)
+            && self.present_edges.is_disjoint(&other.present_edges)
+    }
+}
+
+impl<'a, Graph: ImmutableGraphContainer + SubgraphBase> EmptyConstructibleSubgraph<'a>
+    for HashSubgraph<'a, Graph>
+where
+    Self: SubgraphBase<RootGraph = Graph>,
+{
+    fn new_empty(root_graph: &'a <Self as SubgraphBase>::RootGraph) -> Self {
+        Self {
+            parent_graph: root_graph,
+            present_nodes: Default::default(),
+            present_edges: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+    use crate::implementation::subgraphs::hash_subgraph::HashSubgraph;
+    use crate::interface::subgraph::MutableSubgraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_same_as_bit_vector_subgraph() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..10).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..9)
+            .map(|i| graph.add_edge(n[i], n[(i * 7 + 3) % 10], i))
+            .collect();
+
+        let mut hash_subgraph = HashSubgraph::new_empty(&graph);
+        let mut bit_vector_subgraph = BitVectorSubgraph::new_empty(&graph);
+        for i in [7, 3, 0, 9, 4, 3] {
+            hash_subgraph.enable_node(n[i]);
+            bit_vector_subgraph.enable_node(n[i]);
+        }
+        for i in [8, 1, 2, 6, 8] {
+            hash_subgraph.enable_edge(e[i]);
+            bit_vector_subgraph.enable_edge(e[i]);
+        }
+        hash_subgraph.disable_node(n[4]);
+        bit_vector_subgraph.disable_node(n[4]);
+        hash_subgraph.disable_edge(e[6]);
+        bit_vector_subgraph.disable_edge(e[6]);
+
+        assert_eq!(hash_subgraph.node_count(), bit_vector_subgraph.node_count());
+        assert_eq!(hash_subgraph.edge_count(), bit_vector_subgraph.edge_count());
+        assert_eq!(
+            hash_subgraph.node_indices().collect::<Vec<_>>(),
+            bit_vector_subgraph.node_indices().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            hash_subgraph.edge_indices().collect::<Vec<_>>(),
+            bit_vector_subgraph.edge_indices().collect::<Vec<_>>()
+        );
+        for &node_id in &n {
+            assert_eq!(
+                hash_subgraph.out_neighbors(node_id).collect::<Vec<_>>(),
+                bit_vector_subgraph
+                    .out_neighbors(node_id)
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                hash_subgraph.in_neighbors(node_id).collect::<Vec<_>>(),
+                bit_vector_subgraph
+                    .in_neighbors(node_id)
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        hash_subgraph.fill();
+        assert_eq!(hash_subgraph.node_count(), 10);
+        assert_eq!(hash_subgraph.edge_count(), 9);
+        hash_subgraph.clear();
+        assert_eq!(hash_subgraph.node_indices().next(), None);
+    }
+}
@@ -1,25 +1,59 @@
 use crate::implementation::subgraphs::filter_iterators::{
     FilterEdgeIndexIterator, FilterNeighborIterator,
 };
-use crate::index::GraphIndex;
+use crate::index::{GraphIndex, OptionalGraphIndex};
 use crate::interface::subgraph::{MutableSubgraph, SubgraphBase};
-use crate::interface::{Edge, GraphBase, ImmutableGraphContainer, NavigableGraph};
-use std::iter::Filter;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::Filter;
+use core::marker::PhantomData;
 
 type IntegerType = usize;
 
+/// A single change recorded by an [IncrementalSubgraph], such that it can be rolled back.
+#[derive(Debug, Clone, Copy)]
+enum Change<NodeIndex, EdgeIndex> {
+    EnableNode(NodeIndex),
+    EnableEdge(EdgeIndex),
+    DisableNode(NodeIndex),
+    DisableEdge(EdgeIndex),
+}
+
+/// A checkpoint in the history of an incremental subgraph, created by [IncrementalSubgraph::checkpoint]
+/// or [InducedIncrementalSubgraph::checkpoint](super::induced_incremental_subgraph::InducedIncrementalSubgraph::checkpoint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Checkpoint(pub(super) usize);
+
 /// A subgraph that stores the presence or absence of a node or edge using integers.
 ///
 /// Additionally, this subgraph has a current step that can be altered.
 /// Nodes and edges are added with that step, and only nodes and edges with a step lower or equal to the current one are counted as present.
-/// This allows to combine multiple subgraphs into one, if they are totally ordered by the subset relation.
+/// Nodes and edges can also be removed with the current step, and are then only counted as present in steps lower than that.
+/// This allows to combine multiple subgraphs into one, if they form a sequence in which each node and edge is present in a contiguous range of steps.
+/// Hence, each node and edge can be enabled and disabled at most once.
+///
+/// The amount of present nodes and edges is tracked, so [node_count](ImmutableGraphContainer::node_count)
+/// and [edge_count](ImmutableGraphContainer::edge_count) take constant time.
+/// Changing the current step takes time linear in the amount of steps between the old and the new step.
+///
+/// All enabled and disabled nodes and edges are recorded, such that they can be rolled back to a [checkpoint](Self::checkpoint)
+/// in time linear in the amount of changes since the checkpoint.
 pub struct IncrementalSubgraph<'a, Graph: GraphBase> {
     parent_graph: &'a Graph,
     present_nodes: Vec<IntegerType>,
     present_edges: Vec<IntegerType>,
+    absent_nodes: Vec<IntegerType>,
+    absent_edges: Vec<IntegerType>,
     new_nodes: Vec<Vec<Graph::NodeIndex>>,
     new_edges: Vec<Vec<Graph::EdgeIndex>>,
+    removed_nodes: Vec<Vec<Graph::NodeIndex>>,
+    removed_edges: Vec<Vec<Graph::EdgeIndex>>,
     current_step: IntegerType,
+    node_count: usize,
+    edge_count: usize,
+    changes: Vec<Change<Graph::NodeIndex, Graph::EdgeIndex>>,
 }
 
 impl<Graph: GraphBase> GraphBase for IncrementalSubgraph<'_, Graph> {
@@ -44,17 +78,97 @@ impl<'a, Graph: ImmutableGraphContainer> IncrementalSubgraph<'a, Graph> {
     pub fn new_with_incremental_steps(graph: &'a Graph, incremental_steps: usize) -> Self {
         Self {
             parent_graph: graph,
-            present_nodes: vec![IntegerType::MAX; graph.node_count()],
-            present_edges: vec![IntegerType::MAX; graph.edge_count()],
+            present_nodes: vec![IntegerType::MAX; graph.node_index_bound()],
+            present_edges: vec![IntegerType::MAX; graph.edge_index_bound()],
+            absent_nodes: vec![IntegerType::MAX; graph.node_index_bound()],
+            absent_edges: vec![IntegerType::MAX; graph.edge_index_bound()],
             new_nodes: vec![Default::default(); incremental_steps],
             new_edges: vec![Default::default(); incremental_steps],
+            removed_nodes: vec![Default::default(); incremental_steps],
+            removed_edges: vec![Default::default(); incremental_steps],
             current_step: 0,
+            node_count: 0,
+            edge_count: 0,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Returns a checkpoint of the current state that can be restored with [rollback_to](Self::rollback_to).
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.changes.len())
+    }
+
+    /// Undoes all nodes and edges enabled or disabled after the given checkpoint was created.
+    ///
+    /// The current step is not changed.
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) {
+        debug_assert!(
+            checkpoint.0 <= self.changes.len(),
+            "The checkpoint was already rolled back"
+        );
+        while self.changes.len() > checkpoint.0 {
+            match self.changes.pop().unwrap() {
+                Change::EnableNode(node_index) => {
+                    let step = self.present_nodes[node_index.as_usize()];
+                    debug_assert_eq!(self.new_nodes[step].last(), Some(&node_index));
+                    self.new_nodes[step].pop();
+                    self.present_nodes[node_index.as_usize()] = IntegerType::MAX;
+                    if step <= self.current_step {
+                        self.node_count -= 1;
+                    }
+                }
+                Change::EnableEdge(edge_index) => {
+                    let step = self.present_edges[edge_index.as_usize()];
+                    debug_assert_eq!(self.new_edges[step].last(), Some(&edge_index));
+                    self.new_edges[step].pop();
+                    self.present_edges[edge_index.as_usize()] = IntegerType::MAX;
+                    if step <= self.current_step {
+                        self.edge_count -= 1;
+                    }
+                }
+                Change::DisableNode(node_index) => {
+                    let step = self.absent_nodes[node_index.as_usize()];
+                    debug_assert_eq!(self.removed_nodes[step].last(), Some(&node_index));
+                    self.removed_nodes[step].pop();
+                    self.absent_nodes[node_index.as_usize()] = IntegerType::MAX;
+                    if step <= self.current_step {
+                        self.node_count += 1;
+                    }
+                }
+                Change::DisableEdge(edge_index) => {
+                    let step = self.absent_edges[edge_index.as_usize()];
+                    debug_assert_eq!(self.removed_edges[step].last(), Some(&edge_index));
+                    self.removed_edges[step].pop();
+                    self.absent_edges[edge_index.as_usize()] = IntegerType::MAX;
+                    if step <= self.current_step {
+                        self.edge_count += 1;
+                    }
+                }
+            }
         }
     }
 
     /// Set the current incremental step of the graph.
     pub fn set_current_step(&mut self, current_step: IntegerType) {
         debug_assert!(current_step < self.new_nodes.len() && current_step < self.new_edges.len());
+        let (steps, forward) = if current_step > self.current_step {
+            (self.current_step + 1..=current_step, true)
+        } else {
+            (current_step + 1..=self.current_step, false)
+        };
+        for step in steps {
+            let (added_nodes, removed_nodes) =
+                (self.new_nodes[step].len(), self.removed_nodes[step].len());
+            let (added_edges, removed_edges) =
+                (self.new_edges[step].len(), self.removed_edges[step].len());
+            if forward {
+                self.node_count = self.node_count + added_nodes - removed_nodes;
+                self.edge_count = self.edge_count + added_edges - removed_edges;
+            } else {
+                self.node_count = self.node_count + removed_nodes - added_nodes;
+                self.edge_count = self.edge_count + removed_edges - added_edges;
+            }
+        }
         self.current_step = current_step;
     }
 
@@ -70,6 +184,18 @@ impl<'a, Graph: ImmutableGraphContainer> IncrementalSubgraph<'a, Graph> {
         &self.new_edges[self.current_step]
     }
 
+    /// Return the nodes that are removed in the current incremental step.
+    pub fn removed_nodes(&self) -> &Vec<Graph::NodeIndex> {
+        debug_assert!(self.current_step < self.removed_nodes.len());
+        &self.removed_nodes[self.current_step]
+    }
+
+    /// Return the edges that are removed in the current incremental step.
+    pub fn removed_edges(&self) -> &Vec<Graph::EdgeIndex> {
+        debug_assert!(self.current_step < self.removed_edges.len());
+        &self.removed_edges[self.current_step]
+    }
+
     /// Returns true if this node was added in the current step.
     pub fn is_new_node(&self, node_index: <Self as GraphBase>::NodeIndex) -> bool {
         debug_assert!(node_index.as_usize() < self.present_nodes.capacity());
@@ -82,6 +208,18 @@ impl<'a, Graph: ImmutableGraphContainer> IncrementalSubgraph<'a, Graph> {
         self.present_edges[edge_index.as_usize()] == self.current_step
     }
 
+    /// Returns true if this node was removed in the current step.
+    pub fn is_removed_node(&self, node_index: <Self as GraphBase>::NodeIndex) -> bool {
+        debug_assert!(node_index.as_usize() < self.absent_nodes.len());
+        self.absent_nodes[node_index.as_usize()] == self.current_step
+    }
+
+    /// Returns true if this edge was removed in the current step.
+    pub fn is_removed_edge(&self, edge_index: <Self as GraphBase>::EdgeIndex) -> bool {
+        debug_assert!(edge_index.as_usize() < self.absent_edges.len());
+        self.absent_edges[edge_index.as_usize()] == self.current_step
+    }
+
     /// Returns true if this node was removed in the current reverse step.
     pub fn is_newly_removed_node(&self, node_index: <Self as GraphBase>::NodeIndex) -> bool {
         debug_assert!(node_index.as_usize() < self.present_nodes.capacity());
@@ -93,6 +231,152 @@ impl<'a, Graph: ImmutableGraphContainer> IncrementalSubgraph<'a, Graph> {
         debug_assert!(edge_index.as_usize() < self.present_edges.capacity());
         self.present_edges[edge_index.as_usize()] == self.current_step + 1
     }
+
+    /// Returns the nodes and edges that differ between the subgraph at step `from_step` and the subgraph at step `to_step`.
+    /// The current step is not changed.
+    ///
+    /// Computing the difference takes time linear in the amount of nodes and edges added or removed in the steps between `from_step` and `to_step`.
+    pub fn step_diff(&self, from_step: IntegerType, to_step: IntegerType) -> StepDiff<'_, Graph> {
+        debug_assert!(from_step < self.new_nodes.len() && to_step < self.new_nodes.len());
+        StepDiff {
+            new_nodes: &self.new_nodes,
+            new_edges: &self.new_edges,
+            removed_nodes: &self.removed_nodes,
+            removed_edges: &self.removed_edges,
+            present_nodes: &self.present_nodes,
+            present_edges: &self.present_edges,
+            absent_nodes: &self.absent_nodes,
+            absent_edges: &self.absent_edges,
+            lower_step: from_step.min(to_step),
+            upper_step: from_step.max(to_step),
+            forward: from_step <= to_step,
+        }
+    }
+}
+
+/// The difference between an [IncrementalSubgraph] at two steps, as returned by [IncrementalSubgraph::step_diff].
+pub struct StepDiff<'a, Graph: GraphBase> {
+    new_nodes: &'a [Vec<Graph::NodeIndex>],
+    new_edges: &'a [Vec<Graph::EdgeIndex>],
+    removed_nodes: &'a [Vec<Graph::NodeIndex>],
+    removed_edges: &'a [Vec<Graph::EdgeIndex>],
+    present_nodes: &'a [IntegerType],
+    present_edges: &'a [IntegerType],
+    absent_nodes: &'a [IntegerType],
+    absent_edges: &'a [IntegerType],
+    lower_step: IntegerType,
+    upper_step: IntegerType,
+    forward: bool,
+}
+
+impl<'a, Graph: GraphBase> StepDiff<'a, Graph> {
+    /// Returns the nodes that are present at the second step but not at the first step.
+    pub fn added_nodes(&self) -> StepDiffIterator<'a, Graph::NodeIndex, Graph::OptionalNodeIndex> {
+        if self.forward {
+            self.entering(self.new_nodes, self.absent_nodes)
+        } else {
+            self.leaving(self.removed_nodes, self.present_nodes)
+        }
+    }
+
+    /// Returns the edges that are present at the second step but not at the first step.
+    pub fn added_edges(&self) -> StepDiffIterator<'a, Graph::EdgeIndex, Graph::OptionalEdgeIndex> {
+        if self.forward {
+            self.entering(self.new_edges, self.absent_edges)
+        } else {
+            self.leaving(self.removed_edges, self.present_edges)
+        }
+    }
+
+    /// Returns the nodes that are present at the first step but not at the second step.
+    pub fn removed_nodes(
+        &self,
+    ) -> StepDiffIterator<'a, Graph::NodeIndex, Graph::OptionalNodeIndex> {
+        if self.forward {
+            self.leaving(self.removed_nodes, self.present_nodes)
+        } else {
+            self.entering(self.new_nodes, self.absent_nodes)
+        }
+    }
+
+    /// Returns the edges that are present at the first step but not at the second step.
+    pub fn removed_edges(
+        &self,
+    ) -> StepDiffIterator<'a, Graph::EdgeIndex, Graph::OptionalEdgeIndex> {
+        if self.forward {
+            self.leaving(self.removed_edges, self.present_edges)
+        } else {
+            self.entering(self.new_edges, self.absent_edges)
+        }
+    }
+
+    /// Iterates over the indices added after the lower step up to the upper step that are not removed until the upper step.
+    fn entering<Index, OptionalIndex>(
+        &self,
+        added: &'a [Vec<Index>],
+        absent: &'a [IntegerType],
+    ) -> StepDiffIterator<'a, Index, OptionalIndex> {
+        StepDiffIterator {
+            steps: added[self.lower_step + 1..self.upper_step + 1].iter(),
+            current: [].iter(),
+            other_steps: absent,
+            lower_step: self.lower_step,
+            upper_step: self.upper_step,
+            entering: true,
+            phantom_optional_index: PhantomData,
+        }
+    }
+
+    /// Iterates over the indices removed after the lower step up to the upper step that were added until the lower step.
+    fn leaving<Index, OptionalIndex>(
+        &self,
+        removed: &'a [Vec<Index>],
+        present: &'a [IntegerType],
+    ) -> StepDiffIterator<'a, Index, OptionalIndex> {
+        StepDiffIterator {
+            steps: removed[self.lower_step + 1..self.upper_step + 1].iter(),
+            current: [].iter(),
+            other_steps: present,
+            lower_step: self.lower_step,
+            upper_step: self.upper_step,
+            entering: false,
+            phantom_optional_index: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the nodes or edges added or removed between two steps of an [IncrementalSubgraph].
+pub struct StepDiffIterator<'a, Index, OptionalIndex> {
+    steps: core::slice::Iter<'a, Vec<Index>>,
+    current: core::slice::Iter<'a, Index>,
+    other_steps: &'a [IntegerType],
+    lower_step: IntegerType,
+    upper_step: IntegerType,
+    entering: bool,
+    phantom_optional_index: PhantomData<OptionalIndex>,
+}
+
+impl<Index: GraphIndex<OptionalIndex>, OptionalIndex: OptionalGraphIndex<Index>> Iterator
+    for StepDiffIterator<'_, Index, OptionalIndex>
+{
+    type Item = Index;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            for &index in self.current.by_ref() {
+                let other_step = self.other_steps[index.as_usize()];
+                let included = if self.entering {
+                    other_step > self.upper_step
+                } else {
+                    other_step <= self.lower_step
+                };
+                if included {
+                    return Some(index);
+                }
+            }
+            self.current = self.steps.next()?.iter();
+        }
+    }
 }
 
 impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for IncrementalSubgraph<'_, Graph> {
@@ -104,6 +388,10 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for IncrementalSubg
         = Filter<Graph::EdgeIndices<'a>, Box<dyn 'a + Fn(&<Graph as GraphBase>::EdgeIndex) -> bool>>
     where
         Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
 
     fn node_indices(&self) -> Self::NodeIndices<'_> {
         self.parent_graph
@@ -116,8 +404,8 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for IncrementalSubg
             .edge_indices()
             .filter(Box::new(|e| self.contains_edge_index(*e)))
     }
-    type NodeIndicesCopied = std::vec::IntoIter<Graph::NodeIndex>;
-    type EdgeIndicesCopied = std::vec::IntoIter<Graph::EdgeIndex>;
+    type NodeIndicesCopied = alloc::vec::IntoIter<Graph::NodeIndex>;
+    type EdgeIndicesCopied = alloc::vec::IntoIter<Graph::EdgeIndex>;
     fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
         self.node_indices().collect::<Vec<_>>().into_iter()
     }
@@ -129,19 +417,29 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for IncrementalSubg
     fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
         debug_assert!(node_id.as_usize() < self.present_nodes.len());
         self.present_nodes[node_id.as_usize()] <= self.current_step
+            && self.current_step < self.absent_nodes[node_id.as_usize()]
     }
 
     fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
         debug_assert!(edge_id.as_usize() < self.present_edges.len());
         self.present_edges[edge_id.as_usize()] <= self.current_step
+            && self.current_step < self.absent_edges[edge_id.as_usize()]
     }
 
     fn node_count(&self) -> usize {
-        self.node_indices().count()
+        self.node_count
     }
 
     fn edge_count(&self) -> usize {
-        self.edge_indices().count()
+        self.edge_count
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.parent_graph.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.parent_graph.edge_index_bound()
     }
 
     fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
@@ -158,6 +456,10 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for IncrementalSubg
         debug_assert!(self.contains_edge_index(edge_id));
         self.parent_graph.edge_endpoints(edge_id)
     }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
 }
 
 impl<Graph: NavigableGraph> NavigableGraph for IncrementalSubgraph<'_, Graph> {
@@ -209,31 +511,208 @@ impl<Graph: ImmutableGraphContainer + SubgraphBase> MutableSubgraph
         &mut self,
         node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
     ) {
-        debug_assert!(!self.contains_node_index(node_index));
+        debug_assert_eq!(self.present_nodes[node_index.as_usize()], IntegerType::MAX);
         self.new_nodes[self.current_step].push(node_index);
         self.present_nodes[node_index.as_usize()] = self.current_step;
+        self.node_count += 1;
+        self.changes.push(Change::EnableNode(node_index));
     }
 
     fn enable_edge(
         &mut self,
         edge_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::EdgeIndex,
     ) {
-        debug_assert!(!self.contains_edge_index(edge_index));
+        debug_assert_eq!(self.present_edges[edge_index.as_usize()], IntegerType::MAX);
         self.new_edges[self.current_step].push(edge_index);
         self.present_edges[edge_index.as_usize()] = self.current_step;
+        self.edge_count += 1;
+        self.changes.push(Change::EnableEdge(edge_index));
     }
 
     fn disable_node(
         &mut self,
-        _node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
+        node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
     ) {
-        unimplemented!("Not supported")
+        debug_assert!(self.contains_node_index(node_index));
+        debug_assert_eq!(self.absent_nodes[node_index.as_usize()], IntegerType::MAX);
+        self.removed_nodes[self.current_step].push(node_index);
+        self.absent_nodes[node_index.as_usize()] = self.current_step;
+        self.node_count -= 1;
+        self.changes.push(Change::DisableNode(node_index));
     }
 
     fn disable_edge(
         &mut self,
-        _edge_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::EdgeIndex,
+        edge_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::EdgeIndex,
     ) {
-        unimplemented!("Not supported")
+        debug_assert!(self.contains_edge_index(edge_index));
+        debug_assert_eq!(self.absent_edges[edge_index.as_usize()], IntegerType::MAX);
+        self.removed_edges[self.current_step].push(edge_index);
+        self.absent_edges[edge_index.as_usize()] = self.current_step;
+        self.edge_count -= 1;
+        self.changes.push(Change::DisableEdge(edge_index));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::subgraphs::incremental_subgraph::IncrementalSubgraph;
+    use crate::interface::subgraph::MutableSubgraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_counts() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..3).map(|i| graph.add_edge(n[i], n[i + 1], ())).collect();
+        let mut subgraph = IncrementalSubgraph::new_with_incremental_steps(&graph, 3);
+
+        subgraph.enable_node(n[0]);
+        subgraph.set_current_step(1);
+        subgraph.enable_node(n[1]);
+        subgraph.enable_edge(e[0]);
+        subgraph.set_current_step(2);
+        subgraph.enable_node(n[2]);
+        subgraph.enable_node(n[3]);
+        subgraph.enable_edge(e[2]);
+        assert_eq!(subgraph.node_count(), 4);
+        assert_eq!(subgraph.edge_count(), 2);
+
+        subgraph.set_current_step(0);
+        assert_eq!(subgraph.node_count(), 1);
+        assert_eq!(subgraph.edge_count(), 0);
+        subgraph.set_current_step(1);
+        assert_eq!(subgraph.node_count(), subgraph.node_indices().count());
+        assert_eq!(subgraph.edge_count(), subgraph.edge_indices().count());
+        subgraph.set_current_step(2);
+        assert_eq!(subgraph.node_count(), 4);
+        assert_eq!(subgraph.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_removals() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..2).map(|i| graph.add_edge(n[i], n[i + 1], ())).collect();
+        let mut subgraph = IncrementalSubgraph::new_with_incremental_steps(&graph, 4);
+
+        subgraph.enable_node(n[0]);
+        subgraph.enable_node(n[1]);
+        subgraph.enable_edge(e[0]);
+        subgraph.set_current_step(1);
+        subgraph.enable_node(n[2]);
+        subgraph.enable_edge(e[1]);
+        subgraph.disable_edge(e[0]);
+        subgraph.disable_node(n[0]);
+        subgraph.set_current_step(2);
+        subgraph.disable_edge(e[1]);
+        subgraph.set_current_step(3);
+        subgraph.disable_node(n[1]);
+        assert!(subgraph.is_removed_node(n[1]));
+        assert_eq!(subgraph.removed_nodes(), &vec![n[1]]);
+
+        let expected = [
+            (vec![n[0], n[1]], vec![e[0]]),
+            (vec![n[1], n[2]], vec![e[1]]),
+            (vec![n[1], n[2]], vec![]),
+            (vec![n[2]], vec![]),
+        ];
+        for step in [0, 3, 1, 2, 0, 2, 3] {
+            subgraph.set_current_step(step);
+            let (nodes, edges) = &expected[step];
+            assert_eq!(&subgraph.node_indices().collect::<Vec<_>>(), nodes);
+            assert_eq!(&subgraph.edge_indices().collect::<Vec<_>>(), edges);
+            assert_eq!(subgraph.node_count(), nodes.len());
+            assert_eq!(subgraph.edge_count(), edges.len());
+        }
+    }
+
+    #[test]
+    fn test_rollback_to_checkpoint() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..3).map(|i| graph.add_edge(n[i], n[i + 1], ())).collect();
+        let mut subgraph = IncrementalSubgraph::new_with_incremental_steps(&graph, 3);
+
+        subgraph.enable_node(n[0]);
+        subgraph.enable_node(n[1]);
+        subgraph.enable_edge(e[0]);
+        let checkpoint = subgraph.checkpoint();
+        subgraph.set_current_step(1);
+        subgraph.enable_node(n[2]);
+        subgraph.enable_edge(e[1]);
+        let intermediate_checkpoint = subgraph.checkpoint();
+        subgraph.disable_edge(e[0]);
+        subgraph.set_current_step(2);
+        subgraph.enable_node(n[3]);
+        subgraph.enable_edge(e[2]);
+        subgraph.disable_node(n[0]);
+
+        subgraph.rollback_to(intermediate_checkpoint);
+        assert_eq!(
+            subgraph.node_indices().collect::<Vec<_>>(),
+            n[0..3].to_vec()
+        );
+        assert_eq!(
+            subgraph.edge_indices().collect::<Vec<_>>(),
+            e[0..2].to_vec()
+        );
+        assert_eq!(subgraph.node_count(), 3);
+        assert_eq!(subgraph.edge_count(), 2);
+        assert!(subgraph.new_nodes().is_empty());
+        assert!(subgraph.removed_nodes().is_empty());
+
+        subgraph.rollback_to(checkpoint);
+        assert_eq!(
+            subgraph.node_indices().collect::<Vec<_>>(),
+            n[0..2].to_vec()
+        );
+        assert_eq!(subgraph.edge_count(), 1);
+        subgraph.set_current_step(1);
+        assert!(subgraph.new_nodes().is_empty());
+        assert_eq!(subgraph.node_count(), 2);
+
+        subgraph.enable_node(n[3]);
+        assert_eq!(subgraph.node_count(), 3);
+    }
+
+    #[test]
+    fn test_step_diff() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..3).map(|i| graph.add_edge(n[i], n[i + 1], ())).collect();
+        let mut subgraph = IncrementalSubgraph::new_with_incremental_steps(&graph, 4);
+
+        subgraph.enable_node(n[0]);
+        subgraph.enable_node(n[1]);
+        subgraph.enable_edge(e[0]);
+        subgraph.set_current_step(1);
+        subgraph.enable_node(n[2]);
+        subgraph.enable_edge(e[1]);
+        subgraph.disable_edge(e[0]);
+        subgraph.set_current_step(2);
+        subgraph.enable_node(n[3]);
+        subgraph.disable_node(n[3]);
+        subgraph.disable_node(n[0]);
+        subgraph.set_current_step(3);
+        subgraph.enable_edge(e[2]);
+
+        let diff = subgraph.step_diff(0, 2);
+        assert_eq!(diff.added_nodes().collect::<Vec<_>>(), [n[2]]);
+        assert_eq!(diff.removed_nodes().collect::<Vec<_>>(), [n[0]]);
+        assert_eq!(diff.added_edges().collect::<Vec<_>>(), [e[1]]);
+        assert_eq!(diff.removed_edges().collect::<Vec<_>>(), [e[0]]);
+
+        let diff = subgraph.step_diff(3, 1);
+        assert_eq!(diff.added_nodes().collect::<Vec<_>>(), [n[0]]);
+        assert_eq!(diff.removed_nodes().count(), 0);
+        assert_eq!(diff.added_edges().count(), 0);
+        assert_eq!(diff.removed_edges().collect::<Vec<_>>(), [e[2]]);
+
+        let diff = subgraph.step_diff(2, 2);
+        assert_eq!(diff.added_nodes().count(), 0);
+        assert_eq!(diff.removed_edges().count(), 0);
+        assert_eq!(subgraph.node_count(), 2);
     }
 }
@@ -1,15 +1,24 @@
+use crate::implementation::subgraphs::bit_vector_subgraph::{is_bit_disjoint, is_bit_subset};
 use crate::index::GraphIndex;
-use crate::interface::subgraph::{MutableSubgraph, SubgraphBase};
-use crate::interface::{Edge, GraphBase, ImmutableGraphContainer};
+use crate::interface::subgraph::{MutableSubgraph, SubgraphBase, SubgraphRelations};
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 use bitvec::bitvec;
 use bitvec::vec::BitVec;
 
 /// A subgraph implementation based on bitvectors.
 /// This subgraph only allows to enable or disable nodes,
 /// and edges are automatically contained if their endpoints exist.
+///
+/// The amount of present nodes and edges is tracked, so [node_count](ImmutableGraphContainer::node_count)
+/// and [edge_count](ImmutableGraphContainer::edge_count) take constant time.
+/// To keep the edge count up to date, enabling or disabling a node takes time linear in its degree in the parent graph.
 pub struct InducedBitVectorSubgraph<'a, Graph> {
     parent_graph: &'a Graph,
     present_nodes: BitVec,
+    node_count: usize,
+    edge_count: usize,
 }
 
 impl<'a, Graph: SubgraphBase> InducedBitVectorSubgraph<'a, Graph>
@@ -21,11 +30,36 @@ where
     pub fn new_empty(parent_graph: &'a Graph) -> Self {
         Self {
             parent_graph,
-            present_nodes: bitvec![0; parent_graph.root().node_count()],
+            present_nodes: bitvec![0; parent_graph.root().node_index_bound()],
+            node_count: 0,
+            edge_count: 0,
         }
     }
 }
 
+impl<Graph: NavigableGraph> InducedBitVectorSubgraph<'_, Graph> {
+    /// Returns the amount of edges in the parent graph that are incident to the given node
+    /// and whose other endpoint is present in this subgraph.
+    /// Self-loops are counted once.
+    fn present_incident_edge_count(&self, node_index: Graph::NodeIndex) -> usize {
+        let out_count = self
+            .parent_graph
+            .out_neighbors(node_index)
+            .filter(|neighbor| {
+                neighbor.node_id == node_index || self.present_nodes[neighbor.node_id.as_usize()]
+            })
+            .count();
+        let in_count = self
+            .parent_graph
+            .in_neighbors(node_index)
+            .filter(|neighbor| {
+                neighbor.node_id != node_index && self.present_nodes[neighbor.node_id.as_usize()]
+            })
+            .count();
+        out_count + in_count
+    }
+}
+
 impl<Graph: GraphBase> GraphBase for InducedBitVectorSubgraph<'_, Graph> {
     type NodeData = Graph::NodeData;
     type EdgeData = Graph::EdgeData;
@@ -39,7 +73,7 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer
     for InducedBitVectorSubgraph<'_, Graph>
 {
     type NodeIndices<'node_indices>
-        = std::iter::Filter<
+        = core::iter::Filter<
         Graph::NodeIndices<'node_indices>,
         Box<dyn 'node_indices + Fn(&Graph::NodeIndex) -> bool>,
     >
@@ -47,13 +81,18 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer
         Self: 'node_indices,
         Graph: 'node_indices;
     type EdgeIndices<'edge_indices>
-        = std::iter::Filter<
+        = core::iter::Filter<
         Graph::EdgeIndices<'edge_indices>,
         Box<dyn 'edge_indices + Fn(&Graph::EdgeIndex) -> bool>,
     >
     where
         Self: 'edge_indices,
         Graph: 'edge_indices;
+    type EdgeReferences<'edge_indices>
+        = EdgeReferences<'edge_indices, Self>
+    where
+        Self: 'edge_indices,
+        Graph: 'edge_indices;
 
     fn node_indices(&self) -> Self::NodeIndices<'_> {
         self.parent_graph
@@ -66,8 +105,8 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer
             .edge_indices()
             .filter(Box::new(|&edge_index| self.contains_edge_index(edge_index)))
     }
-    type NodeIndicesCopied = std::vec::IntoIter<Graph::NodeIndex>;
-    type EdgeIndicesCopied = std::vec::IntoIter<Graph::EdgeIndex>;
+    type NodeIndicesCopied = alloc::vec::IntoIter<Graph::NodeIndex>;
+    type EdgeIndicesCopied = alloc::vec::IntoIter<Graph::EdgeIndex>;
     fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
         self.node_indices().collect::<Vec<_>>().into_iter()
     }
@@ -91,11 +130,19 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer
     }
 
     fn node_count(&self) -> usize {
-        self.node_indices().count()
+        self.node_count
     }
 
     fn edge_count(&self) -> usize {
-        self.edge_indices().count()
+        self.edge_count
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.parent_graph.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.parent_graph.edge_index_bound()
     }
 
     fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
@@ -111,6 +158,10 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer
     fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
         self.parent_graph.edge_endpoints(edge_id)
     }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
 }
 
 impl<Graph: ImmutableGraphContainer + SubgraphBase> SubgraphBase
@@ -123,16 +174,19 @@ impl<Graph: ImmutableGraphContainer + SubgraphBase> SubgraphBase
     }
 }
 
-impl<Graph: ImmutableGraphContainer + SubgraphBase> MutableSubgraph
-    for InducedBitVectorSubgraph<'_, Graph>
+impl<Graph: NavigableGraph + SubgraphBase> MutableSubgraph for InducedBitVectorSubgraph<'_, Graph>
 where
     Self: GraphBase<
         NodeIndex = <Graph as GraphBase>::NodeIndex,
         EdgeIndex = <Graph as GraphBase>::EdgeIndex,
     >,
 {
+    const INDUCED: bool = true;
+
     fn clear(&mut self) {
         self.present_nodes.fill(false);
+        self.node_count = 0;
+        self.edge_count = 0;
     }
 
     fn fill(&mut self) {
@@ -146,7 +200,10 @@ where
         node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
     ) {
         debug_assert!(self.parent_graph.contains_node_index(node_index));
-        self.present_nodes.set(node_index.as_usize(), true);
+        if !self.present_nodes.replace(node_index.as_usize(), true) {
+            self.node_count += 1;
+            self.edge_count += self.present_incident_edge_count(node_index);
+        }
     }
 
     fn enable_edge(
@@ -161,7 +218,10 @@ where
         node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
     ) {
         debug_assert!(self.parent_graph.contains_node_index(node_index));
-        self.present_nodes.set(node_index.as_usize(), false);
+        if self.present_nodes.replace(node_index.as_usize(), false) {
+            self.node_count -= 1;
+            self.edge_count -= self.present_incident_edge_count(node_index);
+        }
     }
 
     fn disable_edge(
@@ -171,3 +231,63 @@ where
         unimplemented!("the induced bitvector subgraph allows only nodes to be enabled/disabled");
     }
 }
+
+impl<Graph: ImmutableGraphContainer + SubgraphBase> SubgraphRelations
+    for InducedBitVectorSubgraph<'_, Graph>
+{
+    /// Since the edges are induced by the nodes, only the nodes are compared.
+    fn is_subset_of(&self, other: &Self) -> bool {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        self.node_count <= other.node_count
+            && is_bit_subset(&self.present_nodes, &other.present_nodes)
+    }
+
+    /// Since the edges are induced by the nodes, only the nodes are compared.
+    fn is_disjoint_from(&self, other: &Self) -> bool {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        is_bit_disjoint(&self.present_nodes, &other.present_nodes)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::subgraphs::induced_bit_vector_subgraph::InducedBitVectorSubgraph;
+    use crate::interface::subgraph::MutableSubgraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_counts() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[0], ());
+        graph.add_edge(n[1], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        graph.add_edge(n[2], n[3], ());
+        let mut subgraph = InducedBitVectorSubgraph::new_empty(&graph);
+
+        subgraph.enable_node(n[1]);
+        assert_eq!(subgraph.node_count(), 1);
+        assert_eq!(subgraph.edge_count(), 1);
+        subgraph.enable_node(n[0]);
+        subgraph.enable_node(n[0]);
+        subgraph.enable_node(n[3]);
+        assert_eq!(subgraph.node_count(), 3);
+        assert_eq!(subgraph.edge_count(), 3);
+        assert_eq!(subgraph.edge_count(), subgraph.edge_indices().count());
+
+        subgraph.enable_node(n[2]);
+        assert_eq!(subgraph.edge_count(), 5);
+        subgraph.disable_node(n[1]);
+        subgraph.disable_node(n[1]);
+        assert_eq!(subgraph.node_count(), 3);
+        assert_eq!(subgraph.edge_count(), 1);
+        assert_eq!(subgraph.edge_count(), subgraph.edge_indices().count());
+
+        subgraph.clear();
+        subgraph.fill();
+        assert_eq!(subgraph.node_count(), 4);
+        assert_eq!(subgraph.edge_count(), 5);
+    }
+}
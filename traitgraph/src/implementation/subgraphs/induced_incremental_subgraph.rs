@@ -1,8 +1,12 @@
+use crate::implementation::subgraphs::incremental_subgraph::Checkpoint;
 use crate::index::{GraphIndex, OptionalGraphIndex};
 use crate::interface::subgraph::SubgraphBase;
-use crate::interface::{Edge, GraphBase, ImmutableGraphContainer};
-use std::iter::Filter;
-use std::marker::PhantomData;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::Filter;
+use core::marker::PhantomData;
 
 type IntegerType = usize;
 
@@ -13,11 +17,25 @@ type IntegerType = usize;
 /// This allows to combine multiple subgraphs into one, if they are totally ordered by the subset relation.
 ///
 /// In this variant of the incremental subgraph, edges are part of a subgraph if their endpoints are part of the subgraph.
+/// Hence, only nodes can be enabled, and this subgraph does not implement [MutableSubgraph](crate::interface::subgraph::MutableSubgraph).
+///
+/// The amount of present nodes and edges is tracked, so [node_count](ImmutableGraphContainer::node_count)
+/// and [edge_count](ImmutableGraphContainer::edge_count) take constant time.
+/// To this end, enabling a node takes time linear in its degree.
+/// Changing the current step takes time linear in the amount of steps between the old and the new step.
+///
+/// All enabled nodes are recorded, such that they can be rolled back to a [checkpoint](Self::checkpoint)
+/// in time linear in the amount of nodes enabled since the checkpoint.
 pub struct InducedIncrementalSubgraph<'a, Graph: GraphBase> {
     parent_graph: &'a Graph,
     present_nodes: Vec<IntegerType>,
     new_nodes: Vec<Vec<Graph::NodeIndex>>,
+    /// The amount of edges whose endpoints are both present from each step on.
+    new_edge_counts: Vec<usize>,
     current_step: IntegerType,
+    node_count: usize,
+    edge_count: usize,
+    enabled_nodes: Vec<Graph::NodeIndex>,
 }
 
 impl<Graph: GraphBase> GraphBase for InducedIncrementalSubgraph<'_, Graph> {
@@ -37,20 +55,130 @@ impl<Graph: SubgraphBase> SubgraphBase for InducedIncrementalSubgraph<'_, Graph>
     }
 }
 
-impl<'a, Graph: ImmutableGraphContainer> InducedIncrementalSubgraph<'a, Graph> {
+impl<'a, Graph: ImmutableGraphContainer + NavigableGraph> InducedIncrementalSubgraph<'a, Graph> {
     /// Create an incremental subgraph with the given amount of incremental steps.
     pub fn new_with_incremental_steps(graph: &'a Graph, incremental_steps: usize) -> Self {
         Self {
             parent_graph: graph,
-            present_nodes: vec![IntegerType::MAX; graph.node_count()],
+            present_nodes: vec![IntegerType::MAX; graph.node_index_bound()],
             new_nodes: vec![Default::default(); incremental_steps],
+            new_edge_counts: vec![0; incremental_steps],
             current_step: 0,
+            node_count: 0,
+            edge_count: 0,
+            enabled_nodes: Vec::new(),
+        }
+    }
+
+    /// Removes all nodes from the subgraph in all steps.
+    ///
+    /// The current step is not changed, and all checkpoints are invalidated.
+    pub fn clear(&mut self) {
+        self.present_nodes.fill(IntegerType::MAX);
+        self.new_nodes.iter_mut().for_each(Vec::clear);
+        self.new_edge_counts.fill(0);
+        self.enabled_nodes.clear();
+        self.node_count = 0;
+        self.edge_count = 0;
+    }
+
+    /// Enables all nodes of the parent graph that are not yet part of the subgraph in the current step.
+    ///
+    /// No node may be enabled in a later step than the current one.
+    pub fn fill(&mut self) {
+        for node_index in self.parent_graph.node_indices() {
+            if self.present_nodes[node_index.as_usize()] == IntegerType::MAX {
+                self.enable_node(node_index);
+            } else {
+                debug_assert!(self.contains_node_index(node_index));
+            }
+        }
+    }
+
+    /// Enables the given node in the current step.
+    /// The node must not be enabled in any step.
+    pub fn enable_node(&mut self, node_index: Graph::NodeIndex) {
+        debug_assert_eq!(self.present_nodes[node_index.as_usize()], IntegerType::MAX);
+        self.new_nodes[self.current_step].push(node_index);
+        self.present_nodes[node_index.as_usize()] = self.current_step;
+        self.node_count += 1;
+        self.enabled_nodes.push(node_index);
+        self.update_incident_edge_counts(node_index, true);
+    }
+
+    /// Adds or removes the edges that are incident to the given node and whose other endpoint is enabled in any step to or from the edge counts.
+    fn update_incident_edge_counts(&mut self, node_index: Graph::NodeIndex, add: bool) {
+        let parent_graph = self.parent_graph;
+        let node_step = self.present_nodes[node_index.as_usize()];
+        // Self-loops appear as both out- and in-neighbors, so they are only counted as out-neighbors.
+        let neighbors = parent_graph.out_neighbors(node_index).chain(
+            parent_graph
+                .in_neighbors(node_index)
+                .filter(|neighbor| neighbor.node_id != node_index),
+        );
+
+        for neighbor in neighbors {
+            let neighbor_step = self.present_nodes[neighbor.node_id.as_usize()];
+            if neighbor_step == IntegerType::MAX {
+                continue;
+            }
+
+            let edge_step = node_step.max(neighbor_step);
+            let is_present = edge_step <= self.current_step;
+            if add {
+                self.new_edge_counts[edge_step] += 1;
+                self.edge_count += usize::from(is_present);
+            } else {
+                self.new_edge_counts[edge_step] -= 1;
+                self.edge_count -= usize::from(is_present);
+            }
+        }
+    }
+
+    /// Returns a checkpoint of the current state that can be restored with [rollback_to](Self::rollback_to).
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.enabled_nodes.len())
+    }
+
+    /// Undoes all nodes enabled after the given checkpoint was created.
+    ///
+    /// The current step is not changed.
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) {
+        debug_assert!(
+            checkpoint.0 <= self.enabled_nodes.len(),
+            "The checkpoint was already rolled back"
+        );
+        while self.enabled_nodes.len() > checkpoint.0 {
+            let node_index = self.enabled_nodes.pop().unwrap();
+            let step = self.present_nodes[node_index.as_usize()];
+            debug_assert_eq!(self.new_nodes[step].last(), Some(&node_index));
+            self.update_incident_edge_counts(node_index, false);
+            self.new_nodes[step].pop();
+            self.present_nodes[node_index.as_usize()] = IntegerType::MAX;
+            if step <= self.current_step {
+                self.node_count -= 1;
+            }
         }
     }
 
     /// Set the current incremental step of the graph.
     pub fn set_current_step(&mut self, current_step: IntegerType) {
         debug_assert!(current_step < self.new_nodes.len());
+        if current_step > self.current_step {
+            let steps = self.current_step + 1..=current_step;
+            self.node_count += self.new_nodes[steps.clone()]
+                .iter()
+                .map(Vec::len)
+                .sum::<usize>();
+            self.edge_count += self.new_edge_counts[steps].iter().sum::<usize>();
+        } else {
+            let steps = current_step + 1..=self.current_step;
+            self.node_count -= self.new_nodes[steps.clone()]
+                .iter()
+                .map(Vec::len)
+                .sum::<usize>();
+            self.edge_count -= self.new_edge_counts[steps].iter().sum::<usize>();
+        }
         self.current_step = current_step;
     }
 
@@ -104,6 +232,19 @@ pub struct FilterNodeIndexIterator<
     phantom_optional_index: PhantomData<OptionalNodeIndex>,
 }
 
+impl<'a, NodeIndex, OptionalNodeIndex, NodeIndices: Iterator<Item = NodeIndex>, Graph>
+    FilterNodeIndexIterator<'a, NodeIndex, OptionalNodeIndex, NodeIndices, Graph>
+{
+    /// Creates a new instance iterating over the given `iterator` while filtering out nodes that are not in `graph`.
+    pub fn new(iterator: NodeIndices, graph: &'a Graph) -> Self {
+        Self {
+            iterator,
+            graph,
+            phantom_optional_index: PhantomData,
+        }
+    }
+}
+
 impl<
         NodeIndex: GraphIndex<OptionalNodeIndex>,
         OptionalNodeIndex: OptionalGraphIndex<NodeIndex>,
@@ -137,6 +278,10 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer
         = Filter<Graph::EdgeIndices<'a>, Box<dyn 'a + Fn(&<Graph as GraphBase>::EdgeIndex) -> bool>>
     where
         Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
 
     fn node_indices(&self) -> Self::NodeIndices<'_> {
         FilterNodeIndexIterator {
@@ -151,8 +296,8 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer
             .edge_indices()
             .filter(Box::new(|e| self.contains_edge_index(*e)))
     }
-    type NodeIndicesCopied = std::vec::IntoIter<Graph::NodeIndex>;
-    type EdgeIndicesCopied = std::vec::IntoIter<Graph::EdgeIndex>;
+    type NodeIndicesCopied = alloc::vec::IntoIter<Graph::NodeIndex>;
+    type EdgeIndicesCopied = alloc::vec::IntoIter<Graph::EdgeIndex>;
     fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
         self.node_indices().collect::<Vec<_>>().into_iter()
     }
@@ -172,11 +317,19 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer
     }
 
     fn node_count(&self) -> usize {
-        self.node_indices().count()
+        self.node_count
     }
 
     fn edge_count(&self) -> usize {
-        self.edge_indices().count()
+        self.edge_count
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.parent_graph.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.parent_graph.edge_index_bound()
     }
 
     fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
@@ -192,4 +345,105 @@ impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer
     fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
         self.parent_graph.edge_endpoints(edge_id)
     }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::subgraphs::induced_incremental_subgraph::InducedIncrementalSubgraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_rollback_to_checkpoint() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..3).map(|i| graph.add_edge(n[i], n[i + 1], ())).collect();
+        let mut subgraph = InducedIncrementalSubgraph::new_with_incremental_steps(&graph, 2);
+
+        subgraph.enable_node(n[0]);
+        let checkpoint = subgraph.checkpoint();
+        subgraph.enable_node(n[1]);
+        subgraph.set_current_step(1);
+        subgraph.enable_node(n[2]);
+        assert_eq!(subgraph.node_count(), 3);
+        assert_eq!(
+            subgraph.edge_indices().collect::<Vec<_>>(),
+            e[0..2].to_vec()
+        );
+
+        subgraph.rollback_to(checkpoint);
+        assert_eq!(subgraph.node_count(), 1);
+        assert_eq!(subgraph.node_indices().collect::<Vec<_>>(), vec![n[0]]);
+        assert!(subgraph.new_nodes().is_empty());
+        subgraph.set_current_step(0);
+        assert_eq!(subgraph.new_nodes(), &vec![n[0]]);
+    }
+
+    #[test]
+    fn test_clear_and_fill() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[2], ());
+        let mut subgraph = InducedIncrementalSubgraph::new_with_incremental_steps(&graph, 2);
+
+        subgraph.enable_node(n[1]);
+        subgraph.set_current_step(1);
+        subgraph.fill();
+        assert_eq!(subgraph.node_count(), 3);
+        assert_eq!(subgraph.edge_count(), 2);
+        assert_eq!(subgraph.new_nodes(), &vec![n[0], n[2]]);
+        subgraph.set_current_step(0);
+        assert_eq!(subgraph.node_count(), 1);
+        assert_eq!(subgraph.edge_count(), 0);
+
+        subgraph.clear();
+        assert_eq!(subgraph.node_count(), 0);
+        assert!(subgraph.new_nodes().is_empty());
+        subgraph.set_current_step(1);
+        assert_eq!(subgraph.node_count(), 0);
+        assert!(!subgraph.contains_node_index(n[1]));
+        subgraph.enable_node(n[1]);
+        assert_eq!(subgraph.node_count(), 1);
+    }
+
+    #[test]
+    fn test_edge_count() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(0, 1), (1, 0), (1, 1), (1, 2), (2, 3), (3, 0), (0, 1)] {
+            graph.add_edge(n[from], n[to], ());
+        }
+        let mut subgraph = InducedIncrementalSubgraph::new_with_incremental_steps(&graph, 3);
+        let assert_edge_count = |subgraph: &InducedIncrementalSubgraph<_>| {
+            assert_eq!(subgraph.edge_count(), subgraph.edge_indices().count());
+        };
+
+        subgraph.set_current_step(2);
+        subgraph.enable_node(n[3]);
+        subgraph.set_current_step(0);
+        subgraph.enable_node(n[1]);
+        assert_eq!(subgraph.edge_count(), 1);
+        subgraph.enable_node(n[0]);
+        assert_eq!(subgraph.edge_count(), 4);
+        let checkpoint = subgraph.checkpoint();
+        subgraph.set_current_step(1);
+        subgraph.enable_node(n[2]);
+        for step in [1, 2, 0, 2] {
+            subgraph.set_current_step(step);
+            assert_edge_count(&subgraph);
+        }
+        assert_eq!(subgraph.edge_count(), 7);
+
+        subgraph.rollback_to(checkpoint);
+        assert_eq!(subgraph.edge_count(), 5);
+        subgraph.set_current_step(1);
+        assert_eq!(subgraph.edge_count(), 4);
+        subgraph.clear();
+        assert_eq!(subgraph.edge_count(), 0);
+    }
 }
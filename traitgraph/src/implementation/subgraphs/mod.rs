@@ -2,6 +2,11 @@
 pub mod bit_vector_subgraph;
 /// Iterators that filter out nodes or edges missing from subgraphs.
 pub mod filter_iterators;
+/// A subgraph defined lazily by predicates over node and edge indices.
+pub mod filter_subgraph;
+/// A subgraph implementation based on hash sets, whose size is independent of the size of the root graph.
+#[cfg(feature = "std")]
+pub mod hash_subgraph;
 /// A subgraph implementation that allows to combine multiple subgraphs into one if they are totally ordered by the subset relation.
 pub mod incremental_subgraph;
 /// A subgraph implementation based on bitvectors.
@@ -14,5 +19,10 @@ pub mod induced_bit_vector_subgraph;
 /// This subgraph only allows to enable or disable nodes,
 /// and edges are automatically contained if their endpoints exist.
 pub mod induced_incremental_subgraph;
+/// A subgraph implementation based on roaring bitmaps, suited for clustered indices.
+#[cfg(feature = "roaring")]
+pub mod roaring_subgraph;
+/// A detached record of the nodes and edges present in a subgraph, which can be serialised and re-attached to its root graph.
+pub mod subgraph_membership;
 /// Inverting subgraphs and computing the union or cut set of subgraphs.
 pub mod subgraph_operators;
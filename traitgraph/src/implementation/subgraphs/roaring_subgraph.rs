@@ -0,0 +1,367 @@
+use crate::implementation::subgraphs::filter_iterators::{
+    FilterEdgeIndexIterator, FilterNeighborIterator,
+};
+use crate::index::{GraphIndex, OptionalGraphIndex};
+use crate::interface::subgraph::{
+    EmptyConstructibleSubgraph, MutableSubgraph, SubgraphBase, SubgraphRelations,
+};
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use core::marker::PhantomData;
+use roaring::RoaringBitmap;
+
+/// A subgraph that stores the presence or absence of a node or edge using roaring bitmaps.
+///
+/// Roaring bitmaps compress runs and sparse regions of indices,
+/// so for subgraphs whose indices are clustered, this is both smaller and faster than a [BitVectorSubgraph](super::bit_vector_subgraph::BitVectorSubgraph).
+/// Iterating over the nodes or edges does not iterate over the parent graph, and counting them takes constant time.
+/// Subgraphs of the same root graph can be combined in place with [union_with](Self::union_with) and [intersect_with](Self::intersect_with).
+///
+/// Roaring bitmaps store 32-bit integers, so enabling a node or edge with an index that does not fit into an `u32` panics.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::roaring_subgraph::RoaringSubgraph;
+/// use traitgraph::interface::subgraph::MutableSubgraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// let mut graph = PetGraph::<_, ()>::new();
+/// let nodes: Vec<_> = (0..10).map(|i| graph.add_node(i)).collect();
+///
+/// let mut subgraph = RoaringSubgraph::new_empty(&graph);
+/// let mut other = RoaringSubgraph::new_empty(&graph);
+/// nodes[..6].iter().for_each(|&node_id| subgraph.enable_node(node_id));
+/// nodes[4..].iter().for_each(|&node_id| other.enable_node(node_id));
+///
+/// subgraph.intersect_with(&other);
+/// assert_eq!(subgraph.node_indices().collect::<Vec<_>>(), nodes[4..6].to_vec());
+/// subgraph.union_with(&other);
+/// assert_eq!(subgraph.node_count(), 6);
+/// ```
+pub struct RoaringSubgraph<'a, Graph> {
+    parent_graph: &'a Graph,
+    present_nodes: RoaringBitmap,
+    present_edges: RoaringBitmap,
+}
+
+impl<'a, Graph> RoaringSubgraph<'a, Graph> {
+    /// Constructs a new instance decorating the given graph.
+    /// The subgraph is initialised empty.
+    pub fn new_empty(parent_graph: &'a Graph) -> Self {
+        Self {
+            parent_graph,
+            present_nodes: RoaringBitmap::new(),
+            present_edges: RoaringBitmap::new(),
+        }
+    }
+}
+
+impl<Graph: SubgraphBase> RoaringSubgraph<'_, Graph> {
+    /// Adds all nodes and edges of the other subgraph to this subgraph.
+    ///
+    /// Both subgraphs need to have the same root graph.
+    pub fn union_with(&mut self, other: &Self) {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        self.present_nodes |= &other.present_nodes;
+        self.present_edges |= &other.present_edges;
+    }
+
+    /// Removes all nodes and edges from this subgraph that are not contained in the other subgraph.
+    ///
+    /// Both subgraphs need to have the same root graph.
+    pub fn intersect_with(&mut self, other: &Self) {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        self.present_nodes &= &other.present_nodes;
+        self.present_edges &= &other.present_edges;
+    }
+}
+
+fn bitmap_key<Index: GraphIndex<OptionalIndex>, OptionalIndex: OptionalGraphIndex<Index>>(
+    index: Index,
+) -> u32 {
+    u32::try_from(index.as_usize()).expect("roaring subgraphs support only indices below 2^32")
+}
+
+/// An iterator over the indices stored in a roaring bitmap, in ascending order.
+pub struct RoaringIndexIterator<Index, BitmapIterator> {
+    iterator: BitmapIterator,
+    phantom_index: PhantomData<Index>,
+}
+
+impl<Index, BitmapIterator> RoaringIndexIterator<Index, BitmapIterator> {
+    fn new(iterator: BitmapIterator) -> Self {
+        Self {
+            iterator,
+            phantom_index: PhantomData,
+        }
+    }
+}
+
+impl<Index: From<usize>, BitmapIterator: Iterator<Item = u32>> Iterator
+    for RoaringIndexIterator<Index, BitmapIterator>
+{
+    type Item = Index;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator
+            .next()
+            .map(|index| Index::from(index as usize))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<Graph: GraphBase> GraphBase for RoaringSubgraph<'_, Graph> {
+    type NodeData = Graph::NodeData;
+    type EdgeData = Graph::EdgeData;
+    type OptionalNodeIndex = Graph::OptionalNodeIndex;
+    type OptionalEdgeIndex = Graph::OptionalEdgeIndex;
+    type NodeIndex = Graph::NodeIndex;
+    type EdgeIndex = Graph::EdgeIndex;
+}
+
+impl<Graph: ImmutableGraphContainer> ImmutableGraphContainer for RoaringSubgraph<'_, Graph> {
+    type NodeIndices<'a>
+        = RoaringIndexIterator<Graph::NodeIndex, roaring::bitmap::Iter<'a>>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = RoaringIndexIterator<Graph::EdgeIndex, roaring::bitmap::Iter<'a>>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+    type NodeIndicesCopied = RoaringIndexIterator<Graph::NodeIndex, roaring::bitmap::IntoIter>;
+    type EdgeIndicesCopied = RoaringIndexIterator<Graph::EdgeIndex, roaring::bitmap::IntoIter>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        RoaringIndexIterator::new(self.present_nodes.iter())
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        RoaringIndexIterator::new(self.present_edges.iter())
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        RoaringIndexIterator::new(self.present_nodes.clone().into_iter())
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        RoaringIndexIterator::new(self.present_edges.clone().into_iter())
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        u32::try_from(node_id.as_usize()).is_ok_and(|key| self.present_nodes.contains(key))
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        u32::try_from(edge_id.as_usize()).is_ok_and(|key| self.present_edges.contains(key))
+    }
+
+    fn node_count(&self) -> usize {
+        self.present_nodes.len() as usize
+    }
+
+    fn edge_count(&self) -> usize {
+        self.present_edges.len() as usize
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.parent_graph.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.parent_graph.edge_index_bound()
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        debug_assert!(self.contains_node_index(node_id));
+        self.parent_graph.node_data(node_id)
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.parent_graph.edge_data(edge_id)
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.parent_graph.edge_endpoints(edge_id)
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<Graph: NavigableGraph> NavigableGraph for RoaringSubgraph<'_, Graph> {
+    type OutNeighbors<'a>
+        = FilterNeighborIterator<'a, <Graph as NavigableGraph>::OutNeighbors<'a>, Self>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = FilterNeighborIterator<'a, <Graph as NavigableGraph>::InNeighbors<'a>, Self>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = FilterEdgeIndexIterator<'a, <Graph as NavigableGraph>::EdgesBetween<'a>, Self>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        FilterNeighborIterator::new(self.parent_graph.out_neighbors(node_id), self)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        FilterNeighborIterator::new(self.parent_graph.in_neighbors(node_id), self)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        FilterEdgeIndexIterator::new(
+            self.parent_graph.edges_between(from_node_id, to_node_id),
+            self,
+        )
+    }
+}
+
+impl<Graph: SubgraphBase> SubgraphBase for RoaringSubgraph<'_, Graph> {
+    type RootGraph = Graph::RootGraph;
+
+    fn root(&self) -> &Self::RootGraph {
+        self.parent_graph.root()
+    }
+}
+
+impl<Graph: ImmutableGraphContainer + SubgraphBase> MutableSubgraph for RoaringSubgraph<'_, Graph>
+where
+    Self: GraphBase<
+        NodeIndex = <Graph as GraphBase>::NodeIndex,
+        EdgeIndex = <Graph as GraphBase>::EdgeIndex,
+    >,
+{
+    fn clear(&mut self) {
+        self.present_nodes.clear();
+        self.present_edges.clear();
+    }
+
+    fn fill(&mut self) {
+        self.present_nodes
+            .extend(self.parent_graph.node_indices().map(bitmap_key));
+        self.present_edges
+            .extend(self.parent_graph.edge_indices().map(bitmap_key));
+    }
+
+    fn enable_node(
+        &mut self,
+        node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
+    ) {
+        debug_assert!(self.parent_graph.contains_node_index(node_index));
+        self.present_nodes.insert(bitmap_key(node_index));
+    }
+
+    fn enable_edge(
+        &mut self,
+        edge_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::EdgeIndex,
+    ) {
+        debug_assert!(self.parent_graph.contains_edge_index(edge_index));
+        self.present_edges.insert(bitmap_key(edge_index));
+    }
+
+    fn disable_node(
+        &mut self,
+        node_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::NodeIndex,
+    ) {
+        debug_assert!(self.parent_graph.contains_node_index(node_index));
+        self.present_nodes.remove(bitmap_key(node_index));
+    }
+
+    fn disable_edge(
+        &mut self,
+        edge_index: <<Self as SubgraphBase>::RootGraph as GraphBase>::EdgeIndex,
+    ) {
+        debug_assert!(self.parent_graph.contains_edge_index(edge_index));
+        self.present_edges.remove(bitmap_key(edge_index));
+    }
+}
+
+impl<Graph: SubgraphBase> SubgraphRelations for RoaringSubgraph<'_, Graph> {
+    fn is_subset_of(&self, other: &Self) -> bool {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        self.present_nodes.is_subset(&other.present_nodes)
+            && self.present_edges.is_subset(&other.present_edges)
+    }
+
+    fn is_disjoint_from(&self, other: &Self) -> bool {
+        debug_assert!(core::ptr::eq(self.root(), other.root()));
+        self.present_nodes.is_disjoint(&other.present_nodes)
+            && self.present_edges.is_disjoint(&other.present_edges)
+    }
+}
+
+impl<'a, Graph: ImmutableGraphContainer + SubgraphBase> EmptyConstructibleSubgraph<'a>
+    for RoaringSubgraph<'a, Graph>
+where
+    Self: SubgraphBase<RootGraph = Graph>,
+{
+    fn new_empty(root_graph: &'a <Self as SubgraphBase>::RootGraph) -> Self {
+        Self {
+            parent_graph: root_graph,
+            present_nodes: RoaringBitmap::new(),
+            present_edges: RoaringBitmap::new(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::subgraphs::roaring_subgraph::RoaringSubgraph;
+    use crate::interface::subgraph::MutableSubgraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_set_operations() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..5).map(|i| graph.add_edge(n[i], n[i + 1], i)).collect();
+
+        let mut subgraph0 = RoaringSubgraph::new_empty(&graph);
+        (0..4).for_each(|i| subgraph0.enable_node(n[i]));
+        (0..3).for_each(|i| subgraph0.enable_edge(e[i]));
+        let mut subgraph1 = RoaringSubgraph::new_empty(&graph);
+        (2..6).for_each(|i| subgraph1.enable_node(n[i]));
+        (2..5).for_each(|i| subgraph1.enable_edge(e[i]));
+
+        let mut intersection = RoaringSubgraph::new_empty(&graph);
+        intersection.fill();
+        intersection.intersect_with(&subgraph0);
+        intersection.intersect_with(&subgraph1);
+        assert_eq!(
+            intersection.node_indices().collect::<Vec<_>>(),
+            n[2..4].to_vec()
+        );
+        assert_eq!(
+            intersection.edge_indices_copied().collect::<Vec<_>>(),
+            vec![e[2]]
+        );
+        assert_eq!(intersection.out_neighbors(n[2]).count(), 1);
+        assert_eq!(intersection.out_neighbors(n[3]).count(), 0);
+
+        subgraph0.union_with(&subgraph1);
+        assert_eq!(subgraph0.node_count(), 6);
+        assert_eq!(subgraph0.edge_count(), 5);
+        subgraph0.disable_edge(e[1]);
+        assert!(!subgraph0.contains_edge_between(n[1], n[2]));
+        subgraph0.clear();
+        assert_eq!(subgraph0.node_indices().next(), None);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::interface::subgraph::{MutableSubgraph, SubgraphBase};
+use crate::interface::ImmutableGraphContainer;
+use alloc::vec::Vec;
+
+/// The node and edge indices present in a subgraph, detached from the subgraph's root graph.
+///
+/// Subgraphs borrow their root graph, so they cannot be stored on their own.
+/// This type stores only which nodes and edges are present, and can be applied to a new subgraph of the root graph later,
+/// e.g. to persist the result of an expensive filter.
+/// With the `serde` feature, it implements `Serialize` and `Deserialize`.
+///
+/// The [generation](ImmutableGraphContainer::generation) of the root graph is recorded as well,
+/// and applying the membership to a subgraph of a root graph that was mutated in the meantime panics in debug builds.
+/// The generation is not serialised, so deserialised memberships are not checked.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+/// use traitgraph::implementation::subgraphs::subgraph_membership::SubgraphMembership;
+/// use traitgraph::interface::subgraph::MutableSubgraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// let mut graph = PetGraph::new();
+/// let n0 = graph.add_node(0);
+/// let n1 = graph.add_node(1);
+/// let e0 = graph.add_edge(n0, n1, ());
+///
+/// let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+/// subgraph.enable_node(n1);
+/// let membership = SubgraphMembership::new(&subgraph);
+///
+/// let mut restored = BitVectorSubgraph::new_empty(&graph);
+/// membership.apply_to(&mut restored);
+/// assert_eq!(restored.node_indices().collect::<Vec<_>>(), [n1]);
+/// assert!(!restored.contains_edge_index(e0));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubgraphMembership<NodeIndex, EdgeIndex> {
+    nodes: Vec<NodeIndex>,
+    edges: Vec<EdgeIndex>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    root_generation: Option<u64>,
+}
+
+impl<NodeIndex: Copy, EdgeIndex: Copy> SubgraphMembership<NodeIndex, EdgeIndex> {
+    /// Records the nodes and edges present in the given subgraph.
+    pub fn new<
+        Subgraph: ImmutableGraphContainer<NodeIndex = NodeIndex, EdgeIndex = EdgeIndex> + SubgraphBase,
+    >(
+        subgraph: &Subgraph,
+    ) -> Self
+    where
+        Subgraph::RootGraph: ImmutableGraphContainer,
+    {
+        Self {
+            nodes: subgraph.node_indices().collect(),
+            edges: subgraph.edge_indices().collect(),
+            root_generation: subgraph.root().generation(),
+        }
+    }
+
+    /// Returns the recorded node indices, in the order in which they were iterated in the subgraph.
+    pub fn nodes(&self) -> &[NodeIndex] {
+        &self.nodes
+    }
+
+    /// Returns the recorded edge indices, in the order in which they were iterated in the subgraph.
+    pub fn edges(&self) -> &[EdgeIndex] {
+        &self.edges
+    }
+
+    /// Enables all recorded nodes and edges in the given subgraph.
+    ///
+    /// If the subgraph is [induced](MutableSubgraph::INDUCED), then only the nodes are enabled,
+    /// and the edges between them are present in the subgraph whether they were recorded or not.
+    ///
+    /// Panics if a recorded node or edge does not exist in the root graph of the subgraph,
+    /// e.g. because the membership was recorded from a subgraph of a different graph.
+    /// In debug builds, also panics if the root graph was mutated since the membership was recorded.
+    pub fn apply_to<Subgraph: MutableSubgraph<NodeIndex = NodeIndex, EdgeIndex = EdgeIndex>>(
+        &self,
+        subgraph: &mut Subgraph,
+    ) where
+        Subgraph::RootGraph: ImmutableGraphContainer,
+    {
+        let root = subgraph.root();
+        assert!(
+            self.nodes
+                .iter()
+                .all(|&node_id| root.contains_node_index(node_id)),
+            "the membership contains a node that is not in the root graph"
+        );
+        assert!(
+            self.edges
+                .iter()
+                .all(|&edge_id| root.contains_edge_index(edge_id)),
+            "the membership contains an edge that is not in the root graph"
+        );
+        // Checked after the indices, since the generation cannot tell apart different graphs.
+        debug_assert!(
+            self.root_generation.is_none() || self.root_generation == root.generation(),
+            "the root graph was mutated after the membership was recorded"
+        );
+
+        for &node_id in &self.nodes {
+            subgraph.enable_node(node_id);
+        }
+        if !Subgraph::INDUCED {
+            for &edge_id in &self.edges {
+                subgraph.enable_edge(edge_id);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+    use crate::implementation::subgraphs::subgraph_membership::SubgraphMembership;
+    use crate::interface::subgraph::MutableSubgraph;
+    use crate::interface::MutableGraphContainer;
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        use crate::index::{EdgeIndex, NodeIndex};
+        use crate::interface::ImmutableGraphContainer;
+
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..3).map(|i| graph.add_edge(n[i], n[i + 1], i)).collect();
+
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        subgraph.enable_node(n[1]);
+        subgraph.enable_node(n[2]);
+        subgraph.enable_edge(e[1]);
+
+        let json = serde_json::to_string(&SubgraphMembership::new(&subgraph)).unwrap();
+        debug_assert_eq!(json, r#"{"nodes":[1,2],"edges":[1]}"#);
+        let membership: SubgraphMembership<NodeIndex<usize>, EdgeIndex<usize>> =
+            serde_json::from_str(&json).unwrap();
+        let mut restored = BitVectorSubgraph::new_empty(&graph);
+        membership.apply_to(&mut restored);
+        debug_assert_eq!(
+            restored.node_indices().collect::<Vec<_>>(),
+            subgraph.node_indices().collect::<Vec<_>>()
+        );
+        debug_assert_eq!(
+            restored.edge_indices().collect::<Vec<_>>(),
+            subgraph.edge_indices().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_induced_round_trip() {
+        use crate::implementation::subgraphs::induced_bit_vector_subgraph::InducedBitVectorSubgraph;
+        use crate::implementation::subgraphs::induced_incremental_subgraph::InducedIncrementalSubgraph;
+        use crate::interface::ImmutableGraphContainer;
+
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..3).map(|i| graph.add_edge(n[i], n[i + 1], i)).collect();
+
+        let mut incremental = InducedIncrementalSubgraph::new_with_incremental_steps(&graph, 1);
+        incremental.enable_node(n[1]);
+        incremental.enable_node(n[2]);
+        let membership = SubgraphMembership::new(&incremental);
+        debug_assert_eq!(membership.edges(), [e[1]]);
+
+        let mut induced = InducedBitVectorSubgraph::new_empty(&graph);
+        membership.apply_to(&mut induced);
+        debug_assert_eq!(induced.node_indices().collect::<Vec<_>>(), n[1..3]);
+        debug_assert_eq!(induced.edge_indices().collect::<Vec<_>>(), [e[1]]);
+
+        let mut restored = InducedBitVectorSubgraph::new_empty(&graph);
+        SubgraphMembership::new(&induced).apply_to(&mut restored);
+        debug_assert_eq!(restored.node_count(), 2);
+        debug_assert_eq!(restored.edge_count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the membership contains a node that is not in the root graph")]
+    fn test_apply_to_wrong_graph() {
+        let mut graph = PetGraph::<_, ()>::new();
+        graph.add_node(0);
+        let node = graph.add_node(1);
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        subgraph.enable_node(node);
+        let membership = SubgraphMembership::new(&subgraph);
+
+        let mut other_graph = PetGraph::<_, ()>::new();
+        other_graph.add_node(0);
+        let mut other_subgraph = BitVectorSubgraph::new_empty(&other_graph);
+        membership.apply_to(&mut other_subgraph);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "the root graph was mutated after the membership was recorded")]
+    fn test_apply_to_mutated_graph() {
+        let mut graph = PetGraph::<_, ()>::new();
+        let n0 = graph.add_node(0);
+        let n1 = graph.add_node(1);
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        subgraph.enable_node(n1);
+        let membership = SubgraphMembership::new(&subgraph);
+
+        // After removing n0, petgraph moves n1 to the index of n0,
+        // so the membership would silently refer to the wrong node.
+        graph.remove_node(n0);
+        graph.add_node(2);
+        let mut restored = BitVectorSubgraph::new_empty(&graph);
+        membership.apply_to(&mut restored);
+    }
+}
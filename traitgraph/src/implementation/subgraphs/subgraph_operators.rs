@@ -1,6 +1,13 @@
+/// A subgraph that contains all nodes and edges of a universe subgraph that an inner subgraph does not contain,
+/// except for those edges that are missing endpoints after complementing.
+pub mod complement_within_subgraph;
+
 /// A subgraph that contains all nodes and edges that another subgraph does not contain,
 /// except for those edges that are missing endpoints after inversion.
 pub mod inverted_subgraph;
 
+/// A subgraph built from the union of arbitrarily many subgraphs.
+pub mod multi_union_subgraph;
+
 /// A subgraph built from the union of two graphs.
 pub mod union_subgraph;
@@ -0,0 +1,240 @@
+use crate::implementation::subgraphs::filter_iterators::{
+    FilterEdgeIndexIterator, FilterNeighborIterator,
+};
+use crate::implementation::subgraphs::induced_incremental_subgraph::FilterNodeIndexIterator;
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::vec::Vec;
+
+/// A subgraph that contains all nodes and edges of a universe subgraph that an inner subgraph does not contain,
+/// except for those edges that are missing endpoints after complementing.
+///
+/// In contrast to the [InvertedSubgraph](super::inverted_subgraph::InvertedSubgraph),
+/// which complements against the whole root graph, this restricts the complement to the universe.
+/// Both subgraphs are expected to have the same root graph.
+/// Membership is evaluated lazily, so counting the nodes or edges takes time linear in the size of the universe.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+/// use traitgraph::implementation::subgraphs::subgraph_operators::complement_within_subgraph::ComplementWithinSubgraph;
+/// use traitgraph::interface::subgraph::MutableSubgraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// let mut graph = PetGraph::new();
+/// let nodes: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+/// let edges: Vec<_> = (0..3).map(|i| graph.add_edge(nodes[i], nodes[i + 1], ())).collect();
+///
+/// let mut universe = BitVectorSubgraph::new_empty(&graph);
+/// nodes[0..3].iter().for_each(|&node_id| universe.enable_node(node_id));
+/// edges[0..2].iter().for_each(|&edge_id| universe.enable_edge(edge_id));
+/// let mut inner = BitVectorSubgraph::new_empty(&graph);
+/// inner.enable_node(nodes[2]);
+///
+/// let complement = ComplementWithinSubgraph::new(&universe, &inner);
+/// assert_eq!(complement.node_indices().collect::<Vec<_>>(), nodes[0..2]);
+/// assert_eq!(complement.edge_indices().collect::<Vec<_>>(), edges[0..1]);
+/// # }
+/// ```
+pub struct ComplementWithinSubgraph<'a, Universe, Inner> {
+    universe: &'a Universe,
+    inner: &'a Inner,
+}
+
+impl<'a, Universe, Inner> ComplementWithinSubgraph<'a, Universe, Inner> {
+    /// Create a new subgraph containing the nodes and edges of `universe` that are not in `inner`.
+    pub fn new(universe: &'a Universe, inner: &'a Inner) -> Self {
+        Self { universe, inner }
+    }
+}
+
+impl<Universe: GraphBase, Inner> GraphBase for ComplementWithinSubgraph<'_, Universe, Inner> {
+    type NodeData = Universe::NodeData;
+    type EdgeData = Universe::EdgeData;
+    type OptionalNodeIndex = Universe::OptionalNodeIndex;
+    type OptionalEdgeIndex = Universe::OptionalEdgeIndex;
+    type NodeIndex = Universe::NodeIndex;
+    type EdgeIndex = Universe::EdgeIndex;
+}
+
+impl<Universe: SubgraphBase, Inner> SubgraphBase for ComplementWithinSubgraph<'_, Universe, Inner> {
+    type RootGraph = Universe::RootGraph;
+
+    fn root(&self) -> &Self::RootGraph {
+        self.universe.root()
+    }
+}
+
+impl<
+        Universe: ImmutableGraphContainer,
+        Inner: ImmutableGraphContainer<NodeIndex = Universe::NodeIndex, EdgeIndex = Universe::EdgeIndex>,
+    > ImmutableGraphContainer for ComplementWithinSubgraph<'_, Universe, Inner>
+{
+    type NodeIndices<'a>
+        = FilterNodeIndexIterator<
+        'a,
+        Self::NodeIndex,
+        Self::OptionalNodeIndex,
+        Universe::NodeIndices<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = FilterEdgeIndexIterator<'a, Universe::EdgeIndices<'a>, Self>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+    type NodeIndicesCopied = alloc::vec::IntoIter<Universe::NodeIndex>;
+    type EdgeIndicesCopied = alloc::vec::IntoIter<Universe::EdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        FilterNodeIndexIterator::new(self.universe.node_indices(), self)
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        FilterEdgeIndexIterator::new(self.universe.edge_indices(), self)
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        self.node_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        self.edge_indices().collect::<Vec<_>>().into_iter()
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        self.universe.contains_node_index(node_id) && !self.inner.contains_node_index(node_id)
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        if !self.universe.contains_edge_index(edge_id) || self.inner.contains_edge_index(edge_id) {
+            return false;
+        }
+        let Edge { from_node, to_node } = self.universe.edge_endpoints(edge_id);
+        self.contains_node_index(from_node) && self.contains_node_index(to_node)
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_indices().count()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_indices().count()
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.universe.node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.universe.edge_index_bound()
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        debug_assert!(self.contains_node_index(node_id));
+        self.universe.node_data(node_id)
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.universe.edge_data(edge_id)
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.universe.edge_endpoints(edge_id)
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<
+        Universe: NavigableGraph,
+        Inner: ImmutableGraphContainer<NodeIndex = Universe::NodeIndex, EdgeIndex = Universe::EdgeIndex>,
+    > NavigableGraph for ComplementWithinSubgraph<'_, Universe, Inner>
+{
+    type OutNeighbors<'a>
+        = FilterNeighborIterator<'a, <Universe as NavigableGraph>::OutNeighbors<'a>, Self>
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = FilterNeighborIterator<'a, <Universe as NavigableGraph>::InNeighbors<'a>, Self>
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = FilterEdgeIndexIterator<'a, <Universe as NavigableGraph>::EdgesBetween<'a>, Self>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        FilterNeighborIterator::new(self.universe.out_neighbors(node_id), self)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        FilterNeighborIterator::new(self.universe.in_neighbors(node_id), self)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        FilterEdgeIndexIterator::new(self.universe.edges_between(from_node_id, to_node_id), self)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+    use crate::implementation::subgraphs::subgraph_operators::complement_within_subgraph::ComplementWithinSubgraph;
+    use crate::interface::subgraph::MutableSubgraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_complement_within_subgraph() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = [(0, 1), (1, 2), (2, 3), (3, 4), (1, 3), (1, 3)]
+            .into_iter()
+            .map(|(from, to)| graph.add_edge(n[from], n[to], ()))
+            .collect();
+
+        let mut universe = BitVectorSubgraph::new_empty(&graph);
+        universe.fill();
+        universe.disable_node(n[4]);
+        universe.disable_edge(e[3]);
+        universe.disable_edge(e[5]);
+        let mut inner = BitVectorSubgraph::new_empty(&graph);
+        inner.enable_node(n[0]);
+        inner.enable_node(n[4]);
+
+        let complement = ComplementWithinSubgraph::new(&universe, &inner);
+        assert_eq!(
+            complement.node_indices().collect::<Vec<_>>(),
+            n[1..4].to_vec()
+        );
+        assert_eq!(
+            complement.edge_indices().collect::<Vec<_>>(),
+            [e[1], e[2], e[4]]
+        );
+        assert_eq!(complement.node_count(), 3);
+        assert_eq!(complement.edge_count(), 3);
+        assert_eq!(complement.in_neighbors(n[1]).count(), 0);
+        assert_eq!(complement.out_neighbors(n[1]).count(), 2);
+        assert_eq!(
+            complement.edges_between(n[1], n[3]).collect::<Vec<_>>(),
+            [e[4]]
+        );
+    }
+}
@@ -1,11 +1,19 @@
+use crate::implementation::subgraphs::filter_iterators::{
+    FilterEdgeIndexIterator, FilterNeighborIterator,
+};
+use crate::implementation::subgraphs::induced_incremental_subgraph::FilterNodeIndexIterator;
 use crate::index::{GraphIndex, OptionalGraphIndex};
 use crate::interface::subgraph::SubgraphBase;
-use crate::interface::{Edge, GraphBase, ImmutableGraphContainer};
-use std::iter;
-use std::marker::PhantomData;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::vec::Vec;
+use core::iter;
+use core::marker::PhantomData;
 
-/// A subgraph that contains all nodes and edges that another subgraph does not contain,
+/// A subgraph that contains all nodes and edges of the root graph that another subgraph does not contain,
 /// except for those edges that are missing endpoints after inversion.
+///
+/// Indices below the index bound of the root graph that do not belong to a node or edge of the root graph,
+/// e.g. those of removed nodes and edges, are not contained.
 pub struct InvertedSubgraph<'a, Graph>(&'a Graph);
 
 impl<'a, Graph> InvertedSubgraph<'a, Graph> {
@@ -32,12 +40,14 @@ impl<Graph: SubgraphBase> SubgraphBase for InvertedSubgraph<'_, Graph> {
     }
 }
 
-/// An iterator that returns all graph indices not present in another iterator.
+/// An iterator that returns all graph indices below a limit that are not present in another iterator.
 /// The iterator `UninvertedIterator` is expected to be sorted.
+///
+/// The returned indices are not checked for existence in any graph.
 pub struct InvertedIndexIterator<Index, OptionalIndex, UninvertedIterator: Iterator> {
     uninverted_iterator: iter::Peekable<UninvertedIterator>,
     current: usize,
-    maximum: usize,
+    limit: usize,
     phantom_index: PhantomData<Index>,
     phantom_optional_index: PhantomData<OptionalIndex>,
 }
@@ -51,7 +61,7 @@ impl<
     type Item = UninvertedIterator::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.current <= self.maximum {
+        while self.current < self.limit {
             if let Some(next_missing_index) = self.uninverted_iterator.peek() {
                 if self.current < next_missing_index.as_usize() {
                     let result = self.current;
@@ -78,77 +88,93 @@ where
     <Graph as SubgraphBase>::RootGraph: ImmutableGraphContainer,
 {
     type NodeIndices<'a>
-        = InvertedIndexIterator<Self::NodeIndex, Self::OptionalNodeIndex, Graph::NodeIndices<'a>>
+        = FilterNodeIndexIterator<
+        'a,
+        Self::NodeIndex,
+        Self::OptionalNodeIndex,
+        InvertedIndexIterator<Self::NodeIndex, Self::OptionalNodeIndex, Graph::NodeIndices<'a>>,
+        Self,
+    >
     where
         Self: 'a;
     type EdgeIndices<'a>
-        = InvertedIndexIterator<Self::EdgeIndex, Self::OptionalEdgeIndex, Graph::EdgeIndices<'a>>
+        = FilterEdgeIndexIterator<
+        'a,
+        InvertedIndexIterator<Self::EdgeIndex, Self::OptionalEdgeIndex, Graph::EdgeIndices<'a>>,
+        Self,
+    >
     where
         Self: 'a;
-    type NodeIndicesCopied =
-        InvertedIndexIterator<Self::NodeIndex, Self::OptionalNodeIndex, Graph::NodeIndicesCopied>;
-    type EdgeIndicesCopied =
-        InvertedIndexIterator<Self::EdgeIndex, Self::OptionalEdgeIndex, Graph::EdgeIndicesCopied>;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+    type NodeIndicesCopied = alloc::vec::IntoIter<Self::NodeIndex>;
+    type EdgeIndicesCopied = alloc::vec::IntoIter<Self::EdgeIndex>;
 
     fn node_indices(&self) -> Self::NodeIndices<'_> {
-        InvertedIndexIterator {
-            uninverted_iterator: self.0.node_indices().peekable(),
-            current: 0,
-            maximum: self.root().node_count(),
-            phantom_index: Default::default(),
-            phantom_optional_index: Default::default(),
-        }
+        FilterNodeIndexIterator::new(
+            InvertedIndexIterator {
+                uninverted_iterator: self.0.node_indices().peekable(),
+                current: 0,
+                limit: self.root().node_index_bound(),
+                phantom_index: Default::default(),
+                phantom_optional_index: Default::default(),
+            },
+            self,
+        )
     }
 
     fn edge_indices(&self) -> Self::EdgeIndices<'_> {
-        InvertedIndexIterator {
-            uninverted_iterator: self.0.edge_indices().peekable(),
-            current: 0,
-            maximum: self.root().edge_count(),
-            phantom_index: Default::default(),
-            phantom_optional_index: Default::default(),
-        }
+        FilterEdgeIndexIterator::new(
+            InvertedIndexIterator {
+                uninverted_iterator: self.0.edge_indices().peekable(),
+                current: 0,
+                limit: self.root().edge_index_bound(),
+                phantom_index: Default::default(),
+                phantom_optional_index: Default::default(),
+            },
+            self,
+        )
     }
 
     fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
-        InvertedIndexIterator {
-            uninverted_iterator: self.0.node_indices_copied().peekable(),
-            current: 0,
-            maximum: self.root().node_count(),
-            phantom_index: Default::default(),
-            phantom_optional_index: Default::default(),
-        }
+        self.node_indices().collect::<Vec<_>>().into_iter()
     }
 
     fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
-        InvertedIndexIterator {
-            uninverted_iterator: self.0.edge_indices_copied().peekable(),
-            current: 0,
-            maximum: self.root().edge_count(),
-            phantom_index: Default::default(),
-            phantom_optional_index: Default::default(),
-        }
+        self.edge_indices().collect::<Vec<_>>().into_iter()
     }
 
     fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
-        !self.0.contains_node_index(node_id)
+        self.root().contains_node_index(node_id) && !self.0.contains_node_index(node_id)
     }
 
     fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
-        let Edge { from_node, to_node } = self.edge_endpoints(edge_id);
-        !self.0.contains_edge_index(edge_id)
-            && self.contains_node_index(from_node)
-            && self.contains_node_index(to_node)
+        if !self.root().contains_edge_index(edge_id) || self.0.contains_edge_index(edge_id) {
+            return false;
+        }
+        let Edge { from_node, to_node } = self.root().edge_endpoints(edge_id);
+        self.contains_node_index(from_node) && self.contains_node_index(to_node)
     }
 
     fn node_count(&self) -> usize {
-        self.node_indices().count()
+        self.root().node_count() - self.0.node_count()
     }
 
     fn edge_count(&self) -> usize {
+        // Edges of the root graph that are missing endpoints are not contained, so they need to be counted one by one.
         self.edge_indices().count()
     }
 
+    fn node_index_bound(&self) -> usize {
+        self.root().node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.root().edge_index_bound()
+    }
+
     fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
         debug_assert!(self.contains_node_index(node_id));
         self.root().node_data(node_id)
@@ -163,31 +189,83 @@ where
         debug_assert!(self.contains_edge_index(edge_id));
         self.root().edge_endpoints(edge_id)
     }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<Graph: ImmutableGraphContainer + SubgraphBase> NavigableGraph for InvertedSubgraph<'_, Graph>
+where
+    <Graph as SubgraphBase>::RootGraph: NavigableGraph,
+{
+    type OutNeighbors<'a>
+        = FilterNeighborIterator<
+        'a,
+        <<Graph as SubgraphBase>::RootGraph as NavigableGraph>::OutNeighbors<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = FilterNeighborIterator<
+        'a,
+        <<Graph as SubgraphBase>::RootGraph as NavigableGraph>::InNeighbors<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = FilterEdgeIndexIterator<
+        'a,
+        <<Graph as SubgraphBase>::RootGraph as NavigableGraph>::EdgesBetween<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        FilterNeighborIterator::new(self.root().out_neighbors(node_id), self)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        FilterNeighborIterator::new(self.root().in_neighbors(node_id), self)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        FilterEdgeIndexIterator::new(self.root().edges_between(from_node_id, to_node_id), self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::implementation::subgraphs::subgraph_operators::inverted_subgraph::InvertedIndexIterator;
     use crate::index::{GraphIndex, NodeIndex};
+    use alloc::vec::Vec;
 
     #[test]
     fn test_inverted_index_iterator() {
         let tests = [
             (
                 [3, 5, 6, 7, 10].as_slice(),
-                13,
+                14,
                 [0, 1, 2, 4, 8, 9, 11, 12, 13].as_slice(),
             ),
-            (&[0, 3, 5, 6, 7, 10, 12, 13], 13, &[1, 2, 4, 8, 9, 11]),
-            (&[0, 1, 2, 3], 3, &[]),
-            (&[], 3, &[0, 1, 2, 3]),
+            (&[0, 3, 5, 6, 7, 10, 12, 13], 14, &[1, 2, 4, 8, 9, 11]),
+            (&[0, 1, 2, 3], 4, &[]),
+            (&[], 4, &[0, 1, 2, 3]),
+            (&[], 0, &[]),
         ];
 
-        for (iterator, maximum, expected) in tests {
+        for (iterator, limit, expected) in tests {
             let inverted_iterator = InvertedIndexIterator {
                 uninverted_iterator: iterator.iter().map(|n| NodeIndex::from(*n)).peekable(),
                 current: 0,
-                maximum,
+                limit,
                 phantom_index: Default::default(),
                 phantom_optional_index: Default::default(),
             };
@@ -198,4 +276,86 @@ mod tests {
             assert_eq!(expected, actual);
         }
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_inverted_subgraph() {
+        use crate::implementation::petgraph_impl::PetGraph;
+        use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+        use crate::implementation::subgraphs::subgraph_operators::inverted_subgraph::InvertedSubgraph;
+        use crate::interface::subgraph::MutableSubgraph;
+        use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = [(0, 1), (1, 2), (2, 3), (3, 0), (1, 2)]
+            .into_iter()
+            .map(|(from, to)| graph.add_edge(n[from], n[to], ()))
+            .collect();
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        subgraph.enable_node(n[3]);
+        subgraph.enable_edge(e[4]);
+        let inverted = InvertedSubgraph::new(&subgraph);
+
+        assert_eq!(
+            inverted.node_indices().collect::<Vec<_>>(),
+            n[0..3].to_vec()
+        );
+        assert_eq!(
+            inverted.edge_indices().collect::<Vec<_>>(),
+            e[0..2].to_vec()
+        );
+        assert_eq!(
+            inverted.edge_indices_copied().collect::<Vec<_>>(),
+            e[0..2].to_vec()
+        );
+        assert_eq!(
+            inverted
+                .out_neighbors(n[1])
+                .map(|neighbor| neighbor.edge_id)
+                .collect::<Vec<_>>(),
+            vec![e[1]]
+        );
+        assert_eq!(inverted.in_neighbors(n[0]).count(), 0);
+        assert_eq!(
+            inverted.edges_between(n[1], n[2]).collect::<Vec<_>>(),
+            vec![e[1]]
+        );
+    }
+
+    #[test]
+    fn test_inverted_subgraph_with_removed_nodes() {
+        use crate::implementation::stable_graph::StableGraph;
+        use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+        use crate::implementation::subgraphs::subgraph_operators::inverted_subgraph::InvertedSubgraph;
+        use crate::interface::subgraph::MutableSubgraph;
+        use crate::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+        let mut graph = StableGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]
+            .into_iter()
+            .map(|(from, to)| graph.add_edge(n[from], n[to], ()))
+            .collect();
+        graph.remove_node(n[1]);
+        graph.remove_edge(e[3]);
+        let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+        subgraph.enable_node(n[2]);
+        let inverted = InvertedSubgraph::new(&subgraph);
+
+        assert_eq!(
+            inverted.node_indices().collect::<Vec<_>>(),
+            [n[0], n[3], n[4]]
+        );
+        assert_eq!(
+            inverted.node_indices_copied().collect::<Vec<_>>(),
+            [n[0], n[3], n[4]]
+        );
+        assert_eq!(inverted.node_count(), 3);
+        assert!(!inverted.contains_node_index(n[1]));
+        assert_eq!(inverted.edge_indices().collect::<Vec<_>>(), [e[4]]);
+        assert_eq!(inverted.edge_count(), 1);
+        assert!(!inverted.contains_edge_index(e[0]));
+        assert!(!inverted.contains_edge_index(e[3]));
+    }
 }
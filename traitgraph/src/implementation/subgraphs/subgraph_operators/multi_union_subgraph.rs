@@ -0,0 +1,335 @@
+use crate::implementation::subgraphs::filter_iterators::{
+    FilterEdgeIndexIterator, FilterNeighborIterator,
+};
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+/// A subgraph built from the union of arbitrarily many subgraphs of the same root graph.
+///
+/// This avoids nesting [UnionSubgraph](super::union_subgraph::UnionSubgraph)s when building the union of many subgraphs.
+/// Iterating over the nodes or edges merges the sorted index iterators of all subgraphs,
+/// and testing if a node or edge is contained takes time linear in the amount of subgraphs.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+/// use traitgraph::implementation::subgraphs::subgraph_operators::multi_union_subgraph::MultiUnionSubgraph;
+/// use traitgraph::interface::subgraph::MutableSubgraph;
+/// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+///
+/// let mut graph = PetGraph::<_, ()>::new();
+/// let nodes: Vec<_> = (0..10).map(|i| graph.add_node(i)).collect();
+///
+/// let subgraphs: Vec<_> = (0..3)
+///     .map(|seed| {
+///         let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+///         subgraph.enable_node(nodes[seed]);
+///         subgraph.enable_node(nodes[seed + 5]);
+///         subgraph
+///     })
+///     .collect();
+/// let union = MultiUnionSubgraph::new(&subgraphs);
+///
+/// assert_eq!(union.node_count(), 6);
+/// assert_eq!(
+///     union.node_indices().collect::<Vec<_>>(),
+///     [0, 1, 2, 5, 6, 7].map(|i| nodes[i]).to_vec()
+/// );
+/// # }
+/// ```
+pub struct MultiUnionSubgraph<'a, Graph> {
+    subgraphs: Vec<&'a Graph>,
+}
+
+impl<'a, Graph> MultiUnionSubgraph<'a, Graph> {
+    /// Construct a new subgraph from the union of the given subgraphs.
+    ///
+    /// Panics if no subgraphs are given, since then the root graph would be unknown.
+    pub fn new(subgraphs: impl IntoIterator<Item = &'a Graph>) -> Self {
+        let subgraphs: Vec<_> = subgraphs.into_iter().collect();
+        assert!(
+            !subgraphs.is_empty(),
+            "the union needs at least one subgraph"
+        );
+        Self { subgraphs }
+    }
+
+    /// Returns the subgraphs this union is built from.
+    pub fn subgraphs(&self) -> &[&'a Graph] {
+        &self.subgraphs
+    }
+}
+
+impl<Graph: GraphBase> GraphBase for MultiUnionSubgraph<'_, Graph> {
+    type NodeData = Graph::NodeData;
+    type EdgeData = Graph::EdgeData;
+    type OptionalNodeIndex = Graph::OptionalNodeIndex;
+    type OptionalEdgeIndex = Graph::OptionalEdgeIndex;
+    type NodeIndex = Graph::NodeIndex;
+    type EdgeIndex = Graph::EdgeIndex;
+}
+
+impl<Graph: SubgraphBase> SubgraphBase for MultiUnionSubgraph<'_, Graph> {
+    type RootGraph = Graph::RootGraph;
+
+    fn root(&self) -> &Self::RootGraph {
+        self.subgraphs[0].root()
+    }
+}
+
+/// An iterator that returns the union of arbitrarily many sorted iterators over graph indices.
+///
+/// The iterators are merged with a binary heap, so each returned index takes time logarithmic in the amount of iterators,
+/// plus the time to skip its duplicates.
+pub struct MultiUnionIndexIterator<Index, IndexIterator> {
+    index_iterators: Vec<IndexIterator>,
+    heap: BinaryHeap<Reverse<(Index, usize)>>,
+}
+
+impl<Index: Ord, IndexIterator: Iterator<Item = Index>>
+    MultiUnionIndexIterator<Index, IndexIterator>
+{
+    /// Creates a new iterator over the union of the given sorted iterators.
+    pub fn new(index_iterators: impl IntoIterator<Item = IndexIterator>) -> Self {
+        let mut index_iterators: Vec<_> = index_iterators.into_iter().collect();
+        let heap = index_iterators
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(position, iterator)| {
+                iterator.next().map(|index| Reverse((index, position)))
+            })
+            .collect();
+        Self {
+            index_iterators,
+            heap,
+        }
+    }
+
+    fn advance(&mut self, position: usize) {
+        if let Some(index) = self.index_iterators[position].next() {
+            self.heap.push(Reverse((index, position)));
+        }
+    }
+}
+
+impl<Index: Ord, IndexIterator: Iterator<Item = Index>> Iterator
+    for MultiUnionIndexIterator<Index, IndexIterator>
+{
+    type Item = Index;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((index, position)) = self.heap.pop()?;
+        self.advance(position);
+        while let Some(Reverse((duplicate, _))) = self.heap.peek() {
+            if *duplicate != index {
+                break;
+            }
+            let Reverse((_, position)) = self.heap.pop().unwrap();
+            self.advance(position);
+        }
+        Some(index)
+    }
+}
+
+impl<Graph: ImmutableGraphContainer + SubgraphBase> ImmutableGraphContainer
+    for MultiUnionSubgraph<'_, Graph>
+where
+    <Graph as SubgraphBase>::RootGraph: ImmutableGraphContainer,
+{
+    type NodeIndices<'a>
+        = MultiUnionIndexIterator<Self::NodeIndex, Graph::NodeIndices<'a>>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = MultiUnionIndexIterator<Self::EdgeIndex, Graph::EdgeIndices<'a>>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+    type NodeIndicesCopied = MultiUnionIndexIterator<Self::NodeIndex, Graph::NodeIndicesCopied>;
+    type EdgeIndicesCopied = MultiUnionIndexIterator<Self::EdgeIndex, Graph::EdgeIndicesCopied>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        MultiUnionIndexIterator::new(
+            self.subgraphs
+                .iter()
+                .map(|subgraph| subgraph.node_indices()),
+        )
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        MultiUnionIndexIterator::new(
+            self.subgraphs
+                .iter()
+                .map(|subgraph| subgraph.edge_indices()),
+        )
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        MultiUnionIndexIterator::new(
+            self.subgraphs
+                .iter()
+                .map(|subgraph| subgraph.node_indices_copied()),
+        )
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        MultiUnionIndexIterator::new(
+            self.subgraphs
+                .iter()
+                .map(|subgraph| subgraph.edge_indices_copied()),
+        )
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        self.subgraphs
+            .iter()
+            .any(|subgraph| subgraph.contains_node_index(node_id))
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        self.subgraphs
+            .iter()
+            .any(|subgraph| subgraph.contains_edge_index(edge_id))
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_indices().count()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_indices().count()
+    }
+
+    fn node_index_bound(&self) -> usize {
+        self.root().node_index_bound()
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.root().edge_index_bound()
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        debug_assert!(self.contains_node_index(node_id));
+        self.root().node_data(node_id)
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.root().edge_data(edge_id)
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        debug_assert!(self.contains_edge_index(edge_id));
+        self.root().edge_endpoints(edge_id)
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<Graph: ImmutableGraphContainer + SubgraphBase> NavigableGraph for MultiUnionSubgraph<'_, Graph>
+where
+    <Graph as SubgraphBase>::RootGraph: NavigableGraph,
+{
+    type OutNeighbors<'a>
+        = FilterNeighborIterator<
+        'a,
+        <<Graph as SubgraphBase>::RootGraph as NavigableGraph>::OutNeighbors<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = FilterNeighborIterator<
+        'a,
+        <<Graph as SubgraphBase>::RootGraph as NavigableGraph>::InNeighbors<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = FilterEdgeIndexIterator<
+        'a,
+        <<Graph as SubgraphBase>::RootGraph as NavigableGraph>::EdgesBetween<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        FilterNeighborIterator::new(self.root().out_neighbors(node_id), self)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        FilterNeighborIterator::new(self.root().in_neighbors(node_id), self)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        FilterEdgeIndexIterator::new(self.root().edges_between(from_node_id, to_node_id), self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiUnionIndexIterator;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_multi_union_index_iterator() {
+        let iterators = vec![vec![1, 4, 5, 9], vec![], vec![0, 4, 9, 12], vec![4, 5, 6]];
+        let union: Vec<_> =
+            MultiUnionIndexIterator::new(iterators.into_iter().map(Vec::into_iter)).collect();
+        assert_eq!(union, vec![0, 1, 4, 5, 6, 9, 12]);
+
+        let union: Vec<usize> =
+            MultiUnionIndexIterator::new(Vec::<vec::IntoIter<usize>>::new()).collect();
+        assert!(union.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_navigation() {
+        use super::MultiUnionSubgraph;
+        use crate::implementation::petgraph_impl::PetGraph;
+        use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+        use crate::interface::subgraph::MutableSubgraph;
+        use crate::interface::{ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = (0..4).map(|i| graph.add_edge(n[i], n[i + 1], ())).collect();
+        let subgraphs: Vec<_> = (0..4)
+            .step_by(2)
+            .map(|i| {
+                let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+                subgraph.enable_node(n[i]);
+                subgraph.enable_node(n[i + 1]);
+                subgraph.enable_edge(e[i]);
+                subgraph
+            })
+            .collect();
+        let union = MultiUnionSubgraph::new(&subgraphs);
+
+        assert_eq!(union.node_indices().collect::<Vec<_>>(), n[0..4].to_vec());
+        assert_eq!(union.edge_indices().collect::<Vec<_>>(), vec![e[0], e[2]]);
+        assert_eq!(union.out_neighbors(n[0]).count(), 1);
+        assert_eq!(union.out_neighbors(n[1]).count(), 0);
+        assert_eq!(union.in_neighbors(n[3]).count(), 1);
+        assert!(union.contains_edge_between(n[2], n[3]));
+    }
+}
@@ -1,11 +1,17 @@
+use crate::implementation::subgraphs::filter_iterators::{
+    FilterEdgeIndexIterator, FilterNeighborIterator,
+};
 use crate::index::{GraphIndex, OptionalGraphIndex};
 use crate::interface::subgraph::SubgraphBase;
-use crate::interface::{Edge, GraphBase, ImmutableGraphContainer};
-use std::cmp::Ordering;
-use std::iter::Peekable;
-use std::marker::PhantomData;
+use crate::interface::{Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use core::cmp::Ordering;
+use core::iter::Peekable;
+use core::marker::PhantomData;
 
 /// A subgraph built from the union of two graphs.
+///
+/// If the root graph is navigable, then so is the union.
+/// Its neighbors are the neighbors in the root graph that are connected via an edge contained in either of the two graphs.
 pub struct UnionSubgraph<'a, Graph0, Graph1>(&'a Graph0, &'a Graph1);
 
 impl<'a, Graph0, Graph1> UnionSubgraph<'a, Graph0, Graph1> {
@@ -115,6 +121,10 @@ where
         Graph0::EdgeIndices<'a>,
         Graph1::EdgeIndices<'a>,
     >
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
     where
         Self: 'a;
     type NodeIndicesCopied = UnionIndexIterator<
@@ -182,6 +192,14 @@ where
         self.edge_indices().count()
     }
 
+    fn node_index_bound(&self) -> usize {
+        self.0.node_index_bound().max(self.1.node_index_bound())
+    }
+
+    fn edge_index_bound(&self) -> usize {
+        self.0.edge_index_bound().max(self.1.edge_index_bound())
+    }
+
     fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
         self.root().node_data(node_id)
     }
@@ -193,6 +211,77 @@ where
     fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
         self.root().edge_endpoints(edge_id)
     }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<
+        NodeIndex: GraphIndex<OptionalNodeIndex>,
+        OptionalNodeIndex: OptionalGraphIndex<NodeIndex>,
+        EdgeIndex: GraphIndex<OptionalEdgeIndex>,
+        OptionalEdgeIndex: OptionalGraphIndex<EdgeIndex>,
+        Graph0: ImmutableGraphContainer
+            + SubgraphBase
+            + GraphBase<
+                NodeIndex = NodeIndex,
+                OptionalNodeIndex = OptionalNodeIndex,
+                EdgeIndex = EdgeIndex,
+                OptionalEdgeIndex = OptionalEdgeIndex,
+            >,
+        Graph1: ImmutableGraphContainer
+            + SubgraphBase
+            + GraphBase<
+                NodeIndex = NodeIndex,
+                OptionalNodeIndex = OptionalNodeIndex,
+                EdgeIndex = EdgeIndex,
+                OptionalEdgeIndex = OptionalEdgeIndex,
+            >,
+    > NavigableGraph for UnionSubgraph<'_, Graph0, Graph1>
+where
+    <Self as SubgraphBase>::RootGraph: NavigableGraph,
+{
+    type OutNeighbors<'a>
+        = FilterNeighborIterator<
+        'a,
+        <<Self as SubgraphBase>::RootGraph as NavigableGraph>::OutNeighbors<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = FilterNeighborIterator<
+        'a,
+        <<Self as SubgraphBase>::RootGraph as NavigableGraph>::InNeighbors<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = FilterEdgeIndexIterator<
+        'a,
+        <<Self as SubgraphBase>::RootGraph as NavigableGraph>::EdgesBetween<'a>,
+        Self,
+    >
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        FilterNeighborIterator::new(self.root().out_neighbors(node_id), self)
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        FilterNeighborIterator::new(self.root().in_neighbors(node_id), self)
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        FilterEdgeIndexIterator::new(self.root().edges_between(from_node_id, to_node_id), self)
+    }
 }
 
 /*impl<'a, NodeIndex, EdgeIndex, Graph0: GraphBase<NodeIndex = NodeIndex, EdgeIndex = EdgeIndex> + DecoratingSubgraph, Graph1: GraphBase<NodeIndex = NodeIndex, EdgeIndex = EdgeIndex> + DecoratingSubgraph> DecoratingSubgraph for UnionSubgraph<'a, Graph0, Graph1>
@@ -254,3 +343,53 @@ where
         unimplemented!("Will not implement if not necessary")
     }
 }*/
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+    use crate::implementation::subgraphs::subgraph_operators::union_subgraph::UnionSubgraph;
+    use crate::interface::subgraph::MutableSubgraph;
+    use crate::interface::{MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_navigation() {
+        let mut graph = PetGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        let e: Vec<_> = [(0, 1), (0, 2), (0, 3), (1, 0), (0, 2)]
+            .into_iter()
+            .map(|(from, to)| graph.add_edge(n[from], n[to], ()))
+            .collect();
+
+        let mut subgraph0 = BitVectorSubgraph::new_empty(&graph);
+        subgraph0.enable_node(n[0]);
+        subgraph0.enable_node(n[1]);
+        subgraph0.enable_edge(e[0]);
+        let mut subgraph1 = BitVectorSubgraph::new_empty(&graph);
+        subgraph1.enable_node(n[0]);
+        subgraph1.enable_node(n[1]);
+        subgraph1.enable_node(n[2]);
+        subgraph1.enable_edge(e[3]);
+        subgraph1.enable_edge(e[4]);
+        let union = UnionSubgraph::new(&subgraph0, &subgraph1);
+
+        let mut out_edges: Vec<_> = union
+            .out_neighbors(n[0])
+            .map(|neighbor| neighbor.edge_id)
+            .collect();
+        out_edges.sort_unstable();
+        debug_assert_eq!(out_edges, vec![e[0], e[4]]);
+        debug_assert_eq!(
+            union
+                .in_neighbors(n[0])
+                .map(|neighbor| neighbor.node_id)
+                .collect::<Vec<_>>(),
+            vec![n[1]]
+        );
+        debug_assert_eq!(
+            union.edges_between(n[0], n[2]).collect::<Vec<_>>(),
+            vec![e[4]]
+        );
+        debug_assert!(!union.contains_edge_between(n[0], n[3]));
+    }
+}
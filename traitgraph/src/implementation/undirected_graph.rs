@@ -0,0 +1,163 @@
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{
+    GraphBase, MutableGraphContainer, NavigableGraph, UndirectedGraph, UndirectedNeighbors,
+};
+
+/// A wrapper around a directed graph that implements [UndirectedGraph] by ignoring the direction of the edges.
+///
+/// The neighbors of a node are its outgoing neighbors followed by its incoming neighbors,
+/// as returned by [`undirected_neighbors`](NavigableGraph::undirected_neighbors).
+/// The wrapped graph is still accessible as a directed graph through [NavigableGraph],
+/// so the orientation in which the edges were inserted is not lost.
+#[derive(Debug, Clone, Default)]
+pub struct Undirected<Graph> {
+    graph: Graph,
+}
+
+impl<Graph> Undirected<Graph> {
+    /// Wraps the given directed graph.
+    pub fn new(graph: Graph) -> Self {
+        Self { graph }
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns the wrapped graph.
+    pub fn into_inner(self) -> Graph {
+        self.graph
+    }
+}
+
+delegate_graph!(impl[Graph] Undirected<Graph> => graph: Graph);
+
+impl<Graph: NavigableGraph> UndirectedGraph for Undirected<Graph> {
+    type Neighbors<'a>
+        = UndirectedNeighbors<'a, Graph>
+    where
+        Self: 'a;
+
+    fn neighbors(&self, node_id: Self::NodeIndex) -> Self::Neighbors<'_> {
+        self.graph.undirected_neighbors(node_id)
+    }
+
+    fn degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.graph.undirected_degree(node_id)
+    }
+
+    fn is_adjacent(&self, a: Self::NodeIndex, b: Self::NodeIndex) -> bool {
+        self.graph.contains_edge_between(a, b) || self.graph.contains_edge_between(b, a)
+    }
+}
+
+impl<Graph: MutableGraphContainer> MutableGraphContainer for Undirected<Graph> {
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        self.graph.node_data_mut(node_id)
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        self.graph.edge_data_mut(edge_id)
+    }
+
+    fn add_node(&mut self, node_data: Self::NodeData) -> Self::NodeIndex {
+        self.graph.add_node(node_data)
+    }
+
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: Self::EdgeData,
+    ) -> Self::EdgeIndex {
+        self.graph.add_edge(from, to, edge_data)
+    }
+
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.graph.reserve_nodes(additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.graph.reserve_edges(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.graph.shrink_to_fit();
+    }
+
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData> {
+        self.graph.remove_node(node_id)
+    }
+
+    fn remove_nodes_sorted_slice(&mut self, node_ids: &[Self::NodeIndex]) {
+        self.graph.remove_nodes_sorted_slice(node_ids)
+    }
+
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<Self::EdgeData> {
+        self.graph.remove_edge(edge_id)
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        self.graph.remove_edges_sorted(edge_ids)
+    }
+
+    fn clear(&mut self) {
+        self.graph.clear()
+    }
+}
+
+impl<Graph: GraphBase> SubgraphBase for Undirected<Graph> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::undirected_graph::Undirected;
+    use crate::interface::{MutableGraphContainer, Neighbor, UndirectedGraph};
+
+    #[test]
+    fn test_undirected_neighbors() {
+        let mut graph = Undirected::new(PetGraph::new());
+        let n0 = graph.add_node(());
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let e0 = graph.add_edge(n0, n1, ());
+        let e1 = graph.add_edge(n2, n0, ());
+        let e2 = graph.add_edge(n1, n1, ());
+
+        let mut neighbors: Vec<_> = graph.neighbors(n0).collect();
+        neighbors.sort_by_key(|neighbor| neighbor.edge_id);
+        debug_assert_eq!(
+            neighbors,
+            [
+                Neighbor {
+                    edge_id: e0,
+                    node_id: n1
+                },
+                Neighbor {
+                    edge_id: e1,
+                    node_id: n2
+                }
+            ]
+        );
+        debug_assert_eq!(graph.degree(n0), 2);
+        debug_assert_eq!(graph.degree(n1), 2);
+        debug_assert_eq!(graph.neighbors(n1).count(), 2);
+        debug_assert_eq!(graph.degree(n2), 1);
+
+        debug_assert!(graph.is_adjacent(n0, n2));
+        debug_assert!(graph.is_adjacent(n2, n0));
+        debug_assert!(graph.is_adjacent(n1, n1));
+        debug_assert!(!graph.is_adjacent(n1, n2));
+
+        debug_assert_eq!(graph.opposite_endpoint(e1, n0), n2);
+        debug_assert_eq!(graph.opposite_endpoint(e1, n2), n0);
+        debug_assert_eq!(graph.opposite_endpoint(e2, n1), n1);
+    }
+}
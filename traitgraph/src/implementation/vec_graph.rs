@@ -0,0 +1,490 @@
+use crate::implementation::generation::GraphGeneration;
+use crate::index::{GraphIndex, GraphIndices};
+use crate::interface::subgraph::SubgraphBase;
+use crate::interface::{
+    Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, MutableGraphContainer,
+    NavigableGraph, Neighbor,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+struct VecGraphNode<NodeData> {
+    data: NodeData,
+    out_edges: Vec<usize>,
+    in_edges: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct VecGraphEdge<EdgeData> {
+    data: EdgeData,
+    from_node: usize,
+    to_node: usize,
+}
+
+/// A graph that stores its nodes and edges in plain vectors, with an adjacency list for each node.
+///
+/// This graph has no dependencies outside of `alloc`.
+/// Neighbors are iterated in the order in which their edges were added.
+///
+/// Removals behave like in [PetGraph](crate::implementation::petgraph_impl::PetGraph):
+/// when a node or edge is removed, the node or edge with the highest index is moved into its place.
+/// All other indices stay the same.
+#[derive(Debug, Clone)]
+pub struct VecGraph<NodeData, EdgeData> {
+    nodes: Vec<VecGraphNode<NodeData>>,
+    edges: Vec<VecGraphEdge<EdgeData>>,
+    generation: GraphGeneration,
+}
+
+impl<NodeData, EdgeData> VecGraph<NodeData, EdgeData> {
+    /// Creates a new empty graph.
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    /// Creates a new empty graph with space for the given amount of nodes and edges.
+    pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(node_capacity),
+            edges: Vec::with_capacity(edge_capacity),
+            generation: GraphGeneration::new(),
+        }
+    }
+
+    /// Replaces `old_edge_id` by `new_edge_id` in the adjacency lists of the endpoints of the edge stored at `new_edge_id`.
+    fn rename_edge(&mut self, old_edge_id: usize, new_edge_id: usize) {
+        let VecGraphEdge {
+            from_node, to_node, ..
+        } = self.edges[new_edge_id];
+        for edge_id in &mut self.nodes[from_node].out_edges {
+            if *edge_id == old_edge_id {
+                *edge_id = new_edge_id;
+            }
+        }
+        for edge_id in &mut self.nodes[to_node].in_edges {
+            if *edge_id == old_edge_id {
+                *edge_id = new_edge_id;
+            }
+        }
+    }
+
+    /// Replaces `old_node_id` by `new_node_id` in the endpoints of the edges of the node stored at `new_node_id`.
+    fn rename_node(&mut self, old_node_id: usize, new_node_id: usize) {
+        let node = &self.nodes[new_node_id];
+        for &edge_id in &node.out_edges {
+            debug_assert_eq!(self.edges[edge_id].from_node, old_node_id);
+            self.edges[edge_id].from_node = new_node_id;
+        }
+        for &edge_id in &node.in_edges {
+            debug_assert_eq!(self.edges[edge_id].to_node, old_node_id);
+            self.edges[edge_id].to_node = new_node_id;
+        }
+    }
+}
+
+impl<NodeData, EdgeData> Default for VecGraph<NodeData, EdgeData> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<NodeData, EdgeData> GraphBase for VecGraph<NodeData, EdgeData> {
+    type NodeData = NodeData;
+    type EdgeData = EdgeData;
+    type OptionalNodeIndex = crate::index::OptionalNodeIndex<usize>;
+    type OptionalEdgeIndex = crate::index::OptionalEdgeIndex<usize>;
+    type NodeIndex = crate::index::NodeIndex<usize>;
+    type EdgeIndex = crate::index::EdgeIndex<usize>;
+}
+
+impl<NodeData, EdgeData> ImmutableGraphContainer for VecGraph<NodeData, EdgeData> {
+    type NodeIndices<'a>
+        = GraphIndices<Self::NodeIndex, Self::OptionalNodeIndex>
+    where
+        Self: 'a;
+    type EdgeIndices<'a>
+        = GraphIndices<Self::EdgeIndex, Self::OptionalEdgeIndex>
+    where
+        Self: 'a;
+    type EdgeReferences<'a>
+        = EdgeReferences<'a, Self>
+    where
+        Self: 'a;
+
+    type NodeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::NodeIndex, <Self as GraphBase>::OptionalNodeIndex>;
+    type EdgeIndicesCopied =
+        GraphIndices<<Self as GraphBase>::EdgeIndex, <Self as GraphBase>::OptionalEdgeIndex>;
+
+    fn node_indices(&self) -> Self::NodeIndices<'_> {
+        GraphIndices::from((0, self.node_count()))
+    }
+
+    fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+        GraphIndices::from((0, self.edge_count()))
+    }
+
+    fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+        GraphIndices::from((0, self.node_count()))
+    }
+
+    fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+        GraphIndices::from((0, self.edge_count()))
+    }
+
+    fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+        node_id.as_usize() < self.node_count()
+    }
+
+    fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+        edge_id.as_usize() < self.edge_count()
+    }
+
+    fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn generation(&self) -> Option<u64> {
+        Some(self.generation.get())
+    }
+
+    fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+        &self.nodes[node_id.as_usize()].data
+    }
+
+    fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+        &self.edges[edge_id.as_usize()].data
+    }
+
+    fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+        let edge = &self.edges[edge_id.as_usize()];
+        Edge {
+            from_node: edge.from_node.into(),
+            to_node: edge.to_node.into(),
+        }
+    }
+
+    fn edge_references(&self) -> Self::EdgeReferences<'_> {
+        EdgeReferences::new(self)
+    }
+}
+
+impl<NodeData, EdgeData> MutableGraphContainer for VecGraph<NodeData, EdgeData> {
+    fn node_data_mut(&mut self, node_id: Self::NodeIndex) -> &mut Self::NodeData {
+        &mut self.nodes[node_id.as_usize()].data
+    }
+
+    fn edge_data_mut(&mut self, edge_id: Self::EdgeIndex) -> &mut Self::EdgeData {
+        &mut self.edges[edge_id.as_usize()].data
+    }
+
+    fn add_node(&mut self, node_data: Self::NodeData) -> Self::NodeIndex {
+        let node_id = self.nodes.len();
+        self.nodes.push(VecGraphNode {
+            data: node_data,
+            out_edges: Vec::new(),
+            in_edges: Vec::new(),
+        });
+        self.generation.advance();
+        node_id.into()
+    }
+
+    fn add_edge(
+        &mut self,
+        from: Self::NodeIndex,
+        to: Self::NodeIndex,
+        edge_data: Self::EdgeData,
+    ) -> Self::EdgeIndex {
+        let (from_node, to_node) = (from.as_usize(), to.as_usize());
+        debug_assert!(from_node < self.nodes.len() && to_node < self.nodes.len());
+        let edge_id = self.edges.len();
+        self.edges.push(VecGraphEdge {
+            data: edge_data,
+            from_node,
+            to_node,
+        });
+        self.nodes[from_node].out_edges.push(edge_id);
+        self.nodes[to_node].in_edges.push(edge_id);
+        self.generation.advance();
+        edge_id.into()
+    }
+
+    fn reserve_nodes(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.edges.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+    }
+
+    fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData> {
+        let node_id = node_id.as_usize();
+        if node_id >= self.nodes.len() {
+            return None;
+        }
+
+        // Removing the incident edges in descending order ensures that no other incident edge is moved.
+        let node = &self.nodes[node_id];
+        let mut incident_edges: Vec<_> = node
+            .out_edges
+            .iter()
+            .chain(node.in_edges.iter())
+            .copied()
+            .collect();
+        incident_edges.sort_unstable();
+        incident_edges.dedup();
+        for edge_id in incident_edges.into_iter().rev() {
+            self.remove_edge(edge_id.into());
+        }
+
+        let last_node_id = self.nodes.len() - 1;
+        let node = self.nodes.swap_remove(node_id);
+        if node_id != last_node_id {
+            self.rename_node(last_node_id, node_id);
+        }
+        self.generation.advance();
+        Some(node.data)
+    }
+
+    fn remove_edge(&mut self, edge_id: Self::EdgeIndex) -> Option<Self::EdgeData> {
+        let edge_id = edge_id.as_usize();
+        if edge_id >= self.edges.len() {
+            return None;
+        }
+
+        let VecGraphEdge {
+            from_node, to_node, ..
+        } = self.edges[edge_id];
+        let out_edges = &mut self.nodes[from_node].out_edges;
+        out_edges.remove(out_edges.iter().position(|&e| e == edge_id).unwrap());
+        let in_edges = &mut self.nodes[to_node].in_edges;
+        in_edges.remove(in_edges.iter().position(|&e| e == edge_id).unwrap());
+
+        let last_edge_id = self.edges.len() - 1;
+        let edge = self.edges.swap_remove(edge_id);
+        if edge_id != last_edge_id {
+            self.rename_edge(last_edge_id, edge_id);
+        }
+        self.generation.advance();
+        Some(edge.data)
+    }
+
+    fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]) {
+        edge_ids.windows(2).for_each(|w| debug_assert!(w[0] < w[1]));
+
+        for edge_id in edge_ids.iter().rev() {
+            self.remove_edge(*edge_id);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+        self.generation.advance();
+    }
+}
+
+/// An iterator over the neighbors of a node in a [VecGraph].
+pub struct VecGraphNeighbors<'a, EdgeData, NodeIndex, EdgeIndex> {
+    edge_ids: core::slice::Iter<'a, usize>,
+    edges: &'a [VecGraphEdge<EdgeData>],
+    outgoing: bool,
+    index_types: PhantomData<(NodeIndex, EdgeIndex)>,
+}
+
+impl<EdgeData, NodeIndex: From<usize>, EdgeIndex: From<usize>> Iterator
+    for VecGraphNeighbors<'_, EdgeData, NodeIndex, EdgeIndex>
+{
+    type Item = Neighbor<NodeIndex, EdgeIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edge_ids.next().map(|&edge_id| {
+            let edge = &self.edges[edge_id];
+            Neighbor {
+                edge_id: edge_id.into(),
+                node_id: if self.outgoing {
+                    edge.to_node
+                } else {
+                    edge.from_node
+                }
+                .into(),
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edge_ids.size_hint()
+    }
+}
+
+/// An iterator over the edges between two nodes in a [VecGraph].
+pub struct VecGraphEdgesBetween<'a, EdgeData, EdgeIndex> {
+    edge_ids: core::slice::Iter<'a, usize>,
+    edges: &'a [VecGraphEdge<EdgeData>],
+    to_node: usize,
+    edge_index_type: PhantomData<EdgeIndex>,
+}
+
+impl<EdgeData, EdgeIndex: From<usize>> Iterator for VecGraphEdgesBetween<'_, EdgeData, EdgeIndex> {
+    type Item = EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (edges, to_node) = (self.edges, self.to_node);
+        self.edge_ids
+            .find(|&&edge_id| edges[edge_id].to_node == to_node)
+            .map(|&edge_id| edge_id.into())
+    }
+}
+
+impl<NodeData, EdgeData> NavigableGraph for VecGraph<NodeData, EdgeData> {
+    type OutNeighbors<'a>
+        = VecGraphNeighbors<
+        'a,
+        EdgeData,
+        <Self as GraphBase>::NodeIndex,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        Self: 'a;
+    type InNeighbors<'a>
+        = VecGraphNeighbors<
+        'a,
+        EdgeData,
+        <Self as GraphBase>::NodeIndex,
+        <Self as GraphBase>::EdgeIndex,
+    >
+    where
+        Self: 'a;
+    type EdgesBetween<'a>
+        = VecGraphEdgesBetween<'a, EdgeData, <Self as GraphBase>::EdgeIndex>
+    where
+        Self: 'a;
+
+    fn out_neighbors(&self, node_id: Self::NodeIndex) -> Self::OutNeighbors<'_> {
+        VecGraphNeighbors {
+            edge_ids: self.nodes[node_id.as_usize()].out_edges.iter(),
+            edges: &self.edges,
+            outgoing: true,
+            index_types: PhantomData,
+        }
+    }
+
+    fn in_neighbors(&self, node_id: Self::NodeIndex) -> Self::InNeighbors<'_> {
+        VecGraphNeighbors {
+            edge_ids: self.nodes[node_id.as_usize()].in_edges.iter(),
+            edges: &self.edges,
+            outgoing: false,
+            index_types: PhantomData,
+        }
+    }
+
+    fn edges_between(
+        &self,
+        from_node_id: Self::NodeIndex,
+        to_node_id: Self::NodeIndex,
+    ) -> Self::EdgesBetween<'_> {
+        VecGraphEdgesBetween {
+            edge_ids: self.nodes[from_node_id.as_usize()].out_edges.iter(),
+            edges: &self.edges,
+            to_node: to_node_id.as_usize(),
+            edge_index_type: PhantomData,
+        }
+    }
+
+    fn out_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.nodes[node_id.as_usize()].out_edges.len()
+    }
+
+    fn in_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.nodes[node_id.as_usize()].in_edges.len()
+    }
+}
+
+impl<NodeData, EdgeData> SubgraphBase for VecGraph<NodeData, EdgeData> {
+    type RootGraph = Self;
+
+    fn root(&self) -> &Self::RootGraph {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::VecGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::interface::{
+        DynamicGraph, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph,
+    };
+
+    type Snapshot = Vec<(char, Vec<(usize, usize, char)>, Vec<(usize, usize, char)>)>;
+
+    fn snapshot<Graph: DynamicGraph<NodeData = char, EdgeData = usize>>(graph: &Graph) -> Snapshot {
+        let neighbors = |neighbors: &mut dyn Iterator<Item = _>| {
+            let mut neighbors: Vec<_> = neighbors
+                .map(
+                    |neighbor: crate::interface::Neighbor<Graph::NodeIndex, Graph::EdgeIndex>| {
+                        (
+                            *graph.edge_data(neighbor.edge_id),
+                            crate::index::GraphIndex::as_usize(neighbor.node_id),
+                            *graph.node_data(neighbor.node_id),
+                        )
+                    },
+                )
+                .collect();
+            neighbors.sort_unstable();
+            neighbors
+        };
+        graph
+            .node_indices()
+            .map(|node_id| {
+                (
+                    *graph.node_data(node_id),
+                    neighbors(&mut graph.out_neighbors(node_id)),
+                    neighbors(&mut graph.in_neighbors(node_id)),
+                )
+            })
+            .collect()
+    }
+
+    fn apply_operations<Graph: DynamicGraph<NodeData = char, EdgeData = usize>>(graph: &mut Graph) {
+        let n: Vec<_> = ['a', 'b', 'c', 'd', 'e']
+            .into_iter()
+            .map(|data| graph.add_node(data))
+            .collect();
+        for (data, (from, to)) in [(0, 1), (1, 2), (2, 0), (2, 2), (3, 4), (4, 0), (1, 2)]
+            .into_iter()
+            .enumerate()
+        {
+            graph.add_edge(n[from], n[to], data);
+        }
+        graph.remove_edge(1.into());
+        graph.remove_node(n[2]);
+        *graph.edge_data_mut(0.into()) = 10;
+        graph.remove_node(n[0]);
+        let f = graph.add_node('f');
+        graph.add_edge(f, n[1], 11);
+    }
+
+    #[test]
+    fn test_same_behaviour_as_petgraph() {
+        let mut vec_graph = VecGraph::new();
+        let mut petgraph = PetGraph::new();
+        apply_operations(&mut vec_graph);
+        apply_operations(&mut petgraph);
+        debug_assert_eq!(snapshot(&vec_graph), snapshot(&petgraph));
+        debug_assert_eq!(vec_graph.edges_between(0.into(), 2.into()).count(), 1);
+
+        vec_graph.clear();
+        debug_assert!(vec_graph.is_empty());
+    }
+}
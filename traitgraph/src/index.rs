@@ -1,23 +1,44 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+use core::marker::PhantomData;
 use num_traits::{NumCast, PrimInt, ToPrimitive};
-use std::hash::Hash;
-use std::marker::PhantomData;
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 /// A node index that can be `None`.
 ///
 /// This is a hack to get a small sized `Option<NodeIndex>` by storing the `None` variant as `IndexType::max_value()`.
 /// If Rust ever adds support for integer types with invalid values other than 0, this type becomes obsolete.
 pub struct OptionalNodeIndex<IndexType: Sized>(IndexType);
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 /// An edge index that can be `None`.
 ///
 /// This is a hack to get a small sized `Option<EdgeIndex>` by storing the `None` variant as `IndexType::max_value()`.
 /// If Rust ever adds support for integer types with invalid values other than 0, this type becomes obsolete.
 pub struct OptionalEdgeIndex<IndexType: Sized>(IndexType);
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 /// A valid node index.
 pub struct NodeIndex<IndexType: Sized>(IndexType);
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 /// A valid edge index.
 pub struct EdgeIndex<IndexType: Sized>(IndexType);
 
@@ -27,7 +48,7 @@ pub struct EdgeIndex<IndexType: Sized>(IndexType);
 /// If Rust ever adds support for integer types with invalid values other than 0, this trait becomes obsolete.
 pub trait OptionalGraphIndex<MirrorGraphIndex: GraphIndex<Self>>:
     Default
-    + std::fmt::Debug
+    + core::fmt::Debug
     + Eq
     + Ord
     + Hash
@@ -38,8 +59,8 @@ pub trait OptionalGraphIndex<MirrorGraphIndex: GraphIndex<Self>>:
     + From<MirrorGraphIndex>
     + From<Option<MirrorGraphIndex>>
     + Into<Option<MirrorGraphIndex>>
-    + std::ops::Add<usize, Output = Self>
-    + std::ops::Sub<usize, Output = Self>
+    + core::ops::Add<usize, Output = Self>
+    + core::ops::Sub<usize, Output = Self>
 {
     // We don't wanna have OptionalGraphIndex: Into<usize>, to make this type strong, i.e. make it hard to accidentally convert it to a different type.
     /// Get this index as `usize`, but return `None` if this index is marked as invalid.
@@ -77,7 +98,7 @@ pub trait OptionalGraphIndex<MirrorGraphIndex: GraphIndex<Self>>:
 
 /// A valid graph index.
 pub trait GraphIndex<MirrorOptionalGraphIndex: OptionalGraphIndex<Self>>:
-    std::fmt::Debug
+    core::fmt::Debug
     + Eq
     + Ord
     + Hash
@@ -85,16 +106,72 @@ pub trait GraphIndex<MirrorOptionalGraphIndex: OptionalGraphIndex<Self>>:
     + Sized
     + From<usize>
     + Into<MirrorOptionalGraphIndex>
-    + std::ops::Add<usize, Output = Self>
-    + std::ops::Sub<usize, Output = Self>
+    + core::ops::Add<usize, Output = Self>
+    + core::ops::Sub<usize, Output = Self>
 {
     // We don't wanna have GraphIndex: Into<usize>, to make this type strong, i.e. make it hard to accidentally convert it to a different type.
     /// Get this index as `usize`.
     fn as_usize(self) -> usize;
 }
 
+/// The error returned when converting a `usize` into a graph index whose integer type cannot represent it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOverflowError {
+    /// The index that was attempted to be converted.
+    pub index: usize,
+    /// The largest index that the integer type can represent.
+    pub max_index: usize,
+}
+
+impl core::fmt::Display for IndexOverflowError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "index {} exceeds the largest index {} representable by the index type",
+            self.index, self.max_index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexOverflowError {}
+
+/// Converts `source` into `IndexType`, reserving `IndexType::max_value()` as in the index types of this module.
+fn try_index_from_usize<IndexType: PrimInt>(
+    source: usize,
+) -> Result<IndexType, IndexOverflowError> {
+    <IndexType as NumCast>::from(source)
+        .filter(|&source| source != IndexType::max_value())
+        .ok_or_else(|| IndexOverflowError {
+            index: source,
+            max_index: (IndexType::max_value() - IndexType::one())
+                .to_usize()
+                .unwrap_or(usize::MAX),
+        })
+}
+
 macro_rules! impl_graph_index {
     ($GraphIndexType:ident, $OptionalGraphIndexType:ident) => {
+        impl<IndexType: PrimInt> $GraphIndexType<IndexType> {
+            /// Converts the given `usize` into an index, or returns an error if it is not representable by `IndexType`.
+            ///
+            /// Since `IndexType::max_value()` is reserved to mark `None` in the optional index type, it is not representable either.
+            /// This is the checked counterpart of `From<usize>`, which panics in this case.
+            /// It is not an implementation of `TryFrom<usize>`, because that is already implemented infallibly via `From<usize>`.
+            pub fn try_from_usize(source: usize) -> Result<Self, IndexOverflowError> {
+                try_index_from_usize(source).map(Self)
+            }
+        }
+
+        impl<IndexType: PrimInt> $OptionalGraphIndexType<IndexType> {
+            /// Converts the given `usize` into a valid index, or returns an error if it is not representable by `IndexType`.
+            ///
+            /// See [`try_from_usize`]($GraphIndexType::try_from_usize) of the non-optional index type for details.
+            pub fn try_from_usize(source: usize) -> Result<Self, IndexOverflowError> {
+                try_index_from_usize(source).map(Self)
+            }
+        }
+
         impl<IndexType: PrimInt + Hash> OptionalGraphIndex<$GraphIndexType<IndexType>>
             for $OptionalGraphIndexType<IndexType>
         {
@@ -125,8 +202,8 @@ macro_rules! impl_graph_index {
             }
         }
 
-        impl<IndexType: PrimInt + Hash> std::fmt::Debug for $OptionalGraphIndexType<IndexType> {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl<IndexType: PrimInt + Hash> core::fmt::Debug for $OptionalGraphIndexType<IndexType> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 if let Some(value) = self.as_usize() {
                     write!(f, "{}", value)
                 } else {
@@ -135,8 +212,8 @@ macro_rules! impl_graph_index {
             }
         }
 
-        impl<IndexType: PrimInt + Hash> std::fmt::Debug for $GraphIndexType<IndexType> {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl<IndexType: PrimInt + Hash> core::fmt::Debug for $GraphIndexType<IndexType> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 //debug_assert!(self.0 != IndexType::max_value());
                 write!(f, "{}", self.as_usize())
             }
@@ -145,9 +222,7 @@ macro_rules! impl_graph_index {
         impl<IndexType: PrimInt> From<Option<usize>> for $OptionalGraphIndexType<IndexType> {
             fn from(source: Option<usize>) -> Self {
                 if let Some(source) = source {
-                    let source = <IndexType as NumCast>::from(source).unwrap();
-                    debug_assert!(source != IndexType::max_value());
-                    Self(source)
+                    Self::try_from_usize(source).unwrap()
                 } else {
                     Self(IndexType::max_value())
                 }
@@ -156,17 +231,13 @@ macro_rules! impl_graph_index {
 
         impl<IndexType: PrimInt> From<usize> for $OptionalGraphIndexType<IndexType> {
             fn from(source: usize) -> Self {
-                let source = <IndexType as NumCast>::from(source).unwrap();
-                debug_assert!(source != IndexType::max_value());
-                Self(source)
+                Self::try_from_usize(source).unwrap()
             }
         }
 
         impl<IndexType: PrimInt> From<usize> for $GraphIndexType<IndexType> {
             fn from(source: usize) -> Self {
-                let source = <IndexType as NumCast>::from(source).unwrap();
-                debug_assert!(source != IndexType::max_value());
-                Self(source)
+                Self::try_from_usize(source).unwrap()
             }
         }
 
@@ -198,7 +269,7 @@ macro_rules! impl_graph_index {
             }
         }
 
-        impl<IndexType: PrimInt + Hash> std::ops::Add<usize>
+        impl<IndexType: PrimInt + Hash> core::ops::Add<usize>
             for $OptionalGraphIndexType<IndexType>
         {
             type Output = Self;
@@ -208,7 +279,7 @@ macro_rules! impl_graph_index {
             }
         }
 
-        impl<IndexType: PrimInt + Hash> std::ops::Add<usize> for $GraphIndexType<IndexType> {
+        impl<IndexType: PrimInt + Hash> core::ops::Add<usize> for $GraphIndexType<IndexType> {
             type Output = Self;
 
             fn add(self, rhs: usize) -> Self::Output {
@@ -216,7 +287,7 @@ macro_rules! impl_graph_index {
             }
         }
 
-        impl<IndexType: PrimInt + Hash> std::ops::Sub<usize>
+        impl<IndexType: PrimInt + Hash> core::ops::Sub<usize>
             for $OptionalGraphIndexType<IndexType>
         {
             type Output = Self;
@@ -226,7 +297,7 @@ macro_rules! impl_graph_index {
             }
         }
 
-        impl<IndexType: PrimInt + Hash> std::ops::Sub<usize> for $GraphIndexType<IndexType> {
+        impl<IndexType: PrimInt + Hash> core::ops::Sub<usize> for $GraphIndexType<IndexType> {
             type Output = Self;
 
             fn sub(self, rhs: usize) -> Self::Output {
@@ -271,7 +342,7 @@ impl<IndexType: PrimInt> From<EdgeIndex<IndexType>> for usize {
     }
 }*/
 
-impl<T, IndexType: PrimInt + Hash> std::ops::Index<NodeIndex<IndexType>> for Vec<T> {
+impl<T, IndexType: PrimInt + Hash> core::ops::Index<NodeIndex<IndexType>> for Vec<T> {
     type Output = T;
 
     fn index(&self, index: NodeIndex<IndexType>) -> &Self::Output {
@@ -279,7 +350,7 @@ impl<T, IndexType: PrimInt + Hash> std::ops::Index<NodeIndex<IndexType>> for Vec
     }
 }
 
-impl<T, IndexType: PrimInt + Hash> std::ops::Index<EdgeIndex<IndexType>> for Vec<T> {
+impl<T, IndexType: PrimInt + Hash> core::ops::Index<EdgeIndex<IndexType>> for Vec<T> {
     type Output = T;
 
     fn index(&self, index: EdgeIndex<IndexType>) -> &Self::Output {
@@ -287,13 +358,13 @@ impl<T, IndexType: PrimInt + Hash> std::ops::Index<EdgeIndex<IndexType>> for Vec
     }
 }
 
-impl<T, IndexType: PrimInt + Hash> std::ops::IndexMut<NodeIndex<IndexType>> for Vec<T> {
+impl<T, IndexType: PrimInt + Hash> core::ops::IndexMut<NodeIndex<IndexType>> for Vec<T> {
     fn index_mut(&mut self, index: NodeIndex<IndexType>) -> &mut Self::Output {
         &mut self[index.as_usize()]
     }
 }
 
-impl<T, IndexType: PrimInt + Hash> std::ops::IndexMut<EdgeIndex<IndexType>> for Vec<T> {
+impl<T, IndexType: PrimInt + Hash> core::ops::IndexMut<EdgeIndex<IndexType>> for Vec<T> {
     fn index_mut(&mut self, index: EdgeIndex<IndexType>) -> &mut Self::Output {
         &mut self[index.as_usize()]
     }
@@ -373,12 +444,60 @@ impl<IndexType: PrimInt> From<EdgeIndex<IndexType>> for IndexType {
 }*/
 
 /// An iterator over a consecutive sequence of graph indices.
+///
+/// The iterator can be reversed, so e.g. the node indices of a graph with consecutive indices can be iterated
+/// in descending order without collecting them first.
+/// It implements [Iterator::nth] and [DoubleEndedIterator::nth_back] in constant time,
+/// so [Iterator::step_by] is efficient as well.
 pub struct GraphIndices<IndexType, OptionalIndexType> {
     start: IndexType,
     end: IndexType,
     optional_index_type: PhantomData<OptionalIndexType>,
 }
 
+impl<
+        OptionalIndexType: OptionalGraphIndex<IndexType>,
+        IndexType: GraphIndex<OptionalIndexType>,
+    > GraphIndices<IndexType, OptionalIndexType>
+{
+    /// Returns an iterator over the indices in the given range.
+    pub fn range(range: core::ops::Range<IndexType>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end.max(range.start),
+            optional_index_type: Default::default(),
+        }
+    }
+
+    /// Returns an iterator over the indices from zero up to but excluding `end`.
+    pub fn up_to(end: usize) -> Self {
+        Self::from((0, end))
+    }
+
+    /// Returns true if the given index has not been returned yet from either end of this iterator.
+    pub fn contains(&self, index: IndexType) -> bool {
+        self.start <= index && index < self.end
+    }
+}
+
+impl<IndexType: Clone, OptionalIndexType> Clone for GraphIndices<IndexType, OptionalIndexType> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            optional_index_type: PhantomData,
+        }
+    }
+}
+
+impl<IndexType: core::fmt::Debug, OptionalIndexType> core::fmt::Debug
+    for GraphIndices<IndexType, OptionalIndexType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "GraphIndices({:?}..{:?})", self.start, self.end)
+    }
+}
+
 impl<
         RawType: ToPrimitive,
         OptionalIndexType: OptionalGraphIndex<IndexType>,
@@ -415,6 +534,24 @@ impl<
         let len = self.end.as_usize() - self.start.as_usize();
         (len, Some(len))
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.len() {
+            self.start = self.start + n;
+            self.next()
+        } else {
+            self.start = self.end;
+            None
+        }
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
 impl<
@@ -431,6 +568,16 @@ impl<
             None
         }
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.len() {
+            self.end = self.end - n;
+            self.next_back()
+        } else {
+            self.end = self.start;
+            None
+        }
+    }
 }
 
 impl<
@@ -439,3 +586,262 @@ impl<
     > ExactSizeIterator for GraphIndices<IndexType, OptionalIndexType>
 {
 }
+
+impl<
+        OptionalIndexType: OptionalGraphIndex<IndexType>,
+        IndexType: GraphIndex<OptionalIndexType>,
+    > core::iter::FusedIterator for GraphIndices<IndexType, OptionalIndexType>
+{
+}
+
+/// Hands out unique node and edge indices from multiple threads.
+///
+/// Indices are allocated consecutively by atomic counters, so the indices allocated by all threads together
+/// form the ranges `0..node_count()` and `0..edge_count()` (or start at the offsets given to [with_offsets](Self::with_offsets)).
+/// This allows e.g. parallel parsers to agree on the indices of the nodes and edges they create,
+/// before the graph is built from the collected data.
+///
+/// Allocation fails if the resulting amount of nodes or edges is not representable by `IndexType`, see [NodeIndex::try_from_usize].
+/// Then the `allocate_*` methods panic and the `try_allocate_*` methods return an error,
+/// and in both cases the counter stays unchanged, such that the allocator remains usable.
+///
+/// # Example
+///
+/// ```rust
+/// use traitgraph::index::AtomicIndexAllocator;
+///
+/// let allocator = AtomicIndexAllocator::<u32>::new();
+/// std::thread::scope(|scope| {
+///     for _ in 0..4 {
+///         scope.spawn(|| {
+///             allocator.allocate_node();
+///             allocator.allocate_edges(10);
+///         });
+///     }
+/// });
+///
+/// assert_eq!(allocator.node_count(), 4);
+/// assert_eq!(allocator.edge_count(), 40);
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+#[derive(Debug, Default)]
+pub struct AtomicIndexAllocator<IndexType = usize> {
+    node_count: core::sync::atomic::AtomicUsize,
+    edge_count: core::sync::atomic::AtomicUsize,
+    index_type: PhantomData<fn() -> IndexType>,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<IndexType: PrimInt + Hash> AtomicIndexAllocator<IndexType> {
+    /// Creates a new allocator whose first node and edge indices are zero.
+    pub fn new() -> Self {
+        Self::with_offsets(0, 0)
+    }
+
+    /// Creates a new allocator whose first node index is `node_count` and whose first edge index is `edge_count`.
+    ///
+    /// This is useful to allocate indices for nodes and edges that are added to an existing graph with consecutive indices.
+    pub fn with_offsets(node_count: usize, edge_count: usize) -> Self {
+        Self {
+            node_count: core::sync::atomic::AtomicUsize::new(node_count),
+            edge_count: core::sync::atomic::AtomicUsize::new(edge_count),
+            index_type: PhantomData,
+        }
+    }
+
+    /// Allocates a new unique node index.
+    pub fn allocate_node(&self) -> NodeIndex<IndexType> {
+        self.try_allocate_node().unwrap()
+    }
+
+    /// Allocates a new unique edge index.
+    pub fn allocate_edge(&self) -> EdgeIndex<IndexType> {
+        self.try_allocate_edge().unwrap()
+    }
+
+    /// Allocates `count` new unique node indices that are consecutive.
+    pub fn allocate_nodes(
+        &self,
+        count: usize,
+    ) -> GraphIndices<NodeIndex<IndexType>, OptionalNodeIndex<IndexType>> {
+        self.try_allocate_nodes(count).unwrap()
+    }
+
+    /// Allocates `count` new unique edge indices that are consecutive.
+    pub fn allocate_edges(
+        &self,
+        count: usize,
+    ) -> GraphIndices<EdgeIndex<IndexType>, OptionalEdgeIndex<IndexType>> {
+        self.try_allocate_edges(count).unwrap()
+    }
+
+    /// Allocates a new unique node index, or returns an error if the index type is exhausted.
+    pub fn try_allocate_node(&self) -> Result<NodeIndex<IndexType>, IndexOverflowError> {
+        advance_counter::<IndexType>(&self.node_count, 1).map(NodeIndex::from)
+    }
+
+    /// Allocates a new unique edge index, or returns an error if the index type is exhausted.
+    pub fn try_allocate_edge(&self) -> Result<EdgeIndex<IndexType>, IndexOverflowError> {
+        advance_counter::<IndexType>(&self.edge_count, 1).map(EdgeIndex::from)
+    }
+
+    /// Allocates `count` new unique node indices that are consecutive, or returns an error if the index type is exhausted.
+    pub fn try_allocate_nodes(
+        &self,
+        count: usize,
+    ) -> Result<GraphIndices<NodeIndex<IndexType>, OptionalNodeIndex<IndexType>>, IndexOverflowError>
+    {
+        advance_counter::<IndexType>(&self.node_count, count)
+            .map(|start| GraphIndices::from((start, start + count)))
+    }
+
+    /// Allocates `count` new unique edge indices that are consecutive, or returns an error if the index type is exhausted.
+    pub fn try_allocate_edges(
+        &self,
+        count: usize,
+    ) -> Result<GraphIndices<EdgeIndex<IndexType>, OptionalEdgeIndex<IndexType>>, IndexOverflowError>
+    {
+        advance_counter::<IndexType>(&self.edge_count, count)
+            .map(|start| GraphIndices::from((start, start + count)))
+    }
+
+    /// Returns the amount of node indices allocated so far, including the offset given on construction.
+    pub fn node_count(&self) -> usize {
+        self.node_count.load(core::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Returns the amount of edge indices allocated so far, including the offset given on construction.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count.load(core::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+/// Advances `counter` by `count` and returns its previous value, if the advanced value is representable by `IndexType`.
+/// Otherwise, the counter is left unchanged and an error is returned.
+#[cfg(target_has_atomic = "ptr")]
+fn advance_counter<IndexType: PrimInt>(
+    counter: &core::sync::atomic::AtomicUsize,
+    count: usize,
+) -> Result<usize, IndexOverflowError> {
+    counter
+        .fetch_update(
+            core::sync::atomic::Ordering::Relaxed,
+            core::sync::atomic::Ordering::Relaxed,
+            |start| {
+                let end = start.checked_add(count)?;
+                try_index_from_usize::<IndexType>(end).ok().map(|_| end)
+            },
+        )
+        .map_err(|start| {
+            // If the addition overflows, then `usize::MAX` is not representable either, since it is reserved in all index types.
+            try_index_from_usize::<IndexType>(start.saturating_add(count))
+                .map(|_| ())
+                .unwrap_err()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AtomicIndexAllocator, EdgeIndex, GraphIndex, GraphIndices, IndexOverflowError, NodeIndex,
+        OptionalGraphIndex, OptionalNodeIndex,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_checked_conversions() {
+        debug_assert_eq!(
+            NodeIndex::<u8>::try_from_usize(254).unwrap().as_usize(),
+            254
+        );
+        debug_assert_eq!(
+            NodeIndex::<u8>::try_from_usize(255),
+            Err(IndexOverflowError {
+                index: 255,
+                max_index: 254
+            })
+        );
+        debug_assert!(EdgeIndex::<u16>::try_from_usize(70_000).is_err());
+        debug_assert!(OptionalNodeIndex::<u16>::try_from_usize(65_534)
+            .unwrap()
+            .is_some());
+        debug_assert!(OptionalNodeIndex::<u16>::try_from_usize(65_535).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unchecked_conversion_overflow() {
+        let _ = NodeIndex::<u16>::from(65_535);
+    }
+
+    #[test]
+    fn test_graph_indices() {
+        type Indices = GraphIndices<NodeIndex<u32>, OptionalNodeIndex<u32>>;
+        let as_usize = |indices: &mut dyn Iterator<Item = NodeIndex<u32>>| {
+            indices.map(|index| index.as_usize()).collect::<Vec<_>>()
+        };
+
+        debug_assert_eq!(as_usize(&mut Indices::up_to(4).rev()), [3, 2, 1, 0]);
+        debug_assert_eq!(
+            as_usize(&mut Indices::range(2.into()..9.into()).step_by(3)),
+            [2, 5, 8]
+        );
+        debug_assert_eq!(
+            as_usize(&mut Indices::up_to(10).rev().step_by(4)),
+            [9, 5, 1]
+        );
+        debug_assert_eq!(Indices::range(5.into()..3.into()).len(), 0);
+
+        let mut indices = Indices::up_to(6);
+        debug_assert_eq!(indices.nth(2), Some(2.into()));
+        debug_assert_eq!(indices.nth_back(1), Some(4.into()));
+        debug_assert_eq!(indices.len(), 1);
+        debug_assert!(indices.contains(3.into()));
+        debug_assert!(!indices.contains(4.into()));
+        debug_assert_eq!(indices.clone().last(), Some(3.into()));
+        debug_assert_eq!(indices.nth(1), None);
+        debug_assert_eq!(indices.next(), None);
+    }
+
+    #[test]
+    fn test_atomic_index_allocator() {
+        let allocator = AtomicIndexAllocator::<u8>::with_offsets(10, 0);
+        debug_assert_eq!(allocator.allocate_node(), NodeIndex::from(10));
+        debug_assert_eq!(
+            allocator.allocate_nodes(3).collect::<Vec<_>>(),
+            [11.into(), 12.into(), 13.into()]
+        );
+        debug_assert_eq!(allocator.allocate_edges(0).len(), 0);
+        debug_assert_eq!(allocator.allocate_edge(), EdgeIndex::from(0));
+        debug_assert_eq!(allocator.node_count(), 14);
+        debug_assert_eq!(allocator.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_atomic_index_allocator_exhaustion() {
+        let allocator = AtomicIndexAllocator::<u8>::with_offsets(250, 0);
+        debug_assert_eq!(
+            allocator.try_allocate_nodes(10).unwrap_err(),
+            IndexOverflowError {
+                index: 260,
+                max_index: 254
+            }
+        );
+        debug_assert_eq!(allocator.node_count(), 250);
+        debug_assert_eq!(allocator.try_allocate_nodes(4).unwrap().len(), 4);
+        debug_assert!(allocator.try_allocate_node().is_err());
+        debug_assert_eq!(allocator.node_count(), 254);
+        debug_assert_eq!(allocator.try_allocate_edge(), Ok(EdgeIndex::from(0)));
+
+        let allocator = AtomicIndexAllocator::<usize>::with_offsets(0, usize::MAX - 1);
+        debug_assert!(allocator.try_allocate_edges(2).is_err());
+        debug_assert_eq!(allocator.edge_count(), usize::MAX - 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_atomic_index_allocator_overflow() {
+        let allocator = AtomicIndexAllocator::<u8>::with_offsets(250, 0);
+        allocator.allocate_nodes(10);
+    }
+}
@@ -9,7 +9,8 @@
 
 use crate::index::{GraphIndex, OptionalGraphIndex};
 use crate::walks::{EdgeWalk, NodeWalk};
-use std::iter::FromIterator;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
 
 /// A set of traits for subgraphs.
 /// A subgraph is a graph that is backed by an actual graph implementation, but that filters out some nodes or edges.
@@ -43,7 +44,9 @@ pub trait GraphBase {
 
 /// A container that contains a set of nodes and edges.
 ///
-/// Graphs that implement this trait must have their nodes and edges indexed consecutively.
+/// Graphs that implement this trait should have their nodes and edges indexed consecutively.
+/// Graphs that do not, like subgraphs or graphs with stable indices, must override
+/// [`node_index_bound`](Self::node_index_bound) and [`edge_index_bound`](Self::edge_index_bound).
 pub trait ImmutableGraphContainer: GraphBase {
     /// An iterator type over the node indices in this graph.
     type NodeIndices<'a>: Iterator<Item = Self::NodeIndex>
@@ -61,6 +64,13 @@ pub trait ImmutableGraphContainer: GraphBase {
     /// The iterator is independent of the lifetime of self, and hence allows concurrent modifications during iteration.
     /// Note that any modification to the graph is not reflected in the iterator after construction.
     type EdgeIndicesCopied: Iterator<Item = Self::EdgeIndex>;
+    /// An iterator type over the edges in this graph, as returned by [`edge_references`](Self::edge_references).
+    /// Graphs that cannot iterate their edges more efficiently than by their indices use [EdgeReferences].
+    type EdgeReferences<'a>: Iterator<
+        Item = (Self::EdgeIndex, Edge<Self::NodeIndex>, &'a Self::EdgeData),
+    >
+    where
+        Self: 'a;
 
     /// Returns an iterator over the node indices in this graph.
     fn node_indices(&self) -> Self::NodeIndices<'_>;
@@ -90,6 +100,33 @@ pub trait ImmutableGraphContainer: GraphBase {
     /// Returns the amount of edges in this graph.
     fn edge_count(&self) -> usize;
 
+    /// Returns an upper bound for the node indices in this graph, i.e. all node indices are smaller than the returned value.
+    ///
+    /// This is the length of vectors that are indexed by node indices.
+    /// If the nodes are indexed consecutively, then this is the amount of nodes.
+    fn node_index_bound(&self) -> usize {
+        self.node_count()
+    }
+
+    /// Returns an upper bound for the edge indices in this graph, i.e. all edge indices are smaller than the returned value.
+    ///
+    /// This is the length of vectors that are indexed by edge indices.
+    /// If the edges are indexed consecutively, then this is the amount of edges.
+    fn edge_index_bound(&self) -> usize {
+        self.edge_count()
+    }
+
+    /// Returns a counter that changes whenever nodes or edges are added to or removed from this graph,
+    /// or `None` if this graph does not track its mutations.
+    ///
+    /// Data that refers to the indices of a graph without borrowing it, like a
+    /// [SubgraphMembership](crate::implementation::subgraphs::subgraph_membership::SubgraphMembership),
+    /// can record the generation to detect in debug builds that it is used with a graph that was mutated in the meantime.
+    /// Mutating node or edge data does not change the generation.
+    fn generation(&self) -> Option<u64> {
+        None
+    }
+
     /// Returns a reference to the node data associated with the given node id, or None if there is no such node.
     fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData;
 
@@ -116,6 +153,83 @@ pub trait ImmutableGraphContainer: GraphBase {
         }
         true
     }
+
+    /// Returns true if the given edge is a self-loop, i.e. its tail and head are the same node.
+    fn is_self_loop(&self, edge_id: Self::EdgeIndex) -> bool {
+        let Edge { from_node, to_node } = self.edge_endpoints(edge_id);
+        from_node == to_node
+    }
+
+    /// Returns an iterator over the self-loops in this graph, in the order of [`edge_indices`](Self::edge_indices).
+    fn self_loops(&self) -> SelfLoops<'_, Self>
+    where
+        Self: Sized,
+    {
+        SelfLoops {
+            graph: self,
+            edge_indices: self.edge_indices(),
+        }
+    }
+
+    /// Returns an iterator over the edges in this graph, yielding for each edge its index, its endpoints and a reference to its data.
+    ///
+    /// The edges are returned in the order of [`edge_indices`](Self::edge_indices).
+    fn edge_references(&self) -> Self::EdgeReferences<'_>;
+}
+
+/// An iterator over the self-loops of a graph, as returned by [`self_loops`](ImmutableGraphContainer::self_loops).
+pub struct SelfLoops<'a, Graph: ImmutableGraphContainer + 'a> {
+    graph: &'a Graph,
+    edge_indices: Graph::EdgeIndices<'a>,
+}
+
+impl<Graph: ImmutableGraphContainer> Iterator for SelfLoops<'_, Graph> {
+    type Item = Graph::EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let graph = self.graph;
+        self.edge_indices
+            .find(|&edge_id| graph.is_self_loop(edge_id))
+    }
+}
+
+/// An iterator over the edges of a graph that looks up the endpoints and data of each of its [`edge_indices`](ImmutableGraphContainer::edge_indices).
+///
+/// This is the [`edge_references`](ImmutableGraphContainer::edge_references) iterator of graphs without a more efficient one.
+pub struct EdgeReferences<'a, Graph: ImmutableGraphContainer + 'a> {
+    graph: &'a Graph,
+    edge_indices: Graph::EdgeIndices<'a>,
+}
+
+impl<'a, Graph: ImmutableGraphContainer> EdgeReferences<'a, Graph> {
+    /// Creates an iterator over the edges of the given graph.
+    pub fn new(graph: &'a Graph) -> Self {
+        Self {
+            graph,
+            edge_indices: graph.edge_indices(),
+        }
+    }
+}
+
+impl<'a, Graph: ImmutableGraphContainer> Iterator for EdgeReferences<'a, Graph> {
+    type Item = (
+        Graph::EdgeIndex,
+        Edge<Graph::NodeIndex>,
+        &'a Graph::EdgeData,
+    );
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let edge_id = self.edge_indices.next()?;
+        Some((
+            edge_id,
+            self.graph.edge_endpoints(edge_id),
+            self.graph.edge_data(edge_id),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edge_indices.size_hint()
+    }
 }
 
 /// A container that allows adding and removing nodes and edges.
@@ -137,6 +251,88 @@ pub trait MutableGraphContainer: ImmutableGraphContainer {
         edge_data: Self::EdgeData,
     ) -> Self::EdgeIndex;
 
+    /// Reserves capacity for at least `additional` more nodes.
+    ///
+    /// The default implementation does nothing.
+    fn reserve_nodes(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Reserves capacity for at least `additional` more edges.
+    ///
+    /// The default implementation does nothing.
+    fn reserve_edges(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Reserves capacity for at least `nodes` more nodes and `edges` more edges.
+    fn reserve(&mut self, nodes: usize, edges: usize) {
+        self.reserve_nodes(nodes);
+        self.reserve_edges(edges);
+    }
+
+    /// Shrinks the capacity of the graph as much as possible.
+    ///
+    /// The default implementation does nothing.
+    fn shrink_to_fit(&mut self) {}
+
+    /// Adds new nodes with the given `NodeData` to the graph, in the order given by the iterator.
+    /// Returns the indices of the new nodes in the same order.
+    ///
+    /// Capacity is reserved for the lower bound of the size hint of the iterator before adding the nodes.
+    /// If the indices of the new nodes are not needed, [`extend_nodes`](Self::extend_nodes) avoids collecting them.
+    fn add_nodes(
+        &mut self,
+        node_data: impl IntoIterator<Item = Self::NodeData>,
+    ) -> Vec<Self::NodeIndex> {
+        let node_data = node_data.into_iter();
+        self.reserve_nodes(node_data.size_hint().0);
+        node_data
+            .map(|node_data| self.add_node(node_data))
+            .collect()
+    }
+
+    /// Adds new edges given as triples `(from, to, edge_data)` to the graph, in the order given by the iterator.
+    /// Returns the indices of the new edges in the same order.
+    ///
+    /// Capacity is reserved for the lower bound of the size hint of the iterator before adding the edges.
+    /// If the indices of the new edges are not needed, [`extend_edges`](Self::extend_edges) avoids collecting them.
+    fn add_edges(
+        &mut self,
+        edges: impl IntoIterator<Item = (Self::NodeIndex, Self::NodeIndex, Self::EdgeData)>,
+    ) -> Vec<Self::EdgeIndex> {
+        let edges = edges.into_iter();
+        self.reserve_edges(edges.size_hint().0);
+        edges
+            .map(|(from, to, edge_data)| self.add_edge(from, to, edge_data))
+            .collect()
+    }
+
+    /// Adds new nodes with the given `NodeData` to the graph, in the order given by the iterator, without returning their indices.
+    ///
+    /// Capacity is reserved for the lower bound of the size hint of the iterator before adding the nodes.
+    fn extend_nodes(&mut self, node_data: impl IntoIterator<Item = Self::NodeData>) {
+        let node_data = node_data.into_iter();
+        self.reserve_nodes(node_data.size_hint().0);
+        node_data.for_each(|node_data| {
+            self.add_node(node_data);
+        });
+    }
+
+    /// Adds new edges given as triples `(from, to, edge_data)` to the graph, in the order given by the iterator, without returning their indices.
+    ///
+    /// Capacity is reserved for the lower bound of the size hint of the iterator before adding the edges.
+    fn extend_edges(
+        &mut self,
+        edges: impl IntoIterator<Item = (Self::NodeIndex, Self::NodeIndex, Self::EdgeData)>,
+    ) {
+        let edges = edges.into_iter();
+        self.reserve_edges(edges.size_hint().0);
+        edges.for_each(|(from, to, edge_data)| {
+            self.add_edge(from, to, edge_data);
+        });
+    }
+
     /// Removes the node with the given id from the graph.
     /// Note that this may change the ids of existing nodes.
     fn remove_node(&mut self, node_id: Self::NodeIndex) -> Option<Self::NodeData>;
@@ -165,6 +361,34 @@ pub trait MutableGraphContainer: ImmutableGraphContainer {
     /// Note that this may change the ids of existing edges.
     fn remove_edges_sorted(&mut self, edge_ids: &[Self::EdgeIndex]);
 
+    /// Removes all nodes for which `predicate` returns false, together with their incident edges.
+    /// The predicate is evaluated for all nodes before any node is removed, and receives the unmodified graph.
+    ///
+    /// Note that this may change the ids of existing nodes and edges.
+    fn retain_nodes(&mut self, mut predicate: impl FnMut(&Self, Self::NodeIndex) -> bool) {
+        let graph: &Self = self;
+        let mut removed_node_ids: Vec<_> = graph
+            .node_indices()
+            .filter(|&node_id| !predicate(graph, node_id))
+            .collect();
+        removed_node_ids.sort_unstable();
+        self.remove_nodes_sorted_slice(&removed_node_ids);
+    }
+
+    /// Removes all edges for which `predicate` returns false.
+    /// The predicate is evaluated for all edges before any edge is removed, and receives the unmodified graph.
+    ///
+    /// Note that this may change the ids of existing edges.
+    fn retain_edges(&mut self, mut predicate: impl FnMut(&Self, Self::EdgeIndex) -> bool) {
+        let graph: &Self = self;
+        let mut removed_edge_ids: Vec<_> = graph
+            .edge_indices()
+            .filter(|&edge_id| !predicate(graph, edge_id))
+            .collect();
+        removed_edge_ids.sort_unstable();
+        self.remove_edges_sorted(&removed_edge_ids);
+    }
+
     /// Removes all nodes and edges from the graph.
     fn clear(&mut self);
 }
@@ -245,6 +469,272 @@ pub trait NavigableGraph: ImmutableGraphContainer + Sized {
     fn is_join_node(&self, node_id: Self::NodeIndex) -> bool {
         self.in_degree(node_id) > 1
     }
+
+    /// Returns true if the graph contains two edges with the same tail and the same head.
+    fn has_parallel_edges(&self) -> bool {
+        let mut neighbors = Vec::new();
+        self.node_indices().any(|node_id| {
+            neighbors.clear();
+            neighbors.extend(self.out_neighbors(node_id).map(|neighbor| neighbor.node_id));
+            neighbors.sort_unstable();
+            neighbors.windows(2).any(|window| window[0] == window[1])
+        })
+    }
+
+    /// Returns all groups of at least two edges with the same tail and the same head.
+    ///
+    /// The groups are ordered by the position of their tail in [`node_indices`](ImmutableGraphContainer::node_indices)
+    /// and then by their head, and the edges in each group are sorted by their index.
+    fn parallel_edge_groups(&self) -> Vec<Vec<Self::EdgeIndex>> {
+        let mut groups = Vec::new();
+        let mut neighbors = Vec::new();
+        for node_id in self.node_indices() {
+            neighbors.clear();
+            neighbors.extend(
+                self.out_neighbors(node_id)
+                    .map(|neighbor| (neighbor.node_id, neighbor.edge_id)),
+            );
+            neighbors.sort_unstable();
+            let mut group_start = 0;
+            for index in 1..=neighbors.len() {
+                if index == neighbors.len() || neighbors[index].0 != neighbors[group_start].0 {
+                    if index - group_start > 1 {
+                        groups.push(
+                            neighbors[group_start..index]
+                                .iter()
+                                .map(|&(_, edge_id)| edge_id)
+                                .collect(),
+                        );
+                    }
+                    group_start = index;
+                }
+            }
+        }
+        groups
+    }
+
+    /// Returns an iterator over the neighbors of the given node when ignoring the direction of the edges.
+    ///
+    /// The outgoing neighbors are returned first, followed by the incoming neighbors.
+    /// Each incident edge is returned exactly once, so self-loops, which are both outgoing and incoming, are only returned among the outgoing neighbors.
+    /// This is the same convention as in [`UndirectedGraph::neighbors`].
+    fn undirected_neighbors(&self, node_id: Self::NodeIndex) -> UndirectedNeighbors<'_, Self> {
+        UndirectedNeighbors {
+            node_id,
+            out_neighbors: self.out_neighbors(node_id),
+            in_neighbors: self.in_neighbors(node_id),
+        }
+    }
+
+    /// Returns the amount of edges incident to the given node when ignoring the direction of the edges.
+    /// Self-loops are counted once, consistent with [`undirected_neighbors`](Self::undirected_neighbors).
+    fn undirected_degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.out_degree(node_id) + self.in_degree(node_id)
+            - self.edge_count_between(node_id, node_id)
+    }
+
+    /// Returns an iterator over the edges `(a, b)` followed by the edges `(b, a)`.
+    /// If `a == b`, then each self-loop is returned only once.
+    fn undirected_edges_between(
+        &self,
+        a: Self::NodeIndex,
+        b: Self::NodeIndex,
+    ) -> UndirectedEdgesBetween<'_, Self> {
+        UndirectedEdgesBetween {
+            forward: self.edges_between(a, b),
+            backward: (a != b).then(|| self.edges_between(b, a)),
+        }
+    }
+
+    /// Returns a walker over the outgoing neighbors of the given node that gives mutable access to the data of the traversed edges.
+    ///
+    /// The default implementation collects the neighbors before walking them.
+    fn out_neighbors_mut(&mut self, node_id: Self::NodeIndex) -> NeighborsMut<'_, Self>
+    where
+        Self: MutableGraphContainer,
+    {
+        let neighbors = self.out_neighbors(node_id).collect();
+        NeighborsMut::from_collected(self, neighbors)
+    }
+
+    /// Returns a walker over the incoming neighbors of the given node that gives mutable access to the data of the traversed edges.
+    ///
+    /// The default implementation collects the neighbors before walking them.
+    fn in_neighbors_mut(&mut self, node_id: Self::NodeIndex) -> NeighborsMut<'_, Self>
+    where
+        Self: MutableGraphContainer,
+    {
+        let neighbors = self.in_neighbors(node_id).collect();
+        NeighborsMut::from_collected(self, neighbors)
+    }
+}
+
+/// An iterator over the neighbors of a node when ignoring the direction of the edges,
+/// as returned by [`undirected_neighbors`](NavigableGraph::undirected_neighbors).
+pub struct UndirectedNeighbors<'a, Graph: NavigableGraph + 'a> {
+    node_id: Graph::NodeIndex,
+    out_neighbors: Graph::OutNeighbors<'a>,
+    in_neighbors: Graph::InNeighbors<'a>,
+}
+
+impl<Graph: NavigableGraph> Iterator for UndirectedNeighbors<'_, Graph> {
+    type Item = GraphNeighbor<Graph>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(neighbor) = self.out_neighbors.next() {
+            return Some(neighbor);
+        }
+
+        // Self-loops were already returned as outgoing neighbors.
+        let node_id = self.node_id;
+        self.in_neighbors
+            .find(|neighbor| neighbor.node_id != node_id)
+    }
+}
+
+/// An iterator over the edges between two nodes in both directions,
+/// as returned by [`undirected_edges_between`](NavigableGraph::undirected_edges_between).
+pub struct UndirectedEdgesBetween<'a, Graph: NavigableGraph + 'a> {
+    forward: Graph::EdgesBetween<'a>,
+    backward: Option<Graph::EdgesBetween<'a>>,
+}
+
+impl<Graph: NavigableGraph> Iterator for UndirectedEdgesBetween<'_, Graph> {
+    type Item = Graph::EdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.forward
+            .next()
+            .or_else(|| self.backward.as_mut()?.next())
+    }
+}
+
+/// A walker over the neighbors of a node that gives mutable access to the data of the traversed edges.
+///
+/// This is not an [Iterator], because the returned edge data borrows from the walker.
+/// The walker holds the graph mutably, so the graph cannot be modified while walking other than through the returned edge data.
+pub struct NeighborsMut<'a, Graph: GraphBase> {
+    graph: &'a mut Graph,
+    neighbors: NeighborsMutState<Graph>,
+}
+
+type GraphNeighbor<Graph> =
+    Neighbor<<Graph as GraphBase>::NodeIndex, <Graph as GraphBase>::EdgeIndex>;
+
+/// A function that returns the neighbor reached via the given edge, and the edge following it in an adjacency list.
+pub type AdjacencyListStep<Graph> = fn(
+    &Graph,
+    <Graph as GraphBase>::EdgeIndex,
+) -> (
+    GraphNeighbor<Graph>,
+    <Graph as GraphBase>::OptionalEdgeIndex,
+);
+
+enum NeighborsMutState<Graph: GraphBase> {
+    Collected(alloc::vec::IntoIter<GraphNeighbor<Graph>>),
+    AdjacencyList {
+        next_edge: Graph::OptionalEdgeIndex,
+        step: AdjacencyListStep<Graph>,
+    },
+}
+
+impl<'a, Graph: GraphBase> NeighborsMut<'a, Graph> {
+    /// Creates a new walker over the given neighbors.
+    pub fn from_collected(graph: &'a mut Graph, neighbors: Vec<GraphNeighbor<Graph>>) -> Self {
+        Self {
+            graph,
+            neighbors: NeighborsMutState::Collected(neighbors.into_iter()),
+        }
+    }
+
+    /// Creates a new walker over an adjacency list that is stored as a linked list of edges.
+    /// The walk starts at `first_edge`, and `step` returns the neighbor reached via an edge together with the next edge in the list.
+    ///
+    /// This allows to walk the neighbors without collecting them first.
+    pub fn from_adjacency_list(
+        graph: &'a mut Graph,
+        first_edge: Graph::OptionalEdgeIndex,
+        step: AdjacencyListStep<Graph>,
+    ) -> Self {
+        Self {
+            graph,
+            neighbors: NeighborsMutState::AdjacencyList {
+                next_edge: first_edge,
+                step,
+            },
+        }
+    }
+}
+
+impl<Graph: MutableGraphContainer> NeighborsMut<'_, Graph> {
+    /// Returns the next neighbor together with a mutable reference to the data of the edge used to reach it.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(GraphNeighbor<Graph>, &mut Graph::EdgeData)> {
+        let neighbor = match &mut self.neighbors {
+            NeighborsMutState::Collected(neighbors) => neighbors.next()?,
+            NeighborsMutState::AdjacencyList { next_edge, step } => {
+                let (neighbor, following_edge) = step(self.graph, (*next_edge).into()?);
+                *next_edge = following_edge;
+                neighbor
+            }
+        };
+        let edge_data = self.graph.edge_data_mut(neighbor.edge_id);
+        Some((neighbor, edge_data))
+    }
+
+    /// Calls the given function for each remaining neighbor together with a mutable reference to the data of the edge used to reach it.
+    pub fn for_each(mut self, mut f: impl FnMut(GraphNeighbor<Graph>, &mut Graph::EdgeData)) {
+        while let Some((neighbor, edge_data)) = self.next() {
+            f(neighbor, edge_data);
+        }
+    }
+}
+
+/// A graph whose edges have no direction.
+///
+/// Instead of outgoing and incoming neighbors, each node has a single set of neighbors, reachable via its incident edges.
+/// The [Edge] returned by [`edge_endpoints`](ImmutableGraphContainer::edge_endpoints) still has a `from_node` and a `to_node`,
+/// but their order carries no meaning.
+///
+/// Each incident edge of a node is reported exactly once by [`neighbors`](Self::neighbors).
+/// Hence, a self-loop is reported once and contributes one to the degree of its node,
+/// which is the same convention as in [`NavigableGraph::undirected_neighbors`] and [`NavigableGraph::undirected_degree`].
+pub trait UndirectedGraph: ImmutableGraphContainer + Sized {
+    /// The iterator type used to iterate over the neighbors of a node.
+    type Neighbors<'a>: Iterator<Item = Neighbor<Self::NodeIndex, Self::EdgeIndex>>
+    where
+        Self: 'a;
+
+    /// Returns an iterator over the neighbors of the given node.
+    fn neighbors(&self, node_id: Self::NodeIndex) -> Self::Neighbors<'_>;
+
+    /// Returns the amount of edges incident to a node, counting self-loops once.
+    fn degree(&self, node_id: Self::NodeIndex) -> usize {
+        self.neighbors(node_id).count()
+    }
+
+    /// Returns true if the graph contains an edge between `a` and `b`.
+    fn is_adjacent(&self, a: Self::NodeIndex, b: Self::NodeIndex) -> bool {
+        self.neighbors(a).any(|neighbor| neighbor.node_id == b)
+    }
+
+    /// Returns the endpoint of the given edge that is not `node_id`.
+    /// If the edge is a self-loop, then `node_id` is returned.
+    ///
+    /// `node_id` must be an endpoint of the edge.
+    fn opposite_endpoint(
+        &self,
+        edge_id: Self::EdgeIndex,
+        node_id: Self::NodeIndex,
+    ) -> Self::NodeIndex {
+        let Edge { from_node, to_node } = self.edge_endpoints(edge_id);
+        debug_assert!(from_node == node_id || to_node == node_id);
+        if from_node == node_id {
+            to_node
+        } else {
+            from_node
+        }
+    }
 }
 
 /// A helper trait to get the correct walk type from a graph.
@@ -330,3 +820,167 @@ pub enum NodeOrEdge<NodeIndex, EdgeIndex> {
     /// An edge index.
     Edge(EdgeIndex),
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::index::GraphIndex;
+    use crate::interface::{Edge, ImmutableGraphContainer, MutableGraphContainer, NavigableGraph};
+
+    #[test]
+    fn test_neighbors_mut() {
+        let mut graph = PetGraph::new();
+        let n0 = graph.add_node(());
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let e0 = graph.add_edge(n0, n1, 0);
+        let e1 = graph.add_edge(n0, n2, 1);
+        let e2 = graph.add_edge(n1, n2, 2);
+
+        let mut neighbors = graph.out_neighbors_mut(n0);
+        while let Some((neighbor, edge_data)) = neighbors.next() {
+            *edge_data += 10 * neighbor.node_id.as_usize();
+        }
+        graph
+            .in_neighbors_mut(n2)
+            .for_each(|_, edge_data| *edge_data += 100);
+
+        debug_assert_eq!(*graph.edge_data(e0), 10);
+        debug_assert_eq!(*graph.edge_data(e1), 121);
+        debug_assert_eq!(*graph.edge_data(e2), 102);
+
+        // The default implementation collects the neighbors.
+        let mut graph = crate::implementation::stable_graph::StableGraph::new();
+        let n0 = graph.add_node(());
+        let n1 = graph.add_node(());
+        let e0 = graph.add_edge(n0, n0, 0);
+        let e1 = graph.add_edge(n0, n1, 1);
+        graph.out_neighbors_mut(n0).for_each(|neighbor, edge_data| {
+            *edge_data += 10 * neighbor.node_id.as_usize();
+        });
+        graph
+            .in_neighbors_mut(n0)
+            .for_each(|_, edge_data| *edge_data += 100);
+
+        debug_assert_eq!(*graph.edge_data(e0), 100);
+        debug_assert_eq!(*graph.edge_data(e1), 11);
+    }
+
+    #[test]
+    fn test_batch_insertion() {
+        let mut graph = crate::implementation::slab_graph::SlabGraph::new();
+        graph.extend_nodes(0..4);
+        graph.remove_node(1.into());
+        debug_assert_eq!(graph.add_nodes([10, 11]), [1.into(), 4.into()]);
+        debug_assert_eq!(graph.node_count(), 5);
+        debug_assert_eq!(*graph.node_data(1.into()), 10);
+
+        let edges = graph.add_edges((0..4).map(|i| (i.into(), ((i + 1) % 5).into(), i)));
+        debug_assert_eq!(edges.len(), 4);
+        debug_assert_eq!(graph.edge_count(), 4);
+        debug_assert!(graph.contains_edge_between(3.into(), 4.into()));
+        debug_assert_eq!(graph.edge_endpoints(edges[3]).to_node, 4.into());
+
+        let mut graph = PetGraph::new();
+        graph.reserve_nodes(100);
+        let nodes = graph.add_nodes(core::iter::repeat(()).take(100));
+        debug_assert_eq!(nodes, graph.node_indices().collect::<Vec<_>>());
+        let edges = graph.add_edges((1..100).map(|i| (0.into(), i.into(), i)));
+        debug_assert_eq!(edges, graph.edge_indices().collect::<Vec<_>>());
+        debug_assert_eq!(graph.out_degree(0.into()), 99);
+
+        graph.extend_nodes([(), ()]);
+        graph.extend_edges([(100.into(), 101.into(), 100)]);
+        debug_assert_eq!(graph.node_count(), 102);
+        debug_assert_eq!(graph.edge_count(), 100);
+        debug_assert!(graph.contains_edge_between(100.into(), 101.into()));
+    }
+
+    #[test]
+    fn test_edge_references() {
+        let mut graph = PetGraph::new();
+        let n0 = graph.add_node(());
+        let n1 = graph.add_node(());
+        let e0 = graph.add_edge(n0, n1, 'a');
+        let e1 = graph.add_edge(n1, n1, 'b');
+
+        debug_assert_eq!(
+            graph.edge_references().collect::<Vec<_>>(),
+            [
+                (
+                    e0,
+                    Edge {
+                        from_node: n0,
+                        to_node: n1
+                    },
+                    &'a'
+                ),
+                (
+                    e1,
+                    Edge {
+                        from_node: n1,
+                        to_node: n1
+                    },
+                    &'b'
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_undirected_neighborhood() {
+        let mut graph = PetGraph::new();
+        let n0 = graph.add_node(());
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let e0 = graph.add_edge(n0, n1, ());
+        let e1 = graph.add_edge(n1, n1, ());
+        let e2 = graph.add_edge(n2, n1, ());
+        let e3 = graph.add_edge(n1, n0, ());
+
+        let mut neighbors: Vec<_> = graph
+            .undirected_neighbors(n1)
+            .map(|neighbor| neighbor.edge_id)
+            .collect();
+        neighbors.sort_unstable();
+        debug_assert_eq!(neighbors, [e0, e1, e2, e3]);
+        debug_assert_eq!(graph.undirected_degree(n1), 4);
+        debug_assert_eq!(graph.undirected_degree(n0), 2);
+
+        let mut between: Vec<_> = graph.undirected_edges_between(n1, n0).collect();
+        between.sort_unstable();
+        debug_assert_eq!(between, [e0, e3]);
+        debug_assert_eq!(
+            graph.undirected_edges_between(n1, n1).collect::<Vec<_>>(),
+            [e1]
+        );
+        debug_assert_eq!(graph.undirected_edges_between(n0, n2).count(), 0);
+    }
+
+    #[test]
+    fn test_self_loops_and_parallel_edges() {
+        let mut graph = PetGraph::new();
+        let n0 = graph.add_node(());
+        let n1 = graph.add_node(());
+        let e0 = graph.add_edge(n0, n1, ());
+        let e1 = graph.add_edge(n1, n1, ());
+        debug_assert!(!graph.is_self_loop(e0));
+        debug_assert!(graph.is_self_loop(e1));
+        debug_assert!(!graph.has_parallel_edges());
+        debug_assert!(graph.parallel_edge_groups().is_empty());
+
+        let e2 = graph.add_edge(n1, n0, ());
+        let e3 = graph.add_edge(n1, n1, ());
+        let e4 = graph.add_edge(n0, n1, ());
+        let e5 = graph.add_edge(n0, n1, ());
+        debug_assert_eq!(graph.self_loops().collect::<Vec<_>>(), [e1, e3]);
+        debug_assert!(graph.has_parallel_edges());
+        debug_assert_eq!(
+            graph.parallel_edge_groups(),
+            [vec![e0, e4, e5], vec![e1, e3]]
+        );
+        graph.remove_edge(e3);
+        debug_assert_eq!(graph.parallel_edge_groups().len(), 1);
+        debug_assert!(!graph.is_self_loop(e2));
+    }
+}
@@ -1,6 +1,25 @@
-use crate::interface::GraphBase;
+use crate::conversion::{extract_subgraph, GraphIndexMapping};
+use crate::interface::{GraphBase, ImmutableGraphContainer, MutableGraphContainer};
 
 /// A type that represents a subgraph of another graph.
+///
+/// Subgraphs hold a shared reference to their parent graph, so the root graph cannot be mutated while any subgraph of it exists.
+/// This guarantees statically that the indices and index bounds a subgraph was created with stay valid.
+/// Data detached from a subgraph, like a [SubgraphMembership](crate::implementation::subgraphs::subgraph_membership::SubgraphMembership),
+/// is instead checked at runtime against the [generation](ImmutableGraphContainer::generation) of the root graph.
+///
+/// ```rust,compile_fail
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+/// use traitgraph::interface::subgraph::MutableSubgraph;
+/// use traitgraph::interface::MutableGraphContainer;
+///
+/// let mut graph = PetGraph::<_, ()>::new();
+/// let node = graph.add_node(0);
+/// let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+/// graph.add_node(1); // Does not compile, as the subgraph borrows the graph.
+/// subgraph.enable_node(node);
+/// ```
 pub trait SubgraphBase: GraphBase {
     /// The root graph of this subgraph, which is either its parent or the root of a DAG of subgraphs.
     type RootGraph: GraphBase<
@@ -14,11 +33,65 @@ pub trait SubgraphBase: GraphBase {
 
     /// Returns a reference to the root graph of this subgraph.
     fn root(&self) -> &Self::RootGraph;
+
+    /// Copies the nodes and edges of this subgraph together with their data into a new owned graph.
+    ///
+    /// Returns the copy together with the mapping from the indices of this subgraph to the indices of the copy,
+    /// and the mapping in the opposite direction.
+    /// See [extract_subgraph] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use traitgraph::implementation::petgraph_impl::PetGraph;
+    /// use traitgraph::implementation::subgraphs::bit_vector_subgraph::BitVectorSubgraph;
+    /// use traitgraph::interface::subgraph::{MutableSubgraph, SubgraphBase};
+    /// use traitgraph::interface::{ImmutableGraphContainer, MutableGraphContainer};
+    ///
+    /// let mut graph = PetGraph::new();
+    /// let a = graph.add_node('a');
+    /// let b = graph.add_node('b');
+    /// let e = graph.add_edge(a, b, 3);
+    ///
+    /// let mut subgraph = BitVectorSubgraph::new_empty(&graph);
+    /// subgraph.enable_node(b);
+    ///
+    /// let (owned, forward, backward): (PetGraph<_, _>, _, _) = subgraph.to_owned_graph();
+    /// assert_eq!(owned.node_count(), 1);
+    /// assert_eq!(owned.edge_count(), 0);
+    /// assert_eq!(*owned.node_data(forward.node(b).unwrap()), 'b');
+    /// assert_eq!(backward.node(0.into()), Some(b));
+    /// assert_eq!(forward.edge(e), None);
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn to_owned_graph<
+        Target: MutableGraphContainer<NodeData = Self::NodeData, EdgeData = Self::EdgeData> + Default,
+    >(
+        &self,
+    ) -> (
+        Target,
+        GraphIndexMapping<Self, Target>,
+        GraphIndexMapping<Target, Self>,
+    )
+    where
+        Self: ImmutableGraphContainer + Sized,
+        Self::NodeData: Clone,
+        Self::EdgeData: Clone,
+    {
+        extract_subgraph(self)
+    }
 }
 
 /// A type that represents a mutable subgraph, to which nodes and edges existing in the parent graph can be added,
 /// and nodes and edges can be removed.
 pub trait MutableSubgraph: SubgraphBase {
+    /// True if the edges of this subgraph are induced by its nodes, i.e. an edge is present if and only if both of its endpoints are.
+    /// Such subgraphs only allow to enable and disable nodes,
+    /// and [enable_edge](Self::enable_edge) and [disable_edge](Self::disable_edge) panic.
+    const INDUCED: bool = false;
+
     /// Removes all nodes and edges from the subgraph.
     fn clear(&mut self);
 
@@ -54,6 +127,24 @@ pub trait MutableSubgraph: SubgraphBase {
     );
 }
 
+/// Set relations between subgraphs of the same root graph.
+///
+/// The relations take both nodes and edges into account.
+/// Implementations compare their internal representations directly where possible,
+/// e.g. word by word for bitvectors, instead of testing each node and edge individually.
+pub trait SubgraphRelations: SubgraphBase {
+    /// Returns true if all nodes and edges of this subgraph are also contained in the other subgraph.
+    fn is_subset_of(&self, other: &Self) -> bool;
+
+    /// Returns true if all nodes and edges of the other subgraph are also contained in this subgraph.
+    fn is_superset_of(&self, other: &Self) -> bool {
+        other.is_subset_of(self)
+    }
+
+    /// Returns true if this subgraph and the other subgraph have no node and no edge in common.
+    fn is_disjoint_from(&self, other: &Self) -> bool;
+}
+
 /// A subgraph type for which an empty instance can be constructed using an instance of the root graph type.
 pub trait EmptyConstructibleSubgraph<'a>: SubgraphBase {
     /// Construct an empty subgraph instance for the given root graph.
@@ -1,12 +1,34 @@
 #![warn(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 //! This crate offers traits for graph datastructures as well as implementations of these traits.
+//!
+//! The crate supports `#![no_std]` environments with `alloc` when the default feature `std` is disabled.
+
+extern crate alloc;
 
 pub use traitsequence;
 
+#[macro_use]
+mod delegate;
+
+/// Functions that copy graphs, possibly transforming them on the way.
+pub mod conversion;
+/// Human-readable formatting of graphs for debugging.
+pub mod format;
 /// Different implementations of the graph traits.
 pub mod implementation;
 /// Traits and a default implementation for graph indices.
 pub mod index;
 pub mod interface;
+/// Parallel iterators over the indices and edges of graphs.
+#[cfg(feature = "rayon")]
+pub mod parallel;
+/// Containers that associate values with the nodes or edges of a graph.
+pub mod property_map;
+/// Strategies generating random graphs for property-based tests with `proptest`.
+#[cfg(feature = "testing")]
+pub mod testing;
+/// Checks that graph implementations satisfy the contracts of the graph traits.
+pub mod verification;
 /// Traits and implementations of node- and edge-centric walks.
 pub mod walks;
@@ -0,0 +1,238 @@
+use crate::interface::{Edge, ImmutableGraphContainer};
+use core::marker::PhantomData;
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+/// Parallel iterators over the indices and edges of graphs whose nodes and edges are indexed consecutively.
+///
+/// This is implemented for all graphs that are [Sync].
+/// The iterators cover the indices from zero up to the amount of nodes or edges,
+/// so they panic on construction if the graph is not indexed consecutively, like graphs with stable indices after removals.
+/// Such graphs can be compacted first with [compact](crate::conversion::compact).
+///
+/// # Example
+///
+/// ```rust
+/// use rayon::iter::ParallelIterator;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::{MutableGraphContainer, NavigableGraph};
+/// use traitgraph::parallel::ParallelGraph;
+///
+/// let mut graph = PetGraph::new();
+/// graph.extend_nodes(0..1000);
+/// graph.extend_edges((1..1000).map(|i| ((i - 1).into(), i.into(), ())));
+///
+/// let max_degree = graph
+///     .par_node_indices()
+///     .map(|node_id| graph.out_degree(node_id) + graph.in_degree(node_id))
+///     .max();
+/// assert_eq!(max_degree, Some(2));
+/// ```
+pub trait ParallelGraph: ImmutableGraphContainer + Sync {
+    /// Returns a parallel iterator over the node indices in this graph.
+    ///
+    /// Panics if the nodes are not indexed consecutively.
+    fn par_node_indices(&self) -> ParGraphIndices<Self::NodeIndex> {
+        assert_eq!(
+            self.node_index_bound(),
+            self.node_count(),
+            "the nodes are not indexed consecutively"
+        );
+        ParGraphIndices::new(self.node_count())
+    }
+
+    /// Returns a parallel iterator over the edge indices in this graph.
+    ///
+    /// Panics if the edges are not indexed consecutively.
+    fn par_edge_indices(&self) -> ParGraphIndices<Self::EdgeIndex> {
+        assert_eq!(
+            self.edge_index_bound(),
+            self.edge_count(),
+            "the edges are not indexed consecutively"
+        );
+        ParGraphIndices::new(self.edge_count())
+    }
+
+    /// Returns a parallel iterator over the edges in this graph, yielding for each edge its index, its endpoints and a reference to its data,
+    /// like [`edge_references`](ImmutableGraphContainer::edge_references).
+    ///
+    /// Panics if the edges are not indexed consecutively.
+    fn par_edge_references(&self) -> ParEdgeReferences<'_, Self>
+    where
+        Self: Sized,
+    {
+        ParEdgeReferences {
+            graph: self,
+            edge_indices: self.par_edge_indices(),
+        }
+    }
+}
+
+impl<Graph: ImmutableGraphContainer + Sync> ParallelGraph for Graph {}
+
+/// A parallel iterator over the consecutive graph indices from zero up to some bound,
+/// as returned by [`par_node_indices`](ParallelGraph::par_node_indices) and [`par_edge_indices`](ParallelGraph::par_edge_indices).
+pub struct ParGraphIndices<IndexType> {
+    len: usize,
+    index_type: PhantomData<fn() -> IndexType>,
+}
+
+impl<IndexType> ParGraphIndices<IndexType> {
+    fn new(len: usize) -> Self {
+        Self {
+            len,
+            index_type: PhantomData,
+        }
+    }
+}
+
+impl<IndexType: From<usize> + Send> ParallelIterator for ParGraphIndices<IndexType> {
+    type Item = IndexType;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        (0..self.len)
+            .into_par_iter()
+            .map(IndexType::from)
+            .drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<IndexType: From<usize> + Send> IndexedParallelIterator for ParGraphIndices<IndexType> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        (0..self.len)
+            .into_par_iter()
+            .map(IndexType::from)
+            .drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        (0..self.len)
+            .into_par_iter()
+            .map(IndexType::from)
+            .with_producer(callback)
+    }
+}
+
+/// A parallel iterator over the edges of a graph, as returned by [`par_edge_references`](ParallelGraph::par_edge_references).
+pub struct ParEdgeReferences<'a, Graph: ImmutableGraphContainer> {
+    graph: &'a Graph,
+    edge_indices: ParGraphIndices<Graph::EdgeIndex>,
+}
+
+impl<'a, Graph: ImmutableGraphContainer + Sync> ParEdgeReferences<'a, Graph>
+where
+    Graph::NodeIndex: Send,
+    Graph::EdgeIndex: Send,
+    Graph::EdgeData: Sync,
+{
+    fn into_map(
+        self,
+    ) -> impl IndexedParallelIterator<
+        Item = (
+            Graph::EdgeIndex,
+            Edge<Graph::NodeIndex>,
+            &'a Graph::EdgeData,
+        ),
+    > {
+        let graph = self.graph;
+        self.edge_indices.map(move |edge_id| {
+            (
+                edge_id,
+                graph.edge_endpoints(edge_id),
+                graph.edge_data(edge_id),
+            )
+        })
+    }
+}
+
+impl<'a, Graph: ImmutableGraphContainer + Sync> ParallelIterator for ParEdgeReferences<'a, Graph>
+where
+    Graph::NodeIndex: Send,
+    Graph::EdgeIndex: Send,
+    Graph::EdgeData: Sync,
+{
+    type Item = (
+        Graph::EdgeIndex,
+        Edge<Graph::NodeIndex>,
+        &'a Graph::EdgeData,
+    );
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.into_map().drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.edge_indices.len)
+    }
+}
+
+impl<Graph: ImmutableGraphContainer + Sync> IndexedParallelIterator for ParEdgeReferences<'_, Graph>
+where
+    Graph::NodeIndex: Send,
+    Graph::EdgeIndex: Send,
+    Graph::EdgeData: Sync,
+{
+    fn len(&self) -> usize {
+        self.edge_indices.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.into_map().drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.into_map().with_producer(callback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParallelGraph;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::stable_graph::StableGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer};
+    use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+    #[test]
+    fn test_parallel_iterators() {
+        let mut graph = PetGraph::new();
+        graph.extend_nodes(0..100);
+        graph.extend_edges((0..100).map(|i| (i.into(), ((i * 7) % 100).into(), i)));
+
+        debug_assert_eq!(
+            graph.par_node_indices().collect::<Vec<_>>(),
+            graph.node_indices().collect::<Vec<_>>()
+        );
+        debug_assert_eq!(graph.par_edge_indices().len(), 100);
+        debug_assert_eq!(
+            graph.par_edge_references().collect::<Vec<_>>(),
+            graph.edge_references().collect::<Vec<_>>()
+        );
+        debug_assert_eq!(
+            graph
+                .par_edge_references()
+                .filter(|(_, edge, _)| edge.from_node == edge.to_node)
+                .map(|(_, _, &data)| data)
+                .sum::<usize>(),
+            50
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the nodes are not indexed consecutively")]
+    fn test_non_consecutive_indices() {
+        let mut graph = StableGraph::<_, ()>::new();
+        let node = graph.add_node(0);
+        graph.add_node(1);
+        graph.remove_node(node);
+        graph.par_node_indices();
+    }
+}
@@ -0,0 +1,332 @@
+use crate::index::GraphIndex;
+use crate::interface::{GraphBase, ImmutableGraphContainer};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+macro_rules! dense_property_map {
+    ($(#[$attr:meta])* $Map:ident, $Index:ident, $index_bound:ident, $kind:literal) => {
+        $(#[$attr])*
+        pub struct $Map<Graph: GraphBase, T> {
+            data: Vec<T>,
+            graph: PhantomData<fn(&Graph)>,
+        }
+
+        impl<Graph: GraphBase, T> $Map<Graph, T> {
+            #[doc = concat!("Creates a map that associates a clone of `value` with each ", $kind, " index of the given graph.")]
+            pub fn new(graph: &Graph, value: T) -> Self
+            where
+                Graph: ImmutableGraphContainer,
+                T: Clone,
+            {
+                let mut data = Vec::new();
+                data.resize(graph.$index_bound(), value);
+                Self {
+                    data,
+                    graph: PhantomData,
+                }
+            }
+
+            #[doc = concat!("Creates a map that associates the result of `f` with each ", $kind, " index of the given graph.")]
+            pub fn from_fn(graph: &Graph, f: impl FnMut(Graph::$Index) -> T) -> Self
+            where
+                Graph: ImmutableGraphContainer,
+            {
+                Self {
+                    data: (0..graph.$index_bound()).map(Graph::$Index::from).map(f).collect(),
+                    graph: PhantomData,
+                }
+            }
+
+            #[doc = concat!("Returns a reference to the value associated with the given ", $kind, ", or `None` if the index is out of bounds.")]
+            pub fn get(&self, index: Graph::$Index) -> Option<&T> {
+                self.data.get(index.as_usize())
+            }
+
+            #[doc = concat!("Returns a mutable reference to the value associated with the given ", $kind, ", or `None` if the index is out of bounds.")]
+            pub fn get_mut(&mut self, index: Graph::$Index) -> Option<&mut T> {
+                self.data.get_mut(index.as_usize())
+            }
+
+            #[doc = concat!("Grows the map to cover all ", $kind, " indices of the given graph, associating a clone of `value` with the new indices.")]
+            ///
+            /// This is needed after adding elements to the graph.
+            pub fn grow(&mut self, graph: &Graph, value: T)
+            where
+                Graph: ImmutableGraphContainer,
+                T: Clone,
+            {
+                let index_bound = graph.$index_bound();
+                if index_bound > self.data.len() {
+                    self.data.resize(index_bound, value);
+                }
+            }
+
+            /// Returns the amount of indices covered by this map.
+            pub fn len(&self) -> usize {
+                self.data.len()
+            }
+
+            /// Returns true if this map covers no indices.
+            pub fn is_empty(&self) -> bool {
+                self.data.is_empty()
+            }
+
+            /// Returns an iterator over all indices covered by this map together with their values.
+            ///
+            /// If the graph is not indexed consecutively, then this includes indices that do not belong to the graph.
+            pub fn iter(&self) -> impl '_ + Iterator<Item = (Graph::$Index, &T)> {
+                self.data
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| (index.into(), value))
+            }
+
+            /// Returns an iterator over all indices covered by this map together with mutable references to their values.
+            ///
+            /// If the graph is not indexed consecutively, then this includes indices that do not belong to the graph.
+            pub fn iter_mut(&mut self) -> impl '_ + Iterator<Item = (Graph::$Index, &mut T)> {
+                self.data
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(index, value)| (index.into(), value))
+            }
+
+            /// Returns an iterator over all values in order of their indices.
+            pub fn values(&self) -> core::slice::Iter<'_, T> {
+                self.data.iter()
+            }
+
+            /// Returns the values as a vector indexed by the indices as `usize`.
+            pub fn into_vec(self) -> Vec<T> {
+                self.data
+            }
+        }
+
+        impl<Graph: GraphBase, T> Index<Graph::$Index> for $Map<Graph, T> {
+            type Output = T;
+
+            fn index(&self, index: Graph::$Index) -> &Self::Output {
+                &self.data[index.as_usize()]
+            }
+        }
+
+        impl<Graph: GraphBase, T> IndexMut<Graph::$Index> for $Map<Graph, T> {
+            fn index_mut(&mut self, index: Graph::$Index) -> &mut Self::Output {
+                &mut self.data[index.as_usize()]
+            }
+        }
+
+        impl<Graph: GraphBase, T: Clone> Clone for $Map<Graph, T> {
+            fn clone(&self) -> Self {
+                Self {
+                    data: self.data.clone(),
+                    graph: PhantomData,
+                }
+            }
+        }
+
+        impl<Graph: GraphBase, T: core::fmt::Debug> core::fmt::Debug for $Map<Graph, T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_list().entries(self.data.iter()).finish()
+            }
+        }
+    };
+}
+
+dense_property_map!(
+    /// A dense map from the node indices of a graph to values of type `T`.
+    ///
+    /// The values are stored in a vector with one entry per node index below the
+    /// [`node_index_bound`](ImmutableGraphContainer::node_index_bound) of the graph at construction.
+    /// The graph type parameter prevents passing a map of one graph to a function expecting a map of another graph type.
+    NodeMap,
+    NodeIndex,
+    node_index_bound,
+    "node"
+);
+
+dense_property_map!(
+    /// A dense map from the edge indices of a graph to values of type `T`.
+    ///
+    /// The values are stored in a vector with one entry per edge index below the
+    /// [`edge_index_bound`](ImmutableGraphContainer::edge_index_bound) of the graph at construction.
+    /// The graph type parameter prevents passing a map of one graph to a function expecting a map of another graph type.
+    EdgeMap,
+    EdgeIndex,
+    edge_index_bound,
+    "edge"
+);
+
+#[cfg(feature = "std")]
+macro_rules! sparse_property_map {
+    ($(#[$attr:meta])* $Map:ident, $Index:ident, $kind:literal) => {
+        $(#[$attr])*
+        pub struct $Map<Graph: GraphBase, T> {
+            data: std::collections::HashMap<Graph::$Index, T>,
+        }
+
+        impl<Graph: GraphBase, T> $Map<Graph, T> {
+            /// Creates an empty map.
+            pub fn new() -> Self {
+                Self {
+                    data: Default::default(),
+                }
+            }
+
+            #[doc = concat!("Associates the given value with the given ", $kind, ", returning the previously associated value if there was one.")]
+            pub fn insert(&mut self, index: Graph::$Index, value: T) -> Option<T> {
+                self.data.insert(index, value)
+            }
+
+            #[doc = concat!("Removes the value associated with the given ", $kind, " and returns it.")]
+            pub fn remove(&mut self, index: Graph::$Index) -> Option<T> {
+                self.data.remove(&index)
+            }
+
+            #[doc = concat!("Returns a reference to the value associated with the given ", $kind, ".")]
+            pub fn get(&self, index: Graph::$Index) -> Option<&T> {
+                self.data.get(&index)
+            }
+
+            #[doc = concat!("Returns a mutable reference to the value associated with the given ", $kind, ".")]
+            pub fn get_mut(&mut self, index: Graph::$Index) -> Option<&mut T> {
+                self.data.get_mut(&index)
+            }
+
+            #[doc = concat!("Returns true if a value is associated with the given ", $kind, ".")]
+            pub fn contains(&self, index: Graph::$Index) -> bool {
+                self.data.contains_key(&index)
+            }
+
+            #[doc = concat!("Returns a mutable reference to the value associated with the given ", $kind, ", inserting the result of `f` if there is none.")]
+            pub fn get_or_insert_with(&mut self, index: Graph::$Index, f: impl FnOnce() -> T) -> &mut T {
+                self.data.entry(index).or_insert_with(f)
+            }
+
+            /// Returns the amount of values in this map.
+            pub fn len(&self) -> usize {
+                self.data.len()
+            }
+
+            /// Returns true if this map contains no values.
+            pub fn is_empty(&self) -> bool {
+                self.data.is_empty()
+            }
+
+            /// Removes all values from this map.
+            pub fn clear(&mut self) {
+                self.data.clear();
+            }
+
+            /// Returns an iterator over all indices with an associated value together with their values, in arbitrary order.
+            pub fn iter(&self) -> impl '_ + Iterator<Item = (Graph::$Index, &T)> {
+                self.data.iter().map(|(&index, value)| (index, value))
+            }
+
+            /// Returns an iterator over all indices with an associated value together with mutable references to their values, in arbitrary order.
+            pub fn iter_mut(&mut self) -> impl '_ + Iterator<Item = (Graph::$Index, &mut T)> {
+                self.data.iter_mut().map(|(&index, value)| (index, value))
+            }
+        }
+
+        impl<Graph: GraphBase, T> Default for $Map<Graph, T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<Graph: GraphBase, T> Index<Graph::$Index> for $Map<Graph, T> {
+            type Output = T;
+
+            fn index(&self, index: Graph::$Index) -> &Self::Output {
+                &self.data[&index]
+            }
+        }
+
+        impl<Graph: GraphBase, T: Clone> Clone for $Map<Graph, T> {
+            fn clone(&self) -> Self {
+                Self {
+                    data: self.data.clone(),
+                }
+            }
+        }
+
+        impl<Graph: GraphBase, T: core::fmt::Debug> core::fmt::Debug for $Map<Graph, T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_map().entries(self.data.iter()).finish()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+sparse_property_map!(
+    /// A sparse map from the node indices of a graph to values of type `T`, backed by a [HashMap](std::collections::HashMap).
+    ///
+    /// In contrast to [NodeMap], this uses memory only for nodes with an associated value.
+    SparseNodeMap,
+    NodeIndex,
+    "node"
+);
+
+#[cfg(feature = "std")]
+sparse_property_map!(
+    /// A sparse map from the edge indices of a graph to values of type `T`, backed by a [HashMap](std::collections::HashMap).
+    ///
+    /// In contrast to [EdgeMap], this uses memory only for edges with an associated value.
+    SparseEdgeMap,
+    EdgeIndex,
+    "edge"
+);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{EdgeMap, NodeMap, SparseNodeMap};
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::interface::{ImmutableGraphContainer, MutableGraphContainer};
+
+    #[test]
+    fn test_dense_maps() {
+        let mut graph = PetGraph::new();
+        let n0 = graph.add_node('a');
+        let n1 = graph.add_node('b');
+        let e0 = graph.add_edge(n0, n1, ());
+
+        let mut visited = NodeMap::new(&graph, false);
+        visited[n1] = true;
+        debug_assert_eq!(visited.len(), 2);
+        debug_assert_eq!(visited.get(n0), Some(&false));
+        debug_assert_eq!(visited.iter().filter(|(_, &visited)| visited).count(), 1);
+
+        let n2 = graph.add_node('c');
+        debug_assert_eq!(visited.get(n2), None);
+        visited.grow(&graph, true);
+        debug_assert_eq!(visited.into_vec(), [false, true, true]);
+
+        let labels = NodeMap::from_fn(&graph, |node_id| *graph.node_data(node_id));
+        debug_assert_eq!(labels[n2], 'c');
+
+        let mut weights = EdgeMap::new(&graph, 0);
+        *weights.get_mut(e0).unwrap() += 5;
+        weights.iter_mut().for_each(|(_, weight)| *weight *= 2);
+        debug_assert_eq!(weights[e0], 10);
+    }
+
+    #[test]
+    fn test_sparse_map() {
+        let mut graph = PetGraph::<_, ()>::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+
+        let mut distances = SparseNodeMap::<PetGraph<i32, ()>, _>::new();
+        debug_assert!(distances.is_empty());
+        distances.insert(n[3], 7);
+        *distances.get_or_insert_with(n[1], || 0) += 2;
+        *distances.get_or_insert_with(n[3], || 0) += 1;
+        debug_assert_eq!(distances.len(), 2);
+        debug_assert_eq!(distances[n[3]], 8);
+        debug_assert!(!distances.contains(n[0]));
+        debug_assert_eq!(distances.remove(n[1]), Some(2));
+        debug_assert_eq!(distances.iter().collect::<Vec<_>>(), [(n[3], &8)]);
+    }
+}
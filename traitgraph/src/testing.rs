@@ -0,0 +1,259 @@
+use crate::implementation::petgraph_impl::{PetGraph, PetGraphIndexType};
+use crate::interface::MutableGraphContainer;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::RangeInclusive;
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection::vec;
+use proptest::sample::Index;
+use proptest::strategy::{BoxedStrategy, Just, NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+/// A [Strategy] generating random graphs for property-based tests with [proptest].
+///
+/// The amount of nodes and edges are drawn from the configured ranges,
+/// and the endpoints of each edge are drawn uniformly from all nodes.
+/// The data of the nodes and edges is drawn from the strategies set with [with_node_data](Self::with_node_data)
+/// and [with_edge_data](Self::with_edge_data), which default to producing `()`.
+///
+/// When a property fails, proptest shrinks the graph by removing nodes and edges and by shrinking their data.
+/// For graphs with default settings, [PetGraph] also implements [Arbitrary].
+///
+/// # Example
+///
+/// ```rust
+/// use proptest::prelude::*;
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::ImmutableGraphContainer;
+/// use traitgraph::testing::GraphStrategy;
+///
+/// proptest!(|(graph in GraphStrategy::<PetGraph<i32, ()>>::new(5..=10, 0..=20)
+///     .with_node_data(0..100)
+///     .without_self_loops())| {
+///     prop_assert!((5..=10).contains(&graph.node_count()));
+///     prop_assert_eq!(graph.self_loops().count(), 0);
+/// });
+/// ```
+pub struct GraphStrategy<Graph, NodeStrategy = Just<()>, EdgeStrategy = Just<()>> {
+    node_counts: RangeInclusive<usize>,
+    edge_counts: RangeInclusive<usize>,
+    self_loops: bool,
+    node_data: NodeStrategy,
+    edge_data: EdgeStrategy,
+    graph_type: PhantomData<fn() -> Graph>,
+}
+
+impl<Graph> GraphStrategy<Graph> {
+    /// Creates a strategy for graphs with node and edge counts in the given ranges and `()` as node and edge data.
+    ///
+    /// Self-loops and parallel edges are allowed.
+    /// If a generated graph has no nodes, then it has no edges either.
+    ///
+    /// Panics if one of the ranges is empty.
+    pub fn new(node_counts: RangeInclusive<usize>, edge_counts: RangeInclusive<usize>) -> Self {
+        assert!(!node_counts.is_empty(), "the range of node counts is empty");
+        assert!(!edge_counts.is_empty(), "the range of edge counts is empty");
+        Self {
+            node_counts,
+            edge_counts,
+            self_loops: true,
+            node_data: Just(()),
+            edge_data: Just(()),
+            graph_type: PhantomData,
+        }
+    }
+}
+
+impl<Graph, NodeStrategy, EdgeStrategy> GraphStrategy<Graph, NodeStrategy, EdgeStrategy> {
+    /// Replaces the strategy of the node data.
+    pub fn with_node_data<NewNodeStrategy>(
+        self,
+        node_data: NewNodeStrategy,
+    ) -> GraphStrategy<Graph, NewNodeStrategy, EdgeStrategy> {
+        GraphStrategy {
+            node_counts: self.node_counts,
+            edge_counts: self.edge_counts,
+            self_loops: self.self_loops,
+            node_data,
+            edge_data: self.edge_data,
+            graph_type: PhantomData,
+        }
+    }
+
+    /// Replaces the strategy of the edge data.
+    pub fn with_edge_data<NewEdgeStrategy>(
+        self,
+        edge_data: NewEdgeStrategy,
+    ) -> GraphStrategy<Graph, NodeStrategy, NewEdgeStrategy> {
+        GraphStrategy {
+            node_counts: self.node_counts,
+            edge_counts: self.edge_counts,
+            self_loops: self.self_loops,
+            node_data: self.node_data,
+            edge_data,
+            graph_type: PhantomData,
+        }
+    }
+
+    /// Forbids self-loops in the generated graphs.
+    ///
+    /// Graphs with a single node then have no edges.
+    pub fn without_self_loops(mut self) -> Self {
+        self.self_loops = false;
+        self
+    }
+}
+
+impl<Graph, NodeStrategy, EdgeStrategy> GraphStrategy<Graph, NodeStrategy, EdgeStrategy>
+where
+    Graph: MutableGraphContainer<NodeData = NodeStrategy::Value, EdgeData = EdgeStrategy::Value>
+        + Default
+        + Debug
+        + 'static,
+    NodeStrategy: Strategy + Clone + 'static,
+    EdgeStrategy: Strategy + Clone + 'static,
+{
+    /// Combines the strategies of the nodes and edges into one strategy.
+    fn combined(&self) -> BoxedStrategy<Graph> {
+        let self_loops = self.self_loops;
+        // The endpoints are drawn as indices into the nodes that exist after shrinking, such that removing nodes keeps the edges valid.
+        let edges = vec(
+            (any::<Index>(), any::<Index>(), self.edge_data.clone()),
+            self.edge_counts.clone(),
+        );
+        (vec(self.node_data.clone(), self.node_counts.clone()), edges)
+            .prop_map(move |(node_data, edges)| {
+                let node_count = node_data.len();
+                let mut graph = Graph::default();
+                let node_ids = graph.add_nodes(node_data);
+                if node_count == 0 || (node_count == 1 && !self_loops) {
+                    return graph;
+                }
+
+                graph.extend_edges(edges.into_iter().map(|(from, to, edge_data)| {
+                    let from = from.index(node_count);
+                    let to = if self_loops {
+                        to.index(node_count)
+                    } else {
+                        // Skip over the tail to draw the head uniformly from all other nodes.
+                        let to = to.index(node_count - 1);
+                        to + usize::from(to >= from)
+                    };
+                    (node_ids[from], node_ids[to], edge_data)
+                }));
+                graph
+            })
+            .boxed()
+    }
+}
+
+impl<Graph, NodeStrategy, EdgeStrategy> Strategy
+    for GraphStrategy<Graph, NodeStrategy, EdgeStrategy>
+where
+    Graph: MutableGraphContainer<NodeData = NodeStrategy::Value, EdgeData = EdgeStrategy::Value>
+        + Default
+        + Debug
+        + 'static,
+    NodeStrategy: Strategy + Clone + 'static,
+    EdgeStrategy: Strategy + Clone + 'static,
+{
+    type Tree = Box<dyn ValueTree<Value = Graph>>;
+    type Value = Graph;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.combined().new_tree(runner)
+    }
+}
+
+impl<Graph, NodeStrategy: Debug, EdgeStrategy: Debug> Debug
+    for GraphStrategy<Graph, NodeStrategy, EdgeStrategy>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GraphStrategy")
+            .field("node_counts", &self.node_counts)
+            .field("edge_counts", &self.edge_counts)
+            .field("self_loops", &self.self_loops)
+            .field("node_data", &self.node_data)
+            .field("edge_data", &self.edge_data)
+            .finish()
+    }
+}
+
+/// Generates graphs with up to 10 nodes and up to 20 edges, including self-loops and parallel edges.
+impl<
+        NodeData: Arbitrary + 'static,
+        EdgeData: Arbitrary + 'static,
+        IndexType: PetGraphIndexType + 'static,
+    > Arbitrary for PetGraph<NodeData, EdgeData, IndexType>
+{
+    type Parameters = ();
+    type Strategy = GraphStrategy<Self, BoxedStrategy<NodeData>, BoxedStrategy<EdgeData>>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        GraphStrategy::new(0..=10, 0..=20)
+            .with_node_data(any::<NodeData>().boxed())
+            .with_edge_data(any::<EdgeData>().boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphStrategy;
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::interface::{ImmutableGraphContainer, NavigableGraph};
+    use proptest::prelude::*;
+    use proptest::test_runner::{TestError, TestRunner};
+
+    proptest! {
+        #[test]
+        fn test_generate(graph in GraphStrategy::<PetGraph<(), i32>>::new(1..=5, 0..=10)
+            .with_edge_data(0..3)
+            .without_self_loops()) {
+            prop_assert!((1..=5).contains(&graph.node_count()));
+            prop_assert!(graph.edge_count() <= 10);
+            prop_assert_eq!(graph.self_loops().count(), 0);
+            prop_assert!(graph
+                .edge_indices()
+                .all(|edge_id| (0..3).contains(graph.edge_data(edge_id))));
+        }
+
+        #[test]
+        fn test_arbitrary(graph in any::<PetGraph<u8, bool>>()) {
+            prop_assert!(graph.node_count() <= 10);
+            prop_assert!(graph.edge_count() <= 20);
+            prop_assert!(graph.do_all_edges_endpoints_exist());
+        }
+    }
+
+    #[test]
+    fn test_shrink() {
+        let has_cycle_of_length_two = |graph: &PetGraph<(), ()>| {
+            graph.node_indices().any(|node_id| {
+                graph.out_neighbors(node_id).any(|neighbor| {
+                    neighbor.node_id != node_id
+                        && graph.contains_edge_between(neighbor.node_id, node_id)
+                })
+            })
+        };
+
+        let result = TestRunner::deterministic().run(
+            &GraphStrategy::new(2..=20, 0..=60).without_self_loops(),
+            |graph| {
+                prop_assert!(!has_cycle_of_length_two(&graph));
+                Ok(())
+            },
+        );
+        let Err(TestError::Fail(_, minimal)) = result else {
+            panic!("expected a failing graph, but got {result:?}");
+        };
+        debug_assert_eq!(minimal.node_count(), 2);
+        debug_assert_eq!(minimal.edge_count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the range of edge counts is empty")]
+    fn test_empty_range() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let _ = GraphStrategy::<PetGraph<(), ()>>::new(0..=10, 5..=4);
+    }
+}
@@ -0,0 +1,459 @@
+use crate::index::GraphIndex;
+use crate::interface::{Edge, GraphBase, ImmutableGraphContainer, NavigableGraph};
+use alloc::vec;
+use core::fmt::{Debug, Display, Formatter};
+
+/// A violation of the contracts of the graph traits, as found by [validate] or [validate_container].
+///
+/// The error is generic over the node and edge index types of the validated graph, such that it can report the offending nodes and edges.
+/// Use [GraphValidationError] to refer to the error type of a concrete graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError<NodeIndex, EdgeIndex> {
+    /// [`node_count`](ImmutableGraphContainer::node_count) differs from the amount of nodes returned by [`node_indices`](ImmutableGraphContainer::node_indices).
+    NodeCount {
+        /// The value returned by `node_count`.
+        node_count: usize,
+        /// The amount of nodes returned by `node_indices`.
+        node_indices: usize,
+    },
+
+    /// [`edge_count`](ImmutableGraphContainer::edge_count) differs from the amount of edges returned by [`edge_indices`](ImmutableGraphContainer::edge_indices).
+    EdgeCount {
+        /// The value returned by `edge_count`.
+        edge_count: usize,
+        /// The amount of edges returned by `edge_indices`.
+        edge_indices: usize,
+    },
+
+    /// A node index is not smaller than [`node_index_bound`](ImmutableGraphContainer::node_index_bound).
+    NodeIndexOutOfBounds {
+        /// The offending node.
+        node: NodeIndex,
+        /// The value returned by `node_index_bound`.
+        node_index_bound: usize,
+    },
+
+    /// An edge index is not smaller than [`edge_index_bound`](ImmutableGraphContainer::edge_index_bound).
+    EdgeIndexOutOfBounds {
+        /// The offending edge.
+        edge: EdgeIndex,
+        /// The value returned by `edge_index_bound`.
+        edge_index_bound: usize,
+    },
+
+    /// A node is returned more than once by [`node_indices`](ImmutableGraphContainer::node_indices).
+    DuplicateNode {
+        /// The duplicated node.
+        node: NodeIndex,
+    },
+
+    /// An edge is returned more than once by [`edge_indices`](ImmutableGraphContainer::edge_indices).
+    DuplicateEdge {
+        /// The duplicated edge.
+        edge: EdgeIndex,
+    },
+
+    /// A node returned by [`node_indices`](ImmutableGraphContainer::node_indices) is not reported by [`contains_node_index`](ImmutableGraphContainer::contains_node_index).
+    NodeNotContained {
+        /// The offending node.
+        node: NodeIndex,
+    },
+
+    /// An edge returned by [`edge_indices`](ImmutableGraphContainer::edge_indices) is not reported by [`contains_edge_index`](ImmutableGraphContainer::contains_edge_index).
+    EdgeNotContained {
+        /// The offending edge.
+        edge: EdgeIndex,
+    },
+
+    /// An endpoint of an edge is not a node of the graph.
+    DanglingEdge {
+        /// The offending edge.
+        edge: EdgeIndex,
+        /// The endpoint that is not a node of the graph.
+        node: NodeIndex,
+    },
+
+    /// The neighbor of a node reached via an edge does not match the endpoints of the edge.
+    InconsistentNeighbor {
+        /// The node whose neighbors were iterated.
+        node: NodeIndex,
+        /// The edge used to reach the neighbor.
+        edge: EdgeIndex,
+        /// True if the neighbor was returned by [`out_neighbors`](NavigableGraph::out_neighbors),
+        /// and false if it was returned by [`in_neighbors`](NavigableGraph::in_neighbors).
+        outgoing: bool,
+    },
+
+    /// An edge is not returned exactly once as outgoing neighbor of its tail or as incoming neighbor of its head.
+    MissingNeighbor {
+        /// The offending edge.
+        edge: EdgeIndex,
+        /// True if the edge is not returned exactly once by [`out_neighbors`](NavigableGraph::out_neighbors) of its tail,
+        /// and false if it is not returned exactly once by [`in_neighbors`](NavigableGraph::in_neighbors) of its head.
+        outgoing: bool,
+    },
+
+    /// The degree of a node differs from the amount of its neighbors.
+    Degree {
+        /// The offending node.
+        node: NodeIndex,
+        /// True if [`out_degree`](NavigableGraph::out_degree) is wrong,
+        /// and false if [`in_degree`](NavigableGraph::in_degree) is wrong.
+        outgoing: bool,
+    },
+}
+
+/// The [ValidationError] type for the given graph type.
+pub type GraphValidationError<Graph> =
+    ValidationError<<Graph as GraphBase>::NodeIndex, <Graph as GraphBase>::EdgeIndex>;
+
+fn direction(outgoing: bool) -> &'static str {
+    if outgoing {
+        "outgoing"
+    } else {
+        "incoming"
+    }
+}
+
+impl<NodeIndex: Debug, EdgeIndex: Debug> Display for ValidationError<NodeIndex, EdgeIndex> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ValidationError::NodeCount {
+                node_count,
+                node_indices,
+            } => write!(
+                f,
+                "the node count is {node_count}, but there are {node_indices} node indices"
+            ),
+            ValidationError::EdgeCount {
+                edge_count,
+                edge_indices,
+            } => write!(
+                f,
+                "the edge count is {edge_count}, but there are {edge_indices} edge indices"
+            ),
+            ValidationError::NodeIndexOutOfBounds {
+                node,
+                node_index_bound,
+            } => write!(
+                f,
+                "node {node:?} is out of the index bound {node_index_bound}"
+            ),
+            ValidationError::EdgeIndexOutOfBounds {
+                edge,
+                edge_index_bound,
+            } => write!(
+                f,
+                "edge {edge:?} is out of the index bound {edge_index_bound}"
+            ),
+            ValidationError::DuplicateNode { node } => write!(f, "node {node:?} is duplicated"),
+            ValidationError::DuplicateEdge { edge } => write!(f, "edge {edge:?} is duplicated"),
+            ValidationError::NodeNotContained { node } => {
+                write!(f, "node {node:?} is not contained in the graph")
+            }
+            ValidationError::EdgeNotContained { edge } => {
+                write!(f, "edge {edge:?} is not contained in the graph")
+            }
+            ValidationError::DanglingEdge { edge, node } => {
+                write!(f, "edge {edge:?} has endpoint {node:?} which is not a node")
+            }
+            ValidationError::InconsistentNeighbor {
+                node,
+                edge,
+                outgoing,
+            } => write!(
+                f,
+                "the {} neighbor of node {node:?} via edge {edge:?} does not match the edge's endpoints",
+                direction(*outgoing)
+            ),
+            ValidationError::MissingNeighbor { edge, outgoing } => write!(
+                f,
+                "edge {edge:?} is not listed exactly once among the {} neighbors of its endpoint",
+                direction(*outgoing)
+            ),
+            ValidationError::Degree { node, outgoing } => write!(
+                f,
+                "the {} degree of node {node:?} does not match its amount of neighbors",
+                direction(*outgoing)
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<NodeIndex: Debug, EdgeIndex: Debug> std::error::Error
+    for ValidationError<NodeIndex, EdgeIndex>
+{
+}
+
+/// Checks that the given graph satisfies the contracts of [ImmutableGraphContainer].
+///
+/// This checks that the node and edge indices are unique, contained in the graph, smaller than their index bounds and match the node and edge counts,
+/// and that the endpoints of all edges are nodes of the graph.
+/// Together, this implies that graphs that do not override the index bounds are indexed consecutively.
+///
+/// Returns the first violation found.
+pub fn validate_container<Graph: ImmutableGraphContainer>(
+    graph: &Graph,
+) -> Result<(), GraphValidationError<Graph>> {
+    let node_index_bound = graph.node_index_bound();
+    let mut is_node = vec![false; node_index_bound];
+    let mut node_indices = 0;
+    for node in graph.node_indices() {
+        node_indices += 1;
+        let is_node =
+            is_node
+                .get_mut(node.as_usize())
+                .ok_or(ValidationError::NodeIndexOutOfBounds {
+                    node,
+                    node_index_bound,
+                })?;
+        if *is_node {
+            return Err(ValidationError::DuplicateNode { node });
+        }
+        *is_node = true;
+        if !graph.contains_node_index(node) {
+            return Err(ValidationError::NodeNotContained { node });
+        }
+    }
+    if node_indices != graph.node_count() {
+        return Err(ValidationError::NodeCount {
+            node_count: graph.node_count(),
+            node_indices,
+        });
+    }
+
+    let edge_index_bound = graph.edge_index_bound();
+    let mut is_edge = vec![false; edge_index_bound];
+    let mut edge_indices = 0;
+    for edge in graph.edge_indices() {
+        edge_indices += 1;
+        let is_edge =
+            is_edge
+                .get_mut(edge.as_usize())
+                .ok_or(ValidationError::EdgeIndexOutOfBounds {
+                    edge,
+                    edge_index_bound,
+                })?;
+        if *is_edge {
+            return Err(ValidationError::DuplicateEdge { edge });
+        }
+        *is_edge = true;
+        if !graph.contains_edge_index(edge) {
+            return Err(ValidationError::EdgeNotContained { edge });
+        }
+
+        let Edge { from_node, to_node } = graph.edge_endpoints(edge);
+        for node in [from_node, to_node] {
+            if !is_node.get(node.as_usize()).copied().unwrap_or(false) {
+                return Err(ValidationError::DanglingEdge { edge, node });
+            }
+        }
+    }
+    if edge_indices != graph.edge_count() {
+        return Err(ValidationError::EdgeCount {
+            edge_count: graph.edge_count(),
+            edge_indices,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that the given graph satisfies the contracts of [ImmutableGraphContainer] and [NavigableGraph].
+///
+/// In addition to the checks of [validate_container], this checks that each edge is returned exactly once
+/// as outgoing neighbor of its tail and as incoming neighbor of its head, that all neighbors match the endpoints of the edges used to reach them,
+/// and that the degrees of all nodes match their amount of neighbors.
+///
+/// Returns the first violation found.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use traitgraph::implementation::petgraph_impl::PetGraph;
+/// use traitgraph::interface::MutableGraphContainer;
+/// use traitgraph::verification::validate;
+///
+/// let mut graph = PetGraph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// graph.add_edge(a, b, ());
+/// graph.add_edge(b, b, ());
+/// assert_eq!(validate(&graph), Ok(()));
+/// # }
+/// ```
+pub fn validate<Graph: NavigableGraph>(graph: &Graph) -> Result<(), GraphValidationError<Graph>> {
+    validate_container(graph)?;
+
+    for outgoing in [true, false] {
+        let mut occurrences = vec![0usize; graph.edge_index_bound()];
+        for node in graph.node_indices() {
+            let mut degree = 0;
+            let mut check_neighbor = |edge: Graph::EdgeIndex, neighbor: Graph::NodeIndex| {
+                degree += 1;
+                let Edge { from_node, to_node } = graph.edge_endpoints(edge);
+                let expected = if outgoing {
+                    (from_node, to_node)
+                } else {
+                    (to_node, from_node)
+                };
+                match occurrences.get_mut(edge.as_usize()) {
+                    Some(occurrences)
+                        if graph.contains_edge_index(edge) && expected == (node, neighbor) =>
+                    {
+                        *occurrences += 1;
+                        Ok(())
+                    }
+                    _ => Err(ValidationError::InconsistentNeighbor {
+                        node,
+                        edge,
+                        outgoing,
+                    }),
+                }
+            };
+
+            if outgoing {
+                for neighbor in graph.out_neighbors(node) {
+                    check_neighbor(neighbor.edge_id, neighbor.node_id)?;
+                }
+            } else {
+                for neighbor in graph.in_neighbors(node) {
+                    check_neighbor(neighbor.edge_id, neighbor.node_id)?;
+                }
+            }
+
+            let reported_degree = if outgoing {
+                graph.out_degree(node)
+            } else {
+                graph.in_degree(node)
+            };
+            if reported_degree != degree {
+                return Err(ValidationError::Degree { node, outgoing });
+            }
+        }
+
+        for edge in graph.edge_indices() {
+            if occurrences[edge.as_usize()] != 1 {
+                return Err(ValidationError::MissingNeighbor { edge, outgoing });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{validate, validate_container, ValidationError};
+    use crate::implementation::petgraph_impl::PetGraph;
+    use crate::implementation::slab_graph::SlabGraph;
+    use crate::implementation::stable_graph::StableGraph;
+    use crate::index::{EdgeIndex, GraphIndices, NodeIndex, OptionalEdgeIndex, OptionalNodeIndex};
+    use crate::interface::{
+        Edge, EdgeReferences, GraphBase, ImmutableGraphContainer, MutableGraphContainer,
+    };
+
+    #[test]
+    fn test_valid_graphs() {
+        let mut graph = StableGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[1], n[1], ());
+        graph.add_edge(n[2], n[3], ());
+        graph.add_edge(n[3], n[0], ());
+        graph.remove_node(n[2]);
+        debug_assert_eq!(validate(&graph), Ok(()));
+
+        let mut graph = SlabGraph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ());
+        graph.add_edge(n[0], n[1], ());
+        graph.remove_node(n[2]);
+        graph.add_node(7);
+        debug_assert_eq!(validate(&graph), Ok(()));
+    }
+
+    /// A graph that reports one edge more than it contains.
+    struct BrokenGraph(PetGraph<(), ()>);
+
+    impl GraphBase for BrokenGraph {
+        type NodeData = ();
+        type EdgeData = ();
+        type OptionalNodeIndex = OptionalNodeIndex<usize>;
+        type OptionalEdgeIndex = OptionalEdgeIndex<usize>;
+        type NodeIndex = NodeIndex<usize>;
+        type EdgeIndex = EdgeIndex<usize>;
+    }
+
+    impl ImmutableGraphContainer for BrokenGraph {
+        type NodeIndices<'a> = GraphIndices<Self::NodeIndex, Self::OptionalNodeIndex>;
+        type EdgeIndices<'a> = GraphIndices<Self::EdgeIndex, Self::OptionalEdgeIndex>;
+        type NodeIndicesCopied = Self::NodeIndices<'static>;
+        type EdgeIndicesCopied = Self::EdgeIndices<'static>;
+        type EdgeReferences<'a> = EdgeReferences<'a, Self>;
+
+        fn node_indices(&self) -> Self::NodeIndices<'_> {
+            self.0.node_indices()
+        }
+
+        fn edge_indices(&self) -> Self::EdgeIndices<'_> {
+            GraphIndices::from((0, self.edge_count()))
+        }
+
+        fn node_indices_copied(&self) -> Self::NodeIndicesCopied {
+            self.node_indices()
+        }
+
+        fn edge_indices_copied(&self) -> Self::EdgeIndicesCopied {
+            self.edge_indices()
+        }
+
+        fn contains_node_index(&self, node_id: Self::NodeIndex) -> bool {
+            self.0.contains_node_index(node_id)
+        }
+
+        fn contains_edge_index(&self, edge_id: Self::EdgeIndex) -> bool {
+            self.0.contains_edge_index(edge_id)
+        }
+
+        fn node_count(&self) -> usize {
+            self.0.node_count()
+        }
+
+        fn edge_count(&self) -> usize {
+            self.0.edge_count() + 1
+        }
+
+        fn node_data(&self, node_id: Self::NodeIndex) -> &Self::NodeData {
+            self.0.node_data(node_id)
+        }
+
+        fn edge_data(&self, edge_id: Self::EdgeIndex) -> &Self::EdgeData {
+            self.0.edge_data(edge_id)
+        }
+
+        fn edge_endpoints(&self, edge_id: Self::EdgeIndex) -> Edge<Self::NodeIndex> {
+            self.0.edge_endpoints(edge_id)
+        }
+
+        fn edge_references(&self) -> Self::EdgeReferences<'_> {
+            EdgeReferences::new(self)
+        }
+    }
+
+    #[test]
+    fn test_broken_graph() {
+        let mut graph = PetGraph::new();
+        graph.add_node(());
+        graph.add_node(());
+        graph.add_edge(0.into(), 1.into(), ());
+
+        let broken = BrokenGraph(graph);
+        let error = validate_container(&broken).unwrap_err();
+        debug_assert_eq!(error, ValidationError::EdgeNotContained { edge: 1.into() });
+        debug_assert_eq!(error.to_string(), "edge 1 is not contained in the graph");
+    }
+}
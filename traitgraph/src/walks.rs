@@ -1,4 +1,6 @@
 use crate::interface::{GraphBase, StaticGraph};
+use alloc::vec;
+use alloc::vec::Vec;
 use traitsequence::interface::Sequence;
 
 /// A sequence of nodes in a graph, where each consecutive pair of nodes is connected by an edge.
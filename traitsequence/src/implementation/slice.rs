@@ -2,7 +2,7 @@ use crate::interface::{CloneableSequence, Sequence, SequenceMut};
 
 impl<Item> Sequence<Item, [Item]> for [Item] {
     type Iterator<'a>
-        = std::slice::Iter<'a, Item>
+        = core::slice::Iter<'a, Item>
     where
         Item: 'a;
 
@@ -17,7 +17,7 @@ impl<Item> Sequence<Item, [Item]> for [Item] {
 
 impl<Item> SequenceMut<Item, [Item]> for [Item] {
     type IteratorMut<'a>
-        = std::slice::IterMut<'a, Item>
+        = core::slice::IterMut<'a, Item>
     where
         Item: 'a;
     fn iter_mut(&mut self) -> Self::IteratorMut<'_> {
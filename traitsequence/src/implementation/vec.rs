@@ -1,8 +1,9 @@
 use crate::interface::{CloneableSequence, EditableSequence, OwnedSequence, Sequence, SequenceMut};
+use alloc::vec::Vec;
 
 impl<Item> Sequence<Item, [Item]> for Vec<Item> {
     type Iterator<'a>
-        = std::slice::Iter<'a, Item>
+        = core::slice::Iter<'a, Item>
     where
         Item: 'a;
     fn iter(&self) -> Self::Iterator<'_> {
@@ -16,7 +17,7 @@ impl<Item> Sequence<Item, [Item]> for Vec<Item> {
 
 impl<Item> SequenceMut<Item, [Item]> for Vec<Item> {
     type IteratorMut<'a>
-        = std::slice::IterMut<'a, Item>
+        = core::slice::IterMut<'a, Item>
     where
         Item: 'a;
 
@@ -59,7 +60,7 @@ impl<Item> EditableSequence<Item, [Item]> for Vec<Item> {
 
     fn splice(
         &mut self,
-        range: std::ops::Range<usize>,
+        range: core::ops::Range<usize>,
         replace_with: impl IntoIterator<Item = Item>,
     ) {
         self.splice(range, replace_with);
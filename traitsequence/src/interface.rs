@@ -1,6 +1,8 @@
-use std::fmt::{Debug, Write};
-use std::iter::FromIterator;
-use std::ops::{Index, IndexMut, Range};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt::{Debug, Write};
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut, Range};
 
 /// A type behaving like a sequence over the type `Item`.
 pub trait Sequence<Item, Subsequence: Sequence<Item, Subsequence> + ?Sized>:
@@ -89,7 +91,7 @@ pub trait Sequence<Item, Subsequence: Sequence<Item, Subsequence> + ?Sized>:
     fn forward_merge_iter_assume_mergeable<'a>(
         &'a self,
         suffix: &'a Self,
-    ) -> std::iter::Chain<Self::Iterator<'a>, std::iter::Skip<Self::Iterator<'a>>>
+    ) -> core::iter::Chain<Self::Iterator<'a>, core::iter::Skip<Self::Iterator<'a>>>
     where
         Item: Eq,
     {
@@ -112,7 +114,7 @@ pub trait Sequence<Item, Subsequence: Sequence<Item, Subsequence> + ?Sized>:
     fn backward_merge_iter_assume_mergeable<'a>(
         &'a self,
         suffix: &'a Self,
-    ) -> std::iter::Chain<Self::Iterator<'a>, std::iter::Skip<Self::Iterator<'a>>>
+    ) -> core::iter::Chain<Self::Iterator<'a>, core::iter::Skip<Self::Iterator<'a>>>
     where
         Item: Eq,
     {
@@ -271,6 +273,8 @@ pub trait EditableSequence<Item, Subsequence: Sequence<Item, Subsequence> + ?Siz
 #[cfg(test)]
 mod tests {
     use crate::interface::Sequence;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn test_merge_sequences_simple() {
@@ -1,5 +1,10 @@
 #![warn(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 //! This crate offers traits to abstract over sequence implementations.
+//!
+//! The crate supports `#![no_std]` environments with `alloc` when the default feature `std` is disabled.
+
+extern crate alloc;
 
 /// Implementations of this trait for stdlib types.
 pub mod implementation;